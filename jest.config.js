@@ -52,6 +52,7 @@ module.exports = {
     '^tree-sitter-typescript$': '<rootDir>/__mocks__/tree-sitter-lang.js',
     '^tree-sitter-javascript$': '<rootDir>/__mocks__/tree-sitter-lang.js',
     '^tree-sitter-python$': '<rootDir>/__mocks__/tree-sitter-lang.js',
+    '^tree-sitter-rust$': '<rootDir>/__mocks__/tree-sitter-lang.js',
     '^@xenova/transformers$': '<rootDir>/__mocks__/@xenova/transformers.js',
   },
   // Add timeout and force exit to prevent hanging
@@ -32,6 +32,7 @@ module.exports = {
     '^tree-sitter-typescript$': '<rootDir>/__mocks__/tree-sitter-lang.js',
     '^tree-sitter-javascript$': '<rootDir>/__mocks__/tree-sitter-lang.js',
     '^tree-sitter-python$': '<rootDir>/__mocks__/tree-sitter-lang.js',
+    '^tree-sitter-rust$': '<rootDir>/__mocks__/tree-sitter-lang.js',
   },
   // Add timeout and force exit to prevent hanging
   testTimeout: 10000,
@@ -0,0 +1,25 @@
+/**
+ * Real file system for tests that read fixture projects from disk
+ * jest maps fs and fs/promises to __mocks__/fs.js by default; importing this module swaps node's
+ * own back in, so import it before testHelpers and the modules under test
+ */
+
+import { jest } from '@jest/globals';
+
+jest.mock('fs', () => jest.requireActual('node:fs'));
+jest.mock('fs/promises', () => jest.requireActual('node:fs/promises'));
+
+/**
+ * globby stand-in (the real one is ESM-only) listing every file under `cwd`, ignoring the patterns
+ * and ignore list, so the caller's own filtering decides what is kept.
+ * Use as `jest.mock('globby', () => walkingGlobby())`
+ */
+export function walkingGlobby() {
+  const fs = jest.requireActual<typeof import('fs')>('node:fs');
+  const walk = (dir: string, prefix: string): string[] =>
+    fs.readdirSync(dir, { withFileTypes: true }).flatMap(entry => {
+      const rel = prefix ? `${prefix}/${entry.name}` : entry.name;
+      return entry.isDirectory() ? walk(`${dir}/${entry.name}`, rel) : [rel];
+    });
+  return { globby: async (_patterns: string[], options: { cwd: string }) => walk(options.cwd, '') };
+}
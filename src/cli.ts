@@ -578,6 +578,7 @@ function showHelp(options: { expanded?: boolean } = {}): void {
   console.log('    --max-tokens <num>   Maximum tokens in output (default: 3000)');
   console.log('    --max-similar-chunks <num>  Max similar code chunks to include (default: 20)');
   console.log('    --exclude-patterns <patterns>  Patterns to exclude (e.g., "*.md,docs/**")');
+  console.log('    --crate <name>       Scope to one Cargo workspace member (Rust projects)');
   console.log('');
   console.log('  hints:');
   console.log('    --max-files <num>    Maximum files to analyze (default: 100)');
@@ -597,6 +598,7 @@ function showHelp(options: { expanded?: boolean } = {}): void {
   console.log('');
   console.log('  debug:');
   console.log('    --max-matches <num>  Maximum matches to return (default: 20)');
+  console.log('    --format cargo-json  Read a cargo/clippy --message-format=json file (or stdin)');
   console.log('');
   console.log('  grep:');
   console.log('    --language <lang>    Programming language (auto-detected if not provided)');
@@ -637,6 +639,7 @@ function showHelp(options: { expanded?: boolean } = {}): void {
   console.log(
     '  ambiance-mcp debug "TypeError: Cannot read property \'map\' of undefined" --max-matches 10'
   );
+  console.log('  ambiance-mcp debug --format cargo-json clippy.json');
   console.log('');
   console.log('  # AST-based structural code search');
   console.log(
@@ -744,6 +747,7 @@ async function executeToolCommand(
             'maxTokens',
             'maxSimilarChunks',
             'excludePatterns',
+            'crate',
           ]),
         });
         break;
@@ -785,7 +789,12 @@ async function executeToolCommand(
         break;
 
       case 'debug': {
-        const logText = toolArgs.find(arg => !arg.startsWith('--'));
+        // --format cargo-json takes a saved `--message-format=json` artifact (or stdin)
+        const cargoJson = globalOptions.format === 'cargo-json';
+        const input = toolArgs.find(arg => !arg.startsWith('--'));
+        const logText = cargoJson
+          ? require('fs').readFileSync(input && input !== '-' ? input : 0, 'utf8')
+          : input;
         if (!logText) {
           console.error('Error: logText is required for debug command');
           process.exit(1);
@@ -793,7 +802,8 @@ async function executeToolCommand(
         result = await handleLocalDebugContext({
           logText,
           projectPath: globalOptions.projectPath || detectProjectPath(),
-          format: globalOptions.format || 'structured',
+          format: cargoJson ? 'structured' : globalOptions.format || 'structured',
+          inputFormat: cargoJson ? 'cargo-json' : 'auto',
           ...parseToolSpecificArgs(toolArgs, ['maxMatches']),
         });
         break;
@@ -0,0 +1,271 @@
+/**
+ * @fileOverview: Unit tests for Rust symbol extraction in ASTParser
+ * @module: astParserRustTests
+ * @description: Drives parseRust with hand-built tree-sitter nodes (the grammar is mocked in
+ *               jest) to cover impl ownership, visibility, signatures, nested modules, doc
+ *               attachment and extern blocks
+ */
+
+import { describe, it, expect, beforeAll } from '@jest/globals';
+import { ASTParser, ParsedFile, Symbol } from '../astParser';
+
+const SOURCE = [
+  '//! Key-value storage.',
+  'use std::collections::HashMap;',
+  '',
+  '/// A key-value store.',
+  '#[derive(Debug,',
+  '         Clone)]',
+  'pub struct Store<K> where K: Eq {',
+  '    items: HashMap<K, String>,',
+  '}',
+  '',
+  'impl<K: Eq> Store<K> {',
+  '    /// Creates an empty store.',
+  '    pub(crate) fn new() -> Self { todo!() }',
+  '}',
+  '',
+  'impl<K> fmt::Display for Store<K> {',
+  '    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { todo!() }',
+  '}',
+  '',
+  'pub mod nested {',
+  '    pub(super) async fn helper(id: u64) {}',
+  '}',
+  '',
+  'extern "C" {',
+  '    fn ffi_call(x: i32) -> i32;',
+  '}',
+].join('\n');
+
+/**
+ * Minimal stand-in for a tree-sitter node covering the first occurrence of `text` in SOURCE
+ * at or after `after`
+ */
+function node(
+  type: string,
+  text: string,
+  options: { fields?: Record<string, any>; children?: any[]; after?: string } = {}
+): any {
+  const from = options.after ? SOURCE.indexOf(options.after) : 0;
+  const startIndex = SOURCE.indexOf(text, from);
+  if (startIndex === -1) throw new Error(`not in source: ${text}`);
+  const endIndex = startIndex + text.length;
+  const position = (index: number) => {
+    const before = SOURCE.slice(0, index).split('\n');
+    return { row: before.length - 1, column: before[before.length - 1].length };
+  };
+  const children = options.children || [];
+  return {
+    type,
+    text,
+    startIndex,
+    endIndex,
+    startPosition: position(startIndex),
+    endPosition: position(endIndex),
+    children,
+    namedChildren: children,
+    childForFieldName: (field: string) => options.fields?.[field] ?? null,
+  };
+}
+
+function buildTree(): any {
+  const structText = SOURCE.slice(SOURCE.indexOf('pub struct'), SOURCE.indexOf('}\n\nimpl') + 1);
+  const storeStruct = node('struct_item', structText, {
+    fields: {
+      name: node('type_identifier', 'Store', { after: 'pub struct' }),
+      type_parameters: node('type_parameters', '<K>', { after: 'pub struct' }),
+      body: node('field_declaration_list', '{\n    items', {
+        children: [
+          node('field_declaration', 'items: HashMap<K, String>', {
+            fields: { name: node('field_identifier', 'items') },
+          }),
+        ],
+      }),
+    },
+    children: [
+      node('visibility_modifier', 'pub', { after: 'pub struct' }),
+      node('where_clause', 'where K: Eq'),
+    ],
+  });
+
+  const newFn = node('function_item', 'pub(crate) fn new() -> Self { todo!() }', {
+    fields: {
+      name: node('identifier', 'new'),
+      parameters: node('parameters', '()', { after: 'fn new' }),
+      return_type: node('type_identifier', 'Self', { after: 'fn new' }),
+      body: node('block', '{ todo!() }', { after: 'fn new' }),
+    },
+    children: [node('visibility_modifier', 'pub(crate)')],
+  });
+  const inherentImpl = node('impl_item', 'impl<K: Eq> Store<K> {', {
+    fields: {
+      type: node('generic_type', 'Store<K>', { after: 'impl<K: Eq>' }),
+      body: node('declaration_list', '{\n    /// Creates', { children: [newFn] }),
+    },
+  });
+
+  const fmtText = 'fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { todo!() }';
+  const fmtFn = node('function_item', fmtText, {
+    fields: {
+      name: node('identifier', 'fmt', { after: 'fn fmt' }),
+      parameters: node('parameters', '(&self, f: &mut fmt::Formatter)', {
+        children: [
+          node('self_parameter', '&self'),
+          node('parameter', 'f: &mut fmt::Formatter', {
+            fields: {
+              pattern: node('identifier', 'f', { after: '&self, ' }),
+              type: node('reference_type', '&mut fmt::Formatter'),
+            },
+          }),
+        ],
+      }),
+      return_type: node('scoped_type_identifier', 'fmt::Result'),
+      body: node('block', '{ todo!() }', { after: 'fn fmt' }),
+    },
+  });
+  const traitImpl = node('impl_item', 'impl<K> fmt::Display for Store<K> {', {
+    fields: {
+      trait: node('scoped_type_identifier', 'fmt::Display'),
+      type: node('generic_type', 'Store<K>', { after: 'fmt::Display for' }),
+      body: node('declaration_list', '{\n    fn fmt', { children: [fmtFn] }),
+    },
+  });
+
+  const helperFn = node('function_item', 'pub(super) async fn helper(id: u64) {}', {
+    fields: {
+      name: node('identifier', 'helper'),
+      parameters: node('parameters', '(id: u64)', {
+        children: [
+          node('parameter', 'id: u64', {
+            fields: {
+              pattern: node('identifier', 'id', { after: 'helper(' }),
+              type: node('primitive_type', 'u64'),
+            },
+          }),
+        ],
+      }),
+      body: node('block', '{}', { after: 'fn helper' }),
+    },
+    children: [
+      node('visibility_modifier', 'pub(super)'),
+      { ...node('function_modifiers', 'async'), children: [{ type: 'async', text: 'async' }] },
+    ],
+  });
+  const nestedMod = node('mod_item', 'pub mod nested {', {
+    fields: {
+      name: node('identifier', 'nested'),
+      body: node('declaration_list', '{\n    pub(super)', { children: [helperFn] }),
+    },
+    children: [node('visibility_modifier', 'pub', { after: 'pub mod' })],
+  });
+
+  const ffiFn = node('function_signature_item', 'fn ffi_call(x: i32) -> i32;', {
+    fields: {
+      name: node('identifier', 'ffi_call'),
+      parameters: node('parameters', '(x: i32)'),
+      return_type: node('primitive_type', 'i32', { after: 'ffi_call(x: i32) ->' }),
+    },
+  });
+  const externBlock = node('foreign_mod_item', 'extern "C" {', {
+    fields: { body: node('declaration_list', '{\n    fn ffi_call', { children: [ffiFn] }) },
+    children: [node('extern_modifier', 'extern "C"')],
+  });
+
+  const useDecl = node('use_declaration', 'use std::collections::HashMap;', {
+    fields: { argument: node('scoped_identifier', 'std::collections::HashMap') },
+  });
+
+  return node('source_file', SOURCE, {
+    children: [useDecl, storeStruct, inherentImpl, traitImpl, nestedMod, externBlock],
+  });
+}
+
+describe('ASTParser Rust items', () => {
+  let parsed: ParsedFile;
+  const symbol = (name: string): Symbol => parsed.symbols.find(s => s.name === name)!;
+
+  beforeAll(async () => {
+    const parser = new ASTParser();
+    const rootNode = buildTree();
+    (parser as any).parsers.set('rust', { parse: () => ({ rootNode }) });
+    parsed = await (parser as any).parseRust('/project/src/lib.rs', SOURCE);
+  });
+
+  it('extracts types with generics, where clauses, fields and docs', () => {
+    expect(parsed.errors).toEqual([]);
+    expect(symbol('Store')).toMatchObject({
+      type: 'class',
+      isExported: true,
+      visibility: 'pub',
+      generics: '<K>',
+      whereClause: 'where K: Eq',
+      body: 'Fields: items',
+      signature: 'pub struct Store<K> where K: Eq',
+      // Docs sit above a `#[derive]` spread over two lines
+      docstring: 'A key-value store.',
+      startLine: 7,
+    });
+    expect(parsed.moduleDoc).toBe('Key-value storage.');
+  });
+
+  it('attributes methods to the type and trait of their impl block', () => {
+    expect(symbol('new')).toMatchObject({
+      type: 'method',
+      className: 'Store',
+      isMethod: true,
+      visibility: 'pub(crate)',
+      isExported: false,
+      signature: 'pub(crate) fn new() -> Self',
+      returnType: 'Self',
+      docstring: 'Creates an empty store.',
+    });
+    expect(symbol('new').traitName).toBeUndefined();
+
+    // Trait impl methods are as public as the type they are implemented for
+    expect(symbol('fmt')).toMatchObject({
+      type: 'method',
+      className: 'Store',
+      traitName: 'fmt::Display',
+      isExported: true,
+      signature: 'fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result',
+      parameters: [
+        { name: 'self', type: '&self' },
+        { name: 'f', type: '&mut fmt::Formatter', optional: false },
+      ],
+    });
+  });
+
+  it('visits nested modules and keeps restricted visibility private', () => {
+    expect(symbol('helper')).toMatchObject({
+      type: 'function',
+      visibility: 'pub(super)',
+      isExported: false,
+      isAsync: true,
+      qualifiers: ['async'],
+      signature: 'pub(super) async fn helper(id: u64)',
+      parameters: [{ name: 'id', type: 'u64', optional: false }],
+    });
+    expect(symbol('helper').className).toBeUndefined();
+  });
+
+  it('keeps functions declared in extern blocks, tagged with their ABI', () => {
+    expect(symbol('ffi_call')).toMatchObject({
+      type: 'function',
+      qualifiers: ['extern "C"'],
+      signature: 'fn ffi_call(x: i32) -> i32',
+      isExported: false,
+    });
+    expect(symbol('ffi_call').body).toBeUndefined();
+  });
+
+  it('collects use imports and pub exports', () => {
+    expect(parsed.imports).toEqual([
+      {
+        source: 'std::collections',
+        specifiers: [{ name: 'HashMap', alias: undefined, type: 'named' }],
+      },
+    ]);
+    expect(parsed.exports).toEqual([{ name: 'Store', type: 'named' }]);
+  });
+});
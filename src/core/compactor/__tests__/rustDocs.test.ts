@@ -0,0 +1,109 @@
+/**
+ * @fileOverview: Unit tests for Rust doc comment extraction
+ * @module: rustDocsTests
+ * @description: Covers ///, //!, block docs, #[doc] attributes, attribute skipping and doctests
+ */
+
+import { describe, it, expect } from '@jest/globals';
+import {
+  extractRustItemDocs,
+  extractRustModuleDocs,
+  rustDocSummary,
+  splitRustDoctests,
+} from '../rustDocs';
+
+describe('extractRustItemDocs', () => {
+  const lines = [
+    'use std::time::Duration;',
+    '',
+    '/// Token bucket rate limiter. Refills once per `interval`.',
+    '///',
+    '/// ```',
+    '/// let limiter = RateLimiter::new(10);',
+    '/// assert!(limiter.try_acquire());',
+    '/// ```',
+    '#[derive(Debug, Clone)]',
+    '#[serde(',
+    '    rename_all = "camelCase"',
+    ')]',
+    'pub struct RateLimiter {',
+    '    capacity: u32,',
+    '}',
+    '',
+    '#[doc = "Acquire one token.\\nBlocks while empty."]',
+    '#[inline]',
+    'pub fn acquire(&self) {}',
+    '',
+    '/**',
+    ' * Block doc on a const.',
+    ' */',
+    'pub const LIMIT: u32 = 10;',
+    '',
+    '//// Not a doc comment',
+    'fn private() {}',
+  ];
+
+  it('attaches /// docs across attributes and separates doctests', () => {
+    const docs = extractRustItemDocs(lines, 12)!;
+    expect(docs.text).toBe('Token bucket rate limiter. Refills once per `interval`.');
+    expect(docs.doctests).toEqual([
+      'let limiter = RateLimiter::new(10);\nassert!(limiter.try_acquire());',
+    ]);
+    expect(docs.startLine).toBe(2);
+    expect(docs.endLine).toBe(7);
+  });
+
+  it('reads #[doc = "..."] attributes and /** */ blocks', () => {
+    expect(extractRustItemDocs(lines, 18)!.text).toBe('Acquire one token.\nBlocks while empty.');
+    expect(extractRustItemDocs(lines, 23)!.text).toBe('Block doc on a const.');
+  });
+
+  it('ignores //// comments', () => {
+    expect(extractRustItemDocs(lines, 26)).toBeUndefined();
+  });
+});
+
+describe('extractRustModuleDocs', () => {
+  it('reads //! docs after license comments and inner attributes', () => {
+    const content = [
+      '// SPDX-License-Identifier: MIT',
+      '#![deny(missing_docs)]',
+      '',
+      '//! Storage backends for the metrics pipeline.',
+      '//!',
+      '//! ```text',
+      '//! writer -> backend',
+      '//! ```',
+      '',
+      'pub mod sqlite;',
+    ].join('\n');
+    const docs = extractRustModuleDocs(content)!;
+    expect(docs.text).toBe(
+      'Storage backends for the metrics pipeline.\n\n```text\nwriter -> backend\n```'
+    );
+    expect(docs.doctests).toEqual([]);
+    expect([docs.startLine, docs.endLine]).toEqual([3, 7]);
+  });
+
+  it('returns undefined for files without inner docs', () => {
+    expect(extractRustModuleDocs('/// Outer doc\npub fn run() {}')).toBeUndefined();
+  });
+});
+
+describe('splitRustDoctests', () => {
+  it('treats rust, ignore and no_run fences as doctests', () => {
+    const { text, doctests } = splitRustDoctests(
+      ['Intro.', '```rust,no_run', 'run();', '```', '```ignore', 'skip();', '```'].join('\n')
+    );
+    expect(text).toBe('Intro.');
+    expect(doctests).toEqual(['run();', 'skip();']);
+  });
+});
+
+describe('rustDocSummary', () => {
+  it('returns the first sentence', () => {
+    expect(rustDocSummary('Parses a config file. Returns defaults on error.\n\nMore.')).toBe(
+      'Parses a config file.'
+    );
+  });
+});
@@ -14,6 +14,7 @@
  *   - tree-sitter-typescript: TypeScript/TSX parsing support
  *   - tree-sitter-javascript: JavaScript parsing support
  *   - tree-sitter-python: Python parsing support
+ *   - tree-sitter-rust: Rust parsing support
  * @context: Core parsing engine that transforms source code into structured representations for semantic compression, supporting multiple languages with graceful fallbacks
  */
 
@@ -23,12 +24,15 @@ import * as t from '@babel/types';
 import { readFile } from 'fs/promises';
 import { SupportedLanguage } from './fileDiscovery';
 import { logger } from '../../utils/logger';
+import { measureRustFunction, RustComplexity } from './rustComplexity';
+import { extractRustItemDocs, extractRustModuleDocs } from './rustDocs';
 
 // Optional tree-sitter import
 let Parser: any = null;
 let TypeScript: any = null;
 let JavaScript: any = null;
 let Python: any = null;
+let Rust: any = null;
 
 // Dynamic import for ESM-only tree-sitter packages
 async function initializeAstParsers() {
@@ -53,6 +57,11 @@ async function initializeAstParsers() {
       Python = pyModule.default;
     }
 
+    if (!Rust) {
+      const rsModule = await import('tree-sitter-rust');
+      Rust = rsModule.default;
+    }
+
     logger.debug('✅ AST parsers initialized successfully');
   } catch (error) {
     logger.warn('⚠️ AST parsers not available, using Babel fallback', {
@@ -63,7 +72,7 @@ async function initializeAstParsers() {
 
 // Simple parser creation function to replace missing getParser
 function getParser(language: string): any | undefined {
-  if (!TypeScript && !JavaScript && !Python) {
+  if (!TypeScript && !JavaScript && !Python && !Rust) {
     return undefined;
   }
 
@@ -88,6 +97,12 @@ function getParser(language: string): any | undefined {
         return parser;
       }
       break;
+    case 'rust':
+      if (Rust) {
+        parser.setLanguage(Rust);
+        return parser;
+      }
+      break;
   }
 
   return undefined;
@@ -159,6 +174,21 @@ export interface Symbol {
   body?: string;
   className?: string;
   isMethod?: boolean;
+  // Rust-specific detail (populated by parseRust)
+  visibility?: string; // e.g. 'pub', 'pub(crate)', 'pub(super)'
+  qualifiers?: string[]; // e.g. ['async', 'unsafe', 'const']
+  generics?: string; // e.g. '<T: Clone + Send>'
+  whereClause?: string;
+  traitName?: string; // Trait of the owning `impl Trait for Type` block
+  complexity?: RustComplexity; // Per-function cyclomatic/cognitive complexity
+  doctests?: string[]; // Code fences from `///` docs that rustdoc compiles
+  macro?: RustMacroInfo; // The macro a `macro_rules!` or proc-macro function defines
+}
+
+export interface RustMacroInfo {
+  kind: 'declarative' | 'function' | 'derive' | 'attribute';
+  name: string; // Name at the invocation site: `define_metric!`, `#[derive(Metric)]`
+  helpers?: string[]; // Helper attributes of a derive macro
 }
 
 export interface Parameter {
@@ -175,6 +205,7 @@ export interface ParsedFile {
   imports: ImportStatement[];
   exports: ExportStatement[];
   errors: string[];
+  moduleDoc?: string; // Rust `//!` crate/module docs
 }
 
 export interface ImportStatement {
@@ -192,6 +223,25 @@ export interface ExportStatement {
   source?: string;
 }
 
+interface RustItemOwner {
+  kind: 'impl' | 'trait';
+  typeName: string;
+  traitName?: string;
+  isExported: boolean;
+}
+
+interface RustVisitContext {
+  symbols: Symbol[];
+  imports: ImportStatement[];
+  exports: ExportStatement[];
+  typeVisibility: Map<string, boolean>;
+  traitImplMethods: Symbol[];
+  visitItems: (container: any, owner?: RustItemOwner) => void;
+}
+
+const PROC_MACRO_ATTRIBUTE =
+  /^#\[\s*(proc_macro|proc_macro_attribute|proc_macro_derive)\b([\s\S]*)\]$/;
+
 export class ASTParser {
   private parsers: Map<string, any> = new Map();
 
@@ -211,6 +261,9 @@ export class ASTParser {
         case 'python':
           return await this.parsePython(filePath, content);
 
+        case 'rust':
+          return await this.parseRust(filePath, content);
+
         default:
           // For unsupported languages, use tree-sitter fallback
           return await this.parseWithTreeSitter(filePath, content, language);
@@ -516,6 +569,398 @@ export class ASTParser {
     return this.parseWithTreeSitter(filePath, content, 'python');
   }
 
+  /**
+   * Parse Rust files using tree-sitter, capturing signatures, generics, `where` clauses,
+   * visibility, qualifiers and the `impl` block each method belongs to
+   */
+  private async parseRust(filePath: string, content: string): Promise<ParsedFile> {
+    await initializeAstParsers();
+
+    let parser = this.parsers.get('rust');
+    if (!parser) {
+      parser = getParser('rust');
+      if (!parser) {
+        return this.parseWithTreeSitter(filePath, content, 'rust');
+      }
+      this.parsers.set('rust', parser);
+    }
+
+    const symbols: Symbol[] = [];
+    const imports: ImportStatement[] = [];
+    const exports: ExportStatement[] = [];
+    const errors: string[] = [];
+
+    try {
+      const tree = parser.parse(content);
+      const lines = content.split('\n');
+      const typeVisibility = new Map<string, boolean>();
+      const traitImplMethods: Symbol[] = [];
+
+      const visitItems = (container: any, owner?: RustItemOwner) => {
+        for (const node of container?.namedChildren || []) {
+          this.visitRustItem(node, content, lines, owner, {
+            symbols,
+            imports,
+            exports,
+            typeVisibility,
+            traitImplMethods,
+            visitItems,
+          });
+        }
+      };
+
+      visitItems(tree.rootNode);
+
+      // Trait impl methods carry no visibility of their own; they are as public as the type
+      for (const method of traitImplMethods) {
+        method.isExported = typeVisibility.get(method.className || '') ?? false;
+      }
+    } catch (error) {
+      errors.push(`Tree-sitter parsing error: ${(error as Error).message}`);
+    }
+
+    return {
+      absPath: filePath,
+      language: 'rust',
+      symbols,
+      imports,
+      exports,
+      errors,
+      moduleDoc: extractRustModuleDocs(content)?.text || undefined,
+    };
+  }
+
+  private visitRustItem(
+    node: any,
+    content: string,
+    lines: string[],
+    owner: RustItemOwner | undefined,
+    ctx: RustVisitContext
+  ): void {
+    const name = this.rustField(node, 'name');
+    const visibility = this.rustVisibility(node);
+    const isPub = visibility === 'pub';
+    const docs = extractRustItemDocs(lines, node.startPosition.row);
+    const base = {
+      signature: this.rustSignature(node, content),
+      startLine: node.startPosition.row + 1,
+      endLine: node.endPosition.row + 1,
+      docstring: docs?.text || undefined,
+      doctests: docs && docs.doctests.length > 0 ? docs.doctests : undefined,
+      visibility,
+      generics: this.rustField(node, 'type_parameters'),
+      whereClause: this.rustWhereClause(node),
+    };
+
+    switch (node.type) {
+      case 'function_item':
+      case 'function_signature_item': {
+        if (!name) return;
+        const qualifiers = this.rustQualifiers(node);
+        const symbol: Symbol = {
+          ...base,
+          name,
+          type: owner ? 'method' : 'function',
+          isExported: owner?.kind === 'trait' ? owner.isExported : isPub,
+          isAsync: qualifiers.includes('async'),
+          qualifiers: qualifiers.length > 0 ? qualifiers : undefined,
+          parameters: this.rustParameters(node),
+          returnType: this.rustField(node, 'return_type'),
+          body: node.type === 'function_item' ? this.rustBodyPreview(node, content) : undefined,
+          complexity: node.type === 'function_item' ? measureRustFunction(node) : undefined,
+          macro: this.rustProcMacro(node, name),
+        };
+        if (owner) {
+          symbol.className = owner.typeName;
+          symbol.isMethod = true;
+          symbol.traitName = owner.traitName;
+          if (owner.kind === 'impl' && owner.traitName) ctx.traitImplMethods.push(symbol);
+        }
+        ctx.symbols.push(symbol);
+        if (!owner && isPub) ctx.exports.push({ name, type: 'named' });
+        return;
+      }
+
+      case 'struct_item':
+      case 'enum_item':
+      case 'union_item': {
+        if (!name) return;
+        const members = this.rustMemberNames(this.rustFieldNode(node, 'body'));
+        const label = node.type === 'enum_item' ? 'Variants' : 'Fields';
+        ctx.symbols.push({
+          ...base,
+          name,
+          type: 'class',
+          isExported: isPub,
+          body: members.length > 0 ? `${label}: ${members.join(', ')}` : undefined,
+        });
+        ctx.typeVisibility.set(name, isPub);
+        if (isPub) ctx.exports.push({ name, type: 'named' });
+        return;
+      }
+
+      case 'trait_item': {
+        if (!name) return;
+        const qualifiers = this.rustQualifiers(node);
+        ctx.symbols.push({
+          ...base,
+          name,
+          type: 'interface',
+          isExported: isPub,
+          qualifiers: qualifiers.length > 0 ? qualifiers : undefined,
+        });
+        if (isPub) ctx.exports.push({ name, type: 'named' });
+        ctx.visitItems(this.rustFieldNode(node, 'body'), {
+          kind: 'trait',
+          typeName: name,
+          isExported: isPub,
+        });
+        return;
+      }
+
+      case 'impl_item': {
+        const typeText = this.rustField(node, 'type');
+        if (!typeText) return;
+        ctx.visitItems(this.rustFieldNode(node, 'body'), {
+          kind: 'impl',
+          typeName: this.stripRustGenerics(typeText),
+          traitName: this.rustField(node, 'trait'),
+          isExported: false,
+        });
+        return;
+      }
+
+      case 'mod_item':
+        ctx.visitItems(this.rustFieldNode(node, 'body'), owner);
+        return;
+
+      case 'foreign_mod_item': {
+        // `extern "C" { fn f(); static X: i32; }` declares items implemented elsewhere
+        const abi = (node.namedChildren || []).find(
+          (child: any) => child.type === 'extern_modifier'
+        );
+        const first = ctx.symbols.length;
+        ctx.visitItems(this.rustFieldNode(node, 'body'), owner);
+        for (const symbol of ctx.symbols.slice(first)) {
+          symbol.qualifiers = [abi?.text || 'extern', ...(symbol.qualifiers || [])];
+        }
+        return;
+      }
+
+      case 'type_item':
+        if (!name) return;
+        ctx.symbols.push({ ...base, name, type: 'type', isExported: isPub });
+        if (isPub && !owner) ctx.exports.push({ name, type: 'named' });
+        return;
+
+      case 'const_item':
+      case 'static_item':
+        if (!name) return;
+        ctx.symbols.push({
+          ...base,
+          name,
+          type: 'variable',
+          isExported: owner?.kind === 'trait' ? owner.isExported : isPub,
+          className: owner?.typeName,
+        });
+        if (isPub && !owner) ctx.exports.push({ name, type: 'named' });
+        return;
+
+      case 'macro_definition':
+        if (!name) return;
+        ctx.symbols.push({
+          ...base,
+          name,
+          type: 'function',
+          signature: `macro_rules! ${name}`,
+          isExported: this.rustAttributes(node).some(attr => /^#\[\s*macro_export\b/.test(attr)),
+          macro: { kind: 'declarative', name },
+        });
+        return;
+
+      case 'use_declaration':
+        this.collectRustUse(node, isPub, ctx);
+        return;
+    }
+  }
+
+  private collectRustUse(node: any, isPub: boolean, ctx: RustVisitContext): void {
+    const bySource = new Map<string, ImportStatement>();
+    const walk = (tree: any, prefix: string) => {
+      if (!tree) return;
+      const join = (text: string) => (prefix ? `${prefix}::${text}` : text);
+      const add = (fullPath: string, alias?: string, type: 'named' | 'namespace' = 'named') => {
+        const segments = fullPath.split('::');
+        const name = type === 'namespace' ? '*' : segments.pop() || fullPath;
+        const source = type === 'namespace' ? fullPath : segments.join('::');
+        let statement = bySource.get(source);
+        if (!statement) {
+          statement = { source, specifiers: [] };
+          bySource.set(source, statement);
+        }
+        statement.specifiers.push({ name, alias, type });
+        if (isPub) ctx.exports.push({ name: alias || name, type: 'named', source });
+      };
+
+      switch (tree.type) {
+        case 'identifier':
+        case 'scoped_identifier':
+        case 'self':
+        case 'crate':
+        case 'super':
+          add(join(tree.text));
+          break;
+        case 'use_as_clause': {
+          const path = this.rustField(tree, 'path');
+          if (path) add(join(path), this.rustField(tree, 'alias'));
+          break;
+        }
+        case 'use_wildcard':
+          add(join(tree.text.replace(/::\*$/, '')), undefined, 'namespace');
+          break;
+        case 'scoped_use_list': {
+          const path = this.rustField(tree, 'path');
+          walk(this.rustFieldNode(tree, 'list'), path ? join(path) : prefix);
+          break;
+        }
+        case 'use_list':
+          for (const child of tree.namedChildren || []) walk(child, prefix);
+          break;
+      }
+    };
+
+    walk(this.rustFieldNode(node, 'argument'), '');
+    ctx.imports.push(...bySource.values());
+  }
+
+  private rustFieldNode(node: any, field: string): any {
+    return typeof node?.childForFieldName === 'function' ? node.childForFieldName(field) : null;
+  }
+
+  private rustField(node: any, field: string): string | undefined {
+    return this.rustFieldNode(node, field)?.text || undefined;
+  }
+
+  private rustVisibility(node: any): string | undefined {
+    const modifier = (node.namedChildren || []).find(
+      (child: any) => child.type === 'visibility_modifier'
+    );
+    return modifier ? modifier.text.replace(/\s+/g, ' ') : undefined;
+  }
+
+  private rustWhereClause(node: any): string | undefined {
+    const clause = (node.namedChildren || []).find((child: any) => child.type === 'where_clause');
+    return clause ? clause.text.replace(/\s+/g, ' ').trim() : undefined;
+  }
+
+  private rustQualifiers(node: any): string[] {
+    const qualifiers: string[] = [];
+    for (const child of node.children || []) {
+      if (child.type === 'function_modifiers') {
+        for (const modifier of child.children || []) {
+          qualifiers.push(modifier.type === 'extern_modifier' ? modifier.text : modifier.type);
+        }
+      } else if (child.type === 'unsafe') {
+        qualifiers.push('unsafe');
+      }
+    }
+    return qualifiers;
+  }
+
+  private rustParameters(node: any): Parameter[] {
+    const params = this.rustFieldNode(node, 'parameters');
+    const result: Parameter[] = [];
+    for (const param of params?.namedChildren || []) {
+      if (param.type === 'self_parameter') {
+        result.push({ name: 'self', type: param.text });
+      } else if (param.type === 'parameter') {
+        result.push({
+          name: this.rustField(param, 'pattern') || 'param',
+          type: this.rustField(param, 'type'),
+          optional: false,
+        });
+      } else if (param.type === 'variadic_parameter') {
+        result.push({ name: '...', optional: true });
+      }
+    }
+    return result;
+  }
+
+  /**
+   * Item header up to (but excluding) its body, whitespace-normalized
+   */
+  private rustSignature(node: any, content: string): string {
+    const body = this.rustFieldNode(node, 'body');
+    const end = body ? body.startIndex : node.endIndex;
+    return content
+      .slice(node.startIndex, end)
+      .replace(/\s+/g, ' ')
+      .replace(/[;{]\s*$/, '')
+      .trim();
+  }
+
+  private rustBodyPreview(node: any, content: string): string | undefined {
+    const body = this.rustFieldNode(node, 'body');
+    if (!body) return undefined;
+    const bodyText = content.slice(body.startIndex, body.endIndex);
+    if (bodyText.length > 200) {
+      const lines = bodyText.split('\n').slice(1, -1);
+      return lines.slice(0, 3).join('\n') + (lines.length > 3 ? '\n  // ...' : '');
+    }
+    return bodyText;
+  }
+
+  private rustMemberNames(body: any): string[] {
+    const names: string[] = [];
+    for (const child of body?.namedChildren || []) {
+      if (child.type === 'field_declaration' || child.type === 'enum_variant') {
+        const name = this.rustField(child, 'name');
+        if (name) names.push(name);
+      }
+    }
+    return names;
+  }
+
+  /**
+   * Outer attributes (`#[...]`) directly above an item, doc comments between them skipped
+   */
+  private rustAttributes(node: any): string[] {
+    const attrs: string[] = [];
+    for (let prev = node.previousNamedSibling; prev; prev = prev.previousNamedSibling) {
+      if (prev.type === 'attribute_item') attrs.unshift(prev.text);
+      else if (prev.type !== 'line_comment' && prev.type !== 'block_comment') break;
+    }
+    return attrs;
+  }
+
+  /**
+   * `#[proc_macro]`, `#[proc_macro_attribute]` and `#[proc_macro_derive(Name, attributes(..))]`
+   */
+  private rustProcMacro(node: any, fnName: string): RustMacroInfo | undefined {
+    for (const attr of this.rustAttributes(node)) {
+      const match = PROC_MACRO_ATTRIBUTE.exec(attr);
+      if (!match) continue;
+      if (match[1] === 'proc_macro') return { kind: 'function', name: fnName };
+      if (match[1] === 'proc_macro_attribute') return { kind: 'attribute', name: fnName };
+
+      const derived = /^\s*\(\s*([A-Za-z_]\w*)/.exec(match[2])?.[1];
+      const helpers = /attributes\s*\(([^)]*)\)/.exec(match[2])?.[1];
+      return {
+        kind: 'derive',
+        name: derived || fnName,
+        helpers: helpers
+          ?.split(',')
+          .map(helper => helper.trim())
+          .filter(Boolean),
+      };
+    }
+    return undefined;
+  }
+
+  private stripRustGenerics(typeText: string): string {
+    return typeText.replace(/<[\s\S]*>$/, '').trim();
+  }
+
   /**
    * Fallback parsing using tree-sitter with cached parsers
    */
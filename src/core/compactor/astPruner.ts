@@ -16,6 +16,7 @@
 
 import { Symbol, ParsedFile, ImportStatement, ExportStatement } from './astParser';
 import { logger } from '../../utils/logger';
+import { RustComplexity } from './rustComplexity';
 
 export interface PrunedSymbol {
   id: string;
@@ -32,6 +33,7 @@ export interface PrunedSymbol {
   importance: number;
   relationships: Relationship[];
   compactedBody?: string;
+  complexity?: RustComplexity;
 }
 
 export interface Relationship {
@@ -48,6 +50,7 @@ export interface PrunedFile {
   exports: string[];
   summary: string;
   tokenCount: number;
+  moduleDoc?: string; // Rust `//!` crate/module docs
 }
 
 export class ASTProcessingOptions {
@@ -107,6 +110,7 @@ export class ASTPruner {
       exports,
       summary,
       tokenCount: totalTokens,
+      moduleDoc: this.options.includeComments ? parsedFile.moduleDoc : undefined,
     };
   }
 
@@ -133,6 +137,7 @@ export class ASTPruner {
       importance,
       relationships,
       compactedBody,
+      complexity: symbol.complexity,
     };
   }
 
@@ -173,6 +178,14 @@ export class ASTPruner {
       }
     }
 
+    // Extract trait implementation (Rust `impl Trait for Type` methods)
+    if (symbol.traitName) {
+      relationships.push({
+        type: 'implements',
+        target: symbol.traitName,
+      });
+    }
+
     // Extract type references
     const typeRefs = this.extractTypeReferences(symbol.signature);
     typeRefs.forEach(ref => {
@@ -20,6 +20,7 @@ export {
   type ImportStatement,
   type ExportStatement,
 } from './astParser';
+export { measureRustFunction, type RustComplexity } from './rustComplexity';
 
 // AST pruning exports
 export {
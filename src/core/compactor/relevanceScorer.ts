@@ -97,7 +97,7 @@ export class RelevanceScorer {
 
     const relevanceScore = this.calculateRelevanceScore(symbol, context, reasoning);
     const contextScore = this.calculateContextScore(symbol, context, allFiles, reasoning);
-    const qualityScore = this.calculateQualityScore(symbol, reasoning);
+    const qualityScore = this.calculateQualityScore(symbol, context, reasoning);
 
     // Task-specific weighted scoring
     let weights: { relevance: number; context: number; quality: number; importance: number };
@@ -234,13 +234,26 @@ export class RelevanceScorer {
   /**
    * Calculate quality score based on code quality indicators
    */
-  private calculateQualityScore(symbol: HashedSymbol, reasoning: string[]): number {
+  private calculateQualityScore(
+    symbol: HashedSymbol,
+    context: RelevanceContext,
+    reasoning: string[]
+  ): number {
     let score = 0;
 
     // Complexity appropriateness (not too simple, not too complex)
     const complexityScore = this.assessComplexityScore(symbol);
     score += complexityScore * 15;
 
+    // Refactor planning wants the hardest-to-follow functions first
+    if (context.taskType === 'refactor' && symbol.complexity) {
+      const { cyclomatic, cognitive } = symbol.complexity;
+      score += Math.min(cognitive * 2, 40);
+      if (cognitive > 15 || cyclomatic > 10) {
+        reasoning.push(`High complexity (cyclomatic ${cyclomatic}, cognitive ${cognitive})`);
+      }
+    }
+
     // Naming quality
     const namingScore = this.assessNamingQuality(symbol.name);
     score += namingScore * 10;
@@ -502,6 +515,16 @@ export class RelevanceScorer {
    * Assess complexity score (prefer moderate complexity)
    */
   private assessComplexityScore(symbol: HashedSymbol): number {
+    // Rust functions carry AST-derived complexity; cognitive tracks readability best
+    if (symbol.complexity) {
+      const { cognitive } = symbol.complexity;
+      if (cognitive === 0) return 0.5; // Straight-line code
+      if (cognitive <= 7) return 1.0;
+      if (cognitive <= 15) return 0.7;
+      if (cognitive <= 25) return 0.4;
+      return 0.2;
+    }
+
     const relationships = symbol.relationships.length;
     const bodyLines = symbol.compactedBody?.split('\n').length || 0;
     const paramCount = (symbol.signature.match(/,/g) || []).length + 1;
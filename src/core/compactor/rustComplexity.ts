@@ -0,0 +1,155 @@
+/**
+ * @fileOverview: Per-function cyclomatic and cognitive complexity for Rust from the tree-sitter AST
+ * @module: RustComplexity
+ * @keyFunctions:
+ *   - measureRustFunction(): Cyclomatic and nesting-weighted cognitive complexity of a function
+ * @context: Counts Rust control flow the keyword-based JS heuristic misses: match arms and guards,
+ *           `if let`/`while let`, `let ... else`, the `?` operator and closures. Only real boolean
+ *           operators count, so `&&x` patterns and `&&str` types no longer inflate the score
+ */
+
+export interface RustComplexity {
+  cyclomatic: number;
+  cognitive: number;
+  maxNesting: number;
+}
+
+// Constructs that add +1 plus the current nesting level and nest their bodies
+const NESTING_BRANCHES = new Set([
+  'if_expression',
+  'if_let_expression',
+  'match_expression',
+  'for_expression',
+  'while_expression',
+  'while_let_expression',
+  'loop_expression',
+]);
+
+// Items nested inside a body are separate functions with their own score
+const NESTED_ITEMS = new Set(['function_item', 'impl_item', 'trait_item', 'mod_item']);
+
+/**
+ * Measure a tree-sitter `function_item` (or any block) node
+ */
+export function measureRustFunction(node: any): RustComplexity {
+  const result: RustComplexity = { cyclomatic: 1, cognitive: 0, maxNesting: 0 };
+  const body = node.childForFieldName?.('body') ?? node;
+
+  const visit = (current: any, nesting: number) => {
+    for (const child of current.namedChildren || []) {
+      walk(child, nesting);
+    }
+  };
+
+  const walk = (current: any, nesting: number): void => {
+    if (NESTED_ITEMS.has(current.type)) return;
+
+    if (NESTING_BRANCHES.has(current.type)) {
+      // `loop` has no condition of its own; its exits are the `if`/`match` around each `break`
+      if (current.type !== 'loop_expression') result.cyclomatic += 1;
+      result.cognitive += 1 + nesting;
+      result.maxNesting = Math.max(result.maxNesting, nesting + 1);
+
+      for (const child of current.namedChildren || []) {
+        if (child.type === 'else_clause') {
+          walkElse(child, nesting);
+        } else if (current.type === 'match_expression' && child.type === 'match_block') {
+          walkArms(child, nesting + 1);
+        } else {
+          walk(child, nesting + 1);
+        }
+      }
+      return;
+    }
+
+    switch (current.type) {
+      case 'let_declaration': {
+        // `let Some(x) = opt else { return };`
+        const alternative = current.childForFieldName?.('alternative');
+        if (alternative) {
+          result.cyclomatic += 1;
+          result.cognitive += 1 + nesting;
+          for (const child of current.namedChildren || []) {
+            walk(child, child === alternative ? nesting + 1 : nesting);
+          }
+          return;
+        }
+        break;
+      }
+
+      case 'closure_expression':
+        result.maxNesting = Math.max(result.maxNesting, nesting + 1);
+        visit(current, nesting + 1);
+        return;
+
+      case 'try_expression':
+        // `?` is an early return on the error path
+        result.cyclomatic += 1;
+        break;
+
+      case 'binary_expression': {
+        const operator = booleanOperator(current);
+        if (operator) {
+          result.cyclomatic += 1;
+          // A run of the same operator (`a && b && c`) is one cognitive increment
+          if (booleanOperator(current.parent) !== operator) result.cognitive += 1;
+        }
+        break;
+      }
+
+      case 'let_chain':
+        // `if let Some(x) = a && x > 0 && ...` keeps its `&&` tokens flat in one node
+        result.cyclomatic += (current.children || []).filter((c: any) => c.type === '&&').length;
+        result.cognitive += 1;
+        break;
+
+      case 'break_expression':
+      case 'continue_expression':
+        if ((current.namedChildren || []).some((child: any) => child.type === 'label')) {
+          result.cognitive += 1;
+        }
+        break;
+    }
+
+    visit(current, nesting);
+  };
+
+  // `else` and `else if` add a flat increment; the chained `if` is not nested further
+  const walkElse = (clause: any, nesting: number) => {
+    result.cognitive += 1;
+    for (const child of clause.namedChildren || []) {
+      if (child.type === 'if_expression' || child.type === 'if_let_expression') {
+        result.cyclomatic += 1;
+        for (const part of child.namedChildren || []) {
+          if (part.type === 'else_clause') walkElse(part, nesting);
+          else walk(part, nesting + 1);
+        }
+      } else {
+        walk(child, nesting + 1);
+      }
+    }
+  };
+
+  // Every arm after the first is another path; guards are one more each
+  const walkArms = (block: any, nesting: number) => {
+    const arms = (block.namedChildren || []).filter((child: any) => child.type === 'match_arm');
+    result.cyclomatic += Math.max(0, arms.length - 1);
+    for (const arm of arms) {
+      const pattern = arm.childForFieldName?.('pattern');
+      if (pattern?.childForFieldName?.('condition')) {
+        result.cyclomatic += 1;
+        result.cognitive += 1;
+      }
+      visit(arm, nesting);
+    }
+  };
+
+  walk(body, 0);
+  return result;
+}
+
+function booleanOperator(node: any): string | undefined {
+  if (node?.type !== 'binary_expression') return undefined;
+  const operator = node.childForFieldName?.('operator')?.type;
+  return operator === '&&' || operator === '||' ? operator : undefined;
+}
@@ -0,0 +1,217 @@
+/**
+ * @fileOverview: Rust doc comment extraction (`///`, `//!`, `/**` and `/*!` blocks, `#[doc = "..."]`)
+ * @module: RustDocs
+ * @keyFunctions:
+ *   - extractRustItemDocs(): Outer docs attached to the item starting at a given line
+ *   - extractRustModuleDocs(): Inner docs describing the enclosing module or crate
+ *   - splitRustDoctests(): Separate doctest code fences from the prose
+ * @context: Line-based so it works on raw file text as well as next to tree-sitter nodes.
+ *           `////` and `/***` are ordinary comments, as in rustdoc.
+ */
+
+export interface RustDoc {
+  text: string; // Markdown prose with doctest fences removed
+  doctests: string[]; // Bodies of ```rust (or bare ```) fences
+  startLine: number; // 0-based first line of the docs and attributes above the item
+  endLine: number; // 0-based last line of the docs
+}
+
+const OUTER_LINE = /^\/\/\/(?!\/)\s?/;
+const INNER_LINE = /^\/\/!\s?/;
+const DOC_ATTR = /^#\[\s*doc\s*=\s*"((?:[^"\\]|\\.)*)"\s*\]$/;
+const INNER_DOC_ATTR = /^#!\[\s*doc\s*=\s*"((?:[^"\\]|\\.)*)"\s*\]$/;
+const ESCAPES: Record<string, string> = { n: '\n', t: '\t' };
+// Fence info strings rustdoc compiles as Rust
+const DOCTEST_INFO =
+  /^(?:rust|ignore|no_run|should_panic|compile_fail|test_harness|edition\d{4}|allow_fail|,|\s)*$/;
+
+/**
+ * Outer docs for the item whose first line (after attributes) is `itemRow`, 0-based.
+ * Attributes between the docs and the item are skipped, as rustdoc does
+ */
+export function extractRustItemDocs(lines: string[], itemRow: number): RustDoc | undefined {
+  const parts: string[] = [];
+  let startLine = itemRow;
+  let endLine = itemRow - 1;
+
+  for (let i = itemRow - 1; i >= 0; i--) {
+    const line = lines[i].trim();
+
+    if (OUTER_LINE.test(line)) {
+      if (parts.length === 0) endLine = i;
+      parts.unshift(line.replace(OUTER_LINE, ''));
+      startLine = i;
+      continue;
+    }
+
+    const attr = DOC_ATTR.exec(line);
+    if (attr) {
+      if (parts.length === 0) endLine = i;
+      parts.unshift(unescapeRustString(attr[1]));
+      startLine = i;
+      continue;
+    }
+
+    // `/** ... */` ending on this line
+    if (line.endsWith('*/')) {
+      const open = findBlockStart(lines, i, '/**');
+      if (open === undefined) break;
+      if (parts.length === 0) endLine = i;
+      parts.unshift(blockCommentText(lines.slice(open, i + 1), '/**'));
+      startLine = i = open;
+      continue;
+    }
+
+    // Other attributes, including ones spread over several lines
+    if (line.startsWith('#[')) {
+      startLine = i;
+      continue;
+    }
+    if (line.endsWith(']')) {
+      const open = findAttributeStart(lines, i);
+      if (open === undefined) break;
+      startLine = i = open;
+      continue;
+    }
+
+    break;
+  }
+
+  return parts.length > 0
+    ? { ...splitRustDoctests(parts.join('\n')), startLine, endLine }
+    : undefined;
+}
+
+/**
+ * Inner docs at the top of a file (`//!`, `/*!` blocks, `#![doc = "..."]`). A leading
+ * shebang, blank lines and other inner attributes such as `#![deny(...)]` are skipped
+ */
+export function extractRustModuleDocs(content: string): RustDoc | undefined {
+  const lines = content.split('\n');
+  const parts: string[] = [];
+  let startLine = 0;
+  let endLine = 0;
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i].trim();
+    if (i === 0 && line.startsWith('#!') && !line.startsWith('#![')) continue;
+
+    if (INNER_LINE.test(line)) {
+      if (parts.length === 0) startLine = i;
+      parts.push(line.replace(INNER_LINE, ''));
+      endLine = i;
+      continue;
+    }
+
+    if (line.startsWith('/*!')) {
+      const end = lines.findIndex((candidate, j) => j >= i && candidate.includes('*/'));
+      if (end === -1) break;
+      if (parts.length === 0) startLine = i;
+      parts.push(blockCommentText(lines.slice(i, end + 1), '/*!'));
+      i = endLine = end;
+      continue;
+    }
+
+    const attr = INNER_DOC_ATTR.exec(line);
+    if (attr) {
+      if (parts.length === 0) startLine = i;
+      parts.push(unescapeRustString(attr[1]));
+      endLine = i;
+      continue;
+    }
+
+    // Blank lines may separate the docs from `#![...]` attributes; ordinary `//` comments
+    // (license headers) may come before the docs
+    if (line === '' || line.startsWith('#![') || (line.startsWith('//') && parts.length === 0)) {
+      continue;
+    }
+    break;
+  }
+
+  return parts.length > 0
+    ? { ...splitRustDoctests(parts.join('\n')), startLine, endLine }
+    : undefined;
+}
+
+/**
+ * Pull code fences rustdoc would compile out of the prose. Fences tagged with another
+ * language (```text, ```toml) stay in the text
+ */
+export function splitRustDoctests(markdown: string): { text: string; doctests: string[] } {
+  const text: string[] = [];
+  const doctests: string[] = [];
+  let fence: { marker: string; isDoctest: boolean; body: string[] } | undefined;
+
+  for (const line of markdown.split('\n')) {
+    const fenceMatch = /^\s*(`{3,}|~{3,})(.*)$/.exec(line);
+    if (fence) {
+      if (fenceMatch && fenceMatch[1].startsWith(fence.marker) && !fenceMatch[2].trim()) {
+        if (fence.isDoctest) doctests.push(fence.body.join('\n'));
+        else text.push(line);
+        fence = undefined;
+      } else if (fence.isDoctest) {
+        fence.body.push(line);
+      } else {
+        text.push(line);
+      }
+      continue;
+    }
+
+    if (fenceMatch) {
+      const isDoctest = DOCTEST_INFO.test(fenceMatch[2].trim());
+      fence = { marker: fenceMatch[1], isDoctest, body: [] };
+      if (!isDoctest) text.push(line);
+      continue;
+    }
+    text.push(line);
+  }
+
+  // An unterminated fence runs to the end of the docs
+  if (fence?.isDoctest) doctests.push(fence.body.join('\n'));
+
+  return { text: text.join('\n').replace(/\n{3,}/g, '\n\n').trim(), doctests };
+}
+
+/**
+ * First sentence (or line) of a doc comment, for one-line purposes and summaries
+ */
+export function rustDocSummary(text: string, maxLength: number = 200): string | undefined {
+  const paragraph = text.split(/\n\s*\n/)[0].replace(/\s+/g, ' ').trim();
+  if (!paragraph || paragraph.startsWith('#')) return undefined;
+  const sentence = /^(.+?[.!?])(?:\s|$)/.exec(paragraph)?.[1] ?? paragraph;
+  return sentence.substring(0, maxLength);
+}
+
+function findBlockStart(lines: string[], endRow: number, opener: string): number | undefined {
+  for (let i = endRow; i >= 0 && i > endRow - 200; i--) {
+    const line = lines[i].trim();
+    if (line.startsWith(opener) && !line.startsWith(`${opener}*`)) return i;
+    if (line.startsWith('/*')) return undefined; // an ordinary block comment
+  }
+  return undefined;
+}
+
+function findAttributeStart(lines: string[], endRow: number): number | undefined {
+  for (let i = endRow - 1; i >= 0 && i > endRow - 50; i--) {
+    const line = lines[i].trim();
+    if (line.startsWith('#[')) return i;
+    if (line === '' || line.endsWith(';') || line.endsWith('}')) return undefined;
+  }
+  return undefined;
+}
+
+function blockCommentText(block: string[], opener: string): string {
+  return block
+    .map((line, index) => {
+      let text = line.trim();
+      if (index === 0) text = text.slice(opener.length);
+      if (index === block.length - 1) text = text.replace(/\*\/$/, '');
+      return text.replace(/^\*(?!\/)\s?/, '').trimEnd();
+    })
+    .join('\n')
+    .trim();
+}
+
+function unescapeRustString(value: string): string {
+  return value.replace(/\\(.)/g, (_, ch: string) => ESCAPES[ch] ?? ch);
+}
@@ -129,7 +129,7 @@ export class SemanticCompactor {
   constructor(projectPath: string, options: CompactionOptions = {}) {
     this.options = {
       maxFileSize: 100000,
-      supportedLanguages: ['typescript', 'javascript', 'python'],
+      supportedLanguages: ['typescript', 'javascript', 'python', 'rust'],
       includeSourceCode: false,
       includeDocstrings: true,
       maxTokensPerFile: 2000,
@@ -16,6 +16,7 @@
  */
 
 import { PrunedSymbol, PrunedFile, Relationship } from './astPruner';
+import { rustDocSummary } from './rustDocs';
 
 export interface SymbolSummary {
   id: string;
@@ -54,7 +55,7 @@ export class SemanticSummarizer {
    * Summarize individual symbols with concise descriptions
    */
   summarizeSymbol(symbol: PrunedSymbol, file: PrunedFile): SymbolSummary {
-    const purpose = this.generateSymbolPurpose(symbol);
+    const purpose = this.generateSymbolPurpose(symbol, file);
     const complexity = this.assessSymbolComplexity(symbol);
     const connections = this.extractConnections(symbol);
     const breadcrumb = this.generateBreadcrumb(symbol, file);
@@ -124,7 +125,13 @@ export class SemanticSummarizer {
   /**
    * Generate contextual descriptions for symbols
    */
-  private generateSymbolPurpose(symbol: PrunedSymbol): string {
+  private generateSymbolPurpose(symbol: PrunedSymbol, file: PrunedFile): string {
+    // Rust `///` docs state the purpose directly; their first sentence beats name heuristics
+    if (file.language === 'rust' && symbol.docstring) {
+      const summary = rustDocSummary(symbol.docstring);
+      if (summary) return summary;
+    }
+
     const name = symbol.name.toLowerCase();
     const type = symbol.type;
     const signature = symbol.signature.toLowerCase();
@@ -238,6 +245,9 @@ export class SemanticSummarizer {
         case 'extends':
           connections.push(`extends ${rel.target}`);
           break;
+        case 'implements':
+          connections.push(`implements ${rel.target}`);
+          break;
         case 'imports':
           connections.push(`imports ${rel.target}`);
           break;
@@ -342,6 +352,12 @@ export class SemanticSummarizer {
    * Generate file purpose description
    */
   private generateFilePurpose(file: PrunedFile): string {
+    // Crate and module docs (`//!`) describe the file better than its name does
+    if (file.moduleDoc) {
+      const summary = rustDocSummary(file.moduleDoc);
+      if (summary) return summary;
+    }
+
     const fileName = file.absPath.split('/').pop()?.toLowerCase() || '';
     const exports = file.exports.map(e => e.toLowerCase());
     const symbols = file.symbols;
@@ -0,0 +1,203 @@
+/**
+ * @fileOverview: Unit tests for item-aware Rust chunking
+ * @module: RustChunker Tests
+ * @description: Covers item boundaries with docs and attributes, merged declarations, per-method
+ *               impl splitting with the header repeated, and module doc chunks
+ */
+
+import { chunkRustSource } from '../rustChunker';
+
+const SOURCE = [
+  '//! Token bucket rate limiting.',
+  '//!',
+  '//! See [`RateLimiter`].',
+  '',
+  '#![deny(missing_docs)]',
+  '',
+  'use std::collections::HashMap;',
+  'use std::time::{Duration, Instant};',
+  '',
+  'extern crate alloc;',
+  '',
+  'mod clock;',
+  'pub mod pool;',
+  '',
+  '/// Default refill interval.',
+  'pub const REFILL: Duration = Duration::from_millis(100);',
+  'const MAX_TOKENS: u32 = 1_000;',
+  'static NAME: &str = "limiter; {not code}";',
+  '',
+  '/// A token bucket.',
+  '///',
+  '/// ```',
+  '/// let l = zlim::RateLimiter::new(10);',
+  '/// ```',
+  '#[derive(Debug, Clone)]',
+  '#[cfg_attr(feature = "serde", derive(serde::Serialize))]',
+  'pub struct RateLimiter {',
+  '    tokens: u32,',
+  '    last: Instant,',
+  '}',
+  '',
+  '// Keeps the per-key state.',
+  'pub struct Keyed<K> where K: std::hash::Hash {',
+  '    buckets: HashMap<K, RateLimiter>,',
+  '}',
+  '',
+  'impl<K: std::hash::Hash + Eq> Keyed<K> where K: Clone {',
+  '    /// Creates an empty set of buckets.',
+  '    pub fn new() -> Self {',
+  '        Self { buckets: HashMap::new() }',
+  '    }',
+  '',
+  '    const LIMIT: usize = 64;',
+  '    type Key = K;',
+  '',
+  '    /// Acquire a token for `key`.',
+  '    #[inline]',
+  '    pub fn acquire(&mut self, key: K) -> bool {',
+  '        let bucket = self.buckets.entry(key).or_insert_with(|| RateLimiter::new(MAX_TOKENS));',
+  '        let text = "}";',
+  '        if bucket.tokens > 0 {',
+  '            bucket.tokens -= 1;',
+  '            true',
+  '        } else {',
+  '            false',
+  '        }',
+  '    }',
+  '',
+  '    // Drops every bucket.',
+  '    pub fn clear(&mut self) {',
+  '        self.buckets.clear();',
+  '        // a } in a comment',
+  '    }',
+  '}',
+  '',
+  'impl std::fmt::Display for RateLimiter {',
+  '    fn fmt(&self, f: &mut std::fmt::Formatter<\'_>) -> std::fmt::Result {',
+  '        write!(f, "{} tokens", self.tokens)',
+  '    }',
+  '}',
+  '',
+  'macro_rules! bucket {',
+  '    ($n:expr) => {',
+  '        RateLimiter::new($n)',
+  '    };',
+  '}',
+  '',
+  'lazy_static::lazy_static! {',
+  '    static ref GLOBAL: u32 = 1;',
+  '}',
+  '',
+  'extern "C" {',
+  '    fn now_ms() -> u64;',
+  '}',
+  '',
+  '#[cfg(test)]',
+  'mod tests {',
+  '    use super::*;',
+  '',
+  '    #[test]',
+  '    fn acquires() {',
+  '        assert!(Keyed::<u8>::new().acquire(1));',
+  '    }',
+  '',
+  '    #[test]',
+  '    fn clears() {',
+  '        let mut k = Keyed::<u8>::new();',
+  '        k.clear();',
+  '    }',
+  '}',
+].join('\n');
+
+describe('chunkRustSource', () => {
+  test('keeps items whole with their docs, attributes and comments', () => {
+    const chunks = chunkRustSource(SOURCE);
+    expect(chunks.map(chunk => [chunk.startLine, chunk.endLine, chunk.type])).toEqual([
+      [1, 3, 'docstring'],
+      [5, 18, 'code'],
+      [20, 30, 'code'],
+      [32, 35, 'code'],
+      [37, 64, 'code'],
+      [66, 70, 'code'],
+      [72, 76, 'code'],
+      [78, 80, 'code'],
+      [82, 84, 'code'],
+      [86, 100, 'code'],
+    ]);
+
+    const limiter = chunks.find(chunk => chunk.symbols.includes('RateLimiter'))!;
+    expect(limiter.content.startsWith('/// A token bucket.')).toBe(true);
+    expect(limiter.content).toContain('#[cfg_attr(feature = "serde", derive(serde::Serialize))]');
+    expect(chunks[3].content.startsWith('// Keeps the per-key state.')).toBe(true);
+  });
+
+  test('gives crate docs their own chunk', () => {
+    const [docs] = chunkRustSource(SOURCE);
+    expect(docs.content).toBe('Token bucket rate limiting.\n\nSee [`RateLimiter`].');
+    expect(docs.symbols).toEqual([]);
+  });
+
+  test('merges consecutive small declarations', () => {
+    const [, declarations] = chunkRustSource(SOURCE);
+    expect(declarations.content.startsWith('#![deny(missing_docs)]')).toBe(true);
+    expect(declarations.symbols).toEqual([
+      'alloc',
+      'clock',
+      'pool',
+      'REFILL',
+      'MAX_TOKENS',
+      'NAME',
+    ]);
+  });
+
+  test('names impls and macros like the tree-sitter chunker', () => {
+    const symbols = chunkRustSource(SOURCE).map(chunk => chunk.symbols);
+    expect(symbols).toContainEqual(['Keyed<K>']);
+    expect(symbols).toContainEqual(['std::fmt::Display for RateLimiter']);
+    expect(symbols).toContainEqual(['bucket']);
+    expect(symbols).toContainEqual(['lazy_static::lazy_static!']);
+  });
+
+  test('splits an oversized impl per method with the impl header repeated', () => {
+    const chunks = chunkRustSource(SOURCE, 300).filter(
+      chunk => chunk.startLine > 36 && chunk.endLine < 65
+    );
+    expect(chunks.map(chunk => [chunk.startLine, chunk.endLine, chunk.symbols])).toEqual([
+      [38, 41, ['Keyed::new']],
+      [43, 44, ['Keyed::LIMIT', 'Keyed::Key']],
+      [46, 57, ['Keyed::acquire']],
+      [59, 63, ['Keyed::clear']],
+    ]);
+
+    const header = 'impl<K: std::hash::Hash + Eq> Keyed<K> where K: Clone {';
+    for (const chunk of chunks) {
+      expect(chunk.content.startsWith(header)).toBe(true);
+      expect(chunk.content.endsWith('\n}')).toBe(true);
+    }
+    // Braces inside strings and comments do not end the method early
+    expect(chunks[2].content).toContain('            false');
+    expect(chunks[3].content).toContain('// a } in a comment');
+  });
+
+  test('splits a large test module per test with its attributes', () => {
+    const tests = chunkRustSource(SOURCE, 150).filter(chunk => chunk.startLine > 86);
+    expect(tests.map(chunk => [chunk.startLine, chunk.endLine, chunk.type])).toEqual([
+      [88, 88, 'import'],
+      [90, 93, 'code'],
+      [95, 99, 'code'],
+    ]);
+    expect(tests[1].symbols).toEqual(['tests::acquires']);
+    expect(tests[1].content).toBe(
+      [
+        '#[cfg(test)]',
+        'mod tests {',
+        '    #[test]',
+        '    fn acquires() {',
+        '        assert!(Keyed::<u8>::new().acquire(1));',
+        '    }',
+        '}',
+      ].join('\n')
+    );
+  });
+});
@@ -28,6 +28,10 @@ jest.mock('tree-sitter-python', () => ({
   default: jest.fn(),
 }));
 
+jest.mock('tree-sitter-rust', () => ({
+  default: jest.fn(),
+}));
+
 // Mock logger
 jest.mock('../../utils/logger', () => ({
   logger: {
@@ -381,4 +385,73 @@ export default component;
       expect(maxEndLine).toBeGreaterThanOrEqual(totalLines);
     });
   });
+
+  describe('Rust support', () => {
+    // Minimal stand-in for a tree-sitter node with named fields
+    const node = (
+      type: string,
+      text: string,
+      fields: Record<string, any> = {},
+      children: any[] = []
+    ) => ({
+      type,
+      text,
+      children,
+      namedChildren: children,
+      startPosition: { row: 0, column: 0 },
+      endPosition: { row: 0, column: text.length },
+      childForFieldName: (field: string) => fields[field] ?? null,
+    });
+
+    test('should treat Rust items as chunkable and symbol nodes', () => {
+      const itemTypes = [
+        'function_item',
+        'struct_item',
+        'enum_item',
+        'trait_item',
+        'impl_item',
+        'mod_item',
+        'macro_definition',
+      ];
+      for (const type of itemTypes) {
+        expect((processor as any).isChunkableNode({ type }, 'rust')).toBe(true);
+        expect((processor as any).isSymbolNode({ type }, 'rust')).toBe(true);
+      }
+      expect((processor as any).isSymbolNode({ type: 'const_item' }, 'rust')).toBe(true);
+      expect((processor as any).isChunkableNode({ type: 'use_declaration' }, 'rust')).toBe(false);
+    });
+
+    test('should name impl blocks after their trait and type', () => {
+      const impl = node('impl_item', 'impl Display for Point {}', {
+        trait: node('type_identifier', 'Display'),
+        type: node('type_identifier', 'Point'),
+      });
+      const fn = node('function_item', 'fn connect() {}', { name: node('identifier', 'connect') });
+
+      expect((processor as any).getRustSymbolName(impl)).toBe('Display for Point');
+      expect((processor as any).getRustSymbolName(fn)).toBe('connect');
+    });
+
+    test('should flatten nested use trees into names and paths', () => {
+      // use std::{io, collections::HashMap as Map, fmt::*};
+      const list = node('use_list', '{...}', {}, [
+        node('identifier', 'io'),
+        node('use_as_clause', 'collections::HashMap as Map', {
+          path: node('scoped_identifier', 'collections::HashMap'),
+          alias: node('identifier', 'Map'),
+        }),
+        node('use_wildcard', 'fmt::*'),
+      ]);
+      const tree = node('scoped_use_list', 'std::{...}', {
+        path: node('identifier', 'std'),
+        list,
+      });
+
+      expect((processor as any).flattenRustUseTree(tree, '')).toEqual([
+        { name: 'io', path: 'std::io' },
+        { name: 'Map', path: 'std::collections::HashMap' },
+        { name: '*', path: 'std::fmt' },
+      ]);
+    });
+  });
 });
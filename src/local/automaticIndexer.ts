@@ -8,6 +8,7 @@
  *   - getIndexingStatus(): Monitor indexing progress and status
  * @dependencies:
  *   - TreeSitterProcessor: AST parsing and symbol extraction
+ *   - chunkRustSource: Item-aware chunking of Rust files
  *   - LocalProjectManager: Local project state management
  *   - ProjectIdentifier: Project detection and identification
  *   - apiClient: Cloud service synchronization
@@ -25,6 +26,7 @@ import { ProjectIdentifier, ProjectInfo } from './projectIdentifier';
 import { loadIgnorePatterns } from './projectIdentifier';
 import { apiClient } from '../client/apiClient';
 import { LocalEmbeddingGenerator } from './embeddingGenerator';
+import { chunkRustSource } from './rustChunker';
 import { logger } from '../utils/logger';
 
 interface IgnorePatterns {
@@ -1395,10 +1397,17 @@ export class AutomaticIndexer {
         if (ext === '.js' || ext === '.jsx') language = 'javascript';
         else if (ext === '.py') language = 'python';
 
-        // Process file with tree-sitter
-        const result = await this.treeSitter.parseAndChunk(content, language, file);
-        session.chunksCreated += result.chunks.length;
-        session.symbolsExtracted += result.symbols.length;
+        if (ext === '.rs') {
+          // Rust is chunked along item boundaries without the grammar, as for embeddings
+          const chunks = chunkRustSource(content);
+          session.chunksCreated += chunks.length;
+          session.symbolsExtracted += chunks.reduce((sum, chunk) => sum + chunk.symbols.length, 0);
+        } else {
+          // Process file with tree-sitter
+          const result = await this.treeSitter.parseAndChunk(content, language, file);
+          session.chunksCreated += result.chunks.length;
+          session.symbolsExtracted += result.symbols.length;
+        }
 
         session.filesProcessed++;
 
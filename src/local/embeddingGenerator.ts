@@ -12,6 +12,7 @@
  *   - localEmbeddingProvider: Local Transformers.js models (offline fallback)
  *   - embeddingStorage: Local SQLite storage for persistence
  *   - treeSitterProcessor: AST-based chunking and symbol extraction
+ *   - rustChunker: Item-aware Rust chunking (works without the tree-sitter grammar)
  * @context: Provides intelligent embedding generation with explicit provider selection: Local Models → OpenAI (explicit) → VoyageAI (explicit) → Error
  */
 
@@ -27,6 +28,7 @@ import {
   ProjectMetadata,
 } from './embeddingStorage';
 import { TreeSitterProcessor } from './treeSitterProcessor';
+import { chunkRustSource } from './rustChunker';
 import { openaiService } from '../core/openaiService';
 import { apiClient } from '../client/apiClient';
 import {
@@ -75,6 +77,15 @@ export interface ChunkingOptions {
   includeContext?: boolean; // Include surrounding context in chunks
 }
 
+export interface ContentChunk {
+  content: string;
+  index: number;
+  startLine: number;
+  endLine: number;
+  symbols?: string[];
+  type: 'code' | 'comment' | 'docstring' | 'import' | 'export';
+}
+
 export interface GenerationProgress {
   totalFiles: number;
   processedFiles: number;
@@ -1223,22 +1234,14 @@ export class LocalEmbeddingGenerator {
     content: string,
     filePath: string,
     options: ChunkingOptions
-  ): Promise<
-    Array<{
-      content: string;
-      index: number;
-      startLine: number;
-      endLine: number;
-      symbols?: string[];
-      type: 'code' | 'comment' | 'docstring' | 'import' | 'export';
-    }>
-  > {
+  ): Promise<ContentChunk[]> {
     const maxChunkSize = options.maxChunkSize || 2000;
     const overlapSize = options.overlapSize || 100;
     const preferSymbolBoundaries = options.preferSymbolBoundaries !== false;
 
-    // Try TreeSitter-based chunking first
-    if (this.treeSitter && preferSymbolBoundaries) {
+    // Try TreeSitter-based chunking first (Rust is chunked by item without the grammar)
+    const itemAware = this.treeSitter || this.getLanguageFromPath(filePath) === 'rust';
+    if (itemAware && preferSymbolBoundaries) {
       try {
         return await this.smartChunkContent(content, filePath, maxChunkSize);
       } catch (error) {
@@ -1260,16 +1263,32 @@ export class LocalEmbeddingGenerator {
     content: string,
     filePath: string,
     maxChunkSize: number
-  ): Promise<
-    Array<{
-      content: string;
-      index: number;
-      startLine: number;
-      endLine: number;
-      symbols?: string[];
-      type: 'code' | 'comment' | 'docstring' | 'import' | 'export';
-    }>
-  > {
+  ): Promise<ContentChunk[]> {
+    // Rust: whole items with their docs and attributes, oversized impls split per method
+    if (this.getLanguageFromPath(filePath) === 'rust') {
+      const rustChunks: ContentChunk[] = [];
+      for (const rustChunk of chunkRustSource(content, maxChunkSize)) {
+        if (rustChunk.content.length <= maxChunkSize) {
+          rustChunks.push({ ...rustChunk, index: rustChunks.length });
+          continue;
+        }
+
+        // A single item too large on its own (e.g. a long fn): window its own lines
+        const itemSource = this.extractLines(content, rustChunk.startLine, rustChunk.endLine);
+        const subChunks = await this.simpleChunkContent(itemSource, maxChunkSize, 100);
+        for (const subChunk of subChunks) {
+          rustChunks.push({
+            ...subChunk,
+            index: rustChunks.length,
+            startLine: rustChunk.startLine + subChunk.startLine - 1,
+            endLine: rustChunk.startLine + subChunk.endLine - 1,
+            symbols: rustChunk.symbols,
+          });
+        }
+      }
+      return rustChunks;
+    }
+
     if (!this.treeSitter) {
       throw new Error('TreeSitter not initialized');
     }
@@ -1304,7 +1323,7 @@ export class LocalEmbeddingGenerator {
           startLine: astChunk.startLine,
           endLine: astChunk.endLine,
           symbols: astChunk.symbolName ? [astChunk.symbolName] : [],
-          type: 'code' as const,
+          type: astChunk.symbolType === 'module_doc' ? ('docstring' as const) : ('code' as const),
         });
       } else {
         // Split large chunks
@@ -1333,15 +1352,7 @@ export class LocalEmbeddingGenerator {
     content: string,
     maxChunkSize: number,
     overlapSize: number
-  ): Promise<
-    Array<{
-      content: string;
-      index: number;
-      startLine: number;
-      endLine: number;
-      type: 'code' | 'comment' | 'docstring' | 'import' | 'export';
-    }>
-  > {
+  ): Promise<ContentChunk[]> {
     const lines = content.split('\n');
     const chunks: any[] = [];
     let chunkIndex = 0;
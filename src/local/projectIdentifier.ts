@@ -20,6 +20,7 @@ import { execSync } from 'child_process';
 import { minimatch } from 'minimatch';
 
 import { logger } from '../utils/logger';
+import { findCargoWorkspaceRoot } from '../tools/localTools/utils/cargoWorkspace';
 
 export interface ProjectInfo {
   id: string;
@@ -182,7 +183,7 @@ export class ProjectIdentifier {
   private async findWorkspaceRoot(projectPath: string): Promise<string> {
     // Check if current directory has workspace indicators
     if (await this.hasWorkspaceIndicators(projectPath)) {
-      return projectPath;
+      return this.resolveCargoWorkspaceRoot(projectPath);
     }
 
     // Walk up the directory tree to find workspace root
@@ -190,7 +191,7 @@ export class ProjectIdentifier {
     while (currentPath !== path.dirname(currentPath)) {
       const parentPath = path.dirname(currentPath);
       if (await this.hasWorkspaceIndicators(parentPath)) {
-        return parentPath;
+        return this.resolveCargoWorkspaceRoot(parentPath);
       }
       currentPath = parentPath;
     }
@@ -199,6 +200,16 @@ export class ProjectIdentifier {
     return projectPath;
   }
 
+  /**
+   * A Cargo member crate belongs to the enclosing [workspace] manifest, not to its own directory
+   */
+  private resolveCargoWorkspaceRoot(dirPath: string): string {
+    if (!fs.existsSync(path.join(dirPath, 'Cargo.toml'))) {
+      return dirPath;
+    }
+    return findCargoWorkspaceRoot(dirPath) ?? dirPath;
+  }
+
   /**
    * Check if a directory has workspace indicators
    */
@@ -359,7 +370,7 @@ export class ProjectIdentifier {
     let currentPath = path.resolve(projectPath);
     while (currentPath !== path.dirname(currentPath)) {
       if (this.hasWorkspaceIndicatorsSync(currentPath)) {
-        return currentPath;
+        return this.resolveCargoWorkspaceRoot(currentPath);
       }
       currentPath = path.dirname(currentPath);
     }
@@ -0,0 +1,321 @@
+/**
+ * @fileOverview: Item-aware chunking of Rust source for embeddings
+ * @module: RustChunker
+ * @keyFunctions:
+ *   - chunkRustSource(): Split a Rust file into chunks along item boundaries
+ * @context: Text-based, so it works with or without the tree-sitter grammar. Every chunk keeps an
+ *           item together with its `///` docs, `#[...]` attributes and leading comments. `impl`,
+ *           `trait` and inline `mod` blocks over the size budget are split per member with the
+ *           enclosing header repeated as context, and runs of small declarations (`use`, `const`,
+ *           `static`, `type`, `mod x;`) are merged up to the budget.
+ */
+
+import { extractRustModuleDocs } from '../core/compactor/rustDocs';
+import {
+  lineAtOffset,
+  lineOffsets,
+  maskRustSource,
+  matchBracket,
+} from '../tools/localTools/utils/rustSource';
+
+export interface RustChunk {
+  content: string;
+  startLine: number; // 1-based
+  endLine: number; // 1-based, inclusive
+  symbols: string[];
+  type: 'code' | 'docstring' | 'import';
+}
+
+interface RustItemSpan {
+  kind: string; // fn, struct, impl, use, macro, attribute, ...
+  name?: string;
+  startRow: number; // 0-based, including docs, attributes and leading comments
+  endRow: number;
+  body?: { open: number; close: number }; // offsets of the braces of impl/trait/mod bodies
+}
+
+interface ChunkScope {
+  header: string; // lines opening the enclosing blocks, outermost first
+  footer: string;
+  owner?: string; // qualifies member symbols, e.g. `Parser::parse`
+}
+
+const ITEM_HEAD =
+  /^(?:pub(?:\s*\([^)]*\))?\s+)?(?:(?:default|async|const|unsafe|extern(?:\s+"[^"]*")?)\s+)*(fn|struct|enum|union|trait|impl|mod|use|const|static|type|macro_rules!|extern\s+crate|extern)(?!\w)/;
+const MACRO_CALL = /^([\w:]+)\s*!/;
+const IDENT = /^\s*(?:r#)?(\w+)/;
+// Items ending at the first top-level `;`, even when they contain braces (`use a::{b, c};`)
+const SEMICOLON_KINDS = new Set(['use', 'const', 'static', 'type', 'extern crate']);
+// Declarations small enough to be merged with their neighbours
+const DECLARATION_KINDS = new Set([...SEMICOLON_KINDS, 'attribute']);
+const SPLIT_KINDS = new Set(['impl', 'trait', 'mod']);
+
+/**
+ * Chunk a Rust file along item boundaries. Crate/module docs (`//!`) get their own chunk.
+ * Items that exceed maxChunkSize and cannot be split per member are returned whole
+ */
+export function chunkRustSource(content: string, maxChunkSize: number = 2000): RustChunk[] {
+  const { masked } = maskRustSource(content);
+  const lines = content.split('\n');
+  const starts = lineOffsets(content);
+  const chunks: RustChunk[] = [];
+
+  let floor = -1;
+  const moduleDocs = extractRustModuleDocs(content);
+  if (moduleDocs?.text) {
+    chunks.push({
+      content: moduleDocs.text,
+      startLine: moduleDocs.startLine + 1,
+      endLine: moduleDocs.endLine + 1,
+      symbols: [],
+      type: 'docstring',
+    });
+    floor = moduleDocs.endLine;
+  }
+
+  const items = scanItems(masked, 0, masked.length, starts, lines, floor);
+  const scope: ChunkScope = { header: '', footer: '' };
+  chunks.push(...packItems(items, scope, masked, starts, lines, maxChunkSize));
+  return chunks;
+}
+
+// ===== Item scanning =====
+
+/**
+ * Items between two offsets of the masked source, at the nesting level of `from`
+ */
+function scanItems(
+  masked: string,
+  from: number,
+  to: number,
+  starts: number[],
+  lines: string[],
+  floorRow: number
+): RustItemSpan[] {
+  const items: RustItemSpan[] = [];
+  let previousRow = floorRow;
+  let i = from;
+
+  while (i < to) {
+    while (i < to && /\s/.test(masked[i])) i++;
+    if (i >= to) break;
+
+    const itemStart = i;
+    const declStart = skipAttributes(masked, i, to);
+    const head = masked.slice(declStart, Math.min(to, declStart + 200));
+    const match = ITEM_HEAD.exec(head);
+    let kind = match ? match[1].replace(/\s+/, ' ') : 'other';
+    if (!match && declStart === to) kind = 'attribute';
+    const macro = match ? undefined : MACRO_CALL.exec(head);
+    if (macro) kind = 'macro';
+
+    // Inner attributes (`#![...]`) stand alone
+    let end = -1;
+    let open = -1;
+    if (masked.startsWith('#!', itemStart)) {
+      kind = 'attribute';
+      end = matchBracket(masked, masked.indexOf('[', itemStart), to) + 1;
+    } else {
+      let depth = 0;
+      for (let j = declStart; j < to; j++) {
+        const ch = masked[j];
+        if (ch === '(' || ch === '[' || ch === '{') {
+          if (ch === '{' && depth === 0 && open === -1) open = j;
+          depth++;
+        } else if (ch === ')' || ch === ']' || ch === '}') {
+          depth--;
+          if (depth < 0) break;
+          if (ch === '}' && depth === 0 && !SEMICOLON_KINDS.has(kind) && open !== -1) {
+            end = j + 1;
+            break;
+          }
+        } else if (ch === ';' && depth === 0) {
+          end = j + 1;
+          break;
+        }
+      }
+    }
+    if (end <= itemStart) end = to;
+
+    const itemRow = lineAtOffset(starts, itemStart) - 1;
+    const endRow = lineAtOffset(starts, end - 1) - 1;
+    let startRow = itemRow;
+    // Docs and comments directly above the item belong to it; `//!` docs do not
+    while (startRow - 1 > previousRow) {
+      const above = lines[startRow - 1].trim();
+      if (!above || above.startsWith('//!') || above.startsWith('/*!')) break;
+      startRow--;
+    }
+
+    const closed = open !== -1 && masked[end - 1] === '}';
+    items.push({
+      kind,
+      name: itemName(kind, masked.slice(declStart + (match?.[0].length ?? 0), end), macro),
+      startRow,
+      endRow,
+      ...(closed && SPLIT_KINDS.has(kind) ? { body: { open, close: end - 1 } } : {}),
+    });
+    previousRow = endRow;
+    i = end;
+  }
+
+  return items;
+}
+
+function itemName(
+  kind: string,
+  rest: string,
+  macro: RegExpExecArray | null | undefined
+): string | undefined {
+  if (macro) return `${macro[1]}!`;
+  if (kind === 'impl') return implName(rest);
+  if (['use', 'extern', 'attribute', 'other'].includes(kind)) return undefined;
+  return IDENT.exec(rest)?.[1];
+}
+
+/**
+ * `<T> Trait<T> for Type<T> where ... {` -> `Trait<T> for Type<T>`, as tree-sitter names impls
+ */
+function implName(rest: string): string | undefined {
+  let text = rest.trimStart();
+  if (text.startsWith('<')) {
+    let depth = 0;
+    let i = 0;
+    for (; i < text.length; i++) {
+      if (text[i] === '<') depth++;
+      else if (text[i] === '>' && --depth === 0) break;
+    }
+    text = text.slice(i + 1);
+  }
+  const head = text
+    .split('{')[0]
+    .split(/\bwhere\b/)[0]
+    .replace(/\s+/g, ' ')
+    .trim();
+  return head || undefined;
+}
+
+// ===== Packing =====
+
+function packItems(
+  items: RustItemSpan[],
+  scope: ChunkScope,
+  masked: string,
+  starts: number[],
+  lines: string[],
+  maxChunkSize: number
+): RustChunk[] {
+  const chunks: RustChunk[] = [];
+  const overhead = scope.header.length + scope.footer.length + 2;
+  const budget = Math.max(maxChunkSize - overhead, Math.floor(maxChunkSize / 2));
+  let group: RustItemSpan[] = [];
+  let groupSize = 0;
+
+  const flush = () => {
+    if (group.length > 0) chunks.push(makeChunk(group, scope, lines));
+    group = [];
+    groupSize = 0;
+  };
+
+  for (const item of items) {
+    const size = itemText(item, lines).length;
+
+    if (DECLARATION_KINDS.has(item.kind) || (item.kind === 'mod' && !item.body)) {
+      if (group.length > 0 && groupSize + size + 1 > budget) flush();
+      group.push(item);
+      groupSize += size + 1;
+      continue;
+    }
+
+    flush();
+    if (size > budget && item.body) {
+      chunks.push(...splitBody(item, scope, masked, starts, lines, maxChunkSize));
+    } else {
+      chunks.push(makeChunk([item], scope, lines));
+    }
+  }
+  flush();
+
+  return chunks;
+}
+
+/**
+ * Chunk the members of an oversized impl/trait/mod, each wrapped in the block's header
+ */
+function splitBody(
+  item: RustItemSpan,
+  scope: ChunkScope,
+  masked: string,
+  starts: number[],
+  lines: string[],
+  maxChunkSize: number
+): RustChunk[] {
+  const { open, close } = item.body!;
+  const openRow = lineAtOffset(starts, open) - 1;
+  const closeRow = lineAtOffset(starts, close) - 1;
+  const members = scanItems(masked, open + 1, close, starts, lines, openRow);
+  if (members.length === 0) return [makeChunk([item], scope, lines)];
+
+  const header = lines.slice(item.startRow, openRow + 1).join('\n');
+  const closing = lines[closeRow].trim() === '}' ? lines[closeRow].trimEnd() : '}';
+  const owner = item.kind === 'impl' ? implOwner(item.name) : item.name;
+
+  return packItems(
+    members,
+    {
+      header: scope.header ? `${scope.header}\n${header}` : header,
+      footer: scope.footer ? `${closing}\n${scope.footer}` : closing,
+      owner: scope.owner && owner ? `${scope.owner}::${owner}` : owner,
+    },
+    masked,
+    starts,
+    lines,
+    maxChunkSize
+  );
+}
+
+function makeChunk(group: RustItemSpan[], scope: ChunkScope, lines: string[]): RustChunk {
+  const startRow = group[0].startRow;
+  const endRow = group[group.length - 1].endRow;
+  const body = lines.slice(startRow, endRow + 1).join('\n');
+  const names = group
+    .map(item => item.name)
+    .filter((name): name is string => !!name)
+    .map(name => (scope.owner ? `${scope.owner}::${name}` : name));
+  const imports = group.every(item => item.kind === 'use' || item.kind === 'extern crate');
+
+  return {
+    content: scope.header ? `${scope.header}\n${body}\n${scope.footer}` : body,
+    startLine: startRow + 1,
+    endLine: endRow + 1,
+    symbols: Array.from(new Set(names)),
+    type: imports ? 'import' : 'code',
+  };
+}
+
+/**
+ * `Display for Parser<'a>` -> `Parser`
+ */
+function implOwner(name: string | undefined): string | undefined {
+  const target = name?.split(' for ').pop();
+  return target?.split('<')[0].trim() || undefined;
+}
+
+function itemText(item: RustItemSpan, lines: string[]): string {
+  return lines.slice(item.startRow, item.endRow + 1).join('\n');
+}
+
+// ===== Helpers =====
+
+/**
+ * Skip outer attributes (`#[...]`) and whitespace, returning the offset of the declaration
+ */
+function skipAttributes(masked: string, from: number, to: number): number {
+  let i = from;
+  while (i < to && masked.startsWith('#[', i)) {
+    const close = matchBracket(masked, i + 1, to);
+    i = close + 1;
+    while (i < to && /\s/.test(masked[i])) i++;
+  }
+  return i;
+}
@@ -11,16 +11,19 @@
  *   - tree-sitter-typescript: TypeScript/TSX parsing
  *   - tree-sitter-javascript: JavaScript parsing
  *   - tree-sitter-python: Python parsing
+ *   - tree-sitter-rust: Rust parsing
  * @context: Provides robust AST parsing with graceful fallback to basic parsing when tree-sitter dependencies are unavailable, supporting multiple programming languages
  */
 
 import { logger } from '../utils/logger';
+import { extractRustItemDocs, extractRustModuleDocs } from '../core/compactor/rustDocs';
 
 // Optional tree-sitter import to avoid hard native dependency at runtime
 let Parser: any = null;
 let TypeScript: any = null;
 let JavaScript: any = null;
 let Python: any = null;
+let Rust: any = null;
 
 // Dynamic import for ESM-only tree-sitter packages
 async function initializeTreeSitterParsers() {
@@ -45,6 +48,11 @@ async function initializeTreeSitterParsers() {
       Python = pyModule.default;
     }
 
+    if (!Rust) {
+      const rsModule = await import('tree-sitter-rust');
+      Rust = rsModule.default;
+    }
+
     logger.info('✅ Tree-sitter parsers initialized successfully');
   } catch (error) {
     logger.warn('⚠️ Some tree-sitter parsers not available:', {
@@ -53,6 +61,27 @@ async function initializeTreeSitterParsers() {
   }
 }
 
+// Rust items that make meaningful standalone chunks
+const RUST_CHUNK_NODE_TYPES = new Set([
+  'function_item',
+  'struct_item',
+  'enum_item',
+  'union_item',
+  'trait_item',
+  'impl_item',
+  'mod_item',
+  'macro_definition',
+]);
+
+// Rust items reported as symbols (includes bodiless items such as trait method signatures)
+const RUST_SYMBOL_NODE_TYPES = new Set([
+  ...RUST_CHUNK_NODE_TYPES,
+  'function_signature_item',
+  'type_item',
+  'const_item',
+  'static_item',
+]);
+
 export interface CodeChunk {
   content: string;
   startLine: number;
@@ -134,6 +163,18 @@ export class TreeSitterProcessor {
           });
         }
       }
+
+      if (Rust) {
+        const rsParser = new Parser();
+        try {
+          rsParser.setLanguage(Rust);
+          this.parsers.set('rust', rsParser);
+        } catch (error) {
+          logger.warn('Failed to initialize Rust parser:', {
+            error: error instanceof Error ? error.message : String(error),
+          });
+        }
+      }
     } catch (error) {
       logger.warn('Failed to initialize some tree-sitter parsers:', {
         error: error instanceof Error ? error.message : String(error),
@@ -258,7 +299,8 @@ export class TreeSitterProcessor {
 
       // Validate root node with cross-language support
       const rootType = tree.rootNode.type;
-      const acceptedRootTypes = new Set(['program', 'module']); // JS/TS: program, Python: module
+      // JS/TS: program, Python: module, Rust: source_file
+      const acceptedRootTypes = new Set(['program', 'module', 'source_file']);
       if (!acceptedRootTypes.has(rootType)) {
         logger.warn('Unexpected root node type; continuing with cautious extraction', {
           filePath,
@@ -288,11 +330,20 @@ export class TreeSitterProcessor {
     const traverse = (node: any) => {
       try {
         if (this.isChunkableNode(node, language)) {
-          const startLine = node.startPosition?.row + 1;
+          let startLine = node.startPosition?.row + 1;
           const endLine = node.endPosition?.row + 1;
 
+          // Rust `///` docs and attributes are sibling nodes; keep them with their item
+          const rustDocs =
+            language === 'rust' && startLine
+              ? extractRustItemDocs(lines, node.startPosition.row)
+              : undefined;
+          if (rustDocs) startLine = rustDocs.startLine + 1;
+
           if (startLine && endLine && startLine <= endLine) {
-            const nodeContent = this.getNodeContent(node, lines);
+            const nodeContent = rustDocs
+              ? lines.slice(startLine - 1, endLine).join('\n')
+              : this.getNodeContent(node, lines);
 
             if (nodeContent && (endLine - startLine > 10 || nodeContent.length > 100)) {
               chunks.push({
@@ -300,7 +351,8 @@ export class TreeSitterProcessor {
                 startLine,
                 endLine,
                 tokenEstimate: this.estimateTokens(nodeContent),
-                symbolName: this.getSymbolName(node),
+                symbolName:
+                  language === 'rust' ? this.getRustSymbolName(node) : this.getSymbolName(node),
                 symbolType: node.type,
               });
             }
@@ -338,6 +390,20 @@ export class TreeSitterProcessor {
       return this.fallbackChunking(content, '');
     }
 
+    // Crate/module docs (`//!`) get their own chunk so the file's purpose is searchable
+    if (language === 'rust') {
+      const moduleDocs = extractRustModuleDocs(content);
+      if (moduleDocs?.text) {
+        chunks.unshift({
+          content: moduleDocs.text,
+          startLine: moduleDocs.startLine + 1,
+          endLine: moduleDocs.endLine + 1,
+          tokenEstimate: this.estimateTokens(moduleDocs.text),
+          symbolType: 'module_doc',
+        });
+      }
+    }
+
     return chunks;
   }
 
@@ -363,6 +429,10 @@ export class TreeSitterProcessor {
         );
       }
 
+      if (language === 'rust') {
+        return RUST_CHUNK_NODE_TYPES.has(node.type);
+      }
+
       return false;
     } catch (error) {
       logger.warn('Error checking if node is chunkable', {
@@ -474,7 +544,8 @@ export class TreeSitterProcessor {
         const startLine = node.startPosition.row + 1;
         const endLine = node.endPosition.row + 1;
         const nodeContent = this.getNodeContent(node, lines);
-        const symbolName = this.getSymbolName(node);
+        const symbolName =
+          language === 'rust' ? this.getRustSymbolName(node) : this.getSymbolName(node);
 
         if (symbolName) {
           symbols.push({
@@ -521,9 +592,105 @@ export class TreeSitterProcessor {
       ].includes(node.type);
     }
 
+    if (language === 'rust') {
+      return RUST_SYMBOL_NODE_TYPES.has(node.type);
+    }
+
     return false;
   }
 
+  /**
+   * Resolve the display name of a Rust item. Most items expose a `name` field; `impl`
+   * blocks have none, so they are named after their target (`Type` or `Trait for Type`).
+   */
+  private getRustSymbolName(node: any): string | undefined {
+    try {
+      if (!node) return undefined;
+
+      if (node.type === 'impl_item') {
+        const typeText = this.fieldText(node, 'type');
+        if (!typeText) return undefined;
+        const traitText = this.fieldText(node, 'trait');
+        return traitText ? `${traitText} for ${typeText}` : typeText;
+      }
+
+      const name = this.fieldText(node, 'name');
+      if (name) return name;
+
+      const nameNode = Array.isArray(node.children)
+        ? node.children.find(
+            (child: any) =>
+              child && (child.type === 'identifier' || child.type === 'type_identifier')
+          )
+        : undefined;
+      return nameNode?.text;
+    } catch (error) {
+      logger.warn('Error getting Rust symbol name', {
+        error: error instanceof Error ? error.message : String(error),
+      });
+      return undefined;
+    }
+  }
+
+  private fieldText(node: any, field: string): string | undefined {
+    if (!node || typeof node.childForFieldName !== 'function') return undefined;
+    const child = node.childForFieldName(field);
+    return child?.text || undefined;
+  }
+
+  /**
+   * Flatten a Rust `use` tree into the names it brings into scope together with their
+   * fully joined paths, e.g. `use a::{b, c::d as e}` yields `b` (a::b) and `e` (a::c::d).
+   */
+  private flattenRustUseTree(node: any, prefix: string): Array<{ name: string; path: string }> {
+    if (!node) return [];
+    const join = (text: string) => (prefix ? `${prefix}::${text}` : text);
+
+    switch (node.type) {
+      case 'identifier':
+      case 'self':
+      case 'crate':
+      case 'super':
+      case 'metavariable':
+        return [{ name: node.text, path: join(node.text) }];
+      case 'scoped_identifier': {
+        const name = this.fieldText(node, 'name') || node.text.split('::').pop() || node.text;
+        return [{ name, path: join(node.text) }];
+      }
+      case 'use_as_clause': {
+        const path = this.fieldText(node, 'path') || node.text;
+        const alias = this.fieldText(node, 'alias') || path.split('::').pop() || path;
+        return [{ name: alias, path: join(path) }];
+      }
+      case 'use_wildcard': {
+        const base = node.text.replace(/::\*$/, '').replace(/^\*$/, '');
+        return [{ name: '*', path: base ? join(base) : prefix }];
+      }
+      case 'scoped_use_list': {
+        const path = this.fieldText(node, 'path');
+        const list =
+          typeof node.childForFieldName === 'function' ? node.childForFieldName('list') : undefined;
+        return this.flattenRustUseTree(list, path ? join(path) : prefix);
+      }
+      case 'use_list': {
+        const results: Array<{ name: string; path: string }> = [];
+        for (const child of node.namedChildren || node.children || []) {
+          results.push(...this.flattenRustUseTree(child, prefix));
+        }
+        return results;
+      }
+      default:
+        return [];
+    }
+  }
+
+  private hasRustVisibility(node: any): boolean {
+    return (
+      Array.isArray(node?.children) &&
+      node.children.some((child: any) => child && child.type === 'visibility_modifier')
+    );
+  }
+
   private extractXRefs(tree: any, language: string): CodeXRef[] {
     const xrefs: CodeXRef[] = [];
     const traverse = (node: any) => {
@@ -583,6 +750,37 @@ export class TreeSitterProcessor {
             }
           } catch {}
         }
+      } else if (language === 'rust') {
+        // Rust: `use` trees (re-exports via `pub use`) and out-of-line `mod foo;` declarations
+        if (node.type === 'use_declaration') {
+          const kind: 'import' | 'export' = this.hasRustVisibility(node) ? 'export' : 'import';
+          const argument =
+            typeof node.childForFieldName === 'function'
+              ? node.childForFieldName('argument')
+              : undefined;
+          try {
+            for (const entry of this.flattenRustUseTree(argument, '')) {
+              xrefs.push({
+                name: entry.name,
+                kind,
+                startLine: node.startPosition.row + 1,
+                endLine: node.endPosition.row + 1,
+                targetPath: entry.path,
+              });
+            }
+          } catch {}
+        } else if (node.type === 'mod_item' && !this.fieldText(node, 'body')) {
+          const name = this.fieldText(node, 'name');
+          if (name) {
+            xrefs.push({
+              name,
+              kind: 'import',
+              startLine: node.startPosition.row + 1,
+              endLine: node.endPosition.row + 1,
+              targetPath: name,
+            });
+          }
+        }
       }
 
       if (node.children && Array.isArray(node.children)) {
@@ -8,7 +8,8 @@
  *   - OpenAIService: Direct OpenAI API integration
  *   - SemanticCompactor: Code structure analysis
  *   - ProjectHintsGenerator: Project pattern detection
- * @context: Provides AI-powered analysis of project architecture, technical debt, and improvement recommendations
+ * @context: Provides AI-powered analysis of project architecture, technical debt, and improvement recommendations.
+ *           Security analyses of Rust projects include a deterministic unsafe/FFI audit
  */
 
 import { SemanticCompactor } from '../../core/compactor/semanticCompactor';
@@ -19,6 +20,7 @@ import { getOpenAIService } from './aiSemanticCompact';
 import { createInsightsSystemPrompt, createInsightsUserPrompt } from './prompts/insightsPrompts';
 import { buildApiRequest } from './utils/tokenUtils';
 import { compileExcludePatterns, isExcludedPath } from '../utils/toolHelpers';
+import { buildUnsafeAuditSummary } from '../localTools/enhancedHints';
 
 export const aiProjectInsightsTool = {
   name: 'ai_project_insights',
@@ -173,7 +175,8 @@ export async function handleAIProjectInsights(args: any): Promise<any> {
     }
 
     // Get comprehensive project analysis
-    const [compactedProject, projectHints] = await Promise.all([
+    const includeUnsafeAudit = analysisType === 'security' || focusAreas.includes('security');
+    const [compactedProject, projectHints, unsafeAudit] = await Promise.all([
       new SemanticCompactor(analysisPath).compact(),
       new ProjectHintsGenerator().generateProjectHints(analysisPath, {
         format: 'json',
@@ -182,6 +185,7 @@ export async function handleAIProjectInsights(args: any): Promise<any> {
         useAI: false, // We'll do our own AI analysis
         excludePatterns,
       }),
+      includeUnsafeAudit ? buildUnsafeAuditSummary(analysisPath) : Promise.resolve(undefined),
     ]);
 
     // Clean up temporary directory if created
@@ -212,6 +216,20 @@ export async function handleAIProjectInsights(args: any): Promise<any> {
         complexity: f.nodes.length > 20 ? 'high' : f.nodes.length > 10 ? 'medium' : 'low',
       })),
       dependencies: [...new Set(compactedProject.files.flatMap(f => f.dependencies))],
+      // Rollups plus the sites lacking SAFETY comments; the full site list is in the result
+      ...(unsafeAudit
+        ? {
+            unsafeAudit: {
+              total: unsafeAudit.total,
+              unjustified: unsafeAudit.unjustified,
+              crates: unsafeAudit.crates,
+              missingSafetyComments: unsafeAudit.sites
+                .filter(site => !site.justified)
+                .slice(0, 40)
+                .map(site => `${site.file}:${site.line} ${site.kind} ${site.detail}`),
+            },
+          }
+        : {}),
     };
 
     // Create analysis prompt
@@ -285,6 +303,7 @@ export async function handleAIProjectInsights(args: any): Promise<any> {
         format: outputFormat,
       },
       projectOverview: analysisContext.overview,
+      ...(unsafeAudit ? { unsafeAudit } : {}),
       metadata: {
         tokenUsage: aiResponse.usage?.total_tokens || 0,
         processingTime: Date.now() - startTime,
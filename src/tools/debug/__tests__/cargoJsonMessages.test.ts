@@ -0,0 +1,139 @@
+/**
+ * @fileOverview: Unit tests for cargo --message-format=json ingestion
+ * @module: cargoJsonMessagesTests
+ * @description: Verifies stream detection, exact spans and codes, children, dedupe and ranking
+ */
+
+import { describe, it, expect } from '@jest/globals';
+import { isCargoJsonStream, parseCargoJsonMessages } from '../cargoJsonMessages';
+
+const REGISTRY = '/home/u/.cargo/registry/src/index.crates.io-6f17d22bba15001f';
+
+const span = (file: string, line: number, column: number, extra: object = {}) => ({
+  file_name: file,
+  line_start: line,
+  column_start: column,
+  is_primary: true,
+  ...extra,
+});
+
+const message = (target: string, diagnostic: object) =>
+  JSON.stringify({
+    reason: 'compiler-message',
+    package_id: 'demo 0.1.0 (path+file:///work/demo)',
+    target: { name: target },
+    message: diagnostic,
+  });
+
+const NEEDLESS_RETURN = {
+  message: 'unneeded `return` statement',
+  code: { code: 'clippy::needless_return' },
+  level: 'warning',
+  spans: [span('src/lib.rs', 7, 5)],
+  children: [
+    { message: '`#[warn(clippy::needless_return)]` on by default', level: 'note', spans: [] },
+    {
+      message: 'remove `return`',
+      level: 'help',
+      spans: [span('src/lib.rs', 7, 5, { suggested_replacement: 'total' })],
+    },
+  ],
+  rendered: 'warning: unneeded `return` statement\n --> src/lib.rs:7:5\n',
+};
+
+const PEDANTIC = {
+  message: 'item in documentation is missing backticks',
+  code: { code: 'clippy::doc_markdown' },
+  level: 'warning',
+  spans: [span('src/lib.rs', 1, 5)],
+  children: [
+    {
+      message: '`-W clippy::doc-markdown` implied by `-W clippy::pedantic`',
+      level: 'note',
+      spans: [],
+    },
+  ],
+};
+
+const MISMATCH = {
+  message: 'mismatched types',
+  code: { code: 'E0308' },
+  level: 'error',
+  spans: [
+    span('src/main.rs', 4, 4, { is_primary: false, label: 'function defined here' }),
+    span('src/main.rs', 12, 18, { label: 'expected `u32`, found `String`' }),
+  ],
+  children: [{ message: 'try using a conversion method', level: 'help', spans: [] }],
+};
+
+const MACRO_ERROR = {
+  message: 'cannot find value `config_path` in this scope',
+  code: { code: 'E0425' },
+  level: 'error',
+  spans: [
+    span(`${REGISTRY}/lazy_static-1.4.0/src/lib.rs`, 20, 9, {
+      expansion: { span: span('src/config.rs', 8, 1), macro_decl_name: 'lazy_static!' },
+    }),
+  ],
+  children: [],
+};
+
+const STREAM = [
+  message('demo', NEEDLESS_RETURN),
+  message('demo', PEDANTIC),
+  message('demo', MISMATCH),
+  // Same warning again when the test target compiles the file
+  message('demo(test)', NEEDLESS_RETURN),
+  message('demo', MACRO_ERROR),
+  message('demo', {
+    message: 'aborting due to 2 previous errors',
+    code: null,
+    level: 'error',
+    spans: [],
+    children: [],
+  }),
+  JSON.stringify({ reason: 'build-finished', success: false }),
+].join('\n');
+
+describe('isCargoJsonStream', () => {
+  it('detects cargo and bare rustc JSON output', () => {
+    expect(isCargoJsonStream(STREAM)).toBe(true);
+    expect(isCargoJsonStream(JSON.stringify(MISMATCH))).toBe(true);
+    expect(isCargoJsonStream('error[E0308]: mismatched types\n --> src/main.rs:12:18')).toBe(false);
+  });
+});
+
+describe('parseCargoJsonMessages', () => {
+  const errors = parseCargoJsonMessages(STREAM);
+
+  it('dedupes diagnostics reported for several targets and drops spanless summaries', () => {
+    expect(errors).toHaveLength(4);
+  });
+
+  it('ranks errors first, then warnings by lint group', () => {
+    expect(errors.map(e => e.errorType)).toEqual([
+      'E0308',
+      'E0425',
+      'clippy::needless_return',
+      'clippy::doc_markdown',
+    ]);
+    expect(errors[2].lintGroup).toBe('clippy');
+    expect(errors[3].lintGroup).toBe('pedantic');
+  });
+
+  it('uses the primary span and children of each message', () => {
+    expect(errors[0]).toMatchObject({
+      filePath: 'src/main.rs',
+      line: 12,
+      column: 18,
+      severity: 'error',
+      raw: 'error[E0308]: mismatched types',
+      notes: ['help: try using a conversion method'],
+    });
+    expect(errors[2].notes).toContain('help: remove `return` `total`');
+  });
+
+  it('maps spans inside external macros to the invocation site', () => {
+    expect(errors[1]).toMatchObject({ filePath: 'src/config.rs', line: 8, symbol: 'config_path' });
+  });
+});
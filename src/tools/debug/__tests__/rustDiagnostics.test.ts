@@ -0,0 +1,109 @@
+/**
+ * @fileOverview: Unit tests for rustc diagnostic parsing
+ * @module: rustDiagnosticsTests
+ * @description: Verifies error/warning headers, spans, note/help children and macro invocation sites
+ */
+
+import { describe, it, expect } from '@jest/globals';
+import { parseRustcDiagnostics } from '../rustDiagnostics';
+
+const CARGO_OUTPUT = [
+  '   Compiling demo v0.1.0 (/work/demo)',
+  'warning: unused variable: `count`',
+  ' --> src/lib.rs:3:9',
+  '  |',
+  '3 |     let count = 1;',
+  '  |         ^^^^^ help: if this is intentional, prefix it with an underscore: `_count`',
+  '  |',
+  '  = note: `#[warn(unused_variables)]` on by default',
+  '',
+  'error[E0308]: mismatched types',
+  '  --> src/main.rs:12:18',
+  '   |',
+  '12 |     let n: u32 = total();',
+  '   |            ---   ^^^^^^^ expected `u32`, found `String`',
+  '   |            |',
+  '   |            expected due to this',
+  '   |',
+  'note: function defined here',
+  '  --> src/main.rs:4:4',
+  '   |',
+  '4  | fn total() -> String {',
+  '   |    ^^^^^',
+  'help: try using a conversion method',
+  '   |',
+  '12 |     let n: u32 = total().parse().unwrap();',
+  '   |                         ++++++++++++++++',
+  '',
+  'error[E0425]: cannot find value `config_path` in this scope',
+  '  --> /home/u/.cargo/registry/src/index.crates.io-6f17d22bba15001f/lazy_static-1.4.0/src/lib.rs:20:9',
+  '   |',
+  '20 |         $name',
+  '   |         ^^^^^ not found',
+  '   |',
+  '  ::: src/config.rs:8:1',
+  '   |',
+  '8  | / lazy_static! {',
+  '9  | |     static ref CFG: Config = load(config_path);',
+  '10 | | }',
+  '   | |_- in this macro invocation',
+  '',
+  'error: expected expression, found `)`',
+  ' --> src/report.rs:5:23',
+  '  |',
+  '5 |     println!("{}", x, );',
+  '  |     ------------------^- in this macro invocation',
+  '',
+  'error: aborting due to 3 previous errors; 1 warning emitted',
+  '',
+  'Some errors have detailed explanations: E0308, E0425.',
+  'error: could not compile `demo` (bin "demo") due to 3 previous errors; 1 warning emitted',
+].join('\n');
+
+describe('parseRustcDiagnostics', () => {
+  const diagnostics = parseRustcDiagnostics(CARGO_OUTPUT);
+
+  it('parses one entry per diagnostic and skips build summaries', () => {
+    expect(diagnostics.map(d => d.errorType)).toEqual(['warning', 'E0308', 'E0425', 'error']);
+  });
+
+  it('reads severity, primary span and referenced symbol', () => {
+    expect(diagnostics[0]).toMatchObject({
+      filePath: 'src/lib.rs',
+      line: 3,
+      column: 9,
+      severity: 'warning',
+      symbol: 'count',
+    });
+    expect(diagnostics[1]).toMatchObject({
+      filePath: 'src/main.rs',
+      line: 12,
+      column: 18,
+      severity: 'error',
+      raw: 'error[E0308]: mismatched types',
+    });
+    expect(diagnostics[1].symbol).toBeUndefined();
+  });
+
+  it('collects note and help children', () => {
+    expect(diagnostics[0].notes).toEqual(['note: `#[warn(unused_variables)]` on by default']);
+    expect(diagnostics[1].notes).toEqual([
+      'note: function defined here',
+      'help: try using a conversion method',
+    ]);
+  });
+
+  it('reports errors inside external macros at the invocation site', () => {
+    expect(diagnostics[2]).toMatchObject({
+      filePath: 'src/config.rs',
+      line: 8,
+      symbol: 'config_path',
+    });
+    expect(diagnostics[3]).toMatchObject({
+      filePath: 'src/report.rs',
+      line: 5,
+      column: 23,
+      expansion: { filePath: 'src/report.rs', line: 5, column: 5 },
+    });
+  });
+});
@@ -0,0 +1,80 @@
+/**
+ * @fileOverview: Unit tests for Rust panic and backtrace parsing
+ * @module: rustPanicsTests
+ * @description: Verifies panic location/message, frame demangling and runtime frame filtering
+ */
+
+import { describe, it, expect } from '@jest/globals';
+import { parseRustPanics, isRustTraceLine } from '../rustPanics';
+
+const RUSTC = '/rustc/90b35a6239c3d8bdabc530a6a0816f7ff89a0aaf/library';
+const REGISTRY = '/home/u/.cargo/registry/src/index.crates.io-6f17d22bba15001f';
+
+const PANIC_OUTPUT = [
+  "thread 'main' panicked at src/handler.rs:88:14:",
+  'called `Option::unwrap()` on a `None` value',
+  'stack backtrace:',
+  '   0: rust_begin_unwind',
+  `             at ${RUSTC}/std/src/panicking.rs:645:5`,
+  '   1: core::option::unwrap_failed',
+  `             at ${RUSTC}/core/src/option.rs:1985:5`,
+  '   2: demo::handler::load_user',
+  '             at ./src/handler.rs:88:14',
+  '   3: demo::handler::handle::{{closure}}',
+  '             at ./src/handler.rs:40:9',
+  '   4: tokio::runtime::park::CachedParkThread::block_on::{{closure}}',
+  `             at ${REGISTRY}/tokio-1.35.1/src/runtime/park.rs:282:63`,
+  '   5: demo::main',
+  '             at ./src/main.rs:5:5',
+  '   6: core::ops::function::FnOnce::call_once',
+  `             at ${RUSTC}/core/src/ops/function.rs:250:5`,
+  'note: Some details are omitted, run with `RUST_BACKTRACE=full` for a verbose backtrace.',
+].join('\n');
+
+describe('parseRustPanics', () => {
+  it('reads the panic location and message', () => {
+    const [panic] = parseRustPanics(PANIC_OUTPUT);
+    expect(panic).toMatchObject({
+      filePath: 'src/handler.rs',
+      line: 88,
+      column: 14,
+      symbol: 'load_user',
+      errorType: 'panic',
+      message: 'called `Option::unwrap()` on a `None` value',
+    });
+  });
+
+  it('keeps only workspace frames from the backtrace', () => {
+    const frames = parseRustPanics(PANIC_OUTPUT).slice(1);
+    expect(frames.map(f => [f.filePath, f.line, f.symbol])).toEqual([
+      ['src/handler.rs', 40, 'handle'],
+      ['src/main.rs', 5, 'main'],
+    ]);
+  });
+
+  it('moves panics raised in dependencies to the first workspace frame', () => {
+    const errors = parseRustPanics(
+      [
+        "thread 'tokio-runtime-worker' panicked at 'index out of bounds', " +
+          `${REGISTRY}/serde_json-1.0.0/src/de.rs:10:5`,
+        'stack backtrace:',
+        '   0:     0x55d4c1b2c3d4 - std::panicking::begin_panic::h9f8e7d6c5b4a3921',
+        '   1:     0x55d4c1b2c3d4 - _ZN4demo6config4load17h0123456789abcdefE',
+        '                               at /work/demo/src/config.rs:21:13',
+      ].join('\n')
+    );
+    expect(errors).toHaveLength(1);
+    expect(errors[0]).toMatchObject({
+      filePath: '/work/demo/src/config.rs',
+      line: 21,
+      symbol: 'load',
+      message: 'index out of bounds',
+    });
+  });
+
+  it('recognises panic lines so the generic stack parser can skip them', () => {
+    expect(isRustTraceLine("thread 'main' panicked at src/handler.rs:88:14:")).toBe(true);
+    expect(isRustTraceLine('             at ./src/main.rs:5:5')).toBe(true);
+    expect(isRustTraceLine('    at handler (src/handler.js:3:7)')).toBe(false);
+  });
+});
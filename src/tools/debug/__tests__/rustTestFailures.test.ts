@@ -0,0 +1,105 @@
+/**
+ * @fileOverview: Unit tests for cargo test failure parsing
+ * @module: rustTestFailuresTests
+ * @description: Verifies failing test sections, assertion diffs, module grouping and file candidates
+ */
+
+import { describe, it, expect } from '@jest/globals';
+import {
+  parseCargoTestFailures,
+  groupTestFailuresByModule,
+  testFileCandidates,
+} from '../rustTestFailures';
+
+const CARGO_TEST_OUTPUT = [
+  '     Running unittests src/lib.rs (target/debug/deps/demo-1a2b3c)',
+  '',
+  'running 4 tests',
+  'test math::tests::adds ... ok',
+  'test math::tests::subtracts ... FAILED',
+  'test tests::it_works ... FAILED',
+  'test parser::tests::rejects_empty ... FAILED',
+  '',
+  'failures:',
+  '',
+  '---- math::tests::subtracts stdout ----',
+  "thread 'math::tests::subtracts' panicked at src/math.rs:30:9:",
+  'assertion `left == right` failed: subtraction is off',
+  '  left: 1',
+  ' right: 2',
+  'note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace',
+  '',
+  '---- tests::it_works stdout ----',
+  "thread 'tests::it_works' panicked at src/lib.rs:12:9:",
+  'assertion failed: total > 3',
+  '',
+  'failures:',
+  '    math::tests::subtracts',
+  '    parser::tests::rejects_empty',
+  '    tests::it_works',
+  '',
+  'test result: FAILED. 1 passed; 3 failed; 0 ignored; 0 measured; 0 filtered out',
+  '',
+  '     Running tests/api.rs (target/debug/deps/api-4d5e6f)',
+  '',
+  '---- creates_user stdout ----',
+  "thread 'creates_user' panicked at 'assertion failed: `(left == right)`",
+  '  left: `"bob"`,',
+  " right: `\"alice\"`', tests/api.rs:9:5",
+  '',
+  'failures:',
+  '    creates_user',
+].join('\n');
+
+describe('parseCargoTestFailures', () => {
+  const failures = parseCargoTestFailures(CARGO_TEST_OUTPUT);
+  const byPath = (testPath: string) => failures.find(f => f.testPath === testPath);
+
+  it('creates one entry per failing test, including tests without output', () => {
+    expect(failures.map(f => f.testPath)).toEqual([
+      'math::tests::subtracts',
+      'tests::it_works',
+      'parser::tests::rejects_empty',
+      'creates_user',
+    ]);
+  });
+
+  it('reads the panic location and assertion diff', () => {
+    expect(byPath('math::tests::subtracts')).toMatchObject({
+      testName: 'subtracts',
+      module: 'math',
+      target: 'src/lib.rs',
+      panic: { filePath: 'src/math.rs', line: 30, column: 9 },
+      assertion: { kind: 'eq', left: '1', right: '2', message: 'subtraction is off' },
+    });
+    expect(byPath('tests::it_works')?.assertion).toEqual({
+      kind: 'assert',
+      expression: 'total > 3',
+    });
+    expect(byPath('creates_user')).toMatchObject({
+      panic: { filePath: 'tests/api.rs', line: 9 },
+      assertion: { kind: 'eq', left: '"bob"', right: '"alice"' },
+    });
+  });
+
+  it('groups failures by the module under test', () => {
+    expect(
+      groupTestFailuresByModule(failures).map(group => [group.module, group.failures.length])
+    ).toEqual([
+      ['crate', 1],
+      ['math', 1],
+      ['parser', 1],
+      ['tests/api.rs', 1],
+    ]);
+  });
+
+  it('derives candidate files from the panic location and module path', () => {
+    expect(testFileCandidates(byPath('math::tests::subtracts')!)).toEqual([
+      'src/math.rs',
+      'src/math/tests.rs',
+      'src/math/tests/mod.rs',
+      'src/math/mod.rs',
+      'src/lib.rs',
+    ]);
+  });
+});
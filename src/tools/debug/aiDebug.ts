@@ -131,6 +131,22 @@ function createDebugAnalysisPrompt(
     )
     .join('\\n---\\n');
 
+  const failingTests = (debugContext.testFailures ?? [])
+    .flatMap(group =>
+      group.failures.map(f => {
+        const location = f.filePath ? ` (${f.filePath}:${f.line})` : '';
+        const diff =
+          f.assertion?.left !== undefined
+            ? ` left: ${f.assertion.left} right: ${f.assertion.right}`
+            : f.message
+              ? ` ${f.message.split('\n')[0]}`
+              : '';
+        const calls = f.calls?.length ? ` calls: ${f.calls.map(c => c.name).join(', ')}` : '';
+        return `[${group.module}] ${f.testPath}${location}${diff}${calls}`;
+      })
+    )
+    .join('\n');
+
   const analysisInstructions = {
     comprehensive:
       'Provide a complete analysis including root cause, fix suggestions, and prevention strategies.',
@@ -143,7 +159,7 @@ function createDebugAnalysisPrompt(
 
 ## Error Summary
 ${errorSummary}
-
+${failingTests ? `\n## Failing Tests\n${failingTests}\n` : ''}
 ## Code Context (Top Matches)
 ${topMatches}
 
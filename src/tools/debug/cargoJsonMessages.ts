@@ -0,0 +1,164 @@
+/**
+ * @fileOverview: cargo / clippy `--message-format=json` ingestion for local debug context
+ * @module: CargoJsonMessages
+ * @keyFunctions:
+ *   - isCargoJsonStream(): Detect NDJSON compiler message streams
+ *   - parseCargoJsonMessages(): Turn compiler messages into deduplicated, ranked ParsedErrors
+ * @context: Uses the exact spans, codes, levels and children of each diagnostic instead of
+ *           scraping the rendered text. Accepts cargo's `compiler-message` envelopes and bare
+ *           rustc `--error-format=json` diagnostics
+ */
+
+import type { ParsedError } from './localDebugContext';
+import { isToolchainPath, referencedSymbol } from './rustDiagnostics';
+
+interface JsonSpan {
+  file_name: string;
+  line_start: number;
+  column_start: number;
+  is_primary: boolean;
+  label?: string | null;
+  suggested_replacement?: string | null;
+  expansion?: { span: JsonSpan; macro_decl_name?: string } | null;
+}
+
+interface JsonDiagnostic {
+  message: string;
+  code?: { code: string } | null;
+  level: string;
+  spans: JsonSpan[];
+  children?: JsonDiagnostic[];
+  rendered?: string | null;
+}
+
+const LEVEL_RANK: Record<string, number> = { error: 10, warning: 1 };
+
+// Clippy lint groups by how likely they point at a real bug. `clippy` is a warn-by-default lint
+// whose group the notes don't name; `rustc` and `unused` are compiler lints
+const LINT_GROUP_RANK: Record<string, number> = {
+  correctness: 6,
+  suspicious: 4,
+  perf: 3,
+  complexity: 2,
+  rustc: 2,
+  style: 1,
+  clippy: 1,
+  unused: 0.75,
+  pedantic: 0.5,
+  nursery: 0.5,
+  cargo: 0.5,
+  restriction: 0.5,
+};
+const CLIPPY_GROUPS =
+  'correctness|suspicious|perf|complexity|style|pedantic|nursery|cargo|restriction';
+
+/**
+ * True when the log is a cargo or rustc JSON message stream rather than terminal text
+ */
+export function isCargoJsonStream(logText: string): boolean {
+  const first = logText.split(/\r?\n/).find(line => line.trim() !== '');
+  return !!first && /^\s*\{.*"(?:reason|\$message_type|spans)"\s*:/.test(first);
+}
+
+/**
+ * Parse `cargo build|check|clippy --message-format=json` output
+ */
+export function parseCargoJsonMessages(logText: string): ParsedError[] {
+  const seen = new Set<string>();
+  const ranked: Array<{ error: ParsedError; rank: number }> = [];
+
+  for (const line of logText.split(/\r?\n/)) {
+    if (!line.trim().startsWith('{')) continue;
+    let record: any;
+    try {
+      record = JSON.parse(line);
+    } catch {
+      continue; // interleaved non-JSON output or a truncated artifact
+    }
+
+    const diagnostic: JsonDiagnostic | undefined =
+      record.reason === 'compiler-message' ? record.message : record.spans ? record : undefined;
+    if (!diagnostic || !(diagnostic.level in LEVEL_RANK)) continue;
+
+    const primary = diagnostic.spans.find(span => span.is_primary);
+    if (!primary) continue; // "aborting due to ..." and warning counts carry no span
+
+    // The same diagnostic is reported once per target (lib, bin, tests) that compiles the file
+    const key = [
+      diagnostic.level,
+      diagnostic.code?.code,
+      diagnostic.message,
+      primary.file_name,
+      primary.line_start,
+      primary.column_start,
+    ].join('\0');
+    if (seen.has(key)) continue;
+    seen.add(key);
+
+    const lintGroup = lintGroupOf(diagnostic);
+    ranked.push({
+      error: toParsedError(diagnostic, primary, lintGroup),
+      rank: LEVEL_RANK[diagnostic.level] + (lintGroup ? (LINT_GROUP_RANK[lintGroup] ?? 0) : 0),
+    });
+  }
+
+  // Stable sort keeps compiler order within the same rank
+  return ranked.sort((a, b) => b.rank - a.rank).map(entry => entry.error);
+}
+
+function toParsedError(
+  diagnostic: JsonDiagnostic,
+  primary: JsonSpan,
+  lintGroup: string | undefined
+): ParsedError {
+  // Follow macro expansions out to the outermost invocation in the workspace
+  let site: JsonSpan | undefined;
+  for (let span = primary.expansion?.span; span; span = span.expansion?.span) {
+    if (!isToolchainPath(span.file_name)) site = span;
+  }
+  const location = site && isToolchainPath(primary.file_name) ? site : primary;
+
+  const code = diagnostic.code?.code;
+  const header = `${diagnostic.level}${code ? `[${code}]` : ''}: ${diagnostic.message}`;
+  const notes = (diagnostic.children ?? []).map(child => {
+    const replacement = child.spans.find(span => span.suggested_replacement)?.suggested_replacement;
+    return `${child.level}: ${child.message}${replacement ? ` \`${replacement}\`` : ''}`;
+  });
+
+  return {
+    filePath: location.file_name,
+    line: location.line_start,
+    column: location.column_start,
+    symbol: referencedSymbol(diagnostic.message),
+    errorType: code ?? diagnostic.level,
+    severity: diagnostic.level as 'error' | 'warning',
+    raw: header,
+    errorContext: (diagnostic.rendered ?? header).replace(/\s+/g, ' ').trim().substring(0, 200),
+    startLine: location.line_start,
+    endLine: location.line_start,
+    ...(notes.length > 0 ? { notes } : {}),
+    ...(site && location !== site
+      ? {
+          expansion: { filePath: site.file_name, line: site.line_start, column: site.column_start },
+        }
+      : {}),
+    ...(lintGroup ? { lintGroup } : {}),
+  };
+}
+
+/**
+ * Clippy group from the "implied by `-W clippy::pedantic`" / `#[warn(clippy::style)]` notes,
+ * `clippy` when the note only names the lint, and `unused`/`rustc` for compiler lints
+ */
+function lintGroupOf(diagnostic: JsonDiagnostic): string | undefined {
+  const code = diagnostic.code?.code;
+  if (!code || /^E\d{4}$/.test(code)) return undefined;
+
+  const group = new RegExp(`clippy::(${CLIPPY_GROUPS})\\b`);
+  for (const child of diagnostic.children ?? []) {
+    const match = group.exec(child.message);
+    if (match) return match[1];
+  }
+  if (code.startsWith('clippy::')) return 'clippy';
+  return code.startsWith('unused') ? 'unused' : 'rustc';
+}
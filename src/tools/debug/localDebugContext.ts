@@ -16,12 +16,25 @@ import { validateAndResolvePath } from '../utils/pathUtils';
 import { LocalEmbeddingGenerator, GenerationOptions } from '../../local/embeddingGenerator';
 import { LocalEmbeddingStorage, SimilarChunk } from '../../local/embeddingStorage';
 import { ProjectIdentifier } from '../../local/projectIdentifier';
+import { parseRustcDiagnostics } from './rustDiagnostics';
+import { isCargoJsonStream, parseCargoJsonMessages } from './cargoJsonMessages';
+import { isRustTraceLine, parseRustPanics } from './rustPanics';
+import {
+  CargoTestFailure,
+  CargoTestFailureGroup,
+  TestCall,
+  groupTestFailuresByModule,
+  parseCargoTestFailures,
+  testFileCandidates,
+} from './rustTestFailures';
+import { maskRustSource } from '../localTools/utils/rustSource';
 
 // Optional tree-sitter imports to avoid hard dependency at runtime
 let Parser: any = null;
 let TypeScriptLang: any = null;
 let JavaScriptLang: any = null;
 let PythonLang: any = null;
+let RustLang: any = null;
 
 // Dynamic import for ESM-only tree-sitter packages
 async function initializeTreeSitter() {
@@ -46,6 +59,11 @@ async function initializeTreeSitter() {
       PythonLang = pyModule.default;
     }
 
+    if (!RustLang) {
+      const rsModule = await import('tree-sitter-rust');
+      RustLang = rsModule.default;
+    }
+
     logger.info('✅ Tree-sitter parsers loaded successfully');
   } catch (error) {
     logger.warn('⚠️ Tree-sitter parsers not available, falling back to basic parsing', {
@@ -70,6 +88,11 @@ export interface ParsedError {
   errorContext?: string; // Focused context for embedding queries
   startLine: number;
   endLine: number;
+  severity?: 'error' | 'warning'; // rustc diagnostics and panics
+  message?: string; // Rust panic message
+  notes?: string[]; // rustc `note:` / `help:` children
+  expansion?: { filePath: string; line: number; column?: number }; // macro invocation site
+  lintGroup?: string; // clippy lint group or rustc lint family from JSON diagnostics
 }
 
 export interface SymbolInfo {
@@ -108,6 +131,7 @@ export interface DebugContextReport {
     similarChunksFound?: number;
     suggestions?: string[];
   };
+  testFailures?: CargoTestFailureGroup[]; // failing `cargo test` tests grouped by module under test
 }
 
 /**
@@ -125,8 +149,11 @@ export const localDebugContextTool = {
 
 **What this does**:
 - Parses error logs to extract file paths, line numbers, symbols, and error types
+- Understands rustc/cargo diagnostics (error[E0308], warnings, note/help, macro invocations)
+- Parses Rust panics and RUST_BACKTRACE frames, skipping std/core/tokio and ~/.cargo frames
+- Turns \`cargo test\` failures into entries with assertion diffs and the code under test
 - Extracts focused error contexts (~200 characters) for precise embedding queries
-- Uses tree-sitter to build symbol indexes for TypeScript/JavaScript/Python files
+- Uses tree-sitter to build symbol indexes for TypeScript/JavaScript/Python/Rust files
 - Searches codebase for symbol matches with surrounding context
 - **ENHANCED**: Uses semantic embeddings with focused error contexts for better relevance
 - Processes each error/warning separately for improved semantic matching
@@ -163,6 +190,13 @@ export const localDebugContextTool = {
         default: 'structured',
         description: 'Output format preference',
       },
+      inputFormat: {
+        type: 'string',
+        enum: ['auto', 'text', 'cargo-json'],
+        default: 'auto',
+        description:
+          'How to read logText: terminal text, or `cargo build|check|clippy --message-format=json` output. auto detects JSON streams',
+      },
       useEmbeddings: {
         type: 'boolean',
         default: true,
@@ -199,12 +233,19 @@ export const localDebugContextTool = {
  * Parse terminal or log output to extract file paths, line numbers and error types.
  * Enhanced to extract focused error contexts (next 200 characters) for better embedding queries.
  */
-function parseErrorLogs(logText: string): ParsedError[] {
-  const errors: ParsedError[] = [];
+function parseErrorLogs(logText: string, inputFormat: string = 'auto'): ParsedError[] {
+  if (inputFormat === 'cargo-json' || (inputFormat === 'auto' && isCargoJsonStream(logText))) {
+    return parseCargoJsonMessages(logText);
+  }
+
+  const errors: ParsedError[] = [...parseRustcDiagnostics(logText), ...parseRustPanics(logText)];
   const lines = logText.split(/\r?\n/);
   let currentType: string | undefined;
 
   for (const line of lines) {
+    // Already handled by parseRustPanics; the Node pattern would also match these
+    if (isRustTraceLine(line)) continue;
+
     const typeMatch = line.match(/^\s*([A-Za-z]*Error):/);
     if (typeMatch) {
       currentType = typeMatch[1];
@@ -322,6 +363,9 @@ async function buildSymbolIndex(filePath: string): Promise<SymbolInfo[]> {
     case '.py':
       language = PythonLang;
       break;
+    case '.rs':
+      language = RustLang;
+      break;
     default:
       return [];
   }
@@ -346,6 +390,12 @@ async function buildSymbolIndex(filePath: string): Promise<SymbolInfo[]> {
       'class_declaration',
       'class_definition',
       'function_definition',
+      'function_item',
+      'struct_item',
+      'enum_item',
+      'union_item',
+      'trait_item',
+      'macro_definition',
     ];
 
     if (symbolTypes.includes(node.type)) {
@@ -367,6 +417,145 @@ async function buildSymbolIndex(filePath: string): Promise<SymbolInfo[]> {
   return symbols;
 }
 
+/**
+ * Rust symbol index with a brace-matching fallback for when tree-sitter is unavailable
+ */
+async function buildRustSymbolIndex(filePath: string): Promise<SymbolInfo[]> {
+  const symbols = await buildSymbolIndex(filePath);
+  if (symbols.length > 0) return symbols;
+
+  const { masked } = maskRustSource(await fs.readFile(filePath, 'utf8'));
+  const lineAt = (offset: number) => masked.slice(0, offset).split('\n').length;
+  for (const match of masked.matchAll(/\b(fn|struct|enum|union|trait)\s+(\w+)/g)) {
+    const open = masked.slice(match.index!).search(/[{;]/) + match.index!;
+    let end = open;
+    if (masked[open] === '{') {
+      for (let depth = 0; end < masked.length; end++) {
+        if (masked[end] === '{') depth++;
+        else if (masked[end] === '}' && --depth === 0) break;
+      }
+    }
+    symbols.push({
+      name: match[2],
+      type: match[1] === 'fn' ? 'function_item' : `${match[1]}_item`,
+      startLine: lineAt(match.index!),
+      endLine: lineAt(Math.max(end, match.index!)),
+    });
+  }
+  return symbols;
+}
+
+/**
+ * Locate failing Rust tests through the symbol index and collect the workspace functions they call
+ */
+async function locateTestFailures(failures: CargoTestFailure[], projectPath: string) {
+  const indexes = new Map<string, SymbolInfo[]>();
+  const indexFor = async (relPath: string): Promise<SymbolInfo[]> => {
+    let symbols = indexes.get(relPath);
+    if (!symbols) {
+      const absPath = path.resolve(projectPath, relPath);
+      symbols = fsSync.existsSync(absPath) ? await buildRustSymbolIndex(absPath) : [];
+      indexes.set(relPath, symbols);
+    }
+    return symbols;
+  };
+  const findTest = async (files: string[], name: string) => {
+    for (const file of files) {
+      const symbol = (await indexFor(file)).find(
+        s => s.name === name && s.type === 'function_item'
+      );
+      if (symbol) return { file, symbol };
+    }
+    return undefined;
+  };
+  const sources = new Map<string, string>();
+  const sourceOf = async (relPath: string): Promise<string> => {
+    let source = sources.get(relPath);
+    if (source === undefined) {
+      source = await fs.readFile(path.resolve(projectPath, relPath), 'utf8');
+      sources.set(relPath, source);
+    }
+    return source;
+  };
+  // Every Rust file of the project, listed and read once for all failures that need it
+  let rustFiles: string[] | null = null;
+
+  for (const failure of failures) {
+    if (failure.filePath) continue; // doctests carry their own location
+
+    const candidates = testFileCandidates(failure);
+    let found = await findTest(candidates, failure.testName);
+    if (!found) {
+      if (!rustFiles) {
+        rustFiles = await globby(['**/*.rs'], {
+          cwd: projectPath,
+          ignore: ['target/**', '.git/**'],
+        });
+        await Promise.all(rustFiles.map(sourceOf));
+      }
+      const declares = new RegExp(`\\bfn\\s+${escapeRegExp(failure.testName)}\\b`);
+      const declaring: string[] = [];
+      for (const file of rustFiles) {
+        if (declares.test(await sourceOf(file))) declaring.push(file);
+      }
+      found = await findTest(declaring, failure.testName);
+    }
+    if (!found) continue;
+
+    failure.filePath = found.file;
+    failure.line = found.symbol.startLine;
+
+    const lookup = [found.file, ...candidates.filter(c => c !== found!.file)];
+    const source = await sourceOf(found.file);
+    const body = maskRustSource(source)
+      .masked.split('\n')
+      .slice(found.symbol.startLine, found.symbol.endLine)
+      .join('\n');
+    failure.calls = await calledFunctions(body, failure, lookup, indexFor);
+  }
+}
+
+function escapeRegExp(value: string): string {
+  return value.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+}
+
+/**
+ * Calls in a test body that resolve to functions in the test's module chain
+ */
+async function calledFunctions(
+  body: string,
+  failure: CargoTestFailure,
+  lookup: string[],
+  indexFor: (relPath: string) => Promise<SymbolInfo[]>
+): Promise<TestCall[]> {
+  const calls: TestCall[] = [];
+  const seen = new Set<string>();
+  const modules = new Set(['crate', 'super', 'self', 'Self', ...failure.testPath.split('::')]);
+
+  for (const match of body.matchAll(/((?:\w+::)*\w+)\s*(?:::<[^()]*?>)?\s*\(/g)) {
+    const name = match[1];
+    const segments = name.split('::');
+    const last = segments[segments.length - 1];
+    if (seen.has(name) || last === failure.testName) continue;
+    seen.add(name);
+
+    for (const file of lookup) {
+      const index = await indexFor(file);
+      // `Vec::new()` must not resolve to a workspace `new`: the qualifier has to be ours too
+      const qualifier = segments[segments.length - 2];
+      if (qualifier && !modules.has(qualifier) && !index.some(s => s.name === qualifier)) continue;
+      const symbol = index.find(s => s.name === last && s.type === 'function_item');
+      if (symbol) {
+        calls.push({ name, filePath: file, line: symbol.startLine });
+        break;
+      }
+    }
+    if (calls.length >= 10) break;
+  }
+
+  return calls;
+}
+
 /**
  * Search for symbols or keywords within given files and return surrounding context.
  */
@@ -424,6 +613,7 @@ async function calculateScore(
     ReferenceError: 4,
     SyntaxError: 5,
     Error: 3,
+    panic: 5,
   };
 
   let severity = 1;
@@ -432,7 +622,9 @@ async function calculateScore(
   const matchAbs = path.resolve(projectPath, match.filePath);
   const relatedError = errors.find(e => path.resolve(projectPath, e.filePath) === matchAbs);
   if (relatedError?.errorType) {
-    severity = SEVERITY_SCORES[relatedError.errorType] ?? 2;
+    severity =
+      SEVERITY_SCORES[relatedError.errorType] ??
+      (relatedError.severity === 'error' ? 4 : relatedError.severity === 'warning' ? 1 : 2);
   }
 
   // Calculate recency score based on file modification time
@@ -670,6 +862,32 @@ function generateDebugSuggestions(
     suggestions.push('Verify syntax in recently modified files');
   }
 
+  // rustc diagnostics: the compiler's own help comes first, then hints per error code
+  const rustHelp = errors.flatMap(e => (e.notes ?? []).filter(note => note.startsWith('help: ')));
+  suggestions.push(...[...new Set(rustHelp)].slice(0, 2).map(note => note.slice(6)));
+
+  if (errorTypes.includes('E0308')) {
+    suggestions.push('Compare the expected and found types; add a conversion or fix the signature');
+  }
+  if (errorTypes.some(t => t === 'E0425' || t === 'E0433' || t === 'E0412')) {
+    suggestions.push('Check `use` paths, `mod` declarations and item visibility (`pub`)');
+  }
+  if (errorTypes.some(t => t === 'E0382' || t === 'E0499' || t === 'E0502' || t === 'E0505')) {
+    suggestions.push('Check ownership and borrows; clone, borrow, or shorten the borrow scope');
+  }
+  if (errorTypes.includes('panic')) {
+    suggestions.push(
+      'Check unwrap()/expect()/indexing at the panic site; propagate the error with `?` instead'
+    );
+    const panics = errors.filter(e => e.errorType === 'panic');
+    if (panics.every(e => e.raw.includes(' panicked at '))) {
+      suggestions.push('Re-run with RUST_BACKTRACE=1 to see the call path into the panic');
+    }
+  }
+  if (errors.some(e => e.expansion)) {
+    suggestions.push('Error comes from a macro expansion; inspect the macro invocation arguments');
+  }
+
   // Embedding-specific suggestions
   if (embeddingsUsed && similarChunksFound > 0) {
     suggestions.push('Review semantically similar code patterns found via embeddings');
@@ -706,6 +924,7 @@ export async function handleLocalDebugContext(args: any): Promise<DebugContextRe
     projectPath = process.cwd(),
     maxMatches = 20,
     format = 'structured',
+    inputFormat = 'auto',
     useEmbeddings = true,
     embeddingSimilarityThreshold = 0.2,
     maxSimilarChunks = 5,
@@ -725,6 +944,7 @@ export async function handleLocalDebugContext(args: any): Promise<DebugContextRe
     projectPath: resolvedProjectPath,
     maxMatches,
     format,
+    inputFormat,
     useEmbeddings,
     embeddingSimilarityThreshold,
     maxSimilarChunks,
@@ -734,7 +954,7 @@ export async function handleLocalDebugContext(args: any): Promise<DebugContextRe
 
   try {
     // Phase 1: Parse errors and gather context
-    const errors = parseErrorLogs(logText);
+    const errors = parseErrorLogs(logText, inputFormat);
     const symbols: string[] = [];
     const fileHints: string[] = [];
     let allFiles: string[] | null = null;
@@ -743,6 +963,9 @@ export async function handleLocalDebugContext(args: any): Promise<DebugContextRe
       if (err.filePath) {
         const absPath = path.resolve(resolvedProjectPath, err.filePath);
         fileHints.push(absPath);
+        if (err.expansion) {
+          fileHints.push(path.resolve(resolvedProjectPath, err.expansion.filePath));
+        }
 
         if (!err.symbol) {
           const fileSymbols = await buildSymbolIndex(absPath);
@@ -757,10 +980,10 @@ export async function handleLocalDebugContext(args: any): Promise<DebugContextRe
       } else if (err.symbol) {
         symbols.push(err.symbol);
         if (!allFiles) {
-          allFiles = await globby(['**/*.{ts,tsx,js,jsx,py}'], {
+          allFiles = await globby(['**/*.{ts,tsx,js,jsx,py,rs}'], {
             cwd: resolvedProjectPath,
             absolute: true,
-            ignore: ['node_modules/**', 'dist/**', '.git/**'],
+            ignore: ['node_modules/**', 'dist/**', '.git/**', 'target/**'],
           });
         }
       }
@@ -770,6 +993,21 @@ export async function handleLocalDebugContext(args: any): Promise<DebugContextRe
       fileHints.push(...allFiles);
     }
 
+    // cargo test failures: the test function and the code under test it calls
+    const testFailures = parseCargoTestFailures(logText);
+    if (testFailures.length > 0) {
+      await locateTestFailures(testFailures, resolvedProjectPath);
+      for (const failure of testFailures) {
+        if (!failure.filePath) continue;
+        fileHints.push(path.resolve(resolvedProjectPath, failure.filePath));
+        symbols.push(failure.testName);
+        for (const call of failure.calls ?? []) {
+          fileHints.push(path.resolve(resolvedProjectPath, call.filePath));
+          symbols.push(call.name.split('::').pop()!);
+        }
+      }
+    }
+
     // Search for symbol matches
     const matches = await searchSymbols(symbols, resolvedProjectPath, fileHints, maxMatches);
 
@@ -872,6 +1110,7 @@ export async function handleLocalDebugContext(args: any): Promise<DebugContextRe
         similarChunksFound,
         suggestions,
       },
+      ...(testFailures.length > 0 ? { testFailures: groupTestFailuresByModule(testFailures) } : {}),
     };
 
     logger.info('✅ Local debug context gathering completed', {
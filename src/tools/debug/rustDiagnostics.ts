@@ -0,0 +1,180 @@
+/**
+ * @fileOverview: rustc / cargo diagnostic parsing for local debug context
+ * @module: RustDiagnostics
+ * @keyFunctions:
+ *   - parseRustcDiagnostics(): Turn `error[E0308]: ...` / `warning: ...` blocks into ParsedErrors
+ * @context: rustc prints a header, a ` --> file:line:col` primary span, an annotated source
+ *           snippet and `note:`/`help:` children. Errors raised inside macros point at the macro
+ *           definition and mark the call site with "in this macro invocation"
+ */
+
+import type { ParsedError } from './localDebugContext';
+
+const HEADER = /^(error|warning)(?:\[(E\d{4})\])?: (.+)$/;
+const SPAN = /^\s*(-->|:::)\s+(.+?):(\d+):(\d+)\s*$/;
+const GUTTER = /^\s*(\d+)?\s*\|(.*)$/;
+const CHILD = /^\s*(?:=\s*)?(note|help): (.+)$/;
+
+// Build summaries that share the header syntax but carry no diagnostic of their own
+const SUMMARY =
+  /^(?:aborting due to|could not compile|build failed|`[^`]+` \([^)]*\) generated \d+|\d+ warnings? emitted|Some errors have detailed explanations|For more information about)/;
+
+// Primary spans in these locations are outside the project; the macro call site is the useful one
+const EXTERNAL_PATH = /(?:^|[\\/])(?:\.cargo[\\/]registry|rustc[\\/][0-9a-f]+|rustlib)[\\/]/;
+
+// Backticked names that never identify a project symbol
+const NON_SYMBOLS = new Set([
+  'i8', 'i16', 'i32', 'i64', 'i128', 'isize',
+  'u8', 'u16', 'u32', 'u64', 'u128', 'usize',
+  'f32', 'f64', 'bool', 'char', 'str', 'String', 'Self', 'self', 'mut', 'dyn', 'impl',
+]); // prettier-ignore
+
+interface Location {
+  filePath: string;
+  line: number;
+  column?: number;
+}
+
+interface Diagnostic {
+  severity: 'error' | 'warning';
+  code?: string;
+  message: string;
+  headerIndex: number;
+  primary?: Location;
+  expansion?: Location;
+  notes: string[];
+}
+
+/**
+ * Parse rustc diagnostics (as printed by `cargo build` / `cargo check`) from log text
+ */
+export function parseRustcDiagnostics(logText: string): ParsedError[] {
+  const lines = logText.split(/\r?\n/);
+  const diagnostics: Diagnostic[] = [];
+  let current: Diagnostic | null = null;
+  let currentFile: string | undefined;
+  let lastSourceLine: number | undefined;
+  let multiLineStart: number | undefined;
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i];
+
+    const header = HEADER.exec(line);
+    if (header) {
+      current = SUMMARY.test(header[3])
+        ? null
+        : {
+            severity: header[1] as Diagnostic['severity'],
+            code: header[2],
+            message: header[3].trim(),
+            headerIndex: i,
+            notes: [],
+          };
+      if (current) diagnostics.push(current);
+      currentFile = undefined;
+      lastSourceLine = undefined;
+      multiLineStart = undefined;
+      continue;
+    }
+    if (!current) continue;
+
+    const span = SPAN.exec(line);
+    if (span) {
+      currentFile = span[2];
+      lastSourceLine = undefined;
+      multiLineStart = undefined;
+      if (span[1] === '-->' && !current.primary) {
+        current.primary = { filePath: span[2], line: Number(span[3]), column: Number(span[4]) };
+      }
+      continue;
+    }
+
+    const child = CHILD.exec(line);
+    if (child) {
+      current.notes.push(`${child[1]}: ${child[2].trim()}`);
+      continue;
+    }
+
+    const gutter = GUTTER.exec(line);
+    if (gutter) {
+      if (gutter[1]) {
+        lastSourceLine = Number(gutter[1]);
+        // `8 | / lazy_static! {` opens a multi-line span that a later `|_-` label closes
+        if (/^ +\/ /.test(gutter[2])) multiLineStart = lastSourceLine;
+      } else if (
+        /in this macro invocation/.test(gutter[2]) &&
+        currentFile &&
+        lastSourceLine !== undefined &&
+        !current.expansion
+      ) {
+        // Label columns only map to source columns for single-line spans
+        const multiLine = /^ *\|_/.test(gutter[2]) && multiLineStart !== undefined;
+        const marker = gutter[2].search(/[-^]/);
+        current.expansion = multiLine
+          ? { filePath: currentFile, line: multiLineStart! }
+          : {
+              filePath: currentFile,
+              line: lastSourceLine,
+              ...(marker > 0 ? { column: marker } : {}),
+            };
+      }
+      continue;
+    }
+
+    // Anything else (blank line, compiler output) ends the block
+    if (line.trim() === '') continue;
+    current = null;
+  }
+
+  return diagnostics
+    .filter(diagnostic => diagnostic.primary)
+    .map(diagnostic => toParsedError(diagnostic, lines));
+}
+
+function toParsedError(diagnostic: Diagnostic, lines: string[]): ParsedError {
+  const { primary, expansion } = diagnostic;
+  const location =
+    expansion && isToolchainPath(primary!.filePath) ? expansion : (primary as Location);
+  const raw = lines[diagnostic.headerIndex];
+
+  return {
+    filePath: location.filePath,
+    line: location.line,
+    column: location.column,
+    symbol: referencedSymbol(diagnostic.message),
+    errorType: diagnostic.code ?? diagnostic.severity,
+    severity: diagnostic.severity,
+    raw,
+    errorContext: lines
+      .slice(diagnostic.headerIndex, diagnostic.headerIndex + 6)
+      .join(' ')
+      .replace(/\s+/g, ' ')
+      .trim()
+      .substring(0, 200),
+    startLine: location.line,
+    endLine: location.line,
+    ...(diagnostic.notes.length > 0 ? { notes: diagnostic.notes } : {}),
+    ...(expansion && location !== expansion ? { expansion } : {}),
+  };
+}
+
+/**
+ * True for spans in the standard library or in downloaded crates
+ */
+export function isToolchainPath(filePath: string): boolean {
+  return EXTERNAL_PATH.test(filePath);
+}
+
+/**
+ * First backticked name in the message that can be a project item:
+ * "cannot find value `total` in this scope" -> total, "`Foo` doesn't implement ..." -> Foo
+ */
+export function referencedSymbol(message: string): string | undefined {
+  for (const match of message.matchAll(/`([^`]+)`/g)) {
+    const name = match[1].replace(/^&(?:mut\s+)?/, '').replace(/\(\)$/, '');
+    if (!/^[A-Za-z_][\w]*(?:::[A-Za-z_]\w*)*$/.test(name)) continue;
+    const last = name.split('::').pop()!;
+    if (!NON_SYMBOLS.has(last)) return last;
+  }
+  return undefined;
+}
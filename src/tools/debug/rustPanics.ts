@@ -0,0 +1,261 @@
+/**
+ * @fileOverview: Rust panic and RUST_BACKTRACE parsing for local debug context
+ * @module: RustPanics
+ * @keyFunctions:
+ *   - parseRustPanics(): Turn `thread 'x' panicked at ...` blocks and backtraces into ParsedErrors
+ *   - isRustTraceLine(): Recognise panic headers and frame locations so other parsers skip them
+ * @context: Backtrace frames from std/core/tokio, the toolchain and ~/.cargo are dropped so the
+ *           report starts at the first frame in workspace code
+ */
+
+import type { ParsedError } from './localDebugContext';
+
+// Rust >= 1.73: `thread 'main' panicked at src/main.rs:4:5:` with the message on following lines
+const PANIC = /thread '([^']*)' panicked at (.+?):(\d+):(\d+):?\s*$/;
+// Older toolchains: `thread 'main' panicked at 'message', src/main.rs:4:5`
+const LEGACY_PANIC = /thread '([^']*)' panicked at '(.*)', (.+?):(\d+):(\d+)\s*$/;
+// ...whose message may span lines, e.g. assert_eq! output ending in "right: `5`', src/lib.rs:9:5"
+const LEGACY_START = /thread '([^']*)' panicked at '(.*)$/;
+const LEGACY_END = /^(.*)', (.+?):(\d+):(\d+)\s*$/;
+// `  12: mycrate::module::func` or, with RUST_BACKTRACE=full, `  12:  0x55d4c1b2 - mycrate::...`
+const FRAME = /^\s*(\d+):\s+(?:0x[0-9a-f]+\s+-\s+)?(.+?)\s*$/;
+const FRAME_AT = /^\s+at\s+(.+?):(\d+):(\d+)\s*$/;
+const RUST_FRAME_AT = /^\s+at\s+\S+\.rs:\d+:\d+\s*$/;
+
+// Frames located in the toolchain or in downloaded dependencies
+const EXTERNAL_PATH =
+  /(?:^|[\\/])(?:rustc[\\/][0-9a-f]+|rustlib|\.cargo[\\/](?:registry|git)|library[\\/](?:std|core|alloc))[\\/]/;
+
+// Runtime crates and symbols whose frames never point at project code
+const RUNTIME_CRATES = new Set([
+  'std',
+  'core',
+  'alloc',
+  'tokio',
+  'test',
+  'panic_unwind',
+  'backtrace',
+]);
+const RUNTIME_SYMBOL = /^(?:rust_begin_unwind|rust_panic|__rust_\w+|_start|__libc_start_\w+|main)$/;
+
+interface PanicFrame {
+  symbol: string;
+  raw: string;
+  filePath?: string;
+  line?: number;
+  column?: number;
+}
+
+interface Panic {
+  thread: string;
+  message: string;
+  headerIndex: number;
+  filePath: string;
+  line: number;
+  column: number;
+  frames: PanicFrame[];
+}
+
+/**
+ * True for lines owned by a Rust panic report (headers and `at file.rs:line:col` frame locations)
+ */
+export function isRustTraceLine(line: string): boolean {
+  return PANIC.test(line) || LEGACY_PANIC.test(line) || RUST_FRAME_AT.test(line);
+}
+
+/**
+ * Parse Rust panics and the RUST_BACKTRACE output that follows them
+ */
+export function parseRustPanics(logText: string): ParsedError[] {
+  const lines = logText.split(/\r?\n/);
+  const panics: Panic[] = [];
+  let current: Panic | null = null;
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i];
+
+    const legacy = LEGACY_PANIC.exec(line) ?? multiLineLegacyPanic(lines, i);
+    const header = legacy ? null : PANIC.exec(line);
+    if (legacy || header) {
+      current = legacy
+        ? {
+            thread: legacy[1],
+            message: legacy[2],
+            headerIndex: i,
+            filePath: legacy[3],
+            line: Number(legacy[4]),
+            column: Number(legacy[5]),
+            frames: [],
+          }
+        : {
+            thread: header![1],
+            message: '',
+            headerIndex: i,
+            filePath: header![2],
+            line: Number(header![3]),
+            column: Number(header![4]),
+            frames: [],
+          };
+      panics.push(current);
+      if (legacy) i += legacy[2].split('\n').length - 1;
+
+      // The message runs until the backtrace note or a blank line
+      if (header) {
+        const message: string[] = [];
+        while (i + 1 < lines.length && !/^(?:\s*$|note: |stack backtrace:)/.test(lines[i + 1])) {
+          message.push(lines[++i]);
+        }
+        current.message = message.join('\n').trim();
+      }
+      continue;
+    }
+
+    if (!current || !/^stack backtrace:/.test(line)) continue;
+
+    for (let j = i + 1; j < lines.length; j++) {
+      const frame = FRAME.exec(lines[j]);
+      const at = FRAME_AT.exec(lines[j]);
+      if (at && current.frames.length > 0) {
+        const last = current.frames[current.frames.length - 1];
+        if (last.filePath === undefined) {
+          last.filePath = at[1];
+          last.line = Number(at[2]);
+          last.column = Number(at[3]);
+        }
+      } else if (frame) {
+        current.frames.push({ symbol: demangle(frame[2]), raw: lines[j].trim() });
+      } else {
+        i = j - 1;
+        break;
+      }
+      i = j;
+    }
+    current = null;
+  }
+
+  return panics.flatMap(panic => toParsedErrors(panic, lines));
+}
+
+/**
+ * Join a pre-1.73 panic message that continues over several lines into a LEGACY_PANIC-shaped match
+ */
+function multiLineLegacyPanic(lines: string[], start: number): string[] | null {
+  const first = LEGACY_START.exec(lines[start]);
+  if (!first) return null;
+  const message = [first[2]];
+  for (let i = start + 1; i < lines.length && i <= start + 20; i++) {
+    const end = LEGACY_END.exec(lines[i]);
+    if (end) return [lines[start], first[1], [...message, end[1]].join('\n'), ...end.slice(2)];
+    if (PANIC.test(lines[i]) || LEGACY_START.test(lines[i])) return null;
+    message.push(lines[i]);
+  }
+  return null;
+}
+
+function toParsedErrors(panic: Panic, lines: string[]): ParsedError[] {
+  const raw = lines[panic.headerIndex].trim();
+  const errorContext = `${raw} ${panic.message}`.replace(/\s+/g, ' ').trim().substring(0, 200);
+  const ownFrames = panic.frames.filter(isOwnFrame);
+  const located = ownFrames.filter(frame => frame.filePath !== undefined);
+
+  // Panics raised inside a dependency are reported at the first workspace frame instead
+  const origin = isExternalPath(panic.filePath) && located.length > 0 ? located[0] : panic;
+  const originPath = normalizePath(origin.filePath!);
+  const originFrame = located.find(
+    frame => normalizePath(frame.filePath!) === originPath && frame.line === origin.line
+  );
+
+  // With no workspace location left the panic is still reported, without a file to search
+  const external = isExternalPath(originPath);
+  const line = external ? 0 : origin.line!;
+  const errors: ParsedError[] = [
+    {
+      filePath: external ? '' : originPath,
+      line,
+      column: external ? undefined : origin.column,
+      symbol: originFrame ? functionName(originFrame.symbol) : undefined,
+      errorType: 'panic',
+      severity: 'error',
+      raw,
+      errorContext,
+      startLine: line,
+      endLine: line,
+      ...(panic.message ? { message: panic.message } : {}),
+    },
+  ];
+
+  for (const frame of ownFrames) {
+    if (frame === originFrame) continue;
+    const filePath = frame.filePath !== undefined ? normalizePath(frame.filePath) : '';
+    errors.push({
+      filePath,
+      line: frame.line ?? 0,
+      column: frame.column,
+      symbol: functionName(frame.symbol),
+      errorType: 'panic',
+      severity: 'error',
+      raw: frame.raw,
+      errorContext,
+      startLine: frame.line ?? 0,
+      endLine: frame.line ?? 0,
+    });
+  }
+
+  return errors;
+}
+
+function isOwnFrame(frame: PanicFrame): boolean {
+  if (frame.filePath !== undefined && isExternalPath(frame.filePath)) return false;
+  if (RUNTIME_SYMBOL.test(frame.symbol)) return false;
+
+  // `<mycrate::Foo as core::fmt::Display>::fmt` belongs to the crate of the self type
+  const crate = /^<?&?(?:mut\s+|dyn\s+)?(\w+)::/.exec(frame.symbol)?.[1];
+  if (crate) return !RUNTIME_CRATES.has(crate);
+  // Generic frames such as `<F as FnOnce>::call_once` are only kept with a workspace location
+  return frame.filePath !== undefined;
+}
+
+function isExternalPath(filePath: string): boolean {
+  return EXTERNAL_PATH.test(filePath) || filePath.startsWith('<');
+}
+
+function normalizePath(filePath: string): string {
+  return filePath.replace(/^\.[\\/]/, '');
+}
+
+/**
+ * `mycrate::jobs::run::{{closure}}` -> run, `<mycrate::Foo as core::fmt::Display>::fmt` -> fmt
+ */
+function functionName(symbol: string): string | undefined {
+  return /(\w+)\s*$/.exec(symbol.replace(/::\{\{\w+\}\}/g, '').replace(/<[^<>]*>$/, ''))?.[1];
+}
+
+/**
+ * Strip the `::h<hash>` suffix and decode legacy `_ZN...E` mangled names
+ */
+function demangle(symbol: string): string {
+  let name = symbol;
+  const mangled = /^_?_ZN(.+)E$/.exec(name);
+  if (mangled) {
+    const parts: string[] = [];
+    let rest = mangled[1];
+    while (rest.length > 0) {
+      const length = /^\d+/.exec(rest);
+      if (!length) break;
+      const start = length[0].length;
+      parts.push(rest.slice(start, start + Number(length[0])));
+      rest = rest.slice(start + Number(length[0]));
+    }
+    name = parts
+      .join('::')
+      .replace(/\$LT\$/g, '<')
+      .replace(/\$GT\$/g, '>')
+      .replace(/\$RF\$/g, '&')
+      .replace(/\$BP\$/g, '*')
+      .replace(/\$C\$/g, ',')
+      .replace(/\$SP\$/g, '@')
+      .replace(/\$u([0-9a-f]{2})\$/g, (_, hex) => String.fromCharCode(parseInt(hex, 16)))
+      .replace(/\.\./g, '::');
+  }
+  return name.replace(/::h[0-9a-f]{16}$/, '');
+}
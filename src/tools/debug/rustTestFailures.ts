@@ -0,0 +1,235 @@
+/**
+ * @fileOverview: `cargo test` failure parsing for local debug context
+ * @module: RustTestFailures
+ * @keyFunctions:
+ *   - parseCargoTestFailures(): Read `---- path stdout ----` sections and the `failures:` list
+ *   - groupTestFailuresByModule(): Group failures by the module under test
+ *   - testFileCandidates(): Files that may define a failing test, from its module path and target
+ * @context: The debug handler fills in the test function location and the functions it calls
+ *           using the Rust symbol index
+ */
+
+import * as path from 'path';
+import { parseRustPanics } from './rustPanics';
+
+export interface TestAssertion {
+  kind: 'eq' | 'ne' | 'assert';
+  expression?: string;
+  left?: string;
+  right?: string;
+  message?: string;
+}
+
+export interface TestCall {
+  name: string;
+  filePath: string;
+  line: number;
+}
+
+export interface CargoTestFailure {
+  testPath: string; // e.g. math::tests::subtracts
+  testName: string;
+  module: string; // module under test; 'crate' for root-level tests
+  target?: string; // test target from `Running unittests src/lib.rs (...)`
+  panic?: { filePath: string; line: number; column?: number };
+  message?: string;
+  assertion?: TestAssertion;
+  // Filled from the symbol index by the debug handler
+  filePath?: string;
+  line?: number;
+  calls?: TestCall[];
+}
+
+export interface CargoTestFailureGroup {
+  module: string;
+  failures: CargoTestFailure[];
+}
+
+const RUNNING = /^\s*Running (?:unittests )?(\S+)(?: \(.*\))?\s*$/;
+const SECTION = /^---- (.+) stdout ----$/;
+const RESULT_LINE = /^test (.+?) \.\.\. FAILED$/;
+const DOCTEST = /^(.+?) - (.+?) \(line (\d+)\)$/;
+const TEST_MODULE = /^(?:tests?|unit_tests?|\w+_tests?)$/;
+
+/**
+ * Parse failing tests from `cargo test` output
+ */
+export function parseCargoTestFailures(logText: string): CargoTestFailure[] {
+  const lines = logText.split(/\r?\n/);
+  const failures = new Map<string, CargoTestFailure>();
+  let target: string | undefined;
+  let inSummary = false;
+
+  const failureFor = (testPath: string): CargoTestFailure => {
+    const key = `${target ?? ''}\0${testPath}`;
+    let failure = failures.get(key);
+    if (!failure) {
+      failure = createFailure(testPath, target);
+      failures.set(key, failure);
+    }
+    return failure;
+  };
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i];
+
+    const running = RUNNING.exec(line);
+    if (running) {
+      target = running[1];
+      inSummary = false;
+      continue;
+    }
+    if (/^\s*Doc-tests /.test(line)) {
+      target = undefined;
+      inSummary = false;
+      continue;
+    }
+
+    const result = RESULT_LINE.exec(line);
+    if (result) {
+      failureFor(result[1]);
+      continue;
+    }
+
+    const section = SECTION.exec(line);
+    if (section) {
+      const body: string[] = [];
+      while (
+        i + 1 < lines.length &&
+        !SECTION.test(lines[i + 1]) &&
+        !/^(?:failures:|test result:)/.test(lines[i + 1])
+      ) {
+        body.push(lines[++i]);
+      }
+      readSection(failureFor(section[1]), body);
+      continue;
+    }
+
+    // The closing `failures:` block lists every failing test, including ones without output
+    if (/^failures:\s*$/.test(line)) {
+      inSummary = true;
+      continue;
+    }
+    if (inSummary) {
+      const listed = /^ {4}(\S.*?)\s*$/.exec(line);
+      if (listed) failureFor(listed[1]);
+      else if (line.trim() !== '') inSummary = false;
+    }
+  }
+
+  return [...failures.values()];
+}
+
+function createFailure(testPath: string, target: string | undefined): CargoTestFailure {
+  const doctest = DOCTEST.exec(testPath);
+  if (doctest) {
+    const item = doctest[2].split('::');
+    return {
+      testPath,
+      testName: item[item.length - 1],
+      module: item.length > 1 ? item.slice(0, -1).join('::') : 'crate',
+      target: doctest[1],
+      filePath: doctest[1],
+      line: Number(doctest[3]),
+    };
+  }
+
+  const segments = testPath.split('::');
+  const modules = segments.slice(0, -1);
+  while (modules.length > 0 && TEST_MODULE.test(modules[modules.length - 1])) modules.pop();
+
+  // Integration tests exercise the crate from outside; group them by their target file
+  const integration = target !== undefined && !/(?:^|\/)src\//.test(target);
+  const module = modules.length > 0 ? modules.join('::') : integration ? target! : 'crate';
+
+  return {
+    testPath,
+    testName: segments[segments.length - 1],
+    module,
+    ...(target ? { target } : {}),
+  };
+}
+
+function readSection(failure: CargoTestFailure, body: string[]): void {
+  const text = body.join('\n');
+  const [panic] = parseRustPanics(text);
+
+  if (panic) {
+    if (panic.filePath) {
+      failure.panic = { filePath: panic.filePath, line: panic.line, column: panic.column };
+    }
+    failure.message = panic.message;
+  } else {
+    // `#[should_panic]` tests and tests returning Err print a single explanatory line
+    failure.message = body.find(line => line.trim() !== '')?.trim();
+  }
+
+  const assertion = parseAssertion(failure.message ?? '', body);
+  if (assertion) failure.assertion = assertion;
+}
+
+/**
+ * Read assert!/assert_eq!/assert_ne! failures in the current and pre-1.73 formats:
+ * "assertion `left == right` failed" and "assertion failed: `(left == right)`"
+ */
+function parseAssertion(message: string, body: string[]): TestAssertion | undefined {
+  const comparison =
+    /^assertion `left (==|!=) right` failed(?:: ([\s\S]*?))?$/.exec(message.split('\n')[0]) ??
+    /^assertion failed: `\(left (==|!=) right\)`(?::? ([\s\S]*?))?$/.exec(message.split('\n')[0]);
+
+  if (comparison) {
+    const value = (side: 'left' | 'right') => {
+      for (const line of [...message.split('\n'), ...body]) {
+        const match = new RegExp(`^\\s*${side}: (.*?),?\\s*$`).exec(line);
+        if (match) return match[1].replace(/^`([\s\S]*)`$/, '$1');
+      }
+      // Older single-line form: `(left == right)` left: `4`, right: `5`
+      return new RegExp(`${side}: \`(.*?)\``).exec(message)?.[1];
+    };
+    const extra = comparison[2]?.trim().replace(/^left: [\s\S]*$/, '');
+    return {
+      kind: comparison[1] === '==' ? 'eq' : 'ne',
+      left: value('left'),
+      right: value('right'),
+      ...(extra ? { message: extra } : {}),
+    };
+  }
+
+  const plain = /^assertion failed: (.+)$/.exec(message.split('\n')[0]);
+  return plain ? { kind: 'assert', expression: plain[1].trim() } : undefined;
+}
+
+/**
+ * Group failures by the module they test, largest group first
+ */
+export function groupTestFailuresByModule(failures: CargoTestFailure[]): CargoTestFailureGroup[] {
+  const groups = new Map<string, CargoTestFailure[]>();
+  for (const failure of failures) {
+    groups.set(failure.module, [...(groups.get(failure.module) ?? []), failure]);
+  }
+  return [...groups.entries()]
+    .map(([module, members]) => ({ module, failures: members }))
+    .sort((a, b) => b.failures.length - a.failures.length || a.module.localeCompare(b.module));
+}
+
+/**
+ * Relative paths that may contain a failing test: the panic location, then module files from
+ * the test path (`a::b::tests::t` -> src/a/b.rs, src/a/b/mod.rs, src/a.rs, ...), then the target
+ */
+export function testFileCandidates(failure: CargoTestFailure): string[] {
+  const candidates: string[] = [];
+  if (failure.panic) candidates.push(failure.panic.filePath);
+
+  const roots = failure.target ? [failure.target] : ['src/lib.rs', 'src/main.rs'];
+  const modules = failure.testPath.split('::').slice(0, -1);
+  for (const root of roots) {
+    const dir = path.posix.dirname(root.replace(/\\/g, '/'));
+    for (let i = modules.length; i > 0; i--) {
+      const prefix = path.posix.join(dir, ...modules.slice(0, i));
+      candidates.push(`${prefix}.rs`, `${prefix}/mod.rs`);
+    }
+    candidates.push(root);
+  }
+
+  return [...new Set(candidates)];
+}
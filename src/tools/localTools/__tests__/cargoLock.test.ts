@@ -0,0 +1,303 @@
+/**
+ * @fileOverview: Unit tests for the Cargo.lock dependency graph
+ * @module: cargoLockTests
+ * @description: Covers lockfile parsing, per-member direct/transitive dependencies, duplicates,
+ *               git/path/pre-release packages and "why do we depend on X" paths
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from '@jest/globals';
+import '../../../__tests__/utils/realFs';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import {
+  analyzeDependencyGraph,
+  findDependencyPaths,
+  loadCargoLock,
+  parseCargoLock,
+} from '../utils/cargoLock';
+import { buildCargoDependencySummary, generateAnswerDraft } from '../enhancedHints';
+
+const LOCK = [
+  '# This file is automatically @generated by Cargo.',
+  '# It is not intended for manual editing.',
+  'version = 3',
+  '',
+  '[[package]]',
+  'name = "app"',
+  'version = "0.1.0"',
+  'dependencies = [',
+  ' "core",',
+  ' "reqwest",',
+  ' "syn 2.0.48",',
+  ' "tokio-test",',
+  ']',
+  '',
+  '[[package]]',
+  'name = "cc"',
+  'version = "1.0.83"',
+  'source = "registry+https://github.com/rust-lang/crates.io-index"',
+  'checksum = "aaaa"',
+  '',
+  '[[package]]',
+  'name = "core"',
+  'version = "0.1.0"',
+  'dependencies = [',
+  ' "cc",',
+  ' "patched",',
+  ' "serde",',
+  ']',
+  '',
+  '[[package]]',
+  'name = "native-tls"',
+  'version = "0.2.11"',
+  'source = "registry+https://github.com/rust-lang/crates.io-index"',
+  'dependencies = [',
+  ' "openssl-sys",',
+  ']',
+  '',
+  '[[package]]',
+  'name = "openssl-sys"',
+  'version = "0.9.99"',
+  'source = "registry+https://github.com/rust-lang/crates.io-index"',
+  'dependencies = [',
+  ' "cc",',
+  ']',
+  '',
+  '[[package]]',
+  'name = "patched"',
+  'version = "0.3.0-alpha.1"',
+  'dependencies = [',
+  ' "syn 1.0.109",',
+  ']',
+  '',
+  '[[package]]',
+  'name = "reqwest"',
+  'version = "0.11.24"',
+  'source = "registry+https://github.com/rust-lang/crates.io-index"',
+  'dependencies = [',
+  ' "native-tls",',
+  ' "serde",',
+  ']',
+  '',
+  '[[package]]',
+  'name = "serde"',
+  'version = "1.0.196"',
+  'source = "registry+https://github.com/rust-lang/crates.io-index"',
+  'dependencies = [',
+  ' "serde_derive",',
+  ']',
+  '',
+  '[[package]]',
+  'name = "serde_derive"',
+  'version = "1.0.196"',
+  'source = "registry+https://github.com/rust-lang/crates.io-index"',
+  'dependencies = [',
+  ' "syn 2.0.48",',
+  ']',
+  '',
+  '[[package]]',
+  'name = "syn"',
+  'version = "1.0.109"',
+  'source = "registry+https://github.com/rust-lang/crates.io-index"',
+  '',
+  '[[package]]',
+  'name = "syn"',
+  'version = "2.0.48"',
+  'source = "registry+https://github.com/rust-lang/crates.io-index"',
+  '',
+  '[[package]]',
+  'name = "tokio-test"',
+  'version = "0.4.4"',
+  'source = "git+https://github.com/tokio-rs/tokio?branch=master#1a2b3c4d"',
+].join('\n');
+
+describe('parseCargoLock', () => {
+  const graph = parseCargoLock(LOCK, ['app', 'core']);
+
+  it('resolves dependency refs with and without versions', () => {
+    expect(graph.version).toBe(3);
+    const byId = Object.fromEntries(graph.packages.map(pkg => [pkg.id, pkg.dependencies]));
+    expect(byId['app@0.1.0']).toEqual([
+      'core@0.1.0',
+      'reqwest@0.11.24',
+      'syn@2.0.48',
+      'tokio-test@0.4.4',
+    ]);
+    expect(byId['patched@0.3.0-alpha.1']).toEqual(['syn@1.0.109']);
+  });
+
+  it('classifies sources and workspace members', () => {
+    const kinds = graph.packages.map(pkg => [pkg.name, pkg.sourceKind, pkg.member]);
+    expect(kinds).toContainEqual(['core', 'path', true]);
+    expect(kinds).toContainEqual(['patched', 'path', false]);
+    expect(kinds).toContainEqual(['serde', 'registry', false]);
+    expect(graph.packages.find(pkg => pkg.name === 'tokio-test')!.git).toEqual({
+      url: 'https://github.com/tokio-rs/tokio',
+      reference: 'branch=master',
+      commit: '1a2b3c4d',
+    });
+  });
+
+  it('treats every sourceless package as a member when members are unknown', () => {
+    const members = parseCargoLock(LOCK).packages.filter(pkg => pkg.member);
+    expect(members.map(pkg => pkg.name)).toEqual(['app', 'core', 'patched']);
+  });
+
+  it('defaults to the v1/v2 format when there is no version key', () => {
+    expect(parseCargoLock('[[package]]\nname = "a"\nversion = "1.0.0"\n').version).toBe(2);
+  });
+});
+
+describe('analyzeDependencyGraph', () => {
+  const report = analyzeDependencyGraph(parseCargoLock(LOCK, ['app', 'core']));
+
+  it('splits direct and transitive dependencies per member', () => {
+    const app = report.members.find(member => member.crate === 'app')!;
+    expect(app.direct.map(dep => dep.id)).toEqual([
+      'core@0.1.0',
+      'reqwest@0.11.24',
+      'syn@2.0.48',
+      'tokio-test@0.4.4',
+    ]);
+    expect(app.transitive).toContain('openssl-sys@0.9.99');
+    expect(app.transitive).not.toContain('core@0.1.0');
+    expect(app.transitive).not.toContain('reqwest@0.11.24');
+  });
+
+  it('reports duplicate versions with the packages that pull each one in', () => {
+    expect(report.duplicates).toEqual([
+      {
+        name: 'syn',
+        versions: ['1.0.109', '2.0.48'],
+        dependents: {
+          '1.0.109': ['patched@0.3.0-alpha.1'],
+          '2.0.48': ['app@0.1.0', 'serde_derive@1.0.196'],
+        },
+      },
+    ]);
+  });
+
+  it('lists git sources, outside path dependencies and pre-releases', () => {
+    expect(report.packages).toBe(10);
+    expect(report.gitSources.map(pkg => pkg.id)).toEqual(['tokio-test@0.4.4']);
+    expect(report.pathSources.map(pkg => pkg.id)).toEqual(['patched@0.3.0-alpha.1']);
+    expect(report.prereleases.map(pkg => pkg.id)).toEqual(['patched@0.3.0-alpha.1']);
+  });
+});
+
+describe('findDependencyPaths', () => {
+  const graph = parseCargoLock(LOCK, ['app', 'core']);
+
+  it('returns the shortest chain from each member, shortest first', () => {
+    expect(findDependencyPaths(graph, 'openssl-sys')).toEqual([
+      ['app@0.1.0', 'reqwest@0.11.24', 'native-tls@0.2.11', 'openssl-sys@0.9.99'],
+    ]);
+    expect(findDependencyPaths(graph, 'cc')).toEqual([
+      ['core@0.1.0', 'cc@1.0.83'],
+      ['app@0.1.0', 'core@0.1.0', 'cc@1.0.83'],
+    ]);
+  });
+
+  it('reaches every locked version of a duplicated crate', () => {
+    const ends = findDependencyPaths(graph, 'syn', 10).map(chain => chain[chain.length - 1]);
+    expect(new Set(ends)).toEqual(new Set(['syn@1.0.109', 'syn@2.0.48']));
+  });
+});
+
+describe('Cargo dependency summary', () => {
+  let project: { path: string; cleanup: () => Promise<void> };
+
+  beforeAll(async () => {
+    project = await createTestProject([
+      { name: 'Cargo.toml', content: '[workspace]\nmembers = ["crates/*"]\n' },
+      {
+        name: 'crates/app/Cargo.toml',
+        content: [
+          '[package]',
+          'name = "app"',
+          'version = "0.1.0"',
+          '',
+          '[dependencies]',
+          'core = { path = "../core" }',
+          'reqwest = "0.11"',
+          'syn = "2"',
+          '',
+          '[dev-dependencies]',
+          'tokio-test = "0.4"',
+        ].join('\n'),
+      },
+      {
+        name: 'crates/core/Cargo.toml',
+        content: [
+          '[package]',
+          'name = "core"',
+          'version = "0.1.0"',
+          '',
+          '[dependencies]',
+          'serde = "1"',
+          'patched = { path = "../../vendor/patched" }',
+          '',
+          '[build-dependencies]',
+          'cc = "1"',
+        ].join('\n'),
+      },
+      { name: 'crates/app/src/main.rs', content: 'fn main() {}\n' },
+      { name: 'crates/core/src/lib.rs', content: 'pub fn core() {}\n' },
+      { name: 'Cargo.lock', content: LOCK },
+    ]);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('marks workspace members and dependency kinds from the manifests', async () => {
+    const lock = await loadCargoLock(project.path);
+    const report = analyzeDependencyGraph(lock!.graph, lock!.workspace);
+    const core = report.members.find(member => member.crate === 'core')!;
+    expect(core.direct.map(dep => [dep.id, dep.kind])).toEqual([
+      ['cc@1.0.83', 'build'],
+      ['patched@0.3.0-alpha.1', 'normal'],
+      ['serde@1.0.196', 'normal'],
+    ]);
+    const app = report.members.find(member => member.crate === 'app')!;
+    expect(app.direct.find(dep => dep.id === 'tokio-test@0.4.4')!.kind).toBe('dev');
+  });
+
+  it('explains why a queried crate is in the graph', async () => {
+    const summary = await buildCargoDependencySummary(
+      project.path,
+      'Why do we depend on openssl-sys?'
+    );
+    expect(summary!.explain).toEqual({
+      crate: 'openssl-sys',
+      versions: ['0.9.99'],
+      paths: [['app@0.1.0', 'reqwest@0.11.24', 'native-tls@0.2.11', 'openssl-sys@0.9.99']],
+    });
+
+    const draft = generateAnswerDraft(
+      { dependencyGraph: summary, systems: {}, capabilities: { domains: [] }, hints: [] } as any,
+      'Why do we depend on openssl-sys?'
+    );
+    expect(draft).toBe(
+      '`openssl-sys` (0.9.99) is pulled in by: ' +
+        'app@0.1.0 → reqwest@0.11.24 → native-tls@0.2.11 → openssl-sys@0.9.99.'
+    );
+  });
+
+  it('ignores bare crate names outside dependency questions', async () => {
+    const summary = await buildCargoDependencySummary(project.path, 'How is serde configured?');
+    expect(summary!.explain).toBeUndefined();
+  });
+
+  it('is undefined without a Cargo.lock', async () => {
+    const bare = await createTestProject([
+      { name: 'Cargo.toml', content: '[package]\nname = "bare"\n' },
+      { name: 'src/lib.rs', content: '' },
+    ]);
+    try {
+      expect(await buildCargoDependencySummary(bare.path)).toBeUndefined();
+    } finally {
+      await bare.cleanup();
+    }
+  });
+});
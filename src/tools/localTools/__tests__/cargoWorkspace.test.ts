@@ -0,0 +1,230 @@
+/**
+ * @fileOverview: Unit tests for Cargo workspace analysis
+ * @module: cargoWorkspaceTests
+ * @description: Builds small on-disk workspaces and checks members, targets and crate scoping
+ */
+
+import { describe, it, expect, beforeAll, afterAll, jest } from '@jest/globals';
+import { walkingGlobby } from '../../../__tests__/utils/realFs';
+import * as path from 'path';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import { FileDiscovery } from '../../../core/compactor/fileDiscovery';
+import { fingerprintRepo } from '../enhancedLocalContext';
+import {
+  analyzeCargoWorkspace,
+  findCargoWorkspaceRoot,
+  findCrate,
+  resolveCrateScope,
+} from '../utils/cargoWorkspace';
+import { parseToml } from '../utils/toml';
+
+jest.mock('globby', () => walkingGlobby());
+
+describe('parseToml', () => {
+  it('parses tables, arrays of tables, inline tables and multi-line arrays', () => {
+    const parsed = parseToml(`
+# workspace manifest
+[workspace]
+members = [
+  "crates/*", # all crates
+  'tools/cli',
+]
+
+[workspace.dependencies]
+serde = { version = "1", features = ["derive"] }
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+
+[[bin]]
+name = "server"
+[[bin]]
+name = "worker"
+path = "src/worker.rs"
+`);
+
+    expect(parsed.workspace).toEqual({
+      members: ['crates/*', 'tools/cli'],
+      dependencies: { serde: { version: '1', features: ['derive'] } },
+    });
+    expect(parsed.target).toEqual({ 'cfg(unix)': { dependencies: { libc: '0.2' } } });
+    expect(parsed.bin).toEqual([{ name: 'server' }, { name: 'worker', path: 'src/worker.rs' }]);
+  });
+
+  it('throws on malformed input', () => {
+    expect(() => parseToml('[package\nname = "x"')).toThrow(/TOML parse error/);
+  });
+});
+
+describe('Cargo workspace analysis', () => {
+  let project: { path: string; cleanup: () => Promise<void> };
+
+  beforeAll(async () => {
+    project = await createTestProject([
+      {
+        name: 'Cargo.toml',
+        content: `[workspace]
+members = ["crates/*", "apps/api"]
+exclude = ["crates/legacy"]
+
+[workspace.dependencies]
+billing-types = { path = "crates/billing-types" }
+`,
+      },
+      {
+        name: 'crates/billing-core/Cargo.toml',
+        content: `[package]
+name = "billing-core"
+version = "0.3.0"
+
+[dependencies]
+billing-types = { workspace = true }
+serde = "1"
+
+[dev-dependencies]
+proptest = "1"
+`,
+      },
+      { name: 'crates/billing-core/src/lib.rs', content: 'pub fn charge() {}\n' },
+      { name: 'crates/billing-core/tests/charges.rs', content: '#[test]\nfn charges() {}\n' },
+      { name: 'crates/billing-core/benches/throughput.rs', content: 'fn main() {}\n' },
+      {
+        name: 'crates/billing-types/Cargo.toml',
+        content: '[package]\nname = "billing-types"\nversion = "0.1.0"\n',
+      },
+      { name: 'crates/billing-types/src/lib.rs', content: 'pub struct Invoice;\n' },
+      {
+        name: 'crates/legacy/Cargo.toml',
+        content: '[package]\nname = "legacy"\nversion = "0.0.1"\n',
+      },
+      {
+        name: 'apps/api/Cargo.toml',
+        content: `[package]
+name = "api"
+version = "0.1.0"
+
+[[bin]]
+name = "migrate"
+path = "src/migrate.rs"
+
+[dependencies]
+billing-core = { path = "../../crates/billing-core" }
+`,
+      },
+      { name: 'apps/api/src/main.rs', content: 'fn main() {}\n' },
+      { name: 'apps/api/src/bin/seed.rs', content: 'fn main() {}\n' },
+      { name: 'apps/api/examples/client.rs', content: 'fn main() {}\n' },
+    ]);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('fingerprints the workspace even though discovery drops Cargo.toml', async () => {
+    const files = await new FileDiscovery(project.path).discoverFiles();
+    expect(files.map(file => file.relPath)).toContain('apps/api/src/main.rs');
+    expect(files.some(file => file.relPath.endsWith('Cargo.toml'))).toBe(false);
+
+    const fingerprint = await fingerprintRepo(files, project.path);
+    expect(fingerprint.frameworks).toContain('rust');
+    expect(fingerprint.families).toContain('cargo_workspace');
+    expect(fingerprint.crates).toEqual(['api', 'billing-core', 'billing-types']);
+  });
+
+  it('expands member globs and honours exclude', () => {
+    const workspace = analyzeCargoWorkspace(project.path)!;
+
+    expect(workspace.isWorkspace).toBe(true);
+    expect(workspace.members.map(member => [member.name, member.dir])).toEqual([
+      ['api', 'apps/api'],
+      ['billing-core', 'crates/billing-core'],
+      ['billing-types', 'crates/billing-types'],
+    ]);
+  });
+
+  it('collects explicit and auto-discovered targets', () => {
+    const workspace = analyzeCargoWorkspace(project.path)!;
+
+    const api = findCrate(workspace, 'api')!;
+    expect(api.targets.map(target => `${target.kind}:${target.name}`)).toEqual([
+      'bin:migrate',
+      'bin:api',
+      'bin:seed',
+      'example:client',
+    ]);
+
+    const core = findCrate(workspace, 'billing_core')!;
+    expect(core.targets).toEqual([
+      { kind: 'lib', name: 'billing_core', path: 'crates/billing-core/src/lib.rs' },
+      { kind: 'test', name: 'charges', path: 'crates/billing-core/tests/charges.rs' },
+      { kind: 'bench', name: 'throughput', path: 'crates/billing-core/benches/throughput.rs' },
+    ]);
+  });
+
+  it('resolves path dependencies, including workspace-inherited ones', () => {
+    const workspace = analyzeCargoWorkspace(project.path)!;
+
+    expect(findCrate(workspace, 'api')!.pathDependencies).toEqual([
+      { name: 'billing-core', kind: 'normal', path: 'crates/billing-core', crate: 'billing-core' },
+    ]);
+    expect(findCrate(workspace, 'billing-core')!.pathDependencies).toEqual([
+      {
+        name: 'billing-types',
+        kind: 'normal',
+        path: 'crates/billing-types',
+        crate: 'billing-types',
+      },
+    ]);
+  });
+
+  it('climbs from a member crate to the workspace root', () => {
+    const memberDir = path.join(project.path, 'crates', 'billing-core');
+    expect(findCargoWorkspaceRoot(memberDir)).toBe(path.resolve(project.path));
+
+    // Excluded crates are not owned by the workspace
+    const legacyDir = path.join(project.path, 'crates', 'legacy');
+    expect(findCargoWorkspaceRoot(legacyDir)).toBe(path.resolve(legacyDir));
+  });
+
+  it('scopes files to a single crate', () => {
+    const scope = resolveCrateScope(project.path, 'billing-core');
+
+    expect(scope.includes('crates/billing-core/src/lib.rs')).toBe(true);
+    expect(scope.includes('crates/billing-types/src/lib.rs')).toBe(false);
+    expect(scope.includes('apps/api/src/main.rs')).toBe(false);
+    expect(() => resolveCrateScope(project.path, 'missing')).toThrow(/Unknown crate "missing"/);
+  });
+});
+
+describe('Cargo workspace recursive member globs', () => {
+  let project: { path: string; cleanup: () => Promise<void> };
+
+  beforeAll(async () => {
+    const crate = (name: string) => `[package]\nname = "${name}"\nversion = "0.1.0"\n`;
+    project = await createTestProject([
+      { name: 'Cargo.toml', content: '[workspace]\nmembers = ["services/**", "tools/**/cli"]\n' },
+      { name: 'services/auth/Cargo.toml', content: crate('auth') },
+      { name: 'services/billing/api/Cargo.toml', content: crate('billing-api') },
+      { name: 'services/billing/target/package/Cargo.toml', content: crate('packaged') },
+      { name: 'tools/cli/Cargo.toml', content: crate('cli') },
+      { name: 'tools/dev/cli/Cargo.toml', content: crate('dev-cli') },
+      { name: 'tools/dev/lint/Cargo.toml', content: crate('lint') },
+    ]);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('expands ** to any depth, including none, and skips build output', () => {
+    const workspace = analyzeCargoWorkspace(project.path)!;
+
+    expect(workspace.members.map(member => [member.name, member.dir])).toEqual([
+      ['auth', 'services/auth'],
+      ['billing-api', 'services/billing/api'],
+      ['cli', 'tools/cli'],
+      ['dev-cli', 'tools/dev/cli'],
+    ]);
+  });
+});
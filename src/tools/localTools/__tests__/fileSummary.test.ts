@@ -161,6 +161,73 @@ describe('File Summary Tool (Unit Tests)', () => {
     });
   });
 
+  describe('getComprehensiveASTAnalysis Rust captures', () => {
+    beforeEach(() => {
+      mockASTParser.prototype.parseFile.mockResolvedValue({
+        symbols: [],
+        imports: [],
+        exports: [],
+        errors: [],
+        absPath: '/mock/lib.rs',
+        language: 'rust',
+      } as ParsedFile);
+    });
+
+    it('should take names, signatures and visibility from metavariable captures', async () => {
+      const filePath = path.join(tmpdir(), 'lib.rs');
+      const capture = (text: string) => ({ text });
+
+      mockExecSync.mockImplementation((cmd: any) => {
+        const command = typeof cmd === 'string' ? cmd : '';
+        if (command.includes('symbol-functions')) {
+          return (
+            JSON.stringify({
+              range: { start: { line: 2 }, end: { line: 6 } },
+              text: 'pub fn connect(url: &str) -> Client { todo!() }',
+              metaVariables: {
+                single: {
+                  NAME: capture('connect'),
+                  VIS: capture('pub'),
+                  PARAMS: capture('(url: &str)'),
+                  RET: capture('Client'),
+                },
+              },
+            }) + '\n'
+          );
+        }
+        if (command.includes('symbol-methods') && command.includes('impl_item')) {
+          return (
+            JSON.stringify({
+              range: { start: { line: 10 }, end: { line: 12 } },
+              text: 'fn send(&self) {}',
+              metaVariables: {
+                single: {
+                  NAME: capture('send'),
+                  PARAMS: capture('(&self)'),
+                  TYPE: capture('Client<T>'),
+                },
+              },
+            }) + '\n'
+          );
+        }
+        return '';
+      });
+
+      const result = await getComprehensiveASTAnalysis(filePath);
+
+      const connect = result.allFunctions.find(f => f.name === 'connect');
+      expect(connect.signature).toBe('pub fn connect(url: &str) -> Client');
+      expect(connect.isExported).toBe(true);
+      expect(connect.returnType).toBe('Client');
+
+      const send = result.allFunctions.find(f => f.name === 'send');
+      expect(send.isMethod).toBe(true);
+      expect(send.className).toBe('Client');
+      expect(send.isExported).toBe(false);
+      expect(result.exportedSymbols).toEqual(['connect']);
+    });
+  });
+
   describe('getLanguageFromPath Updates', () => {
     it('should return grep code for supported langs', () => {
       expect(getLanguageFromPath('test.py')).toEqual({ lang: 'python', grep: 'py' });
@@ -0,0 +1,364 @@
+/**
+ * @fileOverview: Unit tests for AST queries on Rust files
+ * @module: rustAstQueriesTests
+ * @description: Covers Rust symbol extraction, the import/export/call/new/env/route queries and
+ *               the Rust-only impl/trait/derive/attribute/macro queries
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from '@jest/globals';
+import '../../../__tests__/utils/realFs';
+import * as path from 'path';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import type { FileInfo } from '../../../core/compactor/fileDiscovery';
+import { runAstQueriesOnFiles } from '../astQueryEngine';
+import {
+  extractRustAttributes,
+  extractRustInherentImpls,
+  extractRustSymbols,
+  findRustConstructorCalls,
+} from '../utils/rustSymbols';
+
+const SOURCES: Record<string, string> = {
+  'src/main.rs': [
+    '//! Server entry point. `use fake::Import;` in docs is ignored',
+    'use std::collections::{HashMap, HashSet as Set};',
+    'use axum::{routing::get, Router};',
+    'pub use crate::store::Store as Db;',
+    'mod store;',
+    '',
+    '#[derive(Debug, Clone)]',
+    'pub struct Config {',
+    '    pub name: String,',
+    '}',
+    '',
+    'pub(crate) enum Mode { Fast, Slow }',
+    '',
+    'pub trait Backend: Send {',
+    '    fn get(&self, key: &str) -> Option<String>;',
+    '}',
+    '',
+    '#[tokio::main(flavor = "current_thread")]',
+    'async fn main() {',
+    '    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");',
+    '    let mut cache = HashMap::new();',
+    '    let buf: Vec<u8> = Vec::<u8>::with_capacity(8);',
+    '    cache.insert("url", url);',
+    '    let banner = format!("if({})", env!("GIT_SHA"));',
+    '    let app = Router::new().route("/health", get(health));',
+    '    store::connect(&cache);',
+    '}',
+    '',
+    'pub async fn health() -> &\'static str { "ok" }',
+  ].join('\n'),
+  'src/store.rs': [
+    'pub struct Store;',
+    '',
+    'pub fn connect<T>(cache: &T) -> Store {',
+    '    Store::default()',
+    '}',
+  ].join('\n'),
+};
+
+const STRUCTURE_SOURCES: Record<string, string> = {
+  'src/model.rs': [
+    '#![allow(dead_code)]',
+    'use serde::{Deserialize, Serialize};',
+    '',
+    '#[derive(Debug, Clone, Serialize, serde::Deserialize)]',
+    '#[serde(rename_all = "camelCase")]',
+    'pub struct User {',
+    '    #[serde(default)]',
+    '    pub name: String,',
+    '}',
+    '',
+    '#[derive(Debug, Serialize)]',
+    'pub enum Role { Admin, Guest }',
+    '',
+    'impl User {',
+    '    #[tracing::instrument(skip(self))]',
+    '    pub fn greet(&self) -> String { format!("hi {}", self.name) }',
+    '}',
+    '',
+    'impl std::fmt::Display for User {',
+    '    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {',
+    '        write!(f, "{}", self.name)',
+    '    }',
+    '}',
+  ].join('\n'),
+  'src/store.rs': [
+    'pub trait Store: Send {',
+    '    fn load(&self, id: u64) -> Option<crate::model::User>;',
+    '}',
+    '',
+    'macro_rules! store_error {',
+    '    ($msg:expr) => { format!("store: {}", $msg) };',
+    '}',
+    '',
+    'pub struct Memory<K> { items: Vec<K> }',
+    '',
+    'impl<K: Clone> Memory<K> {',
+    '    pub fn new() -> Self { Memory { items: Vec::new() } }',
+    '}',
+    '',
+    'impl<K> Store for Memory<K> {',
+    '    #[tracing::instrument]',
+    '    fn load(&self, id: u64) -> Option<crate::model::User> {',
+    '        let _ = store_error!("miss");',
+    '        None',
+    '    }',
+    '}',
+    '',
+    '#[cfg(test)]',
+    'mod tests {',
+    '    impl super::Memory<u8> { fn fixture() {} }',
+    '}',
+  ].join('\n'),
+};
+
+describe('extractRustSymbols', () => {
+  const content = SOURCES['src/main.rs'];
+  const symbols = extractRustSymbols(content);
+  const named = (kind: string) =>
+    symbols.filter(symbol => symbol.kind === kind).map(symbol => symbol.name);
+
+  it('maps Rust items onto the JS symbol kinds', () => {
+    expect(named('class')).toEqual(['Config', 'Mode']);
+    expect(named('interface')).toEqual(['Backend']);
+    expect(named('function')).toEqual(['get', 'main', 'health']);
+    expect(named('variable')).toEqual(['url', 'cache', 'buf', 'banner', 'app']);
+  });
+
+  it('reports use trees as imports and pub items as exports', () => {
+    expect(named('import')).toEqual([
+      'import from std::collections::HashMap',
+      'import from std::collections::HashSet',
+      'import from axum::routing::get',
+      'import from axum::Router',
+      'import from crate::store::Store',
+    ]);
+    // `pub(crate)` is not part of the public surface
+    expect(named('export')).toEqual(['Db', 'Config', 'Backend', 'health']);
+  });
+
+  it('names calls like JS callees and skips macros, attributes and string contents', () => {
+    expect(named('call')).toEqual([
+      'std::env::var',
+      'expect',
+      'HashMap::new',
+      'Vec::with_capacity',
+      'cache.insert',
+      'Router::new',
+      'route',
+      'get',
+      'store::connect',
+    ]);
+
+    const insert = symbols.find(symbol => symbol.name === 'cache.insert')!;
+    expect(content.slice(insert.start, insert.end)).toBe('cache.insert("url", url)');
+    expect(insert.line).toBe(23);
+  });
+
+  it('finds associated constructor calls, including turbofish', () => {
+    const calls = findRustConstructorCalls(content, 'HashMap|Vec');
+    expect(calls.map(call => `${call.type}::${call.constructor}`)).toEqual([
+      'HashMap::new',
+      'Vec::with_capacity',
+    ]);
+    expect(content.slice(calls[1].start, calls[1].end)).toBe('Vec::<u8>::with_capacity(8)');
+  });
+
+  it('lists attributes with the item or field they annotate', () => {
+    const attributes = extractRustAttributes(STRUCTURE_SOURCES['src/model.rs']);
+    expect(attributes.map(attr => [attr.path, attr.target])).toEqual([
+      ['allow', undefined],
+      ['derive', 'User'],
+      ['serde', 'User'],
+      ['serde', 'name'],
+      ['derive', 'Role'],
+      ['tracing::instrument', 'greet'],
+    ]);
+    expect(attributes[2].text).toBe('#[serde(rename_all = "camelCase")]');
+    expect(attributes[0].inner).toBe(true);
+  });
+
+  it('finds inherent impls outside test modules', () => {
+    const impls = extractRustInherentImpls(STRUCTURE_SOURCES['src/store.rs']);
+    expect(impls.map(impl => [impl.forType, impl.generics])).toEqual([['Memory<K>', '<K: Clone>']]);
+  });
+});
+
+describe('AST queries on Rust files', () => {
+  let project: { path: string; cleanup: () => Promise<void> };
+  let files: FileInfo[];
+
+  beforeAll(async () => {
+    project = await createTestProject(
+      Object.entries(SOURCES).map(([name, content]) => ({ name, content }))
+    );
+    files = Object.keys(SOURCES).map(relPath => ({
+      absPath: path.join(project.path, relPath),
+      relPath,
+      size: SOURCES[relPath].length,
+      ext: '.rs',
+      language: 'rust',
+    }));
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('answers import and export queries', async () => {
+    const imports = await runAstQueriesOnFiles(files, [{ kind: 'import', source: 'axum' }]);
+    expect(imports.map(candidate => candidate.symbol)).toEqual([
+      'import from axum::routing::get',
+      'import from axum::Router',
+    ]);
+    expect(imports[0]).toMatchObject({ kind: 'import', score: 0.8, role: 'dependency' });
+
+    const exports = await runAstQueriesOnFiles(files, [{ kind: 'export', name: /^(Db|Store)$/ }]);
+    expect(
+      exports.map(candidate => `${path.basename(candidate.file)}:${candidate.symbol}`)
+    ).toEqual(['main.rs:Db', 'store.rs:Store']);
+  });
+
+  it('answers call queries, honouring inFiles', async () => {
+    const calls = await runAstQueriesOnFiles(files, [
+      { kind: 'call', callee: /connect$/, inFiles: ['src/main.rs'] },
+    ]);
+    expect(calls).toHaveLength(1);
+    expect(calls[0]).toMatchObject({
+      symbol: 'store::connect',
+      kind: 'call',
+      reasons: ['call matches: store::connect'],
+    });
+  });
+
+  it('answers new queries with Type::new style constructors', async () => {
+    const created = await runAstQueriesOnFiles(files, [{ kind: 'new', className: 'Store' }]);
+    expect(created).toHaveLength(1);
+    expect(created[0]).toMatchObject({
+      file: path.join(project.path, 'src/store.rs'),
+      symbol: 'Store::default',
+      reasons: ['constructor matches: Store'],
+    });
+  });
+
+  it('answers env queries for runtime and compile-time keys', async () => {
+    const env = await runAstQueriesOnFiles(files, [{ kind: 'env', key: /DATABASE|GIT_SHA/ }]);
+    expect(env.map(candidate => candidate.symbol)).toEqual([
+      'env::var("DATABASE_URL")',
+      'env!("GIT_SHA")',
+    ]);
+    const content = SOURCES['src/main.rs'];
+    expect(content.slice(env[0].start, env[0].end)).toBe('DATABASE_URL');
+    expect(env[0]).toMatchObject({ kind: 'env', role: 'config' });
+  });
+
+  it('answers route queries with the handler location', async () => {
+    const routes = await runAstQueriesOnFiles(files, [{ kind: 'route', method: 'GET' }]);
+    expect(routes).toHaveLength(1);
+    expect(routes[0]).toMatchObject({
+      symbol: 'GET /health',
+      kind: 'export',
+      reasons: ['route:method-path', 'handler: health'],
+      role: 'request handler',
+    });
+    const content = SOURCES['src/main.rs'];
+    expect(content.slice(routes[0].start, routes[0].end)).toContain('fn health()');
+  });
+});
+
+describe('Rust structure queries', () => {
+  let project: { path: string; cleanup: () => Promise<void> };
+  let files: FileInfo[];
+  const symbols = (candidates: { symbol: string }[]) =>
+    candidates.map(candidate => candidate.symbol);
+
+  beforeAll(async () => {
+    project = await createTestProject(
+      Object.entries(STRUCTURE_SOURCES).map(([name, content]) => ({ name, content }))
+    );
+    files = Object.keys(STRUCTURE_SOURCES).map(relPath => ({
+      absPath: path.join(project.path, relPath),
+      relPath,
+      size: STRUCTURE_SOURCES[relPath].length,
+      ext: '.rs',
+      language: 'rust',
+    }));
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('answers impl queries by trait, by type, or both', async () => {
+    const byTrait = await runAstQueriesOnFiles(files, [{ kind: 'impl', trait: 'Display' }]);
+    expect(symbols(byTrait)).toEqual(['impl std::fmt::Display for User']);
+    expect(byTrait[0]).toMatchObject({ kind: 'class', role: 'implementation' });
+
+    // By trait, derives implement it too and the trait definition comes first
+    const serialize = await runAstQueriesOnFiles(files, [{ kind: 'impl', trait: 'Serialize' }]);
+    expect(symbols(serialize)).toEqual(['#[derive(Serialize)] User', '#[derive(Serialize)] Role']);
+    const store = await runAstQueriesOnFiles(files, [{ kind: 'impl', trait: 'Store' }]);
+    expect(symbols(store)).toEqual(['trait Store', 'impl Store for Memory<K>']);
+
+    // Without a trait, inherent impls count too
+    const byType = await runAstQueriesOnFiles(files, [{ kind: 'impl', type: 'Memory' }]);
+    expect(symbols(byType)).toEqual(['impl Store for Memory<K>', 'impl Memory<K>']);
+    expect(byType[1].reasons).toEqual(['inherent impl: Memory<K>']);
+
+    const both = await runAstQueriesOnFiles(files, [
+      { kind: 'impl', trait: 'Store', type: 'User' },
+    ]);
+    expect(both).toEqual([]);
+  });
+
+  it('answers trait queries with supertraits and impl counts', async () => {
+    const traits = await runAstQueriesOnFiles(files, [{ kind: 'trait' }]);
+    expect(symbols(traits)).toEqual(['trait Store']);
+    expect(traits[0].reasons).toEqual([
+      'trait definition: Store',
+      'supertraits: Send',
+      'implementations: 1',
+    ]);
+  });
+
+  it('answers derive queries for plain and path-qualified derives', async () => {
+    const serialize = await runAstQueriesOnFiles(files, [{ kind: 'derive', trait: 'Serialize' }]);
+    expect(symbols(serialize)).toEqual(['#[derive(Serialize)] User', '#[derive(Serialize)] Role']);
+
+    const deserialize = await runAstQueriesOnFiles(files, [
+      { kind: 'derive', trait: 'Deserialize' },
+    ]);
+    expect(symbols(deserialize)).toEqual(['#[derive(serde::Deserialize)] User']);
+  });
+
+  it('answers attribute queries by full path or last segment', async () => {
+    const instrumented = await runAstQueriesOnFiles(files, [
+      { kind: 'attribute', path: 'tracing::instrument' },
+    ]);
+    expect(symbols(instrumented)).toEqual([
+      '#[tracing::instrument] greet',
+      '#[tracing::instrument] load',
+    ]);
+
+    const serde = await runAstQueriesOnFiles(files, [{ kind: 'attribute', path: /rename_all/ }]);
+    expect(symbols(serde)).toEqual(['#[serde] User']);
+    expect(serde[0]).toMatchObject({
+      file: path.join(project.path, 'src/model.rs'),
+      kind: 'attribute',
+      reasons: ['attribute matches: #[serde(rename_all = "camelCase")]'],
+    });
+  });
+
+  it('answers macro queries with definitions and call sites', async () => {
+    const macros = await runAstQueriesOnFiles(files, [{ kind: 'macro', name: 'store_error!' }]);
+    expect(symbols(macros)).toEqual(['store_error!', 'store_error!']);
+    expect(macros[0]).toMatchObject({ role: 'macro definition', kind: 'function' });
+    expect(macros[1]).toMatchObject({
+      role: 'macro call site',
+      reasons: ['macro:invocation:store_error!', 'in: load'],
+    });
+  });
+});
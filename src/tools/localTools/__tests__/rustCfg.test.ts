@@ -0,0 +1,176 @@
+/**
+ * @fileOverview: Unit tests for cfg predicate evaluation and cfg-aware Rust views
+ * @module: rustCfgTests
+ * @description: Covers tri-state evaluation, compiled-out items and files, and the mini-bundle
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from '@jest/globals';
+import '../../../__tests__/utils/realFs';
+import * as path from 'path';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import { FileInfo } from '../../../core/compactor/fileDiscovery';
+import {
+  buildRustCfgView,
+  evaluateCfg,
+  findInactiveRanges,
+  normalizeCfgConfig,
+  parseCfgPredicate,
+  RustCfgConfig,
+  RustCfgView,
+} from '../utils/rustCfg';
+import { assembleMiniBundle } from '../miniBundleAssembler';
+
+const LIB = [
+  '#[cfg(windows)]',
+  'mod win;',
+  '#[cfg(unix)]',
+  'mod unix;',
+  '',
+  'pub struct Conn {',
+  '    #[cfg(feature = "tls")]',
+  '    tls: TlsStream,',
+  '    plain: TcpStream,',
+  '}',
+  '',
+  '#[cfg(not(test))]',
+  'pub fn connect() -> Conn {',
+  '    #[cfg(target_os = "macos")]',
+  '    tune_macos();',
+  '    Conn::new()',
+  '}',
+  '',
+  '#[cfg(test)]',
+  'mod tests {',
+  '    #[cfg(windows)]',
+  '    fn helper() {}',
+  '',
+  '    #[test]',
+  '    fn it_works() {}',
+  '}',
+].join('\n');
+
+const SOURCES: Record<string, string> = {
+  'src/lib.rs': LIB,
+  'src/win.rs': 'mod imp;\npub fn open() {}\n',
+  'src/win/imp.rs': 'pub fn raw() {}\n',
+  'src/unix.rs': 'pub fn open() {}\n',
+  'src/tls.rs': '#![cfg(feature = "tls")]\npub fn handshake() {}\n',
+  'tests/integration.rs': '#[test]\nfn connects() {}\n',
+};
+
+const LINUX: RustCfgConfig = { target_os: 'linux', features: [], test: false };
+
+describe('evaluateCfg', () => {
+  const evaluate = (predicate: string, config: RustCfgConfig) =>
+    evaluateCfg(parseCfgPredicate(predicate), config);
+
+  it('decides predicates the configuration covers', () => {
+    const tls = 'all(unix, feature = "tls")';
+    expect(evaluate(tls, { target_os: 'linux', features: ['tls'] })).toBe(true);
+    expect(evaluate(tls, { target_os: 'linux', features: [] })).toBe(false);
+    expect(evaluate('not(windows)', { target_os: 'linux' })).toBe(true);
+    expect(evaluate('any(target_os = "macos", test)', LINUX)).toBe(false);
+  });
+
+  it('leaves options the configuration does not mention unknown', () => {
+    expect(evaluate('all(unix, feature = "tls")', { target_os: 'linux' })).toBeUndefined();
+    expect(evaluate('any(target_os = "macos", test)', { target_os: 'linux' })).toBeUndefined();
+    expect(evaluate('target_pointer_width = "64"', { target_os: 'linux' })).toBeUndefined();
+  });
+});
+
+describe('normalizeCfgConfig', () => {
+  it('validates the tool argument', () => {
+    expect(normalizeCfgConfig({})).toBeUndefined();
+    expect(normalizeCfgConfig({ features: 'tls, json', test: true })).toEqual({
+      features: ['tls', 'json'],
+      test: true,
+    });
+    expect(() => normalizeCfgConfig({ target: 'linux' })).toThrow('Unknown cfg option "target"');
+    expect(() => normalizeCfgConfig({ test: 'no' })).toThrow('cfg.test must be true or false');
+  });
+});
+
+describe('findInactiveRanges', () => {
+  it('covers compiled-out items, fields and statements, outermost first', () => {
+    const ranges = findInactiveRanges(LIB, LINUX);
+    expect(ranges.map(range => [range.line, range.endLine, range.item])).toEqual([
+      [1, 2, 'mod win'],
+      [7, 8, undefined],
+      [14, 15, undefined],
+      [19, 26, 'mod tests'],
+    ]);
+    expect(findInactiveRanges(LIB, {})).toEqual([]);
+  });
+
+  it('covers the whole file for a false inner attribute', () => {
+    const ranges = findInactiveRanges(SOURCES['src/tls.rs'], LINUX);
+    expect(ranges.map(range => [range.line, range.endLine, range.start, range.end])).toEqual([
+      [1, 2, 0, SOURCES['src/tls.rs'].length],
+    ]);
+  });
+});
+
+describe('buildRustCfgView', () => {
+  let project: { path: string; cleanup: () => Promise<void> };
+  let view: RustCfgView;
+  const abs = (relPath: string) => path.join(project.path, relPath);
+
+  beforeAll(async () => {
+    project = await createTestProject(
+      Object.entries(SOURCES).map(([name, content]) => ({ name, content }))
+    );
+    const files: FileInfo[] = Object.keys(SOURCES).map(relPath => ({
+      absPath: path.join(project.path, relPath),
+      relPath,
+      size: SOURCES[relPath].length,
+      ext: '.rs',
+      language: 'rust',
+    }));
+    view = await buildRustCfgView(files, LINUX);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('drops gated module files with their submodules, and integration tests', () => {
+    const inactive = Array.from(view.inactiveFiles, file => path.relative(project.path, file));
+    expect(inactive.sort()).toEqual(
+      ['src/tls.rs', 'src/win.rs', 'src/win/imp.rs', 'tests/integration.rs'].map(path.normalize)
+    );
+    expect(view.isActive(abs('src/unix.rs'))).toBe(true);
+    expect(view.isActive(abs('src/lib.rs'), LIB.indexOf('Conn::new'))).toBe(true);
+    expect(view.isActive(abs('src/lib.rs'), LIB.indexOf('tune_macos'))).toBe(false);
+    expect(view.isActive('src/lib.rs', LIB.indexOf('it_works'))).toBe(false);
+  });
+
+  it('blanks compiled-out code without moving offsets', () => {
+    const blanked = view.blankInactive(abs('src/lib.rs'), LIB);
+    expect(blanked).toHaveLength(LIB.length);
+    expect(blanked.split('\n')).toHaveLength(LIB.split('\n').length);
+    expect(blanked).toContain('mod unix;');
+    expect(blanked).toContain('plain: TcpStream');
+    expect(blanked).not.toContain('tls: TlsStream');
+    expect(blanked).not.toContain('it_works');
+  });
+
+  it('keeps compiled-out code out of the mini-bundle', async () => {
+    const target = (symbol: string) => ({
+      file: abs('src/lib.rs'),
+      symbol,
+      start: LIB.indexOf(`fn ${symbol}`),
+      end: LIB.indexOf(`fn ${symbol}`) + 20,
+      role: 'operation',
+      confidence: 0.9,
+      why: [],
+    });
+
+    const targets = [target('connect'), target('it_works')];
+    const bundle = await assembleMiniBundle(targets, [], 3000, view);
+    expect(bundle.map(snippet => snippet.symbol)).toEqual(['connect']);
+    expect(bundle[0].snippet).toContain('Conn::new()');
+    expect(bundle[0].snippet).not.toContain('tune_macos');
+    expect(bundle[0].snippet).not.toContain('mod tests');
+  });
+});
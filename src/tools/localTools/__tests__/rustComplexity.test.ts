@@ -0,0 +1,167 @@
+/**
+ * @fileOverview: Unit tests for Rust cyclomatic and cognitive complexity
+ * @module: rustComplexityTests
+ * @description: Drives measureRustFunction with hand-built tree-sitter nodes (the grammar is
+ *               mocked in jest) to check match arms and guards, let-else, `?`, if let, closures
+ *               and `&&` in types
+ */
+
+import { describe, it, expect } from '@jest/globals';
+import { measureRustFunction } from '../../../core/compactor/rustComplexity';
+import { calculateRustComplexity } from '../analyzers/complexityAnalysis';
+
+/**
+ * Minimal stand-in for a named tree-sitter node; fields must also be listed as children
+ */
+function node(type: string, children: any[] = [], fields: Record<string, any> = {}): any {
+  const built: any = {
+    type,
+    children,
+    namedChildren: children,
+    parent: null,
+    childForFieldName: (field: string) => fields[field] ?? null,
+  };
+  for (const child of children) child.parent = built;
+  return built;
+}
+
+function binary(operator: string, left: any, right: any): any {
+  return node('binary_expression', [left, right], { left, operator: { type: operator }, right });
+}
+
+function fn(name: string, parameters: any, body: any): any {
+  const identifier = { ...node('identifier'), text: name };
+  return node('function_item', [identifier, parameters, body], {
+    name: identifier,
+    parameters,
+    body,
+  });
+}
+
+const id = () => node('identifier');
+
+// pub fn simple(x: u32) -> u32 {
+//     x + 1
+// }
+function simple(): any {
+  const body = node('block', [binary('+', id(), node('integer_literal'))]);
+  return fn('simple', node('parameters'), body);
+}
+
+// pub fn classify(value: Option<&&str>, retries: u32) -> Result<u8, Error> {
+//     let Some(text) = value else {
+//         return Err(Error::Missing);
+//     };
+//     let parsed = parse(text)?;
+//     match parsed {
+//         0 => Ok(0),
+//         n if n > 10 && retries > 0 => Ok(1),
+//         _ => {
+//             if let Some(limit) = LIMIT {
+//                 for i in 0..limit {
+//                     if i == parsed {
+//                         return Ok(2);
+//                     }
+//                 }
+//             }
+//             Ok(3)
+//         }
+//     }
+// }
+function classify(): any {
+  const parameters = node('parameters', [
+    node('parameter', [
+      id(),
+      node('generic_type', [node('reference_type', [node('reference_type')])]),
+    ]),
+    node('parameter', [id(), node('primitive_type')]),
+  ]);
+
+  const alternative = node('block', [node('return_expression', [node('call_expression')])]);
+  const letElse = node('let_declaration', [node('tuple_struct_pattern'), id(), alternative], {
+    alternative,
+  });
+  const letTry = node('let_declaration', [id(), node('try_expression', [node('call_expression')])]);
+
+  const guard = binary(
+    '&&',
+    binary('>', id(), node('integer_literal')),
+    binary('>', id(), node('integer_literal'))
+  );
+  const guardedPattern = node('match_pattern', [id(), guard], { condition: guard });
+  const innerIf = node('if_expression', [
+    binary('==', id(), id()),
+    node('block', [node('return_expression', [node('call_expression')])]),
+  ]);
+  const forLoop = node('for_expression', [
+    id(),
+    node('range_expression', [node('integer_literal'), id()]),
+    node('block', [innerIf]),
+  ]);
+  const ifLet = node('if_expression', [
+    node('let_condition', [node('tuple_struct_pattern'), id()]),
+    node('block', [forLoop]),
+  ]);
+  const arm = (pattern: any, value: any) => node('match_arm', [pattern, value], { pattern, value });
+  const match = node('match_expression', [
+    id(),
+    node('match_block', [
+      arm(node('match_pattern', [node('integer_literal')]), node('call_expression')),
+      arm(guardedPattern, node('call_expression')),
+      arm(node('match_pattern'), node('block', [ifLet, node('call_expression')])),
+    ]),
+  ]);
+
+  return fn('classify', parameters, node('block', [letElse, letTry, match]));
+}
+
+// pub fn drain(&mut self) -> usize {
+//     self.items.iter().filter(|item| item.ready || item.forced).count()
+// }
+function drain(): any {
+  const closure = node('closure_expression', [
+    node('closure_parameters', [id()]),
+    binary('||', node('field_expression'), node('field_expression')),
+  ]);
+  const filter = node('call_expression', [node('field_expression'), node('arguments', [closure])]);
+  const count = node('call_expression', [node('field_expression', [filter]), node('arguments')]);
+  return fn('drain', node('parameters', [node('self_parameter')]), node('block', [count]));
+}
+
+describe('Rust complexity', () => {
+  it('scores straight-line functions as 1 / 0', () => {
+    expect(measureRustFunction(simple())).toEqual({ cyclomatic: 1, cognitive: 0, maxNesting: 0 });
+  });
+
+  it('counts match arms, guards, let-else, `?` and if let with nesting', () => {
+    expect(measureRustFunction(classify())).toEqual({
+      cyclomatic: 11,
+      cognitive: 13,
+      maxNesting: 4,
+    });
+  });
+
+  it('counts boolean operators inside closures but not `&&` in types', () => {
+    expect(measureRustFunction(drain())).toEqual({ cyclomatic: 2, cognitive: 1, maxNesting: 1 });
+  });
+
+  it('does not score items nested inside a body', () => {
+    const nested = node('block', [node('function_item', [node('block', [node('if_expression')])])]);
+    expect(measureRustFunction(fn('outer', node('parameters'), nested))).toEqual({
+      cyclomatic: 1,
+      cognitive: 0,
+      maxNesting: 0,
+    });
+  });
+
+  it('rolls functions up for local_file_summary, most complex first', () => {
+    const data = calculateRustComplexity([
+      { name: 'simple', line: 1, complexity: measureRustFunction(simple()) },
+      { name: 'classify', line: 5, complexity: measureRustFunction(classify()) },
+      { name: 'drain', line: 27, className: 'Limiter', complexity: measureRustFunction(drain()) },
+    ]);
+    expect(data.functions.map(fn => fn.name)).toEqual(['classify', 'drain', 'simple']);
+    expect(data.breakdown['Limiter::drain']).toBe(2);
+    expect(data.decisionPoints).toBe(11);
+  });
+});
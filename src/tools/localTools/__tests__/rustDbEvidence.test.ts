@@ -0,0 +1,117 @@
+/**
+ * @fileOverview: Unit tests for Rust database evidence
+ * @module: rustDbEvidenceTests
+ * @description: Verifies sqlx, diesel, rusqlite and tokio-postgres evidence, initializers and
+ *               migrations globbed from the project
+ */
+
+import { describe, it, expect, beforeAll, afterAll, jest } from '@jest/globals';
+import { walkingGlobby } from '../../../__tests__/utils/realFs';
+import * as path from 'path';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import { detectDb, DbInfo } from '../indexers';
+import { collectDbEvidence, detectDatabaseEngine } from '../utils/dbEvidence';
+import type { FileInfo } from '../../../core/compactor/fileDiscovery';
+
+jest.mock('globby', () => walkingGlobby());
+
+describe('detectDatabaseEngine (Rust)', () => {
+  it('detects engines from sqlx pools, rusqlite and tokio-postgres', () => {
+    expect(detectDatabaseEngine('use sqlx::postgres::PgPoolOptions;\n').engine).toBe('postgresql');
+    expect(detectDatabaseEngine('let pool = SqlitePool::connect(&url).await?;\n').engine).toBe(
+      'sqlite'
+    );
+    expect(detectDatabaseEngine('let conn = rusqlite::Connection::open("app.db")?;\n').engine).toBe(
+      'sqlite'
+    );
+    const tokioPostgres = 'let (client, conn) = tokio_postgres::connect(url, NoTls).await?;';
+    expect(detectDatabaseEngine(tokioPostgres).engine).toBe('postgresql');
+  });
+
+  it('collects diesel table! and sqlx query macros as evidence', () => {
+    const evidence = collectDbEvidence(
+      [
+        '// table! { ignored }',
+        'diesel::table! {',
+        '    users (id) { id -> Int4, }',
+        '}',
+        'let row = sqlx::query_as!(User, "SELECT * FROM users WHERE id = $1", id)',
+      ].join('\n')
+    );
+    expect(evidence.map(item => item.line)).toEqual([2, 5]);
+  });
+});
+
+describe('detectDb (Rust)', () => {
+  const sources: Record<string, string> = {
+    'src/db/mod.rs': [
+      'use sqlx::postgres::{PgPool, PgPoolOptions};',
+      '',
+      'pub async fn create_pool(url: &str) -> sqlx::Result<PgPool> {',
+      '    PgPoolOptions::new().max_connections(5).connect(url).await',
+      '}',
+    ].join('\n'),
+    'src/db/users.rs': [
+      'pub struct UserRepository { pool: PgPool }',
+      '',
+      'impl UserRepository {',
+      '    pub async fn find(&self, id: i64) -> sqlx::Result<User> {',
+      '        sqlx::query_as!(User, "SELECT * FROM users WHERE id = $1", id)',
+      '            .fetch_one(&self.pool)',
+      '            .await',
+      '    }',
+      '}',
+    ].join('\n'),
+    'migrations/20240101000000_init.sql': 'CREATE TABLE users (id BIGSERIAL PRIMARY KEY);\n',
+    'schema/2024-02-01-000000_add_email/up.sql': 'ALTER TABLE users ADD COLUMN email TEXT;\n',
+    'schema/2024-02-01-000000_add_email/down.sql': 'ALTER TABLE users DROP COLUMN email;\n',
+  };
+
+  let project: { path: string; cleanup: () => Promise<void> };
+  let db: DbInfo;
+
+  beforeAll(async () => {
+    project = await createTestProject(
+      Object.entries(sources).map(([name, content]) => ({ name, content }))
+    );
+    // File discovery only returns supported languages, so migrations come from the project path
+    const files: FileInfo[] = Object.keys(sources)
+      .filter(relPath => relPath.endsWith('.rs'))
+      .map(relPath => ({
+        absPath: path.join(project.path, relPath),
+        relPath,
+        size: sources[relPath].length,
+        ext: '.rs',
+        language: 'rust',
+      }));
+    db = await detectDb(files, project.path);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('reports the engine, initializers and repository types', () => {
+    expect(db.engine).toBe('postgresql');
+    expect(db.initializers).toEqual(
+      expect.arrayContaining([
+        { file: 'src/db/mod.rs', symbol: 'create_pool', line: 3 },
+        { file: 'src/db/users.rs', symbol: 'UserRepository', line: 1 },
+      ])
+    );
+    expect(db.connections).toEqual(
+      expect.arrayContaining([expect.objectContaining({ file: 'src/db/mod.rs', line: 4 })])
+    );
+  });
+
+  it('counts migrations found on disk as schema evidence', () => {
+    expect(db.evidence?.map(item => item.file)).toEqual(
+      expect.arrayContaining([
+        'migrations/20240101000000_init.sql',
+        'schema/2024-02-01-000000_add_email/down.sql',
+        'schema/2024-02-01-000000_add_email/up.sql',
+      ])
+    );
+    expect(db.confidence).toBeGreaterThan(0.5);
+  });
+});
@@ -0,0 +1,169 @@
+/**
+ * @fileOverview: Unit tests for Rust environment variable detection
+ * @module: rustEnvTests
+ * @description: Verifies std::env, env!, clap, envy, config and figment keys, their usage and how
+ *               usages of one key are merged
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from '@jest/globals';
+import '../../../__tests__/utils/realFs';
+import * as path from 'path';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import { detectEnvKeys, EnvItem } from '../indexers';
+import type { FileInfo } from '../../../core/compactor/fileDiscovery';
+
+describe('detectEnvKeys (Rust)', () => {
+  const sources: Record<string, string> = {
+    'src/main.rs': [
+      'use std::env;',
+      'const TOKEN_VAR: &str = "API_TOKEN";',
+      '',
+      'fn main() -> anyhow::Result<()> {',
+      '    dotenvy::dotenv().ok();',
+      '    let db = env::var("DATABASE_URL").expect("DATABASE_URL must be set");',
+      '    let port: u16 = std::env::var("PORT").map(|p| p.parse().unwrap()).unwrap_or(8080);',
+      '    let token = env::var(TOKEN_VAR)?;',
+      '    if let Ok(level) = env::var("RUST_LOG") {}',
+      '    let home = env::var_os("HOME");',
+      '    let raw = env::var("RAW_VALUE");',
+      '    let version = env!("CARGO_PKG_VERSION");',
+      '    let sha = option_env!("GIT_SHA");',
+      '    // env::var("COMMENTED_OUT")',
+      '    Ok(())',
+      '}',
+      'fn sentry_dsn() -> Option<String> { env::var("SENTRY_DSN").ok() }',
+    ].join('\n'),
+    'src/cli.rs': [
+      '#[derive(clap::Parser)]',
+      'pub struct Args {',
+      '    #[arg(long, env = "BIND_ADDR")]',
+      '    pub bind: String,',
+      '    #[arg(long, env = "WORKERS", default_value_t = 4)]',
+      '    pub workers: usize,',
+      '    /// Enables tracing',
+      '    #[arg(long, env)]',
+      '    pub otel_endpoint: Option<String>,',
+      '}',
+    ].join('\n'),
+    'src/settings.rs': [
+      '#[derive(serde::Deserialize)]',
+      'pub struct Settings {',
+      '    pub redis_url: String,',
+      '    #[serde(default)]',
+      '    pub cache_ttl: u64,',
+      '    pub database: DatabaseSettings,',
+      '}',
+      '',
+      '#[derive(serde::Deserialize)]',
+      'pub struct DatabaseSettings {',
+      '    pub pool_size: Option<u32>,',
+      '}',
+      '',
+      'impl Settings {',
+      '    pub fn new() -> Result<Self, config::ConfigError> {',
+      '        let s = config::Config::builder()',
+      '            .add_source(config::Environment::with_prefix("APP").separator("__"))',
+      '            .build()?;',
+      '        s.try_deserialize()',
+      '    }',
+      '}',
+    ].join('\n'),
+    'src/worker.rs': [
+      '#[derive(serde::Deserialize)]',
+      'struct WorkerConfig {',
+      '    #[serde(rename = "queue")]',
+      '    queue_name: String,',
+      '}',
+      'fn load() -> WorkerConfig {',
+      '    envy::prefixed("WORKER_").from_env::<WorkerConfig>().unwrap()',
+      '}',
+      'fn figment() {',
+      '    let jobs: JobSettings = Figment::new().merge(Env::prefixed("JOBS_")).extract().unwrap();',
+      '}',
+      'fn replica_url() -> Option<String> {',
+      '    std::env::var("DATABASE_URL").ok()',
+      '}',
+      'fn init_sentry() {',
+      '    let dsn = std::env::var("SENTRY_DSN").expect("SENTRY_DSN must be set");',
+      '}',
+    ].join('\n'),
+    'build.rs': 'fn main() { let out = std::env::var("OUT_DIR").unwrap(); }\n',
+  };
+
+  let project: { path: string; cleanup: () => Promise<void> };
+  let envKeys: EnvItem[];
+  const byKey = (key: string) => envKeys.find(env => env.key === key);
+
+  beforeAll(async () => {
+    project = await createTestProject(
+      Object.entries(sources).map(([name, content]) => ({ name, content }))
+    );
+    const files: FileInfo[] = Object.keys(sources).map(relPath => ({
+      absPath: path.join(project.path, relPath),
+      relPath,
+      size: sources[relPath].length,
+      ext: '.rs',
+      language: 'rust',
+    }));
+    envKeys = await detectEnvKeys(files);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('classifies std::env reads by how the result is handled', () => {
+    expect(byKey('DATABASE_URL')).toMatchObject({
+      file: 'src/main.rs',
+      line: 6,
+      usage: 'required',
+    });
+    expect(byKey('PORT')?.usage).toBe('optional');
+    expect(byKey('API_TOKEN')?.usage).toBe('required');
+    expect(byKey('RUST_LOG')?.usage).toBe('optional');
+    expect(byKey('HOME')?.usage).toBe('optional');
+    expect(byKey('RAW_VALUE')?.usage).toBe('read');
+    expect(byKey('GIT_SHA')?.usage).toBe('compile-time');
+  });
+
+  it('merges usages of a key read in several places and keeps the strongest', () => {
+    expect(byKey('DATABASE_URL')).toMatchObject({
+      file: 'src/main.rs',
+      line: 6,
+      usage: 'required',
+      usages: ['required', 'optional'],
+      references: 2,
+    });
+    expect(byKey('SENTRY_DSN')).toMatchObject({
+      file: 'src/worker.rs',
+      line: 16,
+      usage: 'required',
+      usages: ['required', 'optional'],
+    });
+    expect(envKeys.filter(env => env.key === 'DATABASE_URL')).toHaveLength(1);
+  });
+
+  it('skips cargo-provided variables and comments', () => {
+    expect(byKey('CARGO_PKG_VERSION')).toBeUndefined();
+    expect(byKey('OUT_DIR')).toBeUndefined();
+    expect(byKey('COMMENTED_OUT')).toBeUndefined();
+  });
+
+  it('reads clap env arguments', () => {
+    expect(byKey('BIND_ADDR')).toMatchObject({ file: 'src/cli.rs', line: 4, usage: 'required' });
+    expect(byKey('WORKERS')?.usage).toBe('optional');
+    expect(byKey('OTEL_ENDPOINT')).toMatchObject({ line: 9, usage: 'optional' });
+  });
+
+  it('expands struct-driven loaders into prefixed field keys', () => {
+    expect(byKey('APP_REDIS_URL')).toMatchObject({
+      file: 'src/settings.rs',
+      line: 3,
+      usage: 'required',
+    });
+    expect(byKey('APP_CACHE_TTL')?.usage).toBe('optional');
+    expect(byKey('APP_DATABASE__POOL_SIZE')).toMatchObject({ line: 11, usage: 'optional' });
+    expect(byKey('WORKER_QUEUE')).toMatchObject({ file: 'src/worker.rs', usage: 'required' });
+    expect(byKey('JOBS_*')).toMatchObject({ file: 'src/worker.rs', line: 10, usage: 'read' });
+  });
+});
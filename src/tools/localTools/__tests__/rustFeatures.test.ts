@@ -0,0 +1,208 @@
+/**
+ * @fileOverview: Unit tests for the Cargo feature map and cfg(feature) index
+ * @module: rustFeaturesTests
+ * @description: Covers [features] parsing, transitive enablement across members and gated code
+ */
+
+import { describe, it, expect, beforeAll, afterAll, jest } from '@jest/globals';
+import { walkingGlobby } from '../../../__tests__/utils/realFs';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import { parseToml } from '../utils/toml';
+import {
+  extractCfgFeatureSites,
+  parseCargoFeatures,
+  resolveFeatureEnablement,
+} from '../utils/rustFeatures';
+import { buildCargoFeatureSummary, generateAnswerDraft } from '../enhancedHints';
+
+jest.mock('globby', () => walkingGlobby());
+
+const NET_MANIFEST = `[package]
+name = "net"
+
+[dependencies]
+rustls = { version = "0.23", optional = true }
+tokio-rustls = { version = "0.26", optional = true }
+native-tls = { version = "0.2", optional = true }
+serde = { version = "1", optional = true }
+core = { path = "../core" }
+
+[features]
+default = ["tls-rustls"]
+tls = []
+tls-rustls = ["tls", "dep:rustls", "tokio-rustls/early-data", "core/metrics"]
+tls-native = ["tls", "dep:native-tls"]
+serde = ["dep:serde", "core?/serde"]
+`;
+
+const CORE_MANIFEST = `[package]
+name = "core"
+
+[dependencies]
+prometheus = { version = "0.13", optional = true }
+
+[features]
+metrics = ["prometheus"]
+`;
+
+const SOURCES: Record<string, string> = {
+  'crates/net/src/lib.rs': [
+    '#[cfg(feature = "tls-rustls")]',
+    'mod rustls_tls;',
+    '',
+    '#[cfg(all(feature = "tls", not(feature = "tls-rustls")))]',
+    'pub fn fallback() {}',
+    '',
+    '#[cfg(any(feature = "tls-rustls", feature = "tls-native"))]',
+    'impl Connector {',
+    '    pub fn secure(&self) {}',
+    '}',
+    '',
+    '#[cfg_attr(feature = "serde", derive(serde::Serialize))]',
+    'pub struct Config {}',
+    '',
+    'pub fn describe() -> &\'static str {',
+    '    // cfg!(feature = "commented")',
+    '    if cfg!(feature = "tls-rustls") { "rustls" } else { "plain" }',
+    '}',
+  ].join('\n'),
+  'crates/net/src/rustls_tls.rs': 'pub struct RustlsConnector;\n',
+  'crates/net/src/native.rs': [
+    '#![cfg(feature = "tls-native")]',
+    'pub struct NativeConnector;',
+  ].join('\n'),
+};
+
+describe('parseCargoFeatures', () => {
+  const features = parseCargoFeatures(parseToml(NET_MANIFEST), {
+    name: 'net',
+    dir: 'crates/net',
+    manifestPath: 'crates/net/Cargo.toml',
+  });
+
+  it('separates features, dep: activations and crate/feature forwards', () => {
+    expect(features.defaults).toEqual(['tls-rustls']);
+    expect(features.features.find(f => f.name === 'tls-rustls')).toEqual({
+      name: 'tls-rustls',
+      implicit: false,
+      features: ['tls'],
+      dependencies: ['rustls', 'tokio-rustls'],
+      forwards: [
+        { dependency: 'tokio-rustls', feature: 'early-data', weak: false },
+        { dependency: 'core', feature: 'metrics', weak: false },
+      ],
+    });
+    expect(features.features.find(f => f.name === 'serde')?.forwards).toEqual([
+      { dependency: 'core', feature: 'serde', weak: true },
+    ]);
+  });
+
+  it('adds implicit features only for optional dependencies never named with dep:', () => {
+    expect(features.features.filter(f => f.implicit).map(f => f.name)).toEqual(['tokio-rustls']);
+
+    const core = parseCargoFeatures(parseToml(CORE_MANIFEST), {
+      name: 'core',
+      dir: 'crates/core',
+      manifestPath: 'crates/core/Cargo.toml',
+    });
+    expect(core.features.map(f => [f.name, f.implicit, f.features])).toEqual([
+      ['metrics', false, ['prometheus']],
+      ['prometheus', true, []],
+    ]);
+  });
+});
+
+describe('resolveFeatureEnablement', () => {
+  const crates = [
+    parseCargoFeatures(parseToml(NET_MANIFEST), {
+      name: 'net',
+      dir: 'crates/net',
+      manifestPath: 'crates/net/Cargo.toml',
+    }),
+    parseCargoFeatures(parseToml(CORE_MANIFEST), {
+      name: 'core',
+      dir: 'crates/core',
+      manifestPath: 'crates/core/Cargo.toml',
+    }),
+  ];
+
+  it('follows features transitively, into other workspace members', () => {
+    expect(resolveFeatureEnablement(crates, 'net', 'default')).toEqual({
+      crate: 'net',
+      feature: 'default',
+      features: ['tls-rustls', 'tls', 'core/metrics', 'core/prometheus'],
+      dependencies: ['rustls', 'tokio-rustls', 'core/prometheus'],
+      external: ['tokio-rustls/early-data'],
+    });
+  });
+
+  it('does not follow weak forwards and rejects unknown features', () => {
+    expect(resolveFeatureEnablement(crates, 'net', 'serde')?.features).toEqual([]);
+    expect(resolveFeatureEnablement(crates, 'net', 'quic')).toBeUndefined();
+  });
+});
+
+describe('extractCfgFeatureSites', () => {
+  const sites = extractCfgFeatureSites(new Map(Object.entries(SOURCES)));
+
+  it('reduces predicates to required and excluded features', () => {
+    expect(sites.map(site => [site.line, site.kind, site.required, site.excluded])).toEqual([
+      [1, 'cfg', ['tls-rustls'], []],
+      [4, 'cfg', ['tls'], ['tls-rustls']],
+      [7, 'cfg', [], []],
+      [12, 'cfg_attr', ['serde'], []],
+      [17, 'cfg!', ['tls-rustls'], []],
+      [1, 'cfg', ['tls-native'], []],
+    ]);
+    expect(sites[2].mentioned).toEqual(['tls-rustls', 'tls-native']);
+  });
+
+  it('names the gated item and resolves whole files behind the gate', () => {
+    expect(sites.map(site => [site.item, site.gatedFile])).toEqual([
+      ['mod rustls_tls', 'crates/net/src/rustls_tls.rs'],
+      ['fn fallback', undefined],
+      ['impl Connector', undefined],
+      ['struct Config', undefined],
+      [undefined, undefined],
+      [undefined, 'crates/net/src/native.rs'],
+    ]);
+    expect([sites[2].line, sites[2].endLine]).toEqual([7, 10]);
+  });
+});
+
+describe('buildCargoFeatureSummary', () => {
+  let project: { path: string; cleanup: () => Promise<void> };
+
+  beforeAll(async () => {
+    project = await createTestProject([
+      { name: 'Cargo.toml', content: '[workspace]\nmembers = ["crates/*"]\n' },
+      { name: 'crates/net/Cargo.toml', content: NET_MANIFEST },
+      { name: 'crates/core/Cargo.toml', content: CORE_MANIFEST },
+      { name: 'crates/core/src/lib.rs', content: 'pub fn run() {}\n' },
+      ...Object.entries(SOURCES).map(([name, content]) => ({ name, content })),
+    ]);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('lists what each feature enables and the code compiled only with it', async () => {
+    const summary = await buildCargoFeatureSummary(project.path);
+    const net = summary!.crates.find(entry => entry.crate === 'net')!;
+    const rustls = net.features.find(feature => feature.name === 'tls-rustls')!;
+
+    expect(rustls.enables).toEqual(['tls', 'core/metrics', 'core/prometheus']);
+    expect(rustls.gated.map(site => [site.file, site.line, site.gatedFile])).toEqual([
+      ['crates/net/src/lib.rs', 1, 'crates/net/src/rustls_tls.rs'],
+      ['crates/net/src/lib.rs', 17, undefined],
+    ]);
+
+    const draft = generateAnswerDraft(
+      { features: summary, systems: {}, capabilities: { domains: [] }, hints: [] } as any,
+      'What code compiles only with `tls-rustls`?'
+    );
+    expect(draft).toContain('crates/net/src/rustls_tls.rs (mod rustls_tls)');
+    expect(draft).toContain('dep:rustls');
+  });
+});
@@ -0,0 +1,226 @@
+/**
+ * @fileOverview: Unit tests for Rust macro definitions and invocation sites
+ * @module: rustMacrosTests
+ * @description: Covers macro_rules!, proc-macro kinds, bang/attribute/derive calls and caller edges;
+ *               ASTParser macro tags are driven with hand-built tree-sitter nodes
+ */
+
+import { describe, it, expect, beforeAll } from '@jest/globals';
+import { ASTParser, ParsedFile } from '../../../core/compactor/astParser';
+import {
+  buildMacroReferences,
+  buildRustMacroIndex,
+  findMacroUsages,
+  RustMacroIndex,
+} from '../utils/rustMacros';
+
+const SOURCES: Record<string, string> = {
+  'src/metrics.rs': [
+    '#[macro_export]',
+    'macro_rules! define_metric {',
+    '    ($name:ident) => { $crate::register!(stringify!($name)) };',
+    '}',
+    '',
+    'macro_rules! register {',
+    '    ($e:expr) => { $e };',
+    '}',
+  ].join('\n'),
+  'src/server.rs': [
+    'use crate::define_metric;',
+    '',
+    '#[derive(Debug, Metric)]',
+    '#[serde(rename_all = "camelCase")]',
+    '#[metric(prefix = "http")]',
+    'pub struct Requests {',
+    '    #[serde(default)]',
+    '    count: u64,',
+    '}',
+    '',
+    '#[tracing::instrument(skip(self))]',
+    'pub async fn handle(req: Request) -> Response {',
+    '    define_metric!(requests_total);',
+    '    // define_metric!(commented_out);',
+    '    let label = "define_metric!(in_string)";',
+    '    let ids = vec![1, 2];',
+    '    if ids.len() != (2) { log::warn!("odd") }',
+    '    Response::ok()',
+    '}',
+    '',
+    '#[async_trait]',
+    '#[rustfmt::skip]',
+    'impl Service for Server {}',
+    '',
+    'define_metric!(startup_seconds);',
+  ].join('\n'),
+  'macros/src/lib.rs': [
+    'use proc_macro::TokenStream;',
+    '',
+    '/// Implements `Metric` for a struct',
+    '#[proc_macro_derive(Metric, attributes(metric))]',
+    'pub fn derive_metric(input: TokenStream) -> TokenStream {',
+    '    input',
+    '}',
+    '',
+    '#[proc_macro_attribute]',
+    'pub fn timed(_attr: TokenStream, item: TokenStream) -> TokenStream {',
+    '    item',
+    '}',
+    '',
+    '#[proc_macro]',
+    'pub fn sql(input: TokenStream) -> TokenStream {',
+    '    input',
+    '}',
+  ].join('\n'),
+};
+
+describe('buildRustMacroIndex', () => {
+  let index: RustMacroIndex;
+
+  beforeAll(() => {
+    index = buildRustMacroIndex(new Map(Object.entries(SOURCES)));
+  });
+
+  it('indexes declarative and proc-macro definitions by invocation name', () => {
+    expect(index.definitions.map(def => [def.name, def.kind, def.fnName, def.exported])).toEqual([
+      ['define_metric', 'declarative', undefined, true],
+      ['register', 'declarative', undefined, false],
+      ['Metric', 'derive', 'derive_metric', true],
+      ['timed', 'attribute', 'timed', true],
+      ['sql', 'function', 'sql', true],
+    ]);
+    expect(index.definitions[2].helpers).toEqual(['metric']);
+  });
+
+  it('attributes bang invocations to the enclosing function, ignoring comments and strings', () => {
+    const calls = findMacroUsages(index, 'define_metric!').invocations;
+    expect(calls.map(call => [call.file, call.line, call.caller])).toEqual([
+      ['src/server.rs', 13, 'handle'],
+      ['src/server.rs', 25, undefined],
+    ]);
+    expect(findMacroUsages(index, 'register').invocations[0].path).toBe('$crate::register');
+    expect(findMacroUsages(index, /^(vec|warn)$/).invocations.map(call => call.path)).toEqual([
+      'vec',
+      'log::warn',
+    ]);
+  });
+
+  it('records attribute and derive macros but not built-ins or derive helpers', () => {
+    const attributes = index.invocations.filter(call => call.kind !== 'bang');
+    expect(attributes.map(call => [call.kind, call.path, call.caller])).toEqual([
+      ['derive', 'Debug', 'Requests'],
+      ['derive', 'Metric', 'Requests'],
+      ['attribute', 'tracing::instrument', 'handle'],
+      ['attribute', 'async_trait', undefined],
+    ]);
+  });
+});
+
+describe('buildMacroReferences', () => {
+  it('links project macros and their callers in both directions', () => {
+    const index = buildRustMacroIndex(new Map(Object.entries(SOURCES)));
+    const references = buildMacroReferences(index, relPath => `/repo/${relPath}`);
+
+    expect(references.get('/repo/src/metrics.rs:define_metric')).toEqual([
+      '/repo/src/server.rs:handle',
+      '/repo/src/server.rs:define_metric!',
+    ]);
+    expect(references.get('/repo/src/server.rs:handle')).toEqual([
+      '/repo/src/metrics.rs:define_metric',
+    ]);
+    expect(references.get('/repo/macros/src/lib.rs:Metric')).toEqual([
+      '/repo/src/server.rs:Requests',
+    ]);
+  });
+});
+
+/**
+ * Minimal stand-in for a tree-sitter node covering the first occurrence of `text` in `source`
+ * at or after `after`
+ */
+function syntaxNode(
+  source: string,
+  type: string,
+  text: string,
+  options: { fields?: Record<string, any>; after?: string } = {}
+): any {
+  const startIndex = source.indexOf(text, options.after ? source.indexOf(options.after) : 0);
+  if (startIndex === -1) throw new Error(`not in source: ${text}`);
+  const endIndex = startIndex + text.length;
+  const position = (index: number) => {
+    const before = source.slice(0, index).split('\n');
+    return { row: before.length - 1, column: before[before.length - 1].length };
+  };
+  return {
+    type,
+    text,
+    startIndex,
+    endIndex,
+    startPosition: position(startIndex),
+    endPosition: position(endIndex),
+    children: [],
+    namedChildren: [],
+    childForFieldName: (field: string) => options.fields?.[field] ?? null,
+  };
+}
+
+/**
+ * Run ASTParser over top-level items built by hand (the grammar is mocked in jest)
+ */
+async function parseItems(source: string, items: any[]): Promise<ParsedFile> {
+  items.forEach((item, i) => (item.previousNamedSibling = items[i - 1] ?? null));
+  const parser = new ASTParser();
+  const rootNode = { type: 'source_file', children: items, namedChildren: items };
+  (parser as any).parsers.set('rust', { parse: () => ({ rootNode }) });
+  return (parser as any).parseRust('/project/src/lib.rs', source);
+}
+
+describe('ASTParser macro symbols', () => {
+  it('tags proc-macro functions with the macro they define', async () => {
+    const source = SOURCES['macros/src/lib.rs'];
+    const node = (type: string, text: string, options?: { fields?: any; after?: string }) =>
+      syntaxNode(source, type, text, options);
+    const fn = (name: string) =>
+      node('function_item', `pub fn ${name}(`, {
+        fields: {
+          name: node('identifier', name, { after: `fn ${name}` }),
+          body: node('block', '{', { after: `fn ${name}` }),
+        },
+      });
+
+    const parsed = await parseItems(source, [
+      node('use_declaration', 'use proc_macro::TokenStream;'),
+      node('line_comment', '/// Implements `Metric` for a struct'),
+      node('attribute_item', '#[proc_macro_derive(Metric, attributes(metric))]'),
+      fn('derive_metric'),
+      node('attribute_item', '#[proc_macro_attribute]'),
+      fn('timed'),
+      node('attribute_item', '#[proc_macro]'),
+      fn('sql'),
+    ]);
+    expect(parsed.symbols.map(symbol => [symbol.name, symbol.macro])).toEqual([
+      ['derive_metric', { kind: 'derive', name: 'Metric', helpers: ['metric'] }],
+      ['timed', { kind: 'attribute', name: 'timed' }],
+      ['sql', { kind: 'function', name: 'sql' }],
+    ]);
+  });
+
+  it('exports macro_rules! only under #[macro_export]', async () => {
+    const source = SOURCES['src/metrics.rs'];
+    const node = (type: string, text: string, options?: { fields?: any; after?: string }) =>
+      syntaxNode(source, type, text, options);
+    const rules = (name: string) =>
+      node('macro_definition', `macro_rules! ${name} {`, {
+        fields: { name: node('identifier', name, { after: `macro_rules! ${name}` }) },
+      });
+
+    const parsed = await parseItems(source, [
+      node('attribute_item', '#[macro_export]'),
+      rules('define_metric'),
+      rules('register'),
+    ]);
+    expect(parsed.symbols.map(symbol => [symbol.name, symbol.isExported, symbol.macro])).toEqual([
+      ['define_metric', true, { kind: 'declarative', name: 'define_metric' }],
+      ['register', false, { kind: 'declarative', name: 'register' }],
+    ]);
+  });
+});
@@ -0,0 +1,111 @@
+/**
+ * @fileOverview: Unit tests for Rust module-tree resolution in the import graph
+ * @module: rustModulesTests
+ * @description: Verifies mod/use resolution across a small two-crate Cargo workspace
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from '@jest/globals';
+import '../../../__tests__/utils/realFs';
+import * as path from 'path';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import { buildImportGraph } from '../indexers';
+import { expandRustUseTree } from '../utils/rustModules';
+import type { FileInfo } from '../../../core/compactor/fileDiscovery';
+
+describe('expandRustUseTree', () => {
+  it('flattens nested groups, globs, self and renames', () => {
+    expect(expandRustUseTree('crate::{models::{self, Invoice as Inv}, util::*}')).toEqual([
+      ['crate', 'models'],
+      ['crate', 'models', 'Invoice'],
+      ['crate', 'util'],
+    ]);
+  });
+});
+
+describe('buildImportGraph (Rust)', () => {
+  const sources: Record<string, string> = {
+    'Cargo.toml': '[workspace]\nmembers = ["crates/*"]\n',
+    'crates/core/Cargo.toml': '[package]\nname = "billing-core"\nversion = "0.1.0"\n',
+    'crates/core/src/lib.rs': [
+      '//! use billing_api::nothing;',
+      'pub mod models;',
+      'mod util;',
+      '#[path = "gen/schema.rs"]',
+      'mod schema;',
+      'pub use crate::models::Invoice;',
+    ].join('\n'),
+    'crates/core/src/models/mod.rs': [
+      'mod invoice;',
+      'pub use self::invoice::Invoice;',
+      'use super::util::helper;',
+    ].join('\n'),
+    'crates/core/src/models/invoice.rs': [
+      'use crate::util::helper;',
+      'pub struct Invoice;',
+      '#[cfg(test)]',
+      'mod tests {',
+      '    use super::*;',
+      '}',
+    ].join('\n'),
+    'crates/core/src/util.rs': 'pub fn helper() {}\n',
+    'crates/core/src/gen/schema.rs': 'pub struct Table;\n',
+    'crates/api/Cargo.toml': '[package]\nname = "billing-api"\nversion = "0.1.0"\n',
+    'crates/api/src/main.rs': [
+      'use billing_core::{models::Invoice, util};',
+      'use std::collections::HashMap;',
+      'mod routes;',
+      'fn main() {}',
+    ].join('\n'),
+    'crates/api/src/routes.rs': 'use crate::*;\n',
+  };
+
+  let project: { path: string; cleanup: () => Promise<void> };
+  let graph: Map<string, string[]>;
+
+  beforeAll(async () => {
+    project = await createTestProject(
+      Object.entries(sources).map(([name, content]) => ({ name, content }))
+    );
+    const files: FileInfo[] = Object.keys(sources)
+      .filter(relPath => relPath.endsWith('.rs'))
+      .map(relPath => ({
+        absPath: path.join(project.path, relPath),
+        relPath,
+        size: sources[relPath].length,
+        ext: '.rs',
+        language: 'rust',
+      }));
+    graph = await buildImportGraph(files);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('resolves mod declarations including #[path]', () => {
+    expect(graph.get('crates/core/src/lib.rs')).toEqual([
+      'crates/core/src/models/mod.rs',
+      'crates/core/src/util.rs',
+      'crates/core/src/gen/schema.rs',
+    ]);
+    expect(graph.get('crates/api/src/main.rs')).toContain('crates/api/src/routes.rs');
+  });
+
+  it('resolves self:: and super:: relative to the module tree', () => {
+    expect(graph.get('crates/core/src/models/mod.rs')).toEqual([
+      'crates/core/src/models/invoice.rs',
+      'crates/core/src/util.rs',
+    ]);
+    // `use super::*` inside `mod tests` refers back to the file itself
+    expect(graph.get('crates/core/src/models/invoice.rs')).toEqual(['crates/core/src/util.rs']);
+  });
+
+  it('resolves crate:: and workspace crate paths', () => {
+    expect(graph.get('crates/api/src/routes.rs')).toEqual(['crates/api/src/main.rs']);
+    expect(graph.get('crates/api/src/main.rs')).toEqual([
+      'crates/api/src/routes.rs',
+      'crates/core/src/models/mod.rs',
+      'crates/core/src/util.rs',
+    ]);
+  });
+});
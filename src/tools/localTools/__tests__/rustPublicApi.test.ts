@@ -0,0 +1,145 @@
+/**
+ * @fileOverview: Unit tests for the Rust public API surface
+ * @module: rustPublicApiTests
+ * @description: Checks pub mod chains, pub use re-exports, #[doc(hidden)] and ranking of pub(crate)
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from '@jest/globals';
+import '../../../__tests__/utils/realFs';
+import * as path from 'path';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import { buildExportIndex, ExportItem } from '../indexers';
+import { generateRankedHints } from '../scoring';
+import type { FileInfo } from '../../../core/compactor/fileDiscovery';
+
+describe('buildExportIndex (Rust)', () => {
+  const sources: Record<string, string> = {
+    'Cargo.toml': '[package]\nname = "mycrate"\nversion = "0.1.0"\n',
+    'src/lib.rs': [
+      'pub mod client;',
+      'mod internal;',
+      'mod errors;',
+      '#[doc(hidden)]',
+      'pub mod private_api;',
+      'pub use crate::internal::{helper as run_helper, Engine};',
+      'pub use self::errors::*;',
+      'pub(crate) fn crate_only() {}',
+      'pub mod paths;',
+    ].join('\n'),
+    'src/client.rs': [
+      'pub struct Client {',
+      '    url: String,',
+      '}',
+      'impl Client {',
+      '    pub async fn connect(url: &str, retries: u32) -> Result<Self, Error> {',
+      '        todo!()',
+      '    }',
+      '    pub(crate) fn reset(&mut self) {}',
+      '    fn backoff(&self) {}',
+      '}',
+      'impl std::fmt::Display for Client {',
+      '    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { Ok(()) }',
+      '}',
+      '#[cfg(test)]',
+      'mod tests {',
+      '    pub fn fixture() {}',
+      '}',
+    ].join('\n'),
+    'src/internal.rs': [
+      'pub fn helper(a: u8, b: u8) -> u8 { a + b }',
+      'pub struct Engine;',
+      'pub struct Scratch;',
+    ].join('\n'),
+    'src/errors.rs': ['pub enum Error { Io, Parse }', 'pub(crate) struct Backtrace;'].join('\n'),
+    'src/private_api.rs': 'pub fn secret() {}\n',
+    'src/paths.rs': [
+      'pub const ASSETS: &str = "assets/*";',
+      'pub fn visible() {}',
+      '/** Documented. */',
+      'pub fn documented() {}',
+      "pub const OPEN: &str = \"{\"; pub const QUOTE: char = '{';",
+      'pub fn after_braces() {}',
+    ].join('\n'),
+  };
+
+  let project: { path: string; cleanup: () => Promise<void> };
+  let exports: ExportItem[];
+  const byPath = (qualifiedPath: string) =>
+    exports.find(item => item.qualifiedPath === qualifiedPath);
+
+  beforeAll(async () => {
+    project = await createTestProject(
+      Object.entries(sources).map(([name, content]) => ({ name, content }))
+    );
+    const files: FileInfo[] = Object.keys(sources)
+      .filter(relPath => relPath.endsWith('.rs'))
+      .map(relPath => ({
+        absPath: path.join(project.path, relPath),
+        relPath,
+        size: sources[relPath].length,
+        ext: '.rs',
+        language: 'rust',
+      }));
+    exports = await buildExportIndex(files);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('qualifies items through pub mod chains, including inherent methods', () => {
+    expect(byPath('mycrate::client::Client')).toMatchObject({
+      kind: 'class',
+      file: 'src/client.rs',
+      line: 1,
+      visibility: 'public',
+    });
+    expect(byPath('mycrate::client::Client::connect')).toMatchObject({
+      name: 'Client::connect',
+      kind: 'function',
+      params: 2,
+      signature: 'async fn connect(url: &str, retries: u32) -> Result<Self, Error>',
+      visibility: 'public',
+    });
+    expect(exports.some(item => item.name === 'Client::backoff')).toBe(false);
+    expect(exports.some(item => item.name === 'Client::fmt')).toBe(false);
+    expect(exports.some(item => item.name === 'fixture')).toBe(false);
+  });
+
+  it('follows named and glob re-exports from private modules', () => {
+    expect(byPath('mycrate::Engine')).toMatchObject({ file: 'src/internal.rs', line: 2 });
+    expect(byPath('mycrate::run_helper')).toMatchObject({ visibility: 'public', params: 2 });
+    expect(byPath('mycrate::Error')).toMatchObject({ file: 'src/errors.rs', visibility: 'public' });
+  });
+
+  it('ignores comment markers and braces inside string and char literals', () => {
+    expect(byPath('mycrate::paths::visible')).toMatchObject({ line: 2, visibility: 'public' });
+    expect(byPath('mycrate::paths::documented')).toMatchObject({ line: 4, visibility: 'public' });
+    expect(byPath('mycrate::paths::after_braces')).toMatchObject({ line: 6, visibility: 'public' });
+  });
+
+  it('summarizes doc comments of exported items', () => {
+    expect(byPath('mycrate::paths::documented')?.jsdoc).toBe('Documented.');
+    expect(byPath('mycrate::paths::visible')?.jsdoc).toBeUndefined();
+  });
+
+  it('reports pub(crate), unreachable and #[doc(hidden)] items as crate-internal', () => {
+    expect(byPath('mycrate::crate_only')?.visibility).toBe('crate');
+    expect(byPath('mycrate::client::Client::reset')?.visibility).toBe('crate');
+    expect(byPath('mycrate::internal::Scratch')?.visibility).toBe('crate');
+    expect(byPath('mycrate::errors::Backtrace')?.visibility).toBe('crate');
+    expect(byPath('mycrate::private_api::secret')?.visibility).toBe('crate');
+  });
+
+  it('ranks public items above crate internals', () => {
+    const firstInternal = exports.findIndex(item => item.visibility === 'crate');
+    expect(exports.slice(firstInternal).every(item => item.visibility === 'crate')).toBe(true);
+
+    const hints = generateRankedHints(
+      { exports, routes: [], tools: [], importGraph: new Map(), gitMap: {}, queryTerms: [] },
+      exports.length
+    );
+    const rank = (symbol: string) => hints.findIndex(hint => hint.symbol === symbol);
+    expect(rank('mycrate::run_helper')).toBeLessThan(rank('mycrate::crate_only'));
+  });
+});
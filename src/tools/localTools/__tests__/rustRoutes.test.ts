@@ -0,0 +1,281 @@
+/**
+ * @fileOverview: Unit tests for Rust route detection
+ * @module: rustRoutesTests
+ * @description: Verifies axum nest prefixes across files, actix scope/resource/attribute routes,
+ *               poem at()/nest() routes and warp filter chains
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from '@jest/globals';
+import '../../../__tests__/utils/realFs';
+import * as path from 'path';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import { detectRoutes, RouteItem } from '../indexers';
+import type { FileInfo } from '../../../core/compactor/fileDiscovery';
+
+async function detectRustRoutes(sources: Record<string, string>): Promise<RouteItem[]> {
+  const project = await createTestProject(
+    Object.entries(sources).map(([name, content]) => ({ name, content }))
+  );
+  try {
+    const files: FileInfo[] = Object.keys(sources).map(relPath => ({
+      absPath: path.join(project.path, relPath),
+      relPath,
+      size: sources[relPath].length,
+      ext: '.rs',
+      language: 'rust',
+    }));
+    return await detectRoutes(files);
+  } finally {
+    await project.cleanup();
+  }
+}
+
+describe('detectRoutes (axum)', () => {
+  let routes: RouteItem[];
+  const find = (method: string, routePath: string) =>
+    routes.find(route => route.method === method && route.path === routePath);
+
+  beforeAll(async () => {
+    routes = await detectRustRoutes({
+      'src/main.rs': [
+        'use axum::{routing::get, Router};',
+        'mod api;',
+        '',
+        '#[tokio::main]',
+        'async fn main() {',
+        '    let app = Router::new()',
+        '        .route("/health", get(|| async { "ok" }))',
+        '        .nest("/api", api::router());',
+        '    axum::serve(listener, app).await.unwrap();',
+        '}',
+      ].join('\n'),
+      'src/api/mod.rs': [
+        'use axum::{routing::{get, post}, Router};',
+        'mod users;',
+        '',
+        'pub fn router() -> Router {',
+        '    // .route("/commented", get(nothing))',
+        '    Router::new()',
+        '        .nest("/v1/", v1())',
+        '        .route("/", get(users::index))',
+        '}',
+        '',
+        'fn v1() -> Router {',
+        '    Router::new()',
+        '        .route("/users", get(users::list).post(users::create))',
+        '        .route("/users/:id", axum::routing::delete(users::remove))',
+        '}',
+      ].join('\n'),
+      'src/api/users.rs': [
+        'pub async fn index() {}',
+        'pub async fn list() {}',
+        '',
+        'pub async fn create() {}',
+        'pub async fn remove() {}',
+        '#[cfg(test)]',
+        'mod tests {',
+        '    fn app() -> Router { Router::new().route("/test-only", get(list)) }',
+        '}',
+      ].join('\n'),
+    });
+  });
+
+  it('resolves nest prefixes across functions and files', () => {
+    expect(find('get', '/health')).toMatchObject({ file: 'src/main.rs', line: 7 });
+    expect(find('get', '/api')).toMatchObject({ file: 'src/api/users.rs', line: 1 });
+    expect(find('get', '/api/v1/users')).toMatchObject({
+      file: 'src/api/users.rs',
+      line: 2,
+      handler: 'users::list',
+    });
+    expect(find('post', '/api/v1/users')).toMatchObject({ file: 'src/api/users.rs', line: 4 });
+    expect(find('delete', '/api/v1/users/:id')).toMatchObject({ line: 5 });
+  });
+
+  it('does not report nested routers at their unprefixed paths', () => {
+    expect(routes.map(route => route.path)).not.toContain('/v1/users');
+    expect(routes.map(route => route.path)).not.toContain('/commented');
+    expect(routes.map(route => route.path)).not.toContain('/test-only');
+  });
+});
+
+describe('detectRoutes (actix-web)', () => {
+  let routes: RouteItem[];
+  const find = (method: string, routePath: string) =>
+    routes.find(route => route.method === method && route.path === routePath);
+
+  beforeAll(async () => {
+    routes = await detectRustRoutes({
+      'src/main.rs': [
+        'use actix_web::{web, App, HttpServer};',
+        'mod handlers;',
+        '',
+        'fn config(cfg: &mut web::ServiceConfig) {',
+        '    cfg.service(web::resource("/ping").route(web::get().to(handlers::ping)));',
+        '}',
+        '',
+        '#[actix_web::main]',
+        'async fn main() -> std::io::Result<()> {',
+        '    HttpServer::new(|| {',
+        '        App::new()',
+        '            .service(handlers::index)',
+        '            .service(',
+        '                web::scope("/api")',
+        '                    .service(handlers::get_order)',
+        '                    .route("/orders", web::post().to(handlers::create_order))',
+        '                    .configure(config),',
+        '            )',
+        '    })',
+        '    .run()',
+        '    .await',
+        '}',
+      ].join('\n'),
+      'src/handlers.rs': [
+        'use actix_web::{get, HttpResponse};',
+        '',
+        '#[get("/")]',
+        'async fn index() -> HttpResponse { HttpResponse::Ok().finish() }',
+        '',
+        '#[get("/orders/{id}")]',
+        'pub async fn get_order() -> HttpResponse { HttpResponse::Ok().finish() }',
+        '',
+        'pub async fn create_order() -> HttpResponse { HttpResponse::Ok().finish() }',
+        'pub async fn ping() -> &\'static str { "pong" }',
+      ].join('\n'),
+    });
+  });
+
+  it('applies scope prefixes to attribute and builder routes', () => {
+    expect(find('get', '/')).toMatchObject({ file: 'src/handlers.rs', line: 4 });
+    expect(find('get', '/api/orders/{id}')).toMatchObject({
+      file: 'src/handlers.rs',
+      line: 7,
+      handler: 'get_order',
+    });
+    expect(find('post', '/api/orders')).toMatchObject({ file: 'src/handlers.rs', line: 9 });
+    expect(find('get', '/orders/{id}')).toBeUndefined();
+  });
+
+  it('follows configure() into web::resource routes', () => {
+    expect(find('get', '/api/ping')).toMatchObject({
+      file: 'src/handlers.rs',
+      line: 10,
+      handler: 'handlers::ping',
+    });
+  });
+});
+
+describe('detectRoutes (poem)', () => {
+  let routes: RouteItem[];
+  const find = (method: string, routePath: string) =>
+    routes.find(route => route.method === method && route.path === routePath);
+
+  beforeAll(async () => {
+    routes = await detectRustRoutes({
+      'src/main.rs': [
+        'use poem::{get, handler, listener::TcpListener, Route, Server};',
+        'mod api;',
+        '',
+        '#[handler]',
+        'fn hello() -> String { String::new() }',
+        '',
+        '#[tokio::main]',
+        'async fn main() -> Result<(), std::io::Error> {',
+        '    let app = Route::new()',
+        '        .at("/hello/:name", get(hello))',
+        '        .nest("/api", api::routes());',
+        '    Server::new(TcpListener::bind("0.0.0.0:3000")).run(app).await',
+        '}',
+      ].join('\n'),
+      'src/api.rs': [
+        'use poem::{get, handler, Route};',
+        '',
+        'pub fn routes() -> Route {',
+        '    Route::new().at("/users", get(list).post(create))',
+        '}',
+        '',
+        '#[handler]',
+        'async fn list() {}',
+        '#[handler]',
+        'async fn create() {}',
+      ].join('\n'),
+    });
+  });
+
+  it('resolves at() endpoints and nest prefixes', () => {
+    expect(find('get', '/hello/:name')).toMatchObject({
+      file: 'src/main.rs',
+      line: 5,
+      handler: 'hello',
+    });
+    expect(find('get', '/api/users')).toMatchObject({ file: 'src/api.rs', line: 8 });
+    expect(find('post', '/api/users')).toMatchObject({ file: 'src/api.rs', line: 10 });
+    expect(find('get', '/users')).toBeUndefined();
+  });
+});
+
+describe('detectRoutes (warp)', () => {
+  let routes: RouteItem[];
+  const find = (method: string, routePath: string) =>
+    routes.find(route => route.method === method && route.path === routePath);
+
+  beforeAll(async () => {
+    routes = await detectRustRoutes({
+      'src/main.rs': [
+        'use warp::Filter;',
+        'mod api;',
+        'mod handlers;',
+        '',
+        '#[tokio::main]',
+        'async fn main() {',
+        '    let hello = warp::path!("hello" / String)',
+        '        .and(warp::get())',
+        '        .and_then(handlers::hello);',
+        '    let routes = hello.or(api::users());',
+        '    warp::serve(routes).run(([127, 0, 0, 1], 3030)).await;',
+        '}',
+      ].join('\n'),
+      'src/api.rs': [
+        'use warp::Filter;',
+        'use crate::handlers;',
+        '',
+        'pub fn users() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {',
+        '    let list = warp::path("users").and(warp::get()).and_then(handlers::list_users);',
+        '    let create = warp::path("users")',
+        '        .and(warp::post())',
+        '        .and(warp::body::json())',
+        '        .and_then(handlers::create_user);',
+        '    warp::path("api").and(list.or(create))',
+        '}',
+      ].join('\n'),
+      'src/handlers.rs': [
+        'pub async fn hello(name: String) -> Result<String, warp::Rejection> { Ok(name) }',
+        'pub async fn list_users() -> Result<String, warp::Rejection> { Ok(String::new()) }',
+        '',
+        'pub async fn create_user(body: u32) -> Result<String, warp::Rejection> { Ok(body) }',
+      ].join('\n'),
+    });
+  });
+
+  it('joins path filters along and() chains', () => {
+    expect(find('get', '/hello/{String}')).toMatchObject({
+      file: 'src/handlers.rs',
+      line: 1,
+      handler: 'handlers::hello',
+    });
+  });
+
+  it('follows or() alternatives into filter functions', () => {
+    expect(find('get', '/api/users')).toMatchObject({
+      file: 'src/handlers.rs',
+      line: 2,
+      handler: 'handlers::list_users',
+    });
+    expect(find('post', '/api/users')).toMatchObject({
+      file: 'src/handlers.rs',
+      line: 4,
+      handler: 'handlers::create_user',
+    });
+    expect(routes.map(route => route.path)).not.toContain('/users');
+  });
+});
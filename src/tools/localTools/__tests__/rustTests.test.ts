@@ -0,0 +1,240 @@
+/**
+ * @fileOverview: Unit tests for Rust test discovery and code-to-test linkage
+ * @module: rustTestsTests
+ * @description: Covers test harnesses, doctests, the items each test exercises and test lookups
+ */
+
+import { describe, it, expect } from '@jest/globals';
+import {
+  buildRustTestIndex,
+  cargoTestCommand,
+  findRustTests,
+  findTestsForFile,
+  findTestsForSymbol,
+} from '../utils/rustTests';
+
+const SOURCES: Record<string, string> = {
+  'src/lib.rs': [
+    'pub mod limiter;',
+    '',
+    '/// Parses a rate like `10/s`.',
+    '///',
+    '/// ```',
+    '/// let rate = zlim::parse("10/s").unwrap();',
+    '/// assert_eq!(rate.per_second(), 10);',
+    '/// ```',
+    'pub fn parse(spec: &str) -> Option<Rate> {',
+    '    None',
+    '}',
+    '',
+    'pub struct Rate(u32);',
+    '',
+    'impl Rate {',
+    '    pub fn per_second(&self) -> u32 {',
+    '        self.0',
+    '    }',
+    '}',
+  ].join('\n'),
+  'src/limiter.rs': [
+    'use crate::Rate;',
+    '',
+    '/// Token bucket.',
+    '///',
+    '/// ```text',
+    '/// not a doctest',
+    '/// ```',
+    'pub struct RateLimiter {',
+    '    rate: Rate,',
+    '}',
+    '',
+    'impl RateLimiter {',
+    '    pub fn new(rate: Rate) -> Self {',
+    '        RateLimiter { rate }',
+    '    }',
+    '',
+    '    /// Waits for a permit.',
+    '    ///',
+    '    /// ```no_run',
+    '    /// # async fn run(limiter: zlim::limiter::RateLimiter) {',
+    '    /// limiter.acquire().await;',
+    '    /// # }',
+    '    /// ```',
+    '    pub async fn acquire(&self) {}',
+    '',
+    '    pub fn reset(&mut self) {}',
+    '}',
+    '',
+    'impl Drop for RateLimiter {',
+    '    fn drop(&mut self) {}',
+    '}',
+    '',
+    'pub struct Pool;',
+    '',
+    'impl Pool {',
+    '    pub fn reset(&mut self) {}',
+    '}',
+    '',
+    '#[cfg(test)]',
+    'mod tests {',
+    '    use super::*;',
+    '',
+    '    fn limiter() -> RateLimiter {',
+    '        RateLimiter::new(Rate(1))',
+    '    }',
+    '',
+    '    #[tokio::test]',
+    '    async fn acquire_waits() {',
+    '        let limiter = limiter();',
+    '        limiter.acquire().await;',
+    '    }',
+    '',
+    '    #[test]',
+    '    fn reset_clears() {',
+    '        let mut l = limiter();',
+    '        l.reset();',
+    '    }',
+    '',
+    '    #[rstest]',
+    '    #[case(1)]',
+    '    fn builds(#[case] n: u32) {',
+    '        let _ = RateLimiter::new(Rate(n));',
+    '    }',
+    '',
+    '    proptest! {',
+    '        #[test]',
+    '        fn never_panics(n in 0u32..100) {',
+    '            let _ = crate::parse("1/s");',
+    '        }',
+    '    }',
+    '}',
+  ].join('\n'),
+  'tests/api.rs': [
+    'use zlim::limiter::RateLimiter;',
+    '',
+    '#[test]',
+    'fn limiter_from_parsed_rate() {',
+    '    let rate = zlim::parse("5/s").unwrap();',
+    '    let limiter = RateLimiter::new(rate);',
+    '}',
+    '',
+    'fn helper() {}',
+  ].join('\n'),
+  'benches/acquire.rs': [
+    'use criterion::{criterion_group, criterion_main, Criterion};',
+    '',
+    'fn bench_acquire(c: &mut Criterion) {',
+    '    c.bench_function("acquire", |b| b.iter(|| zlim::parse("1/s")));',
+    '}',
+    '',
+    'criterion_group!(benches, bench_acquire);',
+    'criterion_main!(benches);',
+  ].join('\n'),
+};
+
+const index = buildRustTestIndex(new Map(Object.entries(SOURCES)));
+const names = (tests: { name: string }[]) => tests.map(test => test.name);
+
+describe('buildRustTestIndex', () => {
+  it('finds test functions under every harness, benches and doctests', () => {
+    expect(index.tests.map(test => [test.path, test.kind, test.harness, test.line])).toEqual([
+      ['src/lib.rs - parse (line 5)', 'doctest', 'doctest', 5],
+      ['src/limiter.rs - limiter::RateLimiter::acquire (line 19)', 'doctest', 'doctest', 19],
+      ['limiter::tests::acquire_waits', 'unit', 'tokio::test', 47],
+      ['limiter::tests::reset_clears', 'unit', 'test', 53],
+      ['limiter::tests::builds', 'unit', 'rstest', 59],
+      ['limiter::tests::never_panics', 'unit', 'proptest', 66],
+      ['limiter_from_parsed_rate', 'integration', 'test', 3],
+      ['bench_acquire', 'bench', 'criterion', 3],
+    ]);
+    expect(index.testModules).toEqual([
+      { file: 'src/limiter.rs', name: 'tests', line: 39, endLine: 71 },
+    ]);
+  });
+
+  it('indexes non-test items only, with methods under their self type', () => {
+    expect(index.items.map(item => item.name)).toEqual([
+      'parse',
+      'Rate::per_second',
+      'Rate',
+      'RateLimiter::new',
+      'RateLimiter::acquire',
+      'RateLimiter::reset',
+      'RateLimiter::drop',
+      'Pool::reset',
+      'RateLimiter',
+      'Pool',
+    ]);
+  });
+
+  it('links each test to the items it exercises', () => {
+    const covers = Object.fromEntries(index.tests.map(test => [test.name, test.covers]));
+    expect(covers['acquire_waits']).toEqual(['RateLimiter::acquire']);
+    // `reset` is ambiguous between RateLimiter and Pool; the test module only uses RateLimiter
+    expect(covers['reset_clears']).toEqual(['RateLimiter::reset']);
+    expect(covers['builds']).toEqual(['RateLimiter::new', 'RateLimiter', 'Rate']);
+    expect(covers['never_panics']).toEqual(['parse']);
+    expect(covers['limiter_from_parsed_rate']).toEqual([
+      'parse',
+      'RateLimiter::new',
+      'RateLimiter',
+    ]);
+    // A doctest covers the item it documents
+    expect(covers['RateLimiter::acquire (line 19)']).toEqual([
+      'RateLimiter::acquire',
+      'RateLimiter',
+    ]);
+  });
+});
+
+describe('test lookups', () => {
+  it('finds the tests covering a method, a bare name or a type', () => {
+    expect(names(findTestsForSymbol(index, 'crate::limiter::RateLimiter::acquire'))).toEqual([
+      'RateLimiter::acquire (line 19)',
+      'acquire_waits',
+    ]);
+    expect(names(findTestsForSymbol(index, 'reset'))).toEqual(['reset_clears']);
+    expect(names(findTestsForSymbol(index, 'Pool'))).toEqual([]);
+    expect(names(findTestsForSymbol(index, 'parse'))).toEqual([
+      'parse (line 5)',
+      'never_panics',
+      'limiter_from_parsed_rate',
+      'bench_acquire',
+    ]);
+  });
+
+  it('returns the tests to run for a changed file', () => {
+    expect(names(findTestsForFile(index, 'src/lib.rs'))).toEqual([
+      'parse (line 5)',
+      'builds',
+      'never_panics',
+      'limiter_from_parsed_rate',
+      'bench_acquire',
+    ]);
+  });
+
+  it('resolves the symbol or file a query asks about', () => {
+    const bySymbol = findRustTests(index, 'Which tests cover `RateLimiter::acquire`?');
+    expect(bySymbol?.subject).toBe('RateLimiter::acquire');
+    expect(names(bySymbol!.tests)).toEqual(['RateLimiter::acquire (line 19)', 'acquire_waits']);
+
+    expect(findRustTests(index, 'what should I run after editing tests/api.rs')?.subject).toBe(
+      'tests/api.rs'
+    );
+    expect(findRustTests(index, 'tests for Pool')).toEqual({ subject: 'Pool', tests: [] });
+    expect(findRustTests(index, 'how is the limiter tested')).toBeUndefined();
+  });
+
+  it('builds the cargo command for each kind of test', () => {
+    const byName = (name: string) => index.tests.find(test => test.name === name)!;
+    expect(cargoTestCommand({ ...byName('acquire_waits'), crate: 'zlim' })).toBe(
+      'cargo test -p zlim limiter::tests::acquire_waits'
+    );
+    expect(cargoTestCommand(byName('limiter_from_parsed_rate'))).toBe(
+      'cargo test --test api limiter_from_parsed_rate'
+    );
+    expect(cargoTestCommand(byName('bench_acquire'))).toBe(
+      'cargo bench --bench acquire bench_acquire'
+    );
+    expect(cargoTestCommand(byName('parse (line 5)'))).toBe("cargo test --doc 'parse'");
+  });
+});
@@ -0,0 +1,175 @@
+/**
+ * @fileOverview: Unit tests for the Rust trait implementation graph
+ * @module: rustTraitsTests
+ * @description: Covers supertraits, blanket vs generic impls, derives and impl queries by trait
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from '@jest/globals';
+import '../../../__tests__/utils/realFs';
+import * as path from 'path';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import type { FileInfo } from '../../../core/compactor/fileDiscovery';
+import { runAstQueriesOnFiles } from '../astQueryEngine';
+import {
+  buildRustTraitIndex,
+  describeTraitImpl,
+  findTraitImplementors,
+  RustTraitIndex,
+} from '../utils/rustTraits';
+
+const SOURCES: Record<string, string> = {
+  'src/backend.rs': [
+    'use std::fmt;',
+    '',
+    '/// Key-value storage',
+    'pub trait Backend: Send + Sync + fmt::Debug {',
+    '    fn get(&self, key: &str) -> Option<Vec<u8>>;',
+    '}',
+    '',
+    'pub trait CachedBackend: Backend',
+    'where',
+    '    Self: Clone,',
+    '{',
+    '}',
+    '',
+    'impl<T> Backend for std::sync::Arc<T>',
+    'where',
+    '    T: Backend + ?Sized,',
+    '{',
+    '    fn get(&self, key: &str) -> Option<Vec<u8>> { (**self).get(key) }',
+    '}',
+    '',
+    'impl<B: Backend> CachedBackend for B where B: Clone {}',
+    '',
+    '#[cfg(test)]',
+    'mod tests {',
+    '    impl super::Backend for Fake {}',
+    '}',
+  ].join('\n'),
+  'src/memory.rs': [
+    'use crate::backend::Backend;',
+    '',
+    '#[derive(Debug, Clone, serde::Serialize)]',
+    '#[serde(rename_all = "camelCase")]',
+    'pub struct Memory<K: Ord> {',
+    '    items: BTreeMap<K, Vec<u8>>,',
+    '}',
+    '',
+    'impl<K: Ord> Memory<K> {',
+    '    pub fn new() -> Self { todo!() }',
+    '}',
+    '',
+    'impl<K: Ord + Send + Sync + fmt::Debug> Backend for Memory<K> {',
+    '    // impl Backend for Commented {}',
+    '    fn get(&self, key: &str) -> Option<Vec<u8>> { None }',
+    '}',
+    '',
+    'impl crate::backend::Backend for Sqlite {',
+    '    fn get(&self, _: &str) -> Option<Vec<u8>> { Some(b"{".to_vec()) }',
+    '}',
+    '',
+    'impl fmt::Display for Sqlite {',
+    '    fn fmt(&self, f: &mut fmt::Formatter<\'_>) -> fmt::Result { Ok(()) }',
+    '}',
+  ].join('\n'),
+};
+
+describe('buildRustTraitIndex', () => {
+  let index: RustTraitIndex;
+
+  beforeAll(() => {
+    index = buildRustTraitIndex(new Map(Object.entries(SOURCES)));
+  });
+
+  it('records trait definitions with supertraits from bounds and `where Self:`', () => {
+    expect(index.traits.map(def => [def.name, def.supertraits, def.line])).toEqual([
+      ['Backend', ['Send', 'Sync', 'Debug'], 4],
+      ['CachedBackend', ['Backend', 'Clone'], 8],
+    ]);
+  });
+
+  it('separates blanket impls from generic impls on concrete types', () => {
+    const arc = index.impls.find(impl => impl.forType === 'std::sync::Arc<T>')!;
+    expect(arc).toMatchObject({ trait: 'Backend', generics: '<T>', blanket: false });
+
+    const blanket = index.impls.find(impl => impl.trait === 'CachedBackend')!;
+    expect(blanket).toMatchObject({ forType: 'B', blanket: true });
+    expect(blanket.bounds).toEqual(['B: Backend', 'B: Clone']);
+    expect(describeTraitImpl(blanket)).toBe('B: Backend, B: Clone (blanket)');
+  });
+
+  it('indexes derives on the item below stacked attributes', () => {
+    const derived = index.impls.filter(impl => impl.source === 'derive');
+    expect(derived.map(impl => [impl.trait, impl.traitPath, impl.forType])).toEqual([
+      ['Debug', 'Debug', 'Memory<K>'],
+      ['Clone', 'Clone', 'Memory<K>'],
+      ['Serialize', 'serde::Serialize', 'Memory<K>'],
+    ]);
+  });
+
+  it('skips inherent impls, comments and #[cfg(test)] modules', () => {
+    expect(index.impls.map(impl => impl.forType)).not.toEqual(
+      expect.arrayContaining(['Fake', 'Commented'])
+    );
+    expect(index.impls.filter(impl => impl.source === 'impl')).toHaveLength(5);
+  });
+});
+
+describe('findTraitImplementors', () => {
+  const index = buildRustTraitIndex(new Map(Object.entries(SOURCES)));
+
+  it('matches a trait by name or path and lists its subtraits', () => {
+    const byName = findTraitImplementors(index, 'Backend');
+    expect(byName.impls.map(describeTraitImpl)).toEqual([
+      'std::sync::Arc<T> (generic)',
+      'Memory<K> (generic)',
+      'Sqlite',
+    ]);
+    expect(byName.subtraits.map(def => def.name)).toEqual(['CachedBackend']);
+    expect(findTraitImplementors(index, 'crate::backend::Backend').impls).toHaveLength(3);
+  });
+
+  it('accepts regular expressions', () => {
+    const display = findTraitImplementors(index, /Display|Serialize/);
+    expect(display.impls.map(impl => impl.trait)).toEqual(['Display', 'Serialize']);
+  });
+});
+
+describe('impl AST query by trait', () => {
+  let project: { path: string; cleanup: () => Promise<void> };
+  let files: FileInfo[];
+
+  beforeAll(async () => {
+    project = await createTestProject(
+      Object.entries(SOURCES).map(([name, content]) => ({ name, content }))
+    );
+    files = Object.keys(SOURCES).map(relPath => ({
+      absPath: path.join(project.path, relPath),
+      relPath,
+      size: SOURCES[relPath].length,
+      ext: '.rs',
+      language: 'rust',
+    }));
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('returns the trait, its impls and subtraits as candidates', async () => {
+    const candidates = await runAstQueriesOnFiles(files, [{ kind: 'impl', trait: 'Backend' }]);
+    expect(candidates.map(candidate => candidate.symbol)).toEqual([
+      'trait Backend',
+      'impl Backend for std::sync::Arc<T>',
+      'impl Backend for Memory<K>',
+      'impl crate::backend::Backend for Sqlite',
+      'trait CachedBackend',
+    ]);
+
+    const sqlite = candidates[3];
+    expect(sqlite.file).toBe(path.join(project.path, 'src/memory.rs'));
+    expect(SOURCES['src/memory.rs'].slice(sqlite.start, sqlite.end)).toMatch(
+      /^impl crate::backend::Backend for Sqlite \{[\s\S]*\}$/
+    );
+  });
+});
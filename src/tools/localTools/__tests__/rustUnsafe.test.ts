@@ -0,0 +1,185 @@
+/**
+ * @fileOverview: Unit tests for the Rust unsafe code and FFI audit
+ * @module: rustUnsafeTests
+ * @description: Covers site detection, SAFETY comment matching and per-crate/module rollups
+ */
+
+import { describe, it, expect, beforeAll, afterAll, jest } from '@jest/globals';
+import { walkingGlobby } from '../../../__tests__/utils/realFs';
+import { createTestProject } from '../../../__tests__/utils/testHelpers';
+import { extractUnsafeSites } from '../utils/rustUnsafe';
+import { buildUnsafeAuditSummary, generateAnswerDraft } from '../enhancedHints';
+
+jest.mock('globby', () => walkingGlobby());
+
+const FFI = [
+  'use std::os::raw::c_char;',
+  '',
+  '#[link(name = "z")]',
+  'extern "C" {',
+  '    fn zlibVersion() -> *const c_char;',
+  '    fn crc32(crc: u32, buf: *const u8, len: u32) -> u32;',
+  '}',
+  '',
+  'pub struct Buffer {',
+  '    ptr: *mut u8,',
+  '    len: usize,',
+  '}',
+  '',
+  '// SAFETY: Buffer owns its allocation and never shares `ptr`.',
+  'unsafe impl Send for Buffer {}',
+  '',
+  'unsafe impl<T> Sync for Handle<T> {}',
+  '',
+  'impl Buffer {',
+  '    pub fn first(&self) -> u8 {',
+  '        // SAFETY: `len > 0` is checked by the constructor.',
+  '        unsafe { *self.ptr }',
+  '    }',
+  '',
+  '    pub fn set(&mut self, i: usize, v: u8) {',
+  '        unsafe {',
+  '            *self.ptr.add(i) = v;',
+  '        }',
+  '    }',
+  '}',
+  '',
+  '/// Reads a byte.',
+  '///',
+  '/// # Safety',
+  '/// `p` must be valid for reads.',
+  'pub unsafe fn read(p: *const u8) -> u8 {',
+  '    *p',
+  '}',
+  '',
+  'pub unsafe fn scaled(s: *const u8) -> usize {',
+  '    let n = 2 * 3;',
+  '    n * *s as usize',
+  '}',
+  '',
+  '#[no_mangle]',
+  'pub extern "C" fn checksum(data: *const u8, len: usize) -> u32 {',
+  '    let slice = unsafe { std::slice::from_raw_parts(data, len) }; // SAFETY: caller\'s buffer',
+  '    let guard = lock();',
+  '    let copy = *guard;',
+  '    unsafe { crc32(0, slice.as_ptr(), len as u32) }',
+  '}',
+].join('\n');
+
+const LIB = [
+  'mod ffi;',
+  '',
+  'pub fn version() -> &\'static str {',
+  '    unsafe {',
+  '        // SAFETY: zlib returns a static NUL-terminated string.',
+  '        to_str(ffi::zlibVersion())',
+  '    }',
+  '}',
+].join('\n');
+
+const SOURCES: Record<string, string> = { 'src/ffi.rs': FFI, 'src/lib.rs': LIB };
+
+describe('extractUnsafeSites', () => {
+  const sites = extractUnsafeSites(new Map(Object.entries(SOURCES)));
+  const ffi = sites.filter(site => site.file === 'src/ffi.rs');
+
+  it('finds every kind of unsafe site with its line span', () => {
+    expect(ffi.map(site => [site.line, site.endLine, site.kind, site.detail])).toEqual([
+      [4, 7, 'extern_block', 'extern "C" { zlibVersion, crc32 }'],
+      [15, 15, 'unsafe_impl', 'impl Send for Buffer'],
+      [17, 17, 'unsafe_impl', 'impl<T> Sync for Handle<T>'],
+      [22, 22, 'unsafe_block', 'unsafe { .. }'],
+      [22, 22, 'raw_deref', '*self.ptr'],
+      [26, 28, 'unsafe_block', 'unsafe { .. }'],
+      [27, 27, 'raw_deref', '*self.ptr.add(i)'],
+      [36, 38, 'unsafe_fn', 'fn read'],
+      [37, 37, 'raw_deref', '*p'],
+      [40, 43, 'unsafe_fn', 'fn scaled'],
+      [42, 42, 'raw_deref', '*s'],
+      [45, 51, 'no_mangle', 'fn checksum'],
+      [47, 47, 'unsafe_block', 'unsafe { .. }'],
+      [50, 50, 'unsafe_block', 'unsafe { .. }'],
+    ]);
+    expect(ffi.find(site => site.line === 27)?.function).toBe('set');
+  });
+
+  it('does not mistake multiplication or safe derefs for raw pointer derefs', () => {
+    const derefs = sites.filter(site => site.kind === 'raw_deref').map(site => site.detail);
+    expect(derefs).not.toContain('*guard');
+    expect(derefs).not.toContain('* 3');
+  });
+
+  it('accepts SAFETY comments above, trailing, first in block and # Safety sections', () => {
+    const justified = sites.filter(site => site.justified).map(site => [site.file, site.line]);
+    expect(justified).toEqual([
+      ['src/ffi.rs', 15],
+      ['src/ffi.rs', 22],
+      ['src/ffi.rs', 22],
+      ['src/ffi.rs', 36],
+      ['src/ffi.rs', 37],
+      ['src/ffi.rs', 47],
+      ['src/lib.rs', 4],
+    ]);
+    const read = ffi.find(site => site.kind === 'unsafe_fn');
+    expect(read?.safety).toBe('`p` must be valid for reads.');
+    expect(ffi.find(site => site.line === 47)?.safety).toBe("caller's buffer");
+  });
+});
+
+describe('buildUnsafeAuditSummary', () => {
+  let project: { path: string; cleanup: () => Promise<void> };
+
+  beforeAll(async () => {
+    project = await createTestProject([
+      { name: 'Cargo.toml', content: '[package]\nname = "zwrap"\nversion = "0.1.0"\n' },
+      ...Object.entries(SOURCES).map(([name, content]) => ({ name, content })),
+    ]);
+  });
+
+  afterAll(async () => {
+    await project.cleanup();
+  });
+
+  it('rolls sites up per crate and module, riskiest first', async () => {
+    const audit = await buildUnsafeAuditSummary(project.path);
+    expect([audit!.total, audit!.unjustified]).toEqual([15, 8]);
+    expect(audit!.crates).toHaveLength(1);
+
+    const zwrap = audit!.crates[0];
+    expect(zwrap.crate).toBe('zwrap');
+    expect(zwrap.byKind).toEqual({
+      extern_block: 1,
+      unsafe_impl: 2,
+      unsafe_block: 5,
+      raw_deref: 4,
+      unsafe_fn: 2,
+      no_mangle: 1,
+    });
+    expect(zwrap.modules.map(entry => [entry.module, entry.total, entry.unjustified])).toEqual([
+      ['crate::ffi', 14, 8],
+      ['crate', 1, 0],
+    ]);
+
+    const draft = generateAnswerDraft(
+      { unsafeAudit: audit, systems: {}, capabilities: { domains: [] }, hints: [] } as any,
+      'Which unsafe blocks have no SAFETY comment?'
+    );
+    expect(draft).toContain('Found 15 unsafe/FFI sites across zwrap (15)');
+    expect(draft).toContain('*self.ptr.add(i) in set at src/ffi.rs:27');
+  });
+
+  it('is undefined for a crate without unsafe code', async () => {
+    const safe = await createTestProject([
+      { name: 'Cargo.toml', content: '[package]\nname = "safe"\n' },
+      {
+        name: 'src/lib.rs',
+        content: 'pub fn mul(a: u32, b: &u32) -> u32 {\n    a * *b\n}\n'
+      },
+    ]);
+    try {
+      expect(await buildUnsafeAuditSummary(safe.path)).toBeUndefined();
+    } finally {
+      await safe.cleanup();
+    }
+  });
+});
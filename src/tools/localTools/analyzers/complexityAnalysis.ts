@@ -3,9 +3,18 @@
  * @module: ComplexityAnalysis
  * @keyFunctions:
  *   - calculateCyclomaticComplexity(): Calculate cyclomatic complexity metrics
+ *   - calculateRustComplexity(): Roll up AST-derived per-function Rust complexity
  * @context: Provides code complexity analysis for quality assessment
  */
 
+import type { RustComplexity } from '../../../core/compactor/rustComplexity';
+
+export interface FunctionComplexity extends RustComplexity {
+  name: string;
+  line: number;
+  className?: string;
+}
+
 /**
  * Calculate cyclomatic complexity for source code
  */
@@ -51,7 +60,44 @@ export function calculateCyclomaticComplexity(code: string): {
   // Base complexity is 1, plus decision points
   const totalComplexity = 1 + totalDecisionPoints;
 
-  // Determine rating and description
+  return {
+    totalComplexity,
+    decisionPoints: totalDecisionPoints,
+    ...rateComplexity(totalComplexity),
+    breakdown,
+  };
+}
+
+/**
+ * Roll up per-function complexity measured on the Rust AST by ASTParser. The keyword
+ * heuristic above misreads Rust (`match` arms, `if let`, `?`, `&&` in patterns), so Rust
+ * files use this instead. `breakdown` maps each function to its cyclomatic complexity
+ */
+export function calculateRustComplexity(
+  functions: Array<{ name: string; line: number; className?: string; complexity?: RustComplexity }>
+): ReturnType<typeof calculateCyclomaticComplexity> & { functions: FunctionComplexity[] } {
+  const measured: FunctionComplexity[] = functions
+    .filter(fn => fn.complexity)
+    .map(fn => ({ name: fn.name, line: fn.line, className: fn.className, ...fn.complexity! }))
+    .sort((a, b) => b.cognitive - a.cognitive || b.cyclomatic - a.cyclomatic);
+
+  const decisionPoints = measured.reduce((sum, fn) => sum + fn.cyclomatic - 1, 0);
+  const totalComplexity = 1 + decisionPoints;
+  const breakdown: Record<string, number> = {};
+  for (const fn of measured) {
+    breakdown[fn.className ? `${fn.className}::${fn.name}` : fn.name] = fn.cyclomatic;
+  }
+
+  return {
+    totalComplexity,
+    decisionPoints,
+    ...rateComplexity(totalComplexity),
+    breakdown,
+    functions: measured,
+  };
+}
+
+function rateComplexity(totalComplexity: number): { rating: string; description: string } {
   let rating = 'low';
   let description = '';
   if (totalComplexity <= 10) {
@@ -68,11 +114,5 @@ export function calculateCyclomaticComplexity(code: string): {
     description = 'Very complex, difficult to test and maintain';
   }
 
-  return {
-    totalComplexity,
-    decisionPoints: totalDecisionPoints,
-    rating,
-    description,
-    breakdown,
-  };
+  return { rating, description };
 }
@@ -14,6 +14,7 @@ import * as path from 'path';
 import { logger } from '../../../utils/logger';
 import { formatFileSummaryOutput } from '../formatters/fileSummaryFormatters';
 import { JsonASTAnalyzer } from './jsonASTAnalyzer';
+import { extractRustModuleDocs } from '../../../core/compactor/rustDocs';
 
 /**
  * Extract file header comment block or first 15 lines for context
@@ -32,6 +33,18 @@ export async function extractFileHeader(filePath: string): Promise<{
       return { type: 'empty', content: '', lineCount: 0 };
     }
 
+    // Rust crate/module docs (`//!`, `/*! */`, `#![doc = "..."]`) describe the file's purpose
+    if (path.extname(filePath) === '.rs') {
+      const moduleDocs = extractRustModuleDocs(content);
+      if (moduleDocs?.text) {
+        return {
+          type: 'comment',
+          content: moduleDocs.text,
+          lineCount: moduleDocs.text.split('\n').length,
+        };
+      }
+    }
+
     // Check for block comment at the start
     const trimmedFirst = lines[0].trim();
 
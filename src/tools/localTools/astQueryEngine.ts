@@ -6,17 +6,47 @@
  *   - parseFileAst(): Parse files to AST with multi-language support
  *   - matchAstQuery(): Match individual query against AST
  *   - extractSymbolContext(): Extract symbols with surrounding context
- * @context: Provides fast AST-based code searching and symbol extraction
+ *   - matchRustRouteQueries(): Answer route queries for axum/actix-web/rocket/poem/warp
+ *   - matchRustStructureQueries(): Answer Rust impl/trait/derive/attribute/macro queries,
+ *     including "who implements X" as an impl query by trait
+ * @context: Provides fast AST-based code searching and symbol extraction. JS/TS is parsed with
+ *           Babel; Rust symbols come from a masked-source scan with Rust semantics (struct/enum
+ *           as class, trait as interface, `use` as import, `pub` as export, `Type::new` as new)
  */
 
 import * as babel from '@babel/parser';
 import traverse from '@babel/traverse';
 import * as t from '@babel/types';
 import { readFileSync } from 'fs';
+import { readFile } from 'fs/promises';
 import * as path from 'path';
 import { FileInfo } from '../../core/compactor/fileDiscovery';
 import { AstQuery, CandidateSymbol } from './enhancedLocalContext';
 import { logger } from '../../utils/logger';
+import { toPosix } from './utils/pathUtils';
+import {
+  buildRustTraitIndex,
+  describeTraitImpl,
+  findTraitImplementors,
+  RustTraitDef,
+  RustTraitIndex,
+} from './utils/rustTraits';
+import {
+  extractRustAttributes,
+  extractRustInherentImpls,
+  extractRustSymbols,
+  findRustConstructorCalls,
+} from './utils/rustSymbols';
+import { buildRustMacroIndex, findMacroUsages, RustMacroIndex } from './utils/rustMacros';
+import { extractRustEnvKeys } from './utils/rustEnv';
+import { extractRustRoutes } from './utils/rustRoutes';
+
+type RustStructureQuery = Extract<
+  AstQuery,
+  { kind: 'impl' | 'trait' | 'derive' | 'attribute' | 'macro' }
+>;
+
+const RUST_STRUCTURE_KINDS = new Set(['impl', 'trait', 'derive', 'attribute', 'macro']);
 
 // ===== LANGUAGE DETECTION AND PARSING =====
 
@@ -58,6 +88,22 @@ export async function runAstQueriesOnFiles(
   const candidates: CandidateSymbol[] = [];
   let filesProcessed = 0;
 
+  // Rust routers are assembled across files (nest/scope/mount), so routes come from all of them
+  const routeQueries = queries.filter(
+    (query): query is Extract<AstQuery, { kind: 'route' }> => query.kind === 'route'
+  );
+  if (routeQueries.length > 0) {
+    candidates.push(...(await matchRustRouteQueries(files, routeQueries)));
+  }
+
+  // Structural Rust queries need items from several files (a derive's trait, a macro's callers)
+  const structureQueries = queries.filter((query): query is RustStructureQuery =>
+    RUST_STRUCTURE_KINDS.has(query.kind)
+  );
+  if (structureQueries.length > 0) {
+    candidates.push(...(await matchRustStructureQueries(files, structureQueries)));
+  }
+
   // Process files in batches for performance
   const filesToProcess = files.slice(0, maxFiles);
 
@@ -99,6 +145,21 @@ async function parseFileToAst(file: FileInfo): Promise<ParsedFile | null> {
     const content = readFileSync(file.absPath, 'utf8');
     const language = detectLanguageFromFile(file.absPath);
 
+    if (language === 'rust') {
+      return {
+        filePath: file.absPath,
+        relPath: file.relPath,
+        language,
+        ast: null,
+        content,
+        symbols: extractRustSymbols(content).map(symbol => ({
+          ...symbol,
+          file: file.absPath,
+          relFile: file.relPath,
+        })),
+      };
+    }
+
     if (!['javascript', 'typescript'].includes(language)) {
       // For now, only support JS/TS. Could extend to other languages with tree-sitter
       return null;
@@ -376,6 +437,13 @@ async function matchQueryInFile(parsed: ParsedFile, query: AstQuery): Promise<Ca
     case 'route':
       candidates.push(...matchRouteQuery(parsed, query));
       break;
+    case 'impl':
+    case 'trait':
+    case 'derive':
+    case 'attribute':
+    case 'macro':
+      // Answered across files by matchRustStructureQueries
+      break;
   }
 
   return candidates;
@@ -499,6 +567,24 @@ function matchNewQuery(
     typeof classPattern === 'string'
       ? classPattern.replace(/[.*+?^${}()|[\]\\]/g, '\\$&')
       : classPattern.source;
+
+  // Rust has no `new`; constructors are associated fns such as `Type::new(..)`
+  if (parsed.language === 'rust') {
+    for (const call of findRustConstructorCalls(parsed.content, classSource)) {
+      candidates.push({
+        file: parsed.filePath,
+        symbol: `${call.type}::${call.constructor}`,
+        start: call.start,
+        end: call.end,
+        kind: 'call',
+        score: 0.7,
+        reasons: [`constructor matches: ${call.type}`],
+        role: 'operation',
+      });
+    }
+    return candidates;
+  }
+
   const regex = new RegExp(`\\bnew\\s+(${classSource})\\s*\\(`, 'g');
 
   let match: RegExpExecArray | null;
@@ -533,6 +619,33 @@ function matchEnvQuery(
   const candidates: CandidateSymbol[] = [];
   const keyPattern = query.key;
 
+  // Rust: std::env::var, env!/option_env!, clap `env` args and envy/figment config structs
+  if (parsed.language === 'rust') {
+    const keys = extractRustEnvKeys(new Map([[toPosix(parsed.relPath), parsed.content]]));
+    const lines = parsed.content.split('\n');
+    for (const key of keys) {
+      if (!matchesPattern(key.key, keyPattern)) continue;
+      const lineStart = lines
+        .slice(0, key.line - 1)
+        .reduce((sum, line) => sum + line.length + 1, 0);
+      const text = lines[key.line - 1] ?? '';
+      const column = text.indexOf(key.key);
+      const start = lineStart + (column === -1 ? text.length - text.trimStart().length : column);
+
+      candidates.push({
+        file: parsed.filePath,
+        symbol: key.usage === 'compile-time' ? `env!("${key.key}")` : `env::var("${key.key}")`,
+        start,
+        end: column === -1 ? lineStart + text.length : start + key.key.length,
+        kind: 'env',
+        score: 0.8,
+        reasons: [`env key matches: ${key.key}`, `env usage: ${key.usage}`],
+        role: 'config',
+      });
+    }
+    return candidates;
+  }
+
   // Look for process.env usage in the content
   const envRegex = /process\.env\.(\w+)/g;
   let match;
@@ -567,6 +680,9 @@ function matchRouteQuery(
   query: Extract<AstQuery, { kind: 'route' }>
 ): CandidateSymbol[] {
   const candidates: CandidateSymbol[] = [];
+  // Rust routes are answered across files by matchRustRouteQueries
+  if (parsed.language === 'rust') return candidates;
+
   // Determine method pattern
   const methodPattern = query.method
     ? typeof query.method === 'string'
@@ -604,11 +720,328 @@ function matchRouteQuery(
   return candidates;
 }
 
+/**
+ * Match route queries against axum, actix-web, rocket, poem and warp routes of all Rust files,
+ * with prefixes from `nest`/`scope`/`mount` and warp `.and` chains applied. Candidates point at
+ * the handler
+ */
+export async function matchRustRouteQueries(
+  files: FileInfo[],
+  queries: Extract<AstQuery, { kind: 'route' }>[]
+): Promise<CandidateSymbol[]> {
+  const rustFiles = files.filter(file => file.language === 'rust');
+  if (rustFiles.length === 0) return [];
+
+  const sources = new Map<string, string>();
+  const absPaths = new Map<string, string>();
+  for (const file of rustFiles) {
+    try {
+      sources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf8'));
+      absPaths.set(toPosix(file.relPath), file.absPath);
+    } catch (error) {
+      logger.debug('Could not read file for route queries', { file: file.relPath, error });
+    }
+  }
+
+  const routes = extractRustRoutes(sources);
+  const candidates: CandidateSymbol[] = [];
+
+  for (const query of queries) {
+    for (const route of routes) {
+      if (query.method && !matchesPattern(route.method.toLowerCase(), lowerPattern(query.method))) {
+        continue;
+      }
+      if (query.path && !matchesPattern(route.path, query.path)) continue;
+
+      const lines = (sources.get(route.file) ?? '').split('\n');
+      const start = lines.slice(0, route.line - 1).reduce((sum, line) => sum + line.length + 1, 0);
+      candidates.push({
+        file: absPaths.get(route.file) ?? route.file,
+        symbol: `${route.method.toUpperCase()} ${route.path}`,
+        start,
+        end: start + (lines[route.line - 1] ?? '').length,
+        kind: 'export',
+        score: 0.85,
+        reasons: ['route:method-path', ...(route.handler ? [`handler: ${route.handler}`] : [])],
+        role: 'request handler',
+      });
+    }
+  }
+
+  return candidates;
+}
+
+/**
+ * Match Rust structure queries: impl blocks, trait definitions, derives, attributes and macros
+ */
+export async function matchRustStructureQueries(
+  files: FileInfo[],
+  queries: RustStructureQuery[]
+): Promise<CandidateSymbol[]> {
+  const rustFiles = files.filter(file => file.language === 'rust');
+  if (rustFiles.length === 0) return [];
+
+  // One read of the Rust files feeds both the trait and the macro index
+  const sources = new Map<string, string>();
+  const absPaths = new Map<string, string>();
+  for (const file of rustFiles) {
+    try {
+      sources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf8'));
+      absPaths.set(toPosix(file.relPath), file.absPath);
+    } catch (error) {
+      logger.debug('Could not read file for Rust structure queries', { file: file.relPath, error });
+    }
+  }
+
+  const fileOf = (relPath: string) => absPaths.get(relPath) ?? relPath;
+  const needsTraits = queries.some(query => ['impl', 'trait', 'derive'].includes(query.kind));
+  const traitIndex = needsTraits ? buildRustTraitIndex(sources) : undefined;
+  const macroIndex = queries.some(query => query.kind === 'macro')
+    ? buildRustMacroIndex(sources)
+    : undefined;
+  const candidates: CandidateSymbol[] = [];
+
+  for (const query of queries) {
+    switch (query.kind) {
+      case 'impl':
+        candidates.push(...matchRustImplQuery(query, traitIndex!, sources, fileOf));
+        break;
+      case 'trait':
+        candidates.push(...matchRustTraitQuery(query, traitIndex!, fileOf));
+        break;
+      case 'derive':
+        candidates.push(...matchRustDeriveQuery(query, traitIndex!, fileOf));
+        break;
+      case 'attribute':
+        candidates.push(...matchRustAttributeQuery(query, sources, fileOf));
+        break;
+      case 'macro':
+        candidates.push(...matchRustMacroQuery(query, macroIndex!, fileOf));
+        break;
+    }
+  }
+
+  return candidates;
+}
+
+/**
+ * `impl Trait for Type` blocks filtered by trait and/or type. With a trait this answers "who
+ * implements X": the trait definition, its impls (incl. blanket) and derives, and subtraits.
+ * Without one, inherent `impl Type` blocks are included instead
+ */
+function matchRustImplQuery(
+  query: Extract<AstQuery, { kind: 'impl' }>,
+  index: RustTraitIndex,
+  sources: Map<string, string>,
+  fileOf: (relPath: string) => string
+): CandidateSymbol[] {
+  const candidates: CandidateSymbol[] = [];
+  const found = query.trait ? findTraitImplementors(index, query.trait) : undefined;
+  const traitCandidate = (def: RustTraitDef, score: number, reasons: string[]) => {
+    candidates.push({
+      file: fileOf(def.file),
+      symbol: `trait ${def.name}`,
+      start: def.start,
+      end: def.end,
+      kind: 'interface',
+      score,
+      reasons,
+      role: 'interface',
+    });
+  };
+
+  // The trait itself only answers the question when no implementing type was asked for
+  if (found && !query.type) {
+    for (const def of found.definitions) {
+      traitCandidate(def, 0.85, [
+        `trait definition: ${def.name}`,
+        ...(def.supertraits.length > 0 ? [`supertraits: ${def.supertraits.join(' + ')}`] : []),
+      ]);
+    }
+  }
+
+  for (const impl of found ? found.impls : index.impls) {
+    if (impl.negative || (impl.source === 'derive' && !found)) continue;
+    if (query.type && !matchesRustType(impl.forType, query.type)) continue;
+    candidates.push({
+      file: fileOf(impl.file),
+      symbol:
+        impl.source === 'derive'
+          ? `#[derive(${impl.traitPath})] ${impl.forType}`
+          : `impl ${impl.traitPath} for ${impl.forType}`,
+      start: impl.start,
+      end: impl.end,
+      kind: 'class',
+      // Hand-written impls carry the behaviour; derives and blanket impls are mostly wiring
+      score: impl.source === 'derive' ? 0.6 : impl.blanket ? 0.75 : 0.9,
+      reasons: [`implements ${impl.trait}: ${describeTraitImpl(impl)}`],
+      role: 'implementation',
+    });
+  }
+
+  if (found) {
+    for (const def of query.type ? [] : found.subtraits) {
+      traitCandidate(def, 0.65, [`subtrait: ${def.name}: ${def.supertraits.join(' + ')}`]);
+    }
+    return candidates;
+  }
+
+  for (const [relPath, content] of sources) {
+    for (const impl of extractRustInherentImpls(content)) {
+      if (query.type && !matchesRustType(impl.forType, query.type)) continue;
+      candidates.push({
+        file: fileOf(relPath),
+        symbol: `impl ${impl.forType}`,
+        start: impl.start,
+        end: impl.end,
+        kind: 'class',
+        score: 0.85,
+        reasons: [`inherent impl: ${impl.forType}`],
+        role: 'implementation',
+      });
+    }
+  }
+
+  return candidates;
+}
+
+/**
+ * Trait definitions by name, or all of them, with how many impls each has
+ */
+function matchRustTraitQuery(
+  query: Extract<AstQuery, { kind: 'trait' }>,
+  index: RustTraitIndex,
+  fileOf: (relPath: string) => string
+): CandidateSymbol[] {
+  const definitions = query.name
+    ? findTraitImplementors(index, query.name).definitions
+    : index.traits;
+
+  return definitions.map(def => {
+    const implCount = index.impls.filter(impl => impl.trait === def.name && !impl.negative).length;
+    return {
+      file: fileOf(def.file),
+      symbol: `trait ${def.name}`,
+      start: def.start,
+      end: def.end,
+      kind: 'interface',
+      score: 0.85,
+      reasons: [
+        `trait definition: ${def.name}`,
+        ...(def.supertraits.length > 0 ? [`supertraits: ${def.supertraits.join(' + ')}`] : []),
+        `implementations: ${implCount}`,
+      ],
+      role: 'interface',
+    };
+  });
+}
+
+/**
+ * Types deriving a trait, e.g. every `#[derive(Serialize)]`
+ */
+function matchRustDeriveQuery(
+  query: Extract<AstQuery, { kind: 'derive' }>,
+  index: RustTraitIndex,
+  fileOf: (relPath: string) => string
+): CandidateSymbol[] {
+  return findTraitImplementors(index, query.trait)
+    .impls.filter(impl => impl.source === 'derive')
+    .map(impl => ({
+      file: fileOf(impl.file),
+      symbol: `#[derive(${impl.traitPath})] ${impl.forType}`,
+      start: impl.start,
+      end: impl.end,
+      kind: 'class',
+      score: 0.85,
+      reasons: [`derives ${impl.trait}: ${impl.forType}`],
+      role: 'implementation',
+    }));
+}
+
+/**
+ * Attributes by path. A string matches the path as written (`tracing::instrument`) or its
+ * last segment (`instrument`); a RegExp is tested against the path and the whole attribute
+ */
+function matchRustAttributeQuery(
+  query: Extract<AstQuery, { kind: 'attribute' }>,
+  sources: Map<string, string>,
+  fileOf: (relPath: string) => string
+): CandidateSymbol[] {
+  const pattern = query.path;
+  const wanted = typeof pattern === 'string' ? pattern.replace(/^#!?\[|\]$/g, '').trim() : '';
+  const matches = (path: string, text: string) =>
+    typeof pattern === 'string'
+      ? path === wanted || path.split('::').pop() === wanted
+      : pattern.test(path) || pattern.test(text);
+
+  const candidates: CandidateSymbol[] = [];
+  for (const [relPath, content] of sources) {
+    for (const attr of extractRustAttributes(content)) {
+      if (!matches(attr.path, attr.text)) continue;
+      const written = `#${attr.inner ? '!' : ''}[${attr.path}]`;
+      candidates.push({
+        file: fileOf(relPath),
+        symbol: attr.target ? `${written} ${attr.target}` : written,
+        start: attr.start,
+        end: attr.end,
+        kind: 'attribute',
+        score: 0.8,
+        reasons: [`attribute matches: ${attr.text}`],
+        role: 'annotation',
+      });
+    }
+  }
+
+  return candidates;
+}
+
+/**
+ * Macro definitions and their bang, attribute and derive call sites
+ */
+function matchRustMacroQuery(
+  query: Extract<AstQuery, { kind: 'macro' }>,
+  index: RustMacroIndex,
+  fileOf: (relPath: string) => string
+): CandidateSymbol[] {
+  const { definitions, invocations } = findMacroUsages(index, query.name);
+  const candidates: CandidateSymbol[] = definitions.map(def => ({
+    file: fileOf(def.file),
+    symbol: def.kind === 'declarative' ? `${def.name}!` : def.name,
+    start: def.start,
+    end: def.end,
+    kind: 'function',
+    score: 0.9,
+    reasons: [`macro:definition:${def.kind}`],
+    role: 'macro definition',
+  }));
+
+  for (const call of invocations) {
+    const written =
+      call.kind === 'bang'
+        ? `${call.path}!`
+        : call.kind === 'derive'
+          ? `#[derive(${call.path})]`
+          : `#[${call.path}]`;
+    candidates.push({
+      file: fileOf(call.file),
+      symbol: written,
+      start: call.start,
+      end: call.end,
+      kind: 'call',
+      score: 0.8,
+      reasons: [`macro:invocation:${written}`, ...(call.caller ? [`in: ${call.caller}`] : [])],
+      role: 'macro call site',
+    });
+  }
+
+  return candidates;
+}
+
 // ===== UTILITY FUNCTIONS =====
 
 function isSourceCodeFile(filePath: string): boolean {
   const ext = path.extname(filePath).toLowerCase();
-  return ['.js', '.ts', '.jsx', '.tsx', '.mjs', '.cjs'].includes(ext);
+  return ['.js', '.ts', '.jsx', '.tsx', '.mjs', '.cjs', '.rs'].includes(ext);
 }
 
 function detectLanguageFromFile(filePath: string): string {
@@ -641,6 +1074,31 @@ function matchesPattern(text: string, pattern: string | RegExp): boolean {
   }
 }
 
+/**
+ * Route methods are matched case-insensitively, as the JS route regex does
+ */
+function lowerPattern(pattern: string | RegExp): string | RegExp {
+  return typeof pattern === 'string'
+    ? pattern.toLowerCase()
+    : new RegExp(pattern.source, pattern.flags.includes('i') ? pattern.flags : pattern.flags + 'i');
+}
+
+/**
+ * A string matches a Rust type by its name without path, references and generic arguments
+ * (`Cache` matches `&mut crate::cache::Cache<K, V>`); a RegExp is tested against it as written
+ */
+function matchesRustType(forType: string, pattern: string | RegExp): boolean {
+  if (typeof pattern !== 'string') return pattern.test(forType);
+  const typeName = (type: string) =>
+    type
+      .replace(/^(?:&\s*(?:'\w+\s+)?(?:mut\s+)?|dyn\s+)+/, '')
+      .split(/[<+]/)[0]
+      .split('::')
+      .pop()!
+      .trim();
+  return forType === pattern || typeName(forType) === typeName(pattern);
+}
+
 function matchesGlob(filePath: string, pattern: string): boolean {
   // Simple glob matching - could be enhanced with a proper glob library
   const regex = pattern.replace(/\*\*/g, '.*').replace(/\*/g, '[^/]*').replace(/\?/g, '.');
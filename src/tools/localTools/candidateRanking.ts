@@ -40,6 +40,7 @@ export interface ProjectContext {
   env: any[];
   systems: any;
   callGraph?: Map<string, string[]>;
+  macroReferences?: Map<string, string[]>; // Rust macro <-> invoking function edges
 }
 
 // ===== MAIN RANKING FUNCTION =====
@@ -274,7 +275,8 @@ function calculateKeywordScore(candidate: CandidateSymbol, context: ScoringConte
   if (context.attackPlan === 'api-route') {
     if (
       /app\/.*\/route\.(ts|js)$/.test(candidate.file.replace(/\\/g, '/')) ||
-      /pages\/api\//.test(candidate.file.replace(/\\/g, '/'))
+      /pages\/api\//.test(candidate.file.replace(/\\/g, '/')) ||
+      /\/(routes?|handlers?)(\/.*)?\.rs$/.test(candidate.file.replace(/\\/g, '/'))
     ) {
       score += 0.3;
     }
@@ -498,6 +500,11 @@ async function buildCallGraphFromCandidates(
       .map(c => `${c.file}:${c.symbol}`)
       .filter(k => k !== key);
 
+    // A macro is connected to every function that invokes it, and each caller back to it
+    for (const reference of projectContext.macroReferences?.get(key) || []) {
+      if (!related.includes(reference)) related.push(reference);
+    }
+
     callGraph.set(key, related);
   }
 
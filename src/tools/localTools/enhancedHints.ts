@@ -7,10 +7,14 @@
  *   - assessRisks(): Identify potential project risks and issues
  *   - generateNextActions(): Propose concrete next steps for agents
  *   - generateAnswerDraft(): Deterministic query responses
+ *   - buildCargoWorkspaceSummary(): Describe Cargo workspace members, targets and path deps
+ *   - buildCargoFeatureSummary(): Cargo features, what they enable and the code they gate
+ *   - buildUnsafeAuditSummary(): Rust unsafe/FFI sites and missing SAFETY comments per crate
+ *   - buildCargoDependencySummary(): Cargo.lock dependency graph, duplicates and why-paths
  * @context: Transforms raw indexing data into actionable intelligence for AI agents
  */
 
-import { FileInfo } from '../../core/compactor/fileDiscovery';
+import { FileDiscovery, FileInfo } from '../../core/compactor/fileDiscovery';
 import {
   ExportItem,
   RouteItem,
@@ -30,6 +34,21 @@ import { generateRankedHints, ScoredHint, ScoreContext } from './scoring';
 import { logger } from '../../utils/logger';
 import { toPosix } from './utils/pathUtils';
 import { formatSignature } from './utils/publicApi';
+import { analyzeCargoWorkspace } from './utils/cargoWorkspace';
+import {
+  analyzeRustFeatures,
+  CfgFeatureSite,
+  findFeatureGatedCode,
+  resolveFeatureEnablement,
+} from './utils/rustFeatures';
+import { analyzeRustUnsafe, UnsafeCrateRollup, UnsafeSite } from './utils/rustUnsafe';
+import {
+  analyzeDependencyGraph,
+  CargoDependencyReport,
+  CargoLockGraph,
+  findDependencyPaths,
+  loadCargoLock,
+} from './utils/cargoLock';
 import * as path from 'path';
 
 export interface EnhancedProjectSummary {
@@ -40,6 +59,10 @@ export interface EnhancedProjectSummary {
   risks: RiskAssessment;
   hints: ScoredHint[];
   next: NextActions;
+  cargoWorkspace?: CargoWorkspaceSummary;
+  features?: CargoFeatureSummary;
+  unsafeAudit?: UnsafeAuditSummary;
+  dependencyGraph?: CargoDependencySummary;
 }
 
 export interface ProjectSummary {
@@ -53,7 +76,8 @@ export interface PublicSurfaces {
   exports: ExportSummary[];
   routes: RouteSummary[];
   mcpTools: ToolSummary[];
-  envKeys: string[];
+  envKeys: EnvSummary[];
+  envKeyCount: number;
 }
 
 export interface SystemsDetection {
@@ -95,6 +119,7 @@ export interface ExportSummary {
   file: string;
   line: number;
   role?: string;
+  qualifiedPath?: string;
 }
 
 export interface RouteSummary {
@@ -110,6 +135,71 @@ export interface ToolSummary {
   line: number;
 }
 
+export interface EnvSummary {
+  key: string;
+  usage: EnvItem['usage'];
+  file: string;
+  line: number;
+}
+
+export interface CargoWorkspaceSummary {
+  isWorkspace: boolean;
+  members: CrateSummary[];
+}
+
+export interface CrateSummary {
+  name: string;
+  dir: string;
+  version?: string;
+  targets: { kind: string; name: string; path: string }[];
+  pathDependencies: string[];
+}
+
+export interface CargoFeatureSummary {
+  crates: CrateFeatureSummary[];
+}
+
+export interface CrateFeatureSummary {
+  crate: string;
+  defaults: string[];
+  features: FeatureSummary[];
+}
+
+export interface FeatureSummary {
+  name: string;
+  implicit: boolean;
+  enables: string[]; // transitively, other workspace members written `crate/feature`
+  dependencies: string[];
+  external: string[];
+  gated: GatedCodeSummary[]; // code compiled only with this feature
+}
+
+export interface GatedCodeSummary {
+  file: string;
+  line: number;
+  endLine: number;
+  kind: CfgFeatureSite['kind'];
+  item?: string;
+  gatedFile?: string;
+}
+
+export interface UnsafeAuditSummary {
+  total: number;
+  unjustified: number; // sites without a SAFETY comment
+  crates: UnsafeCrateRollup[];
+  sites: UnsafeSite[];
+}
+
+export interface CargoDependencySummary extends CargoDependencyReport {
+  explain?: DependencyExplanation; // set when the query asks about a locked crate
+}
+
+export interface DependencyExplanation {
+  crate: string;
+  versions: string[];
+  paths: string[][]; // package ids, from a workspace member to the crate
+}
+
 export interface RiskFlag {
   type: 'security' | 'performance' | 'maintenance' | 'config';
   severity: 'low' | 'medium' | 'high';
@@ -140,7 +230,7 @@ export async function buildEnhancedProjectSummary(
       detectRoutes(files),
       detectMcpTools(files),
       detectEnvKeys(files),
-      detectDb(files),
+      detectDb(files, projectPath),
       detectGitInfo(projectPath),
     ]);
 
@@ -175,6 +265,16 @@ export async function buildEnhancedProjectSummary(
     const capabilities = buildCapabilitiesMap(exports, routes, mcpTools, envKeys);
     const risks = assessRisks(files, envKeys, exports, mcpTools);
     const next = generateNextActions(hints, query, capabilities, risks);
+    const cargoWorkspace = buildCargoWorkspaceSummary(projectPath);
+    const features = cargoWorkspace ? await buildCargoFeatureSummary(projectPath) : undefined;
+    const unsafeAudit = cargoWorkspace ? await buildUnsafeAuditSummary(projectPath) : undefined;
+    const dependencyGraph = cargoWorkspace
+      ? await buildCargoDependencySummary(projectPath, query)
+      : undefined;
+
+    if (cargoWorkspace?.isWorkspace) {
+      systems.architecture.push('cargo-workspace');
+    }
 
     const result: EnhancedProjectSummary = {
       summary,
@@ -184,6 +284,10 @@ export async function buildEnhancedProjectSummary(
       risks,
       hints,
       next,
+      ...(cargoWorkspace ? { cargoWorkspace } : {}),
+      ...(features ? { features } : {}),
+      ...(unsafeAudit ? { unsafeAudit } : {}),
+      ...(dependencyGraph ? { dependencyGraph } : {}),
     };
 
     logger.info('Enhanced project summary built', {
@@ -203,6 +307,144 @@ export async function buildEnhancedProjectSummary(
   }
 }
 
+/**
+ * Describe the Cargo workspace (or single package) rooted at projectPath
+ */
+export function buildCargoWorkspaceSummary(projectPath: string): CargoWorkspaceSummary | undefined {
+  const workspace = analyzeCargoWorkspace(projectPath);
+  if (!workspace || workspace.members.length === 0) {
+    return undefined;
+  }
+
+  return {
+    isWorkspace: workspace.isWorkspace,
+    members: workspace.members.map(member => ({
+      name: member.name,
+      dir: member.dir,
+      version: member.version,
+      targets: member.targets,
+      // Prefer the member's package name; fall back to the path for crates outside the workspace
+      pathDependencies: Array.from(
+        new Set(member.pathDependencies.map(dep => dep.crate || dep.path || dep.name))
+      ),
+    })),
+  };
+}
+
+/**
+ * Describe the [features] of every crate at projectPath: what each one enables transitively
+ * and which code is compiled only when it is on. Undefined when no crate declares features
+ */
+export async function buildCargoFeatureSummary(
+  projectPath: string
+): Promise<CargoFeatureSummary | undefined> {
+  try {
+    // Gates live anywhere in the tree, so scan every Rust file rather than the ranked subset
+    const files = await new FileDiscovery(projectPath, {
+      supportedExtensions: ['.rs'],
+    }).discoverFiles();
+    const map = await analyzeRustFeatures(projectPath, files);
+    if (!map) return undefined;
+
+    const crates: CrateFeatureSummary[] = map.crates
+      .filter(entry => entry.features.length > 0)
+      .map(entry => ({
+        crate: entry.crate,
+        defaults: entry.defaults,
+        features: entry.features.map(feature => {
+          const enablement = resolveFeatureEnablement(map.crates, entry.crate, feature.name);
+          return {
+            name: feature.name,
+            implicit: feature.implicit,
+            enables: enablement?.features ?? [],
+            dependencies: enablement?.dependencies ?? [],
+            external: enablement?.external ?? [],
+            gated: findFeatureGatedCode(map, feature.name, entry.crate).map(site => ({
+              file: site.file,
+              line: site.line,
+              endLine: site.endLine,
+              kind: site.kind,
+              item: site.item,
+              gatedFile: site.gatedFile,
+            })),
+          };
+        }),
+      }));
+
+    return crates.length > 0 ? { crates } : undefined;
+  } catch (error) {
+    logger.warn('Failed to build Cargo feature summary', {
+      projectPath,
+      error: (error as Error).message,
+    });
+    return undefined;
+  }
+}
+
+/**
+ * Audit unsafe code and FFI in every Rust file at projectPath: unsafe blocks, fns and impls,
+ * extern blocks, `#[no_mangle]` exports and raw-pointer derefs. Undefined when there are none
+ */
+export async function buildUnsafeAuditSummary(
+  projectPath: string
+): Promise<UnsafeAuditSummary | undefined> {
+  try {
+    // A security review needs every site, not just the files ranked for hints
+    const files = await new FileDiscovery(projectPath, {
+      supportedExtensions: ['.rs'],
+    }).discoverFiles();
+    const audit = await analyzeRustUnsafe(projectPath, files);
+    if (!audit) return undefined;
+
+    return {
+      total: audit.sites.length,
+      unjustified: audit.sites.filter(site => !site.justified).length,
+      crates: audit.crates,
+      sites: audit.sites,
+    };
+  } catch (error) {
+    logger.warn('Failed to build unsafe code audit', {
+      projectPath,
+      error: (error as Error).message,
+    });
+    return undefined;
+  }
+}
+
+/**
+ * Read the workspace Cargo.lock into a dependency report. When the query asks about a locked
+ * crate ("why do we depend on `openssl-sys`?"), include the chains that pull it in.
+ * Undefined when there is no lockfile
+ */
+export async function buildCargoDependencySummary(
+  projectPath: string,
+  query?: string
+): Promise<CargoDependencySummary | undefined> {
+  try {
+    const lock = await loadCargoLock(projectPath);
+    if (!lock) return undefined;
+
+    const report = analyzeDependencyGraph(lock.graph, lock.workspace);
+    const crate = query ? findQueriedDependency(lock.graph, query) : undefined;
+    if (!crate) return report;
+
+    return {
+      ...report,
+      explain: {
+        crate,
+        versions: lock.graph.packages.filter(pkg => pkg.name === crate).map(pkg => pkg.version),
+        paths: findDependencyPaths(lock.graph, crate),
+      },
+    };
+  } catch (error) {
+    logger.warn('Failed to build Cargo dependency graph', {
+      projectPath,
+      error: (error as Error).message,
+    });
+    return undefined;
+  }
+}
+
 /**
  * Build basic project summary
  */
@@ -221,7 +463,7 @@ function buildProjectSummary(files: FileInfo[]): ProjectSummary {
     .map(([lang]) => lang);
 
   const entryPoints = files
-    .filter(file => /(index|main|app|server)\.(ts|js|py)$/i.test(file.relPath))
+    .filter(file => /(index|main|app|server)\.(ts|js|py|rs)$/i.test(file.relPath))
     .map(file => file.relPath)
     .slice(0, 5);
 
@@ -257,6 +499,7 @@ function buildPublicSurfaces(
       line: exp.line,
       role: exp.role || inferExportRole(exp.name, exp.kind),
       signature: exp.signature || formatSignature(exp),
+      ...(exp.qualifiedPath ? { qualifiedPath: exp.qualifiedPath } : {}),
     })),
     routes: routes.slice(0, 10).map(route => ({
       method: route.method,
@@ -269,7 +512,17 @@ function buildPublicSurfaces(
       file: toPosix(tool.file),
       line: tool.line,
     })),
-    envKeys: [...new Set(envKeys.map(env => env.key))].slice(0, 15),
+    // The most referenced keys, alphabetical among equals
+    envKeys: [...envKeys]
+      .sort((a, b) => (b.references ?? 1) - (a.references ?? 1))
+      .slice(0, 15)
+      .map(env => ({
+        key: env.key,
+        usage: env.usage,
+        file: toPosix(env.file),
+        line: env.line,
+      })),
+    envKeyCount: envKeys.length,
   };
 }
 
@@ -735,6 +988,59 @@ export function generateAnswerDraft(
   const queryLower = query.toLowerCase();
   const { systems, capabilities, hints } = summary;
 
+  // Dependency queries, e.g. "why do we depend on `openssl-sys`?"
+  const explain = summary.dependencyGraph?.explain;
+  if (explain) {
+    const subject = `\`${explain.crate}\` (${explain.versions.join(', ')})`;
+    if (explain.paths.length === 0) {
+      return `${subject} is in Cargo.lock but no workspace member depends on it.`;
+    }
+    const chains = explain.paths.map(chain => chain.join(' → '));
+    return `${subject} is pulled in by: ${chains.join('; ')}.`;
+  }
+
+  // Cargo feature queries, e.g. "what code compiles only with `tls-rustls`?"
+  const feature = findQueriedFeature(summary.features, query);
+  if (feature) {
+    const gated = feature.gated.slice(0, 8).map(site => {
+      const what = site.gatedFile ? `${site.gatedFile} (${site.item})` : site.item || site.kind;
+      return `${what} at ${site.file}:${site.line}`;
+    });
+    if (feature.gated.length > gated.length) {
+      gated.push(`and ${feature.gated.length - gated.length} more`);
+    }
+    const enables = [...feature.enables, ...feature.dependencies.map(dep => `dep:${dep}`)];
+    return (
+      `Feature \`${feature.name}\`` +
+      (enables.length > 0 ? ` enables ${enables.join(', ')}` : ' enables no other features') +
+      (feature.external.length > 0 ? ` and forwards ${feature.external.join(', ')}` : '') +
+      '. ' +
+      (gated.length > 0
+        ? `Code compiled only with it: ${gated.join('; ')}.`
+        : 'No code is gated on it directly.')
+    );
+  }
+
+  // Unsafe/FFI queries, e.g. "where do we use unsafe without a SAFETY comment?"
+  if (summary.unsafeAudit && /\bunsafe\b|\bffi\b|no_mangle|raw pointer|safety/.test(queryLower)) {
+    const audit = summary.unsafeAudit;
+    const missing = audit.sites.filter(site => !site.justified);
+    const listed = missing.slice(0, 8).map(site => {
+      const where = site.function ? ` in ${site.function}` : '';
+      return `${site.detail}${where} at ${site.file}:${site.line}`;
+    });
+    if (missing.length > listed.length) {
+      listed.push(`and ${missing.length - listed.length} more`);
+    }
+    const crates = audit.crates.map(crate => `${crate.crate} (${crate.total})`).join(', ');
+    return (
+      `Found ${audit.total} unsafe/FFI sites across ${crates}. ` +
+      (listed.length > 0
+        ? `${missing.length} lack a SAFETY comment: ${listed.join('; ')}.`
+        : 'Every site has a SAFETY comment.')
+    );
+  }
+
   // Database queries
   if (/database|db|storage|persist/.test(queryLower)) {
     if (systems.db) {
@@ -796,6 +1102,41 @@ export function generateAnswerDraft(
 
 // Helper functions
 
+/**
+ * The Cargo feature a query names, e.g. `tls-rustls` in "what does tls-rustls turn on"
+ */
+function findQueriedFeature(
+  features: CargoFeatureSummary | undefined,
+  query: string
+): FeatureSummary | undefined {
+  if (!features) return undefined;
+  const words = new Set(query.match(/[A-Za-z0-9_][\w-]*/g) ?? []);
+  const mentioned = features.crates
+    .flatMap(entry => entry.features)
+    .filter(feature => words.has(feature.name));
+  if (mentioned.length === 0) return undefined;
+  // A bare word like "std" only counts when the query is clearly about features
+  const explicit = /feature|cfg|compil|enabl|turn on|gated/i.test(query);
+  const best = mentioned.sort((a, b) => b.name.length - a.name.length)[0];
+  return explicit || best.name.includes('-') ? best : undefined;
+}
+
+/**
+ * The locked crate a dependency query names, e.g. `openssl-sys` in "why do we depend on
+ * openssl-sys". Workspace members never count
+ */
+function findQueriedDependency(graph: CargoLockGraph, query: string): string | undefined {
+  const names = new Set(graph.packages.filter(pkg => !pkg.member).map(pkg => pkg.name));
+  const quoted = Array.from(query.matchAll(/`([\w-]+)`/g), match => match[1]);
+  const quotedHit = quoted.find(word => names.has(word));
+  if (quotedHit) return quotedHit;
+
+  // Bare words like "log" or "time" only count when the query is about dependencies
+  if (!/depend|pull(s|ed)? in|brings? in|\bwhy\b|\bcrate\b/i.test(query)) return undefined;
+  const words = query.match(/[A-Za-z0-9_][\w-]*/g) ?? [];
+  return words.filter(word => names.has(word)).sort((a, b) => b.length - a.length)[0];
+}
+
 function inferExportRole(name: string, kind: string): string {
   const nameLower = name.toLowerCase();
 
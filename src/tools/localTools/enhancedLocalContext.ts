@@ -6,16 +6,31 @@
  *   - buildCandidateGeneration(): AST-based candidate generation with ranking
  *   - assembleMiniBundle(): Token-budgeted snippet assembly
  *   - generateAnswerDraft(): Template-based deterministic answers
+ *   - gatherRustTests(): Rust tests covering the item or file a "test" task asks about
  * @context: Provides actionable context through AST-grep, call-graph slicing, and snippet assembly
  */
 
 import { FileInfo } from '../../core/compactor/fileDiscovery';
 import { EnhancedProjectSummary } from './enhancedHints';
 import { logger } from '../../utils/logger';
+import * as fs from 'fs';
 import * as path from 'path';
 import { estimateTokens as estimateTokensShared } from '../utils/toolHelpers';
 import { validateAndResolvePath } from '../utils/pathUtils';
 import { compileExcludePatterns, isExcludedPath } from '../utils/toolHelpers';
+import {
+  analyzeCargoWorkspace,
+  findCargoWorkspaceRoot,
+  resolveCrateScope,
+} from './utils/cargoWorkspace';
+import {
+  buildRustCfgView,
+  normalizeCfgConfig,
+  RustCfgConfig,
+  RustCfgView,
+} from './utils/rustCfg';
+import type { RustMacroIndex } from './utils/rustMacros';
+import type { RustTestCase } from './utils/rustTests';
 
 // ===== API INTERFACES =====
 
@@ -30,6 +45,8 @@ export interface LocalContextRequest {
   astQueries?: AstQuery[];
   attackPlan?: 'auto' | 'init-read-write' | 'api-route' | 'error-driven' | 'auth';
   excludePatterns?: string[];
+  crate?: string; // Cargo workspace member to scope the analysis to
+  cfg?: RustCfgConfig; // Rust build configuration; compiled-out items are left out
 }
 
 export interface LocalContextResponse {
@@ -73,6 +90,8 @@ export interface ContextMetadata {
   compactedTokens: number;
   bundleTokens: number;
   processingTimeMs: number;
+  crate?: string;
+  cfg?: RustCfgConfig;
 }
 
 // ===== AST QUERY DSL =====
@@ -84,13 +103,25 @@ export type AstQuery =
   | { kind: 'new'; className: string | RegExp }
   | { kind: 'assign'; lhs: string | RegExp; rhsCallee?: string | RegExp }
   | { kind: 'env'; key: string | RegExp }
-  | { kind: 'route'; method?: string | RegExp; path?: string | RegExp };
+  | { kind: 'route'; method?: string | RegExp; path?: string | RegExp }
+  // Rust structure: impl blocks (everything implementing a trait, or inherent ones when no trait
+  // is given), trait definitions, `#[derive(..)]`, attributes by path and macros (definitions and
+  // call sites)
+  | { kind: 'impl'; trait?: string | RegExp; type?: string | RegExp }
+  | { kind: 'trait'; name?: string | RegExp }
+  | { kind: 'derive'; trait: string | RegExp }
+  | { kind: 'attribute'; path: string | RegExp }
+  | { kind: 'macro'; name: string | RegExp };
 
 // ===== ATTACK PLAN RECIPES =====
 
 export const ATTACK_PLAN_RECIPES: Record<string, AstQuery[]> = {
   'init-read-write': [
-    { kind: 'import', source: /(sqlite|better-sqlite3|knex|drizzle|typeorm|mongoose|pg)/i },
+    {
+      kind: 'import',
+      source:
+        /(sqlite|better-sqlite3|knex|drizzle|typeorm|mongoose|pg|sqlx|diesel|sea_orm|rusqlite|tokio_postgres)/i,
+    },
     { kind: 'export', name: /(initialize(Database|DB)|connect|open|init)/i },
     {
       kind: 'call',
@@ -100,7 +131,7 @@ export const ATTACK_PLAN_RECIPES: Record<string, AstQuery[]> = {
     { kind: 'env', key: /(DB_PATH|DATABASE_URL|EMBEDDING_MODEL|DB_)/i },
   ],
   'api-route': [
-    { kind: 'import', source: /(express|fastify|koa|hapi|next|router)/i },
+    { kind: 'import', source: /(express|fastify|koa|hapi|next|router|axum|actix|rocket|poem|warp)/i },
     { kind: 'route', method: /(get|post|put|delete|patch)/i },
     {
       kind: 'call',
@@ -328,11 +359,18 @@ const AUTH_REGEXES: RegExp[] = [
 
 // ===== LLM-READY BUNDLE TYPES =====
 
+export type RepoFingerprint = {
+  languages: string[];
+  frameworks: string[];
+  families: string[];
+  crates?: string[];
+};
+
 export type LocalContextOut = {
   success: true;
   query: string;
   topic: Topic;
-  fingerprint?: { languages: string[]; frameworks: string[]; families: string[] };
+  fingerprint?: RepoFingerprint;
   anchors: { file: string; score: number; reasons: string[]; features: string[] }[];
   neighbors: string[];
   coverage?: Record<string, { found: number; requiredMin: number }>;
@@ -358,6 +396,8 @@ export async function localContext(req: LocalContextRequest): Promise<LocalConte
     taskType: req.taskType,
     attackPlan: req.attackPlan,
     maxTokens: req.maxTokens,
+    crate: req.crate,
+    cfg: req.cfg,
   });
 
   // Validate that projectPath is provided
@@ -379,8 +419,14 @@ export async function localContext(req: LocalContextRequest): Promise<LocalConte
   } as Required<LocalContextRequest>;
 
   try {
+    const cfg = normalizeCfgConfig(request.cfg);
+
     // 1. Load project indices (reuse project_hints cache)
-    const indices = await loadProjectIndices(request.projectPath, request.useProjectHintsCache);
+    const indices = await loadProjectIndices(
+      request.projectPath,
+      request.useProjectHintsCache,
+      request.crate
+    );
 
     // 2. Choose attack plan
     const plan = chooseAttackPlan(request.attackPlan, request.query);
@@ -396,12 +442,25 @@ export async function localContext(req: LocalContextRequest): Promise<LocalConte
     const allExcludePatterns = [...UNIVERSAL_NEGATIVES, ...customExcludePatterns];
     const excludeMatchers = compileExcludePatterns(allExcludePatterns);
 
-    const filteredFiles = prioritizedFiles.filter(
+    let filteredFiles = prioritizedFiles.filter(
       file => !isExcludedPath(file.relPath, excludeMatchers)
     );
 
+    // 3.6 Rust cfg view: drop files and items that don't compile under the configuration
+    const cfgView = cfg ? await buildRustCfgView(filteredFiles, cfg) : undefined;
+    if (cfgView) {
+      filteredFiles = filteredFiles.filter(file => !cfgView.inactiveFiles.has(file.absPath));
+      logger.info('⚙️ Applied Rust cfg view', {
+        cfg,
+        inactiveFiles: cfgView.inactiveFiles.size,
+        inactiveItems: Array.from(cfgView.ranges.values()).reduce((n, r) => n + r.length, 0),
+      });
+    }
+
     // 4. Run AST queries to find matches
-    const astMatches = await runAstQueries(filteredFiles, dslQueries);
+    const isCompiled = (match: CandidateSymbol) =>
+      !cfgView || cfgView.isActive(match.file, match.start);
+    const astMatches = (await runAstQueries(filteredFiles, dslQueries)).filter(isCompiled);
 
     // 4.5 Family/topic-aware detectors beyond generic AST (no embeddings)
     const extraCandidates: CandidateSymbol[] = [];
@@ -415,15 +474,25 @@ export async function localContext(req: LocalContextRequest): Promise<LocalConte
       const dbExtras = await gatherDbSchemaCandidates(filteredFiles);
       extraCandidates.push(...dbExtras);
     }
+    if (topic === 'db' || plan === 'init-read-write') {
+      // Rust persistence code isn't reachable through the JS/TS AST recipes
+      const rustDbExtras = await gatherRustDbCandidates(filteredFiles);
+      extraCandidates.push(...rustDbExtras);
+    }
+    const rustMacroExtras = gatherRustMacroCandidates(
+      filteredFiles,
+      request.query,
+      indices.macroIndex
+    );
+    extraCandidates.push(...rustMacroExtras);
 
     // 5. Generate and rank candidates
-    const allMatches = [...astMatches, ...extraCandidates];
+    const allMatches = [...astMatches, ...extraCandidates.filter(isCompiled)];
     const candidates = await rankCandidates(allMatches, indices, request.query, plan);
 
     // 6. Select top jump targets (respect maxSimilarChunks)
-    let jumpTargets = selectJumpTargets(candidates, {
-      max: Math.max(1, Math.min(request.maxSimilarChunks, 20)),
-    });
+    const maxTargets = Math.max(1, Math.min(request.maxSimilarChunks, 20));
+    let jumpTargets = selectJumpTargets(candidates, { max: maxTargets });
 
     if (jumpTargets.length === 0 && candidates.length > 0) {
       const candidate = candidates[0];
@@ -440,19 +509,42 @@ export async function localContext(req: LocalContextRequest): Promise<LocalConte
       ];
     }
 
-    // 7. Build mini-bundle with token budget
-    const miniBundle = await buildMiniBundle(jumpTargets, indices.files, request.maxTokens);
+    // 6.5 Rust tests linked to the item or changed file the query names. Test files are
+    // excluded from ordinary retrieval, so they are read from the unfiltered file set
+    let rustTests: Awaited<ReturnType<typeof gatherRustTests>>;
+    if (request.taskType === 'test') {
+      const customMatchers = compileExcludePatterns(customExcludePatterns);
+      const testableFiles = indices.files.filter(
+        file =>
+          !isExcludedPath(file.relPath, customMatchers) &&
+          !cfgView?.inactiveFiles.has(file.absPath)
+      );
+      rustTests = await gatherRustTests(testableFiles, request.query, request.projectPath);
+    }
+    if (rustTests && rustTests.tests.length > 0) {
+      const subject = rustTests.subject;
+      const testTargets = rustTests.tests.map(test => rustTestTarget(test, subject));
+      jumpTargets = [...testTargets, ...jumpTargets].slice(0, maxTargets);
+    }
 
-    // 8. Generate deterministic answer draft
-    const answerDraft = await generateDeterministicAnswer(
-      plan,
-      request.taskType,
+    // 7. Build mini-bundle with token budget
+    const miniBundle = await buildMiniBundle(
       jumpTargets,
-      indices
+      indices.files,
+      request.maxTokens,
+      cfgView
     );
 
+    // 8. Generate deterministic answer draft
+    const answerDraft = rustTests
+      ? describeRustTests(rustTests.subject, rustTests.tests)
+      : await generateDeterministicAnswer(plan, request.taskType, jumpTargets, indices);
+
     // 9. Compute next actions
     const nextActions = computeNextActions(jumpTargets, request.taskType);
+    if (rustTests) {
+      nextActions.checks.unshift(...rustTests.commands.slice(0, 3));
+    }
 
     // 10. Build evidence list
     const evidence = buildEvidence(jumpTargets, astMatches);
@@ -465,7 +557,7 @@ export async function localContext(req: LocalContextRequest): Promise<LocalConte
       files: indices.files,
       importGraph: await getOrBuildImportGraph(indices),
       envKeys: (indices.env || []).map((e: any) => e.key),
-      fingerprint: await fingerprintRepo(indices.files),
+      fingerprint: await fingerprintRepo(indices.files, request.projectPath),
     });
 
     const processingTimeMs = Date.now() - startTime;
@@ -484,6 +576,8 @@ export async function localContext(req: LocalContextRequest): Promise<LocalConte
         compactedTokens: 0,
         bundleTokens: miniBundle.reduce((sum, item) => sum + estimateTokensShared(item.snippet), 0),
         processingTimeMs,
+        crate: request.crate,
+        cfg,
       },
       llmBundle,
     };
@@ -514,7 +608,11 @@ export async function localContext(req: LocalContextRequest): Promise<LocalConte
 
 // ===== IMPLEMENTATION FUNCTIONS =====
 
-async function loadProjectIndices(projectPath: string, useCache: boolean): Promise<ProjectContext> {
+async function loadProjectIndices(
+  projectPath: string,
+  useCache: boolean,
+  crate?: string
+): Promise<ProjectContext> {
   // Import project hints functionality
   const { buildEnhancedProjectSummary } = await import('./enhancedHints');
   const { FileDiscovery } = await import('../../core/compactor/fileDiscovery');
@@ -522,12 +620,29 @@ async function loadProjectIndices(projectPath: string, useCache: boolean): Promi
   // Validate and resolve the project path
   const validatedProjectPath = validateAndResolvePath(projectPath);
 
+  // Resolve the crate scope up front so an unknown crate surfaces as an error, not an empty result
+  const crateScope = crate ? resolveCrateScope(validatedProjectPath, crate) : undefined;
+
   try {
     const fileDiscovery = new FileDiscovery(validatedProjectPath, {
       maxFileSize: 200000,
     });
 
-    const files = await fileDiscovery.discoverFiles();
+    const discoveredFiles = await fileDiscovery.discoverFiles();
+    const files = crateScope
+      ? discoveredFiles.filter(file => crateScope.includes(file.relPath))
+      : discoveredFiles;
+
+    if (crateScope) {
+      logger.info('📦 Scoped local context to Cargo crate', {
+        crate: crateScope.crate.name,
+        dir: crateScope.crate.dir,
+        files: files.length,
+      });
+    }
+
+    const macroIndex = await loadRustMacros(files);
+    const macroReferences = await loadRustMacroReferences(files, macroIndex);
 
     if (useCache) {
       // Try to reuse existing enhanced project summary
@@ -566,6 +681,8 @@ async function loadProjectIndices(projectPath: string, useCache: boolean): Promi
         routes: enhancedSummary.surfaces.routes,
         env: enhancedSummary.surfaces.envKeys,
         systems: enhancedSummary.systems,
+        macroReferences,
+        macroIndex,
       };
     } else {
       return {
@@ -575,6 +692,8 @@ async function loadProjectIndices(projectPath: string, useCache: boolean): Promi
         routes: [],
         env: [],
         systems: {},
+        macroReferences,
+        macroIndex,
       };
     }
   } catch (error) {
@@ -712,10 +831,11 @@ function selectJumpTargets(candidates: CandidateSymbol[], options: { max: number
 async function buildMiniBundle(
   targets: JumpTarget[],
   files: FileInfo[],
-  maxTokens: number
+  maxTokens: number,
+  cfgView?: RustCfgView
 ): Promise<BundleSnippet[]> {
   const { assembleMiniBundle } = await import('./miniBundleAssembler');
-  return assembleMiniBundle(targets, files, maxTokens);
+  return assembleMiniBundle(targets, files, maxTokens, cfgView);
 }
 
 async function generateDeterministicAnswer(
@@ -857,7 +977,7 @@ function buildLLMBundle(args: {
   files: FileInfo[];
   importGraph: Map<string, string[]>;
   envKeys: string[];
-  fingerprint: { languages: string[]; frameworks: string[]; families: string[] };
+  fingerprint: RepoFingerprint;
 }): LocalContextOut {
   // Anchor files: top 8 distinct by score
   const rankedByScore = [...args.ranked].sort(
@@ -972,9 +1092,10 @@ function toRel(absFile: string, files: FileInfo[]): string {
 }
 
 // ===== REPO FINGERPRINTING (framework-agnostic) =====
-async function fingerprintRepo(
-  files: FileInfo[]
-): Promise<{ languages: string[]; frameworks: string[]; families: string[] }> {
+export async function fingerprintRepo(
+  files: FileInfo[],
+  projectPath?: string
+): Promise<RepoFingerprint> {
   const languages = unique(files.map(f => f.language));
   const frameworks = new Set<string>();
   const families = new Set<string>();
@@ -985,7 +1106,9 @@ async function fingerprintRepo(
   if (names.some(n => n.endsWith('pyproject.toml') || n.endsWith('requirements.txt')))
     frameworks.add('python');
   if (names.some(n => n.endsWith('go.mod'))) frameworks.add('go');
-  if (names.some(n => n.endsWith('cargo.toml'))) frameworks.add('rust');
+  if (names.some(n => n.endsWith('cargo.toml')) || languages.includes('rust')) {
+    frameworks.add('rust');
+  }
   if (names.some(n => n.endsWith('pom.xml') || n.endsWith('build.gradle'))) frameworks.add('java');
 
   // Family detection from path text (lightweight)
@@ -993,7 +1116,26 @@ async function fingerprintRepo(
     families.add('file_router');
   if (names.some(n => /api\//.test(n))) families.add('method_call_router');
 
-  return { languages, frameworks: Array.from(frameworks), families: Array.from(families) };
+  // Cargo workspaces: report member crates so agents can scope follow-up queries. Discovery
+  // only returns source files, so a virtual workspace's root Cargo.toml is checked on disk
+  let crates: string[] | undefined;
+  const root = projectPath ? validateAndResolvePath(projectPath) : undefined;
+  if (root && fs.existsSync(path.join(root, 'Cargo.toml'))) frameworks.add('rust');
+  const workspaceRoot = root && frameworks.has('rust') ? findCargoWorkspaceRoot(root) : null;
+  if (workspaceRoot) {
+    const workspace = analyzeCargoWorkspace(workspaceRoot);
+    if (workspace?.isWorkspace) families.add('cargo_workspace');
+    if (workspace && workspace.members.length > 0) {
+      crates = workspace.members.map(member => member.name);
+    }
+  }
+
+  return {
+    languages,
+    frameworks: Array.from(frameworks),
+    families: Array.from(families),
+    ...(crates ? { crates } : {}),
+  };
 }
 
 // ===== EXTRA DETECTORS (API / Components / DB) =====
@@ -1064,6 +1206,221 @@ async function gatherDbSchemaCandidates(files: FileInfo[]): Promise<CandidateSym
   return results;
 }
 
+async function gatherRustDbCandidates(files: FileInfo[]): Promise<CandidateSymbol[]> {
+  const { readFile } = await import('fs/promises');
+  const { collectDbEvidence, dbInitializersForFile } = await import('./utils/dbEvidence');
+  const results: CandidateSymbol[] = [];
+
+  for (const f of files) {
+    if (f.language !== 'rust') continue;
+    let content: string;
+    try {
+      content = await readFile(f.absPath, 'utf-8');
+    } catch {
+      continue;
+    }
+    const evidence = collectDbEvidence(content);
+    if (evidence.length === 0) continue;
+
+    const rel = f.relPath.replace(/\\/g, '/');
+    const lines = content.split(/\r?\n/);
+    const seen = new Set<string>();
+    const push = (symbol: string, line: number, role: string, reason: string, score: number) => {
+      if (seen.has(symbol)) return;
+      seen.add(symbol);
+      results.push({
+        file: f.absPath,
+        symbol,
+        start: line,
+        end: line,
+        kind: 'function',
+        score,
+        reasons: [reason],
+        role,
+      });
+    };
+
+    for (const init of dbInitializersForFile(rel, content)) {
+      push(init.match.split(' (')[0], init.line, 'database initializer', 'db:init', 0.85);
+    }
+
+    // Queries (sqlx macros, .execute/.fetch_*) belong to the repository fn that encloses them
+    for (const item of evidence) {
+      for (let i = item.line - 1; i >= 0; i--) {
+        const fn = /\bfn\s+(\w+)/.exec(lines[i]);
+        if (fn) {
+          push(fn[1], i + 1, 'data access', 'db:query', 0.75);
+          break;
+        }
+      }
+    }
+
+    // diesel schema.rs / sea-orm entities
+    if (/(^|\/)schema\.rs$/.test(rel) || /\bDeriveEntityModel\b/.test(content)) {
+      push('DbSchema', 1, 'schema', 'db:schema:path', 0.7);
+    }
+  }
+
+  return results;
+}
+
+/**
+ * Rust macro definitions and their call sites, when the query names a macro (`define_metric!`,
+ * "where is the define_metric macro used")
+ */
+function gatherRustMacroCandidates(
+  files: FileInfo[],
+  query: string,
+  index: RustMacroIndex | undefined
+): CandidateSymbol[] {
+  const bangNames = Array.from(query.matchAll(/([A-Za-z_][\w:]*)!/g), m => m[1].split('::').pop()!);
+  if (bangNames.length === 0 && !/\bmacros?\b/i.test(query)) return [];
+  if (!index) return [];
+
+  try {
+    // The index covers the whole project; keep only files that survived exclusion and cfg
+    const absPaths = new Map(files.map(f => [f.relPath.replace(/\\/g, '/'), f.absPath]));
+    const words = new Set(query.toLowerCase().match(/[a-z_]\w*/g) || []);
+
+    // Project macros named in the query, plus any macro written with `!` (sqlx::query!)
+    const definitions = index.definitions.filter(
+      def => absPaths.has(def.file) && words.has(def.name.toLowerCase())
+    );
+    const names = new Set([...definitions.map(def => def.name), ...bangNames]);
+    const results: CandidateSymbol[] = [];
+
+    for (const def of definitions) {
+      results.push({
+        file: absPaths.get(def.file)!,
+        symbol: def.name,
+        start: def.start,
+        end: def.end,
+        kind: 'function',
+        score: 0.9,
+        reasons: [`macro:definition:${def.kind}`],
+        role: 'macro definition',
+      });
+    }
+
+    // One candidate per calling function, however often it invokes the macro
+    const sites = new Map<string, CandidateSymbol>();
+    for (const call of index.invocations) {
+      const file = absPaths.get(call.file);
+      if (!file || !names.has(call.name)) continue;
+      const symbol = call.caller ?? `${call.name}!`;
+      const existing = sites.get(`${file}:${symbol}`);
+      if (existing) {
+        existing.score = Math.min(0.9, existing.score + 0.02);
+        continue;
+      }
+      sites.set(`${file}:${symbol}`, {
+        file,
+        symbol,
+        start: call.start,
+        end: call.end,
+        kind: 'call',
+        score: 0.8,
+        reasons: [`macro:invocation:${call.kind === 'bang' ? `${call.path}!` : call.path}`],
+        role: 'macro call site',
+      });
+    }
+
+    return [...results, ...sites.values()];
+  } catch {
+    return [];
+  }
+}
+
+/**
+ * Index Rust macro definitions and call sites once per request; undefined without Rust files
+ */
+async function loadRustMacros(files: FileInfo[]): Promise<RustMacroIndex | undefined> {
+  if (!files.some(f => f.language === 'rust')) return undefined;
+  try {
+    const { loadRustMacroIndex } = await import('./utils/rustMacros');
+    return await loadRustMacroIndex(files);
+  } catch (error) {
+    logger.debug('Skipping Rust macro index', { error });
+    return undefined;
+  }
+}
+
+/**
+ * Macro <-> caller edges for the candidate call graph; undefined for projects without Rust
+ */
+async function loadRustMacroReferences(
+  files: FileInfo[],
+  index: RustMacroIndex | undefined
+): Promise<Map<string, string[]> | undefined> {
+  if (!index) return undefined;
+  try {
+    const { buildMacroReferences } = await import('./utils/rustMacros');
+    const absPaths = new Map(files.map(f => [f.relPath.replace(/\\/g, '/'), f.absPath]));
+    return buildMacroReferences(index, relPath => absPaths.get(relPath) ?? relPath);
+  } catch (error) {
+    logger.debug('Skipping Rust macro references', { error });
+    return undefined;
+  }
+}
+
+/**
+ * Rust tests for a "test" task: the tests exercising the item the query names
+ * (`RateLimiter::acquire`), or the tests to run after changing a named `.rs` file
+ */
+async function gatherRustTests(
+  files: FileInfo[],
+  query: string,
+  projectPath: string
+): Promise<{ subject: string; tests: RustTestCase[]; commands: string[] } | undefined> {
+  if (!files.some(f => f.language === 'rust')) return undefined;
+  try {
+    const { cargoTestCommand, findRustTests, loadRustTestIndex } = await import(
+      './utils/rustTests'
+    );
+    const index = await loadRustTestIndex(files, validateAndResolvePath(projectPath));
+    const found = findRustTests(index, query);
+    if (!found) return undefined;
+
+    const absPaths = new Map(files.map(f => [f.relPath.replace(/\\/g, '/'), f.absPath]));
+    return {
+      subject: found.subject,
+      tests: found.tests.map(test => ({ ...test, file: absPaths.get(test.file) ?? test.file })),
+      commands: found.tests.map(cargoTestCommand),
+    };
+  } catch (error) {
+    logger.debug('Skipping Rust test discovery', { error });
+    return undefined;
+  }
+}
+
+function rustTestTarget(test: RustTestCase, subject: string): JumpTarget {
+  return {
+    file: test.file,
+    symbol: test.kind === 'doctest' ? test.name : test.path,
+    start: test.start,
+    end: test.end,
+    role: test.kind === 'doctest' ? 'doctest' : `${test.kind} test`,
+    confidence: 0.95,
+    why: [`rust:test:${test.harness}`, `covers:${subject}`],
+  };
+}
+
+function describeRustTests(subject: string, tests: RustTestCase[]): string {
+  if (tests.length === 0) {
+    return `No Rust tests exercise ${subject}: no #[test] function, bench or doctest calls it.`;
+  }
+
+  const listed = tests.slice(0, 8).map(test => {
+    const kind = test.kind === 'doctest' ? 'doctest' : `${test.kind}, ${test.harness}`;
+    const name = test.kind === 'doctest' ? test.name : test.path;
+    return `${name} (${kind}) at ${getRelativePath(test.file)}:${test.line}`;
+  });
+  if (tests.length > listed.length) listed.push(`and ${tests.length - listed.length} more`);
+
+  const noun = tests.length === 1 ? 'test covers' : 'tests cover';
+  return `${tests.length} Rust ${noun} ${subject}: ${listed.join('; ')}.`;
+}
+
 // ===== INTERFACES FOR INTEGRATION =====
 
 export interface ProjectContext {
@@ -1074,6 +1431,8 @@ export interface ProjectContext {
   env: any[];
   systems: any;
   callGraph?: Map<string, string[]>;
+  macroReferences?: Map<string, string[]>; // Rust macro <-> invoking function edges
+  macroIndex?: RustMacroIndex; // Rust macro definitions and call sites across the project
 }
 
 // Topic-aware file prioritization: apply stoplist and add auth path hint boosts
@@ -1095,7 +1454,7 @@ function prioritizeFilesForTopic(files: FileInfo[], topic: Topic): FileInfo[] {
   const notStopped = files.filter(f => !stoplist.some(glob => matchesGlob(f.relPath, glob)));
 
   if (topic === 'api') {
-    // Frontload Next.js file-router and generic api folders
+    // Frontload Next.js file-router, generic api folders and Rust routes/handlers modules
     const apiHinted: FileInfo[] = [];
     const apiOthers: FileInfo[] = [];
     for (const f of notStopped) {
@@ -1103,7 +1462,8 @@ function prioritizeFilesForTopic(files: FileInfo[], topic: Topic): FileInfo[] {
       if (
         /app\/.*\/route\.(ts|js)$/i.test(rel) ||
         /pages\/api\//i.test(rel) ||
-        /\/api\//i.test(rel)
+        /\/api\//i.test(rel) ||
+        /\/(routes?|handlers?)(\/|\.rs$)/i.test(rel)
       ) {
         apiHinted.push(f);
       } else {
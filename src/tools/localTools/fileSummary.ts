@@ -17,10 +17,21 @@ import { validateAndResolvePath } from '../utils/pathUtils';
 import { formatFileSummaryOutput } from './formatters/fileSummaryFormatters';
 import { generateQuickFileAnalysis } from './formatters/fileSummaryFormatters';
 import { handleNonCodeFile, extractFileHeader } from './analyzers/fileAnalyzers';
-import { calculateCyclomaticComplexity } from './analyzers/complexityAnalysis';
+import {
+  calculateCyclomaticComplexity,
+  calculateRustComplexity,
+  FunctionComplexity,
+} from './analyzers/complexityAnalysis';
 import { handleAstGrep, executeAstGrep } from './astGrep';
 import { getPatterns, preparePattern, SymbolPattern } from './symbolPatterns';
-import { ASTParser } from '../../core/compactor/astParser';
+import { ASTParser, RustMacroInfo } from '../../core/compactor/astParser';
+import { rustDocSummary } from '../../core/compactor/rustDocs';
+import {
+  findInactiveRanges,
+  normalizeCfgConfig,
+  RUST_CFG_SCHEMA,
+  RustCfgConfig,
+} from './utils/rustCfg';
 
 /**
  * Lightweight type resolution that maps AST node kinds to readable identifiers
@@ -120,6 +131,11 @@ export const localFileSummaryTool = {
         default: 'structured',
         description: 'Output format preference',
       },
+      cfg: {
+        ...RUST_CFG_SCHEMA,
+        description:
+          'Rust build configuration (e.g., {"target_os": "linux", "features": ["tls"], "test": false}). Symbols whose #[cfg(...)] is false under it are left out. Rust files only.',
+      },
     },
     required: ['filePath'],
   },
@@ -129,7 +145,7 @@ export const localFileSummaryTool = {
  * Handler for file summary requests
  */
 export async function handleFileSummary(args: any): Promise<any> {
-  const { filePath, includeSymbols = true, maxSymbols = 20, format = 'structured' } = args;
+  const { filePath, includeSymbols = true, maxSymbols = 20, format = 'structured', cfg } = args;
 
   logger.info('🔍 handleFileSummary called with', {
     filePath,
@@ -245,11 +261,21 @@ export async function handleFileSummary(args: any): Promise<any> {
       classes: astAnalysis.allClasses.length,
     });
 
+    // Rust: keep only the symbols compiled under the requested cfg
+    const rustCfg = language === 'rust' ? normalizeCfgConfig(cfg) : undefined;
+    if (rustCfg) {
+      const fs = await import('fs');
+      const content = await fs.promises.readFile(resolvedFilePath, 'utf8');
+      astAnalysis = applyRustCfg(astAnalysis, content, rustCfg);
+    }
+
     // Extract file header information
     const fileHeader = await extractFileHeader(resolvedFilePath);
 
     // Calculate cyclomatic complexity
-    let complexityData = {
+    let complexityData: ReturnType<typeof calculateCyclomaticComplexity> & {
+      functions?: FunctionComplexity[];
+    } = {
       rating: 'low',
       description: 'Simple code',
       totalComplexity: 1,
@@ -257,9 +283,14 @@ export async function handleFileSummary(args: any): Promise<any> {
       breakdown: {},
     };
     try {
-      const fs = await import('fs');
-      const fileContent = await fs.promises.readFile(resolvedFilePath, 'utf8');
-      complexityData = calculateCyclomaticComplexity(fileContent);
+      if (language === 'rust' && astAnalysis.allFunctions.some(fn => fn.complexity)) {
+        // Per-function metrics measured on the Rust AST during parsing
+        complexityData = calculateRustComplexity(astAnalysis.allFunctions);
+      } else {
+        const fs = await import('fs');
+        const fileContent = await fs.promises.readFile(resolvedFilePath, 'utf8');
+        complexityData = calculateCyclomaticComplexity(fileContent);
+      }
     } catch (error) {
       // Fallback to simple symbol-based complexity if file reading fails
       complexityData.rating =
@@ -321,6 +352,7 @@ export async function handleFileSummary(args: any): Promise<any> {
         symbolCount: summary.symbolCount,
         complexity: summary.complexity,
         language: summary.language,
+        cfg: rustCfg,
       },
       usage: `Found ${summary.symbolCount} symbols with ${summary.complexity} complexity`,
     };
@@ -338,6 +370,70 @@ export async function handleFileSummary(args: any): Promise<any> {
   }
 }
 
+type ComprehensiveASTAnalysis = Awaited<ReturnType<typeof getComprehensiveASTAnalysis>>;
+
+/**
+ * Drop symbols declared inside Rust items that are compiled out under `cfg`
+ */
+function applyRustCfg(
+  analysis: ComprehensiveASTAnalysis,
+  content: string,
+  cfg: RustCfgConfig
+): ComprehensiveASTAnalysis {
+  const ranges = findInactiveRanges(content, cfg);
+  if (ranges.length === 0) return analysis;
+
+  const isActive = ({ line }: { line?: number }) =>
+    !line || !ranges.some(range => range.line <= line && line <= range.endLine);
+  const allFunctions = analysis.allFunctions.filter(isActive);
+  const allClasses = analysis.allClasses.filter(isActive);
+  const allInterfaces = analysis.allInterfaces.filter(isActive);
+  const remaining = new Set([...allFunctions, ...allClasses, ...allInterfaces].map(s => s.name));
+  const removed = [
+    ...analysis.allFunctions.filter(symbol => !isActive(symbol)),
+    ...analysis.allClasses.filter(symbol => !isActive(symbol)),
+    ...analysis.allInterfaces.filter(symbol => !isActive(symbol)),
+  ];
+  const removedNames = new Set(removed.map(symbol => symbol.name));
+
+  return {
+    ...analysis,
+    totalSymbols: Math.max(0, analysis.totalSymbols - removed.length),
+    allFunctions,
+    allClasses,
+    allInterfaces,
+    exportedSymbols: analysis.exportedSymbols.filter(
+      name => remaining.has(name) || !removedNames.has(name)
+    ),
+    topSymbols: analysis.topSymbols.filter(isActive),
+  };
+}
+
+/**
+ * First sentence of a Rust item's `///` docs, used as its purpose
+ */
+function docPurpose(docstring: string | undefined, language: string): string | undefined {
+  return language === 'rust' && docstring ? rustDocSummary(docstring) : undefined;
+}
+
+/**
+ * How the macro a Rust function or `macro_rules!` defines is invoked, used as its purpose
+ */
+function macroPurpose(macro: RustMacroInfo | undefined): string | undefined {
+  switch (macro?.kind) {
+    case 'declarative':
+      return `Macro ${macro.name}!`;
+    case 'function':
+      return `Function-like proc macro ${macro.name}!`;
+    case 'attribute':
+      return `Attribute macro #[${macro.name}]`;
+    case 'derive':
+      return `Derive macro #[derive(${macro.name})]`;
+    default:
+      return undefined;
+  }
+}
+
 /**
  * Get language from file path extension with ast-grep code
  */
@@ -374,7 +470,7 @@ export async function extractAllFunctions(filePath: string): Promise<any[]> {
   try {
     const { ASTParser } = await import('../../core/compactor/astParser');
 
-    const language = getLanguageFromPath(filePath) as any;
+    const language = getLanguageFromPath(filePath).lang as any;
     const parser = new ASTParser();
 
     // Parse the file to get all symbols including class methods
@@ -471,7 +567,7 @@ export async function getComprehensiveASTAnalysis(filePath: string): Promise<{
 
     let allFunctions: any[] = [];
     let allClasses: any[] = [];
-    const allInterfaces: any[] = [];
+    let allInterfaces: any[] = [];
     let exportedSymbols: string[] = [];
 
     // Existing JS/TS/Python processing
@@ -488,7 +584,11 @@ export async function getComprehensiveASTAnalysis(filePath: string): Promise<{
           parameters: extractParametersFromSignature(symbol.signature),
           returnType: resolveTypeFromAST(symbol.returnType || ''),
           returnedSymbols: extractReturnedSymbols(symbol.body),
-          purpose: 'Function',
+          complexity: symbol.complexity,
+          doc: docPurpose(symbol.docstring, language),
+          doctests: symbol.doctests?.length,
+          purpose:
+            macroPurpose(symbol.macro) ?? docPurpose(symbol.docstring, language) ?? 'Function',
         });
       } else if (symbol.type === 'method') {
         // Handle individual method symbols with full signatures
@@ -503,12 +603,16 @@ export async function getComprehensiveASTAnalysis(filePath: string): Promise<{
           parameters: symbol.parameters || extractParametersFromSignature(symbol.signature),
           returnType: resolveTypeFromAST(symbol.returnType || ''),
           returnedSymbols: extractReturnedSymbols(symbol.body),
-          purpose: 'Method',
+          complexity: symbol.complexity,
+          doc: docPurpose(symbol.docstring, language),
+          doctests: symbol.doctests?.length,
+          purpose: docPurpose(symbol.docstring, language) ?? 'Method',
         });
       } else if (symbol.type === 'class') {
         allClasses.push({
           name: symbol.name,
           line: symbol.startLine,
+          doc: docPurpose(symbol.docstring, language),
           isExported: symbol.isExported,
           signature: resolveTypeFromAST(symbol.signature),
           methods:
@@ -545,24 +649,39 @@ export async function getComprehensiveASTAnalysis(filePath: string): Promise<{
       });
     }
 
-    // Fallback to ast-grep if few/no symbols or non-JS/TS (force for samples/unsupported)
+    // Fallback to ast-grep if few/no symbols or non-JS/TS (force for samples/unsupported).
+    // Rust has a native parser, so ast-grep only fills in when it produced nothing.
     let initialSymbolCount = parsedFile.symbols.length || 0;
     const isNonJsTs = grepLang && !['ts', 'js', 'jsx', 'tsx'].includes(grepLang);
-    if ((initialSymbolCount < 2 || isNonJsTs) && grepLang) {
+    const hasNativeParser = grepLang === 'rs' && initialSymbolCount > 0;
+    if ((initialSymbolCount < 2 || isNonJsTs) && grepLang && !hasNativeParser) {
       logger.info('🔄 Falling back to ast-grep for symbol extraction', {
         filePath,
         grepLang,
         initialSymbolCount,
       });
       const astGrepSymbols = extractSymbolsWithAstGrep(filePath, grepLang);
-      allFunctions = [...allFunctions, ...astGrepSymbols.functions];
-      allClasses = [...allClasses, ...astGrepSymbols.classes];
-      exportedSymbols = [...exportedSymbols, ...(astGrepSymbols.exports || [])];
+      const isNew = (list: any[]) => (sym: any) =>
+        !list.some(existing => existing.name === sym.name && existing.line === sym.line);
+      allFunctions = [...allFunctions, ...astGrepSymbols.functions.filter(isNew(allFunctions))];
+      allClasses = [...allClasses, ...astGrepSymbols.classes.filter(isNew(allClasses))];
+      allInterfaces = [
+        ...allInterfaces,
+        ...astGrepSymbols.interfaces.filter(isNew(allInterfaces)),
+      ];
+      exportedSymbols = [
+        ...exportedSymbols,
+        ...(astGrepSymbols.exports || []).filter(name => !exportedSymbols.includes(name)),
+      ];
       logger.info('✅ Ast-grep extraction complete', {
         functions: astGrepSymbols.functions.length,
         classes: astGrepSymbols.classes.length,
+        interfaces: astGrepSymbols.interfaces.length,
       });
-      initialSymbolCount += astGrepSymbols.functions.length + astGrepSymbols.classes.length;
+      initialSymbolCount +=
+        astGrepSymbols.functions.length +
+        astGrepSymbols.classes.length +
+        astGrepSymbols.interfaces.length;
     }
 
     // Create top symbols list (prioritize important symbols)
@@ -574,7 +693,7 @@ export async function getComprehensiveASTAnalysis(filePath: string): Promise<{
         name: cls.name,
         type: 'class',
         line: cls.line,
-        purpose: 'Core class',
+        purpose: cls.doc ?? 'Core class',
         signature:
           resolveTypeFromAST(cls.signature).substring(0, 100) +
           (cls.signature.length > 100 ? '...' : ''),
@@ -647,273 +766,285 @@ function extractSymbolsWithAstGrep(
 ): {
   functions: any[];
   classes: any[];
+  interfaces: any[];
   exports: string[];
 } {
   const functions: any[] = [];
   const classes: any[] = [];
+  const interfaces: any[] = [];
   const exports: string[] = [];
 
-  // Get patterns for this lang
-  const funcPatterns = getPatterns(grepLang, 'functions');
-  const classPatterns = getPatterns(grepLang, 'classes');
-  const methodPatterns = getPatterns(grepLang, 'methods') || [];
+  // Patterns that capture $VIS report visibility; older patterns assume everything is exported
+  const recordExport = (pat: SymbolPattern, match: any, name: string): boolean => {
+    const tracksVisibility = pat.captures?.includes('$VIS') ?? false;
+    const isExported = tracksVisibility && getCapture(match, 'VIS') === 'pub';
+    if (isExported || !tracksVisibility) exports.push(name);
+    return isExported;
+  };
 
   // Run for functions
-  for (const pat of funcPatterns) {
-    try {
-      // Use direct CLI call to ast-grep for more reliable results
-      const { execSync } = require('child_process');
-      const command = `npx ast-grep --pattern "${pat.pattern.replace(/"/g, '\\"')}" --lang ${pat.lang} --json=stream "${filePath}"`;
-
-      let matches: any[] = [];
-      try {
-        const stdout = execSync(command, {
-          cwd: process.cwd(),
-          encoding: 'utf8',
-          maxBuffer: 10 * 1024 * 1024, // 10MB buffer
-        });
+  for (const pat of getPatterns(grepLang, 'functions')) {
+    for (const match of runSymbolPattern(pat, filePath, 'functions')) {
+      const fullText = match.lines || match.text;
+      const name =
+        getCapture(match, 'NAME') || nameFromMatchedText(grepLang, fullText, 'function');
+      const params = getCapture(match, 'PARAMS');
+
+      functions.push({
+        name,
+        type: 'function',
+        signature: signatureFromCaptures(pat, match) || fullText.trim(),
+        line: match.range.start.line + 1, // Convert to 1-based line numbers
+        isExported: recordExport(pat, match, name),
+        parameters: params ? extractParametersFromSignature(params) : [],
+        returnType: getCapture(match, 'RET') || '',
+        body: '',
+        purpose: 'Function',
+      });
+    }
+  }
+
+  // Similar for classes and methods (adapt to allClasses/allFunctions)
+  for (const pat of getPatterns(grepLang, 'classes')) {
+    for (const match of runSymbolPattern(pat, filePath, 'classes')) {
+      const fullText = match.lines || match.text;
+      const name = getCapture(match, 'NAME') || nameFromMatchedText(grepLang, fullText, 'class');
+
+      classes.push({
+        name,
+        type: 'class',
+        signature: signatureFromCaptures(pat, match) || fullText.trim(),
+        line: match.range.start.line + 1, // Convert to 1-based line numbers
+        isExported: recordExport(pat, match, name),
+        methods: [], // Methods are handled separately
+        purpose: pat.symbolKind ? capitalize(pat.symbolKind) : 'Class',
+      });
+    }
+  }
 
-        // Parse the JSON stream output
-        matches = stdout
-          .trim()
-          .split('\n')
-          .filter((line: string) => line.trim())
-          .map((line: string) => {
-            try {
-              return JSON.parse(line);
-            } catch (e) {
-              logger.warn('Failed to parse ast-grep JSON line for functions', { line, error: e });
-              return null;
-            }
-          })
-          .filter((match: any) => match !== null);
-      } catch (error) {
-        // Command failed or no matches found
-        logger.warn('ast-grep command failed for functions', {
-          command,
-          error: error instanceof Error ? error.message : String(error),
+  for (const pat of getPatterns(grepLang, 'methods')) {
+    for (const match of runSymbolPattern(pat, filePath, 'methods')) {
+      const matchText = match.lines || match.text;
+      const line = match.range.start.line + 1;
+
+      // Capture-based patterns (e.g. Rust impl/trait methods) carry name and owner directly
+      const capturedName = getCapture(match, 'NAME');
+      if (capturedName) {
+        const params = getCapture(match, 'PARAMS');
+        const owner = getCapture(match, 'TYPE');
+        functions.push({
+          name: capturedName,
+          type: 'method',
+          signature: signatureFromCaptures(pat, match) || matchText.trim(),
+          line,
+          isExported: recordExport(pat, match, capturedName),
+          isMethod: true,
+          className: owner ? owner.replace(/<[\s\S]*>$/, '').trim() : undefined,
+          traitName: getCapture(match, 'TRAIT'),
+          parameters: params ? extractParametersFromSignature(params) : [],
+          returnType: getCapture(match, 'RET') || '',
+          body: '',
+          purpose: 'Method',
         });
-        matches = [];
+        continue;
       }
 
-      const result = { matches };
-
-      if (result && (result as any).matches) {
-        (result as any).matches.forEach((match: any) => {
-          // Parse function name from the matched text
-          let name = 'unknown';
-          const fullText = match.lines || match.text;
-          if (grepLang === 'py' && fullText.includes('def ')) {
-            // Extract function name from "def function_name("
-            const funcMatch = fullText.match(/def\s+(\w+)\s*\(/);
-            if (funcMatch) name = funcMatch[1];
-          } else if (grepLang === 'go' && fullText.includes('func ')) {
-            // Extract function name from "func functionName("
-            const funcMatch = fullText.match(/func\s+(\w+)\s*\(/);
-            if (funcMatch) name = funcMatch[1];
-          } else if (grepLang === 'rust' && fullText.includes('fn ')) {
-            // Extract function name from "fn function_name("
-            const funcMatch = fullText.match(/fn\s+(\w+)\s*\(/);
-            if (funcMatch) name = funcMatch[1];
+      if (grepLang === 'java' && matchText.includes('public ')) {
+        // Check if it's a class declaration first
+        const classMatch = matchText.match(/public\s+class\s+(\w+)/);
+        if (classMatch) {
+          const name = classMatch[1];
+          // Skip if already in classes array (avoid duplicates)
+          if (!classes.find(c => c.name === name && c.line === line)) {
+            classes.push({
+              name,
+              type: 'class',
+              signature: matchText.trim(),
+              line,
+              isExported: true,
+              methods: [],
+              purpose: 'Class',
+            });
+            exports.push(name);
           }
+          continue; // Skip adding to functions
+        }
 
+        // Extract method name from "public ReturnType methodName(", then constructors "public ClassName("
+        const methodMatch =
+          matchText.match(/public\s+(?:static\s+)?\w+\s+(\w+)\s*\(/) ||
+          matchText.match(/public\s+(\w+)\s*\(/);
+        if (methodMatch) {
           functions.push({
-            name,
-            type: 'function',
-            signature: fullText.trim(),
-            line: match.range.start.line + 1, // Convert to 1-based line numbers
-            isExported: false, // Detect via separate pattern if needed
+            name: methodMatch[1],
+            type: 'method',
+            signature: matchText.trim(),
+            line,
+            isExported: false,
+            isMethod: true,
             parameters: [],
             returnType: '',
             body: '',
-            purpose: 'Function',
+            purpose: 'Method',
           });
-          exports.push(name); // Assume exported
-        });
+          exports.push(methodMatch[1]);
+        }
       }
-    } catch (error) {
-      logger.warn('Failed to extract functions with ast-grep', {
-        pattern: pat.pattern,
-        error: error instanceof Error ? error.message : String(error),
-      });
     }
   }
 
-  // Similar for classes and methods (adapt to allClasses/allFunctions)
-  for (const pat of classPatterns) {
-    try {
-      // Use direct CLI call to ast-grep for more reliable results
-      const { execSync } = require('child_process');
-      const command = `npx ast-grep --pattern "${pat.pattern.replace(/"/g, '\\"')}" --lang ${pat.lang} --json=stream "${filePath}"`;
-
-      let matches: any[] = [];
-      try {
-        const stdout = execSync(command, {
-          cwd: process.cwd(),
-          encoding: 'utf8',
-          maxBuffer: 10 * 1024 * 1024, // 10MB buffer
-        });
-
-        // Parse the JSON stream output
-        matches = stdout
-          .trim()
-          .split('\n')
-          .filter((line: string) => line.trim())
-          .map((line: string) => {
-            try {
-              return JSON.parse(line);
-            } catch (e) {
-              return null;
-            }
-          })
-          .filter((match: any) => match !== null);
-      } catch (error) {
-        // Command failed or no matches found
-        matches = [];
-      }
+  // Traits, interfaces and type aliases
+  for (const pat of getPatterns(grepLang, 'types')) {
+    for (const match of runSymbolPattern(pat, filePath, 'types')) {
+      const name = getCapture(match, 'NAME');
+      if (!name) continue;
+      recordExport(pat, match, name);
+      interfaces.push({
+        name,
+        line: match.range.start.line + 1,
+        signature: signatureFromCaptures(pat, match) || (match.lines || match.text).trim(),
+        purpose: pat.symbolKind === 'trait' ? 'Trait definition' : 'Type definition',
+      });
+    }
+  }
 
-      const result = { matches };
-
-      if (result && (result as any).matches) {
-        (result as any).matches.forEach((match: any) => {
-          // Parse class name from the matched text
-          let name = 'unknown';
-          const fullText = match.lines || match.text;
-          if (grepLang === 'py' && fullText.includes('class ')) {
-            // Extract class name from "class ClassName("
-            const classMatch = fullText.match(/class\s+(\w+)/);
-            if (classMatch) name = classMatch[1];
-          } else if (grepLang === 'java' && fullText.includes('class ')) {
-            // Extract class name from "public class ClassName"
-            const classMatch = fullText.match(/class\s+(\w+)/);
-            if (classMatch) name = classMatch[1];
-          }
+  // Constants only contribute to the exported surface
+  for (const pat of getPatterns(grepLang, 'constants')) {
+    for (const match of runSymbolPattern(pat, filePath, 'constants')) {
+      const name = getCapture(match, 'NAME');
+      if (name) recordExport(pat, match, name);
+    }
+  }
 
-          classes.push({
-            name,
-            type: 'class',
-            signature: fullText.trim(),
-            line: match.range.start.line + 1, // Convert to 1-based line numbers
-            isExported: false, // Detect via separate pattern if needed
-            methods: [], // Methods are handled separately
-            purpose: 'Class',
-          });
-          exports.push(name); // Assume exported
-        });
-      }
-    } catch (error) {
-      logger.warn('Failed to extract classes with ast-grep', {
-        pattern: pat.pattern,
-        error: error instanceof Error ? error.message : String(error),
+  for (const pat of getPatterns(grepLang, 'macros')) {
+    for (const match of runSymbolPattern(pat, filePath, 'macros')) {
+      const name = getCapture(match, 'NAME');
+      if (!name) continue;
+      functions.push({
+        name,
+        type: 'function',
+        signature: signatureFromCaptures(pat, match) || `${name}!`,
+        line: match.range.start.line + 1,
+        isExported: false,
+        parameters: [],
+        returnType: '',
+        body: '',
+        purpose: 'Macro',
       });
     }
   }
 
-  for (const pat of methodPatterns) {
-    try {
-      // Use direct CLI call to ast-grep for more reliable results
-      const { execSync } = require('child_process');
-      const command = `npx ast-grep --pattern "${pat.pattern.replace(/"/g, '\\"')}" --lang ${pat.lang} --json=stream "${filePath}"`;
-
-      let matches: any[] = [];
-      try {
-        const stdout = execSync(command, {
-          cwd: process.cwd(),
-          encoding: 'utf8',
-          maxBuffer: 10 * 1024 * 1024, // 10MB buffer
-        });
+  return { functions, classes, interfaces, exports };
+}
 
-        // Parse the JSON stream output
-        matches = stdout
-          .trim()
-          .split('\n')
-          .filter((line: string) => line.trim())
-          .map((line: string) => {
-            try {
-              return JSON.parse(line);
-            } catch (e) {
-              return null;
-            }
-          })
-          .filter((match: any) => match !== null);
-      } catch (error) {
-        // Command failed or no matches found
-        matches = [];
-      }
+/**
+ * Run one symbol pattern against a single file via the ast-grep CLI and return the parsed
+ * JSON-stream matches. Patterns with a `rule` go through `scan --inline-rules`.
+ */
+function runSymbolPattern(pat: SymbolPattern, filePath: string, kind: string): any[] {
+  // Use direct CLI call to ast-grep for more reliable results
+  const { execSync } = require('child_process');
+  const command = pat.rule
+    ? `npx ast-grep scan --inline-rules ${quoteShellArg(
+        JSON.stringify({ id: `symbol-${kind}`, language: pat.lang, rule: pat.rule })
+      )} --json=stream "${filePath}"`
+    : `npx ast-grep --pattern "${(pat.pattern ?? '').replace(/"/g, '\\"')}" --lang ${pat.lang} --json=stream "${filePath}"`;
 
-      const result = { matches };
-
-      if (result && (result as any).matches) {
-        (result as any).matches.forEach((match: any) => {
-          // Parse method name from the matched text
-          let name = 'unknown';
-          let isMethod = false;
-          let isClass = false;
-
-          const matchText = match.lines || match.text;
-          if (grepLang === 'java' && matchText.includes('public ')) {
-            // Check if it's a class declaration first
-            const classMatch = matchText.match(/public\s+class\s+(\w+)/);
-            if (classMatch) {
-              name = classMatch[1];
-              isClass = true;
-              // Skip if already in classes array (avoid duplicates)
-              if (!classes.find(c => c.name === name && c.line === match.range.start.line + 1)) {
-                classes.push({
-                  name,
-                  type: 'class',
-                  signature: matchText.trim(),
-                  line: match.range.start.line + 1,
-                  isExported: true,
-                  methods: [],
-                  purpose: 'Class',
-                });
-                exports.push(name);
-              }
-              return; // Skip adding to functions
-            }
-
-            // Extract method name from "public ReturnType methodName("
-            const methodMatch = matchText.match(/public\s+(?:static\s+)?(\w+)\s+(\w+)\s*\(/);
-            if (methodMatch) {
-              name = methodMatch[2]; // The second capture group is the method name
-              isMethod = true;
-            } else {
-              // Try to match constructors "public ClassName("
-              const constructorMatch = matchText.match(/public\s+(\w+)\s*\(/);
-              if (constructorMatch) {
-                name = constructorMatch[1];
-                isMethod = true;
-              }
-            }
-          }
+  try {
+    const stdout = execSync(command, {
+      cwd: process.cwd(),
+      encoding: 'utf8',
+      maxBuffer: 10 * 1024 * 1024, // 10MB buffer
+    });
 
-          if (isMethod && !isClass) {
-            functions.push({
-              name,
-              type: 'method',
-              signature: matchText.trim(),
-              line: match.range.start.line + 1,
-              isExported: false,
-              isMethod: true,
-              parameters: [],
-              returnType: '',
-              body: '',
-              purpose: 'Method',
-            });
-            exports.push(name);
-          }
-        });
-      }
-    } catch (error) {
-      logger.warn('Failed to extract methods with ast-grep', {
-        pattern: pat.pattern,
-        error: error instanceof Error ? error.message : String(error),
-      });
-    }
+    // Parse the JSON stream output
+    return stdout
+      .trim()
+      .split('\n')
+      .filter((line: string) => line.trim())
+      .map((line: string) => {
+        try {
+          return JSON.parse(line);
+        } catch (e) {
+          logger.warn(`Failed to parse ast-grep JSON line for ${kind}`, { line, error: e });
+          return null;
+        }
+      })
+      .filter((match: any) => match !== null);
+  } catch (error) {
+    // Command failed or no matches found
+    logger.warn(`ast-grep command failed for ${kind}`, {
+      command,
+      error: error instanceof Error ? error.message : String(error),
+    });
+    return [];
+  }
+}
+
+function quoteShellArg(value: string): string {
+  if (process.platform === 'win32') {
+    return '"' + value.replace(/"/g, '\\"') + '"';
+  }
+  return "'" + value.replace(/'/g, "'\\''") + "'";
+}
+
+/**
+ * Read a single metavariable capture (without the `$`) from an ast-grep JSON match
+ */
+function getCapture(match: any, name: string): string | undefined {
+  const text = match?.metaVariables?.single?.[name]?.text;
+  return typeof text === 'string' && text.trim() ? text.trim() : undefined;
+}
+
+/**
+ * Rebuild a compact declaration from captures, e.g. `pub fn connect(url: &str) -> Client`
+ */
+function signatureFromCaptures(pat: SymbolPattern, match: any): string | undefined {
+  const name = getCapture(match, 'NAME');
+  if (!name || pat.lang !== 'rs') return undefined;
+
+  const vis = getCapture(match, 'VIS');
+  const prefix = vis ? `${vis} ` : '';
+  const params = getCapture(match, 'PARAMS');
+  const ret = getCapture(match, 'RET');
+  const type = getCapture(match, 'TYPE');
+
+  switch (pat.symbolKind) {
+    case 'fn':
+    case 'method':
+    case 'trait method':
+      return `${prefix}fn ${name}${params || '()'}${ret ? ` -> ${ret}` : ''}`;
+    case 'type':
+      return `${prefix}type ${name} = ${type}`;
+    case 'const':
+      return `${prefix}const ${name}: ${type}`;
+    case 'macro':
+      return `macro_rules! ${name}`;
+    default:
+      return pat.symbolKind ? `${prefix}${pat.symbolKind} ${name}` : undefined;
   }
+}
+
+/**
+ * Regex fallback for patterns without a $NAME capture
+ */
+function nameFromMatchedText(grepLang: string, text: string, kind: 'function' | 'class'): string {
+  const regexes: Record<string, RegExp> =
+    kind === 'function'
+      ? {
+          py: /def\s+(\w+)\s*\(/, // "def function_name("
+          go: /func\s+(\w+)\s*\(/, // "func functionName("
+        }
+      : {
+          py: /class\s+(\w+)/, // "class ClassName("
+          java: /class\s+(\w+)/, // "public class ClassName"
+        };
+  const match = regexes[grepLang] ? text.match(regexes[grepLang]) : null;
+  return match ? match[1] : 'unknown';
+}
 
-  return { functions, classes, exports };
+function capitalize(value: string): string {
+  return value.charAt(0).toUpperCase() + value.slice(1);
 }
 
 /**
@@ -56,7 +56,12 @@ export function formatFileSummaryAsXML(summary: any, quickAnalysis: string): str
       <is_async>${func.isAsync}</is_async>
       <is_exported>${func.isExported}</is_exported>
       <parameter_count>${func.parameters.length}</parameter_count>
-      <signature>${escapeXml(func.signature)}</signature>
+      <signature>${escapeXml(func.signature)}</signature>${
+        func.complexity
+          ? `
+      <complexity cyclomatic="${func.complexity.cyclomatic}" cognitive="${func.complexity.cognitive}" max_nesting="${func.complexity.maxNesting}"/>`
+          : ''
+      }
     </function>`
       )
       .join('')}
@@ -119,7 +124,11 @@ export function formatFileSummaryAsStructured(summary: any, quickAnalysis: strin
   if (summary.allFunctions && summary.allFunctions.length > 0) {
     output += `## Functions (${summary.allFunctions.length})\n\n`;
     summary.allFunctions.slice(0, 10).forEach((func: any) => {
-      output += `- **${func.name}** (line ${func.line})${func.isAsync ? ' [async]' : ''}${func.isExported ? ' [exported]' : ''}\n`;
+      const doctests = func.doctests ? ` [doctests: ${func.doctests}]` : '';
+      output += `- **${func.name}** (line ${func.line})${func.isAsync ? ' [async]' : ''}${func.isExported ? ' [exported]' : ''}${doctests}\n`;
+      if (func.doc) {
+        output += `  ${func.doc}\n`;
+      }
       if (func.signature) {
         output += `  \`${func.signature.substring(0, 80)}${func.signature.length > 80 ? '...' : ''}\`\n`;
       }
@@ -131,6 +140,9 @@ export function formatFileSummaryAsStructured(summary: any, quickAnalysis: strin
     output += `## Classes (${summary.allClasses.length})\n\n`;
     summary.allClasses.forEach((cls: any) => {
       output += `- **${cls.name}** (line ${cls.line})${cls.isExported ? ' [exported]' : ''}\n`;
+      if (cls.doc) {
+        output += `  ${cls.doc}\n`;
+      }
       if (cls.methods && cls.methods.length > 0) {
         output += `  Methods: ${cls.methods.join(', ')}\n`;
       }
@@ -143,6 +155,18 @@ export function formatFileSummaryAsStructured(summary: any, quickAnalysis: strin
     output += `- **Total Complexity**: ${summary.complexityData.totalComplexity}\n`;
     output += `- **Decision Points**: ${summary.complexityData.decisionPoints}\n`;
     output += `- **Rating**: ${summary.complexityData.rating} - ${summary.complexityData.description}\n\n`;
+
+    const complexFunctions = (summary.complexityData.functions || []).filter(
+      (fn: any) => fn.cyclomatic > 1 || fn.cognitive > 0
+    );
+    if (complexFunctions.length > 0) {
+      output += `### Most Complex Functions\n\n`;
+      complexFunctions.slice(0, 10).forEach((fn: any) => {
+        const name = fn.className ? `${fn.className}::${fn.name}` : fn.name;
+        output += `- **${name}** (line ${fn.line}): cyclomatic ${fn.cyclomatic}, cognitive ${fn.cognitive}, nesting ${fn.maxNesting}\n`;
+      });
+      output += '\n';
+    }
   }
 
   output += `## Quick Analysis\n\n${quickAnalysis}`;
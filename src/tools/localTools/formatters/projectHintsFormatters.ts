@@ -84,7 +84,13 @@ ${topFolders}
 🔧 TOP FUNCTIONS: ${topFunctions}
 
 🚀 ENTRY POINTS: ${hints.entryPoints?.slice(0, 3)?.join(', ') || 'None detected'}
-⚙️ CONFIG: ${hints.configFiles?.slice(0, 3)?.join(', ') || 'None detected'}`;
+⚙️ CONFIG: ${hints.configFiles?.slice(0, 3)?.join(', ') || 'None detected'}${
+    hints.cargoWorkspace?.members?.length
+      ? `\n📦 CRATES (${hints.cargoWorkspace.members.length}): ${crateNameList(hints.cargoWorkspace)}`
+      : ''
+  }${hints.features?.crates?.length ? `\n🚩 FEATURES: ${featureNameList(hints.features)}` : ''}${
+    hints.unsafeAudit ? `\n☢️ UNSAFE: ${unsafeAuditLine(hints.unsafeAudit)}` : ''
+  }${hints.dependencyGraph ? `\n🔗 DEPS: ${dependencyGraphLine(hints.dependencyGraph)}` : ''}`;
 }
 
 /**
@@ -148,7 +154,7 @@ ${
     : '- No classes detected'
 }
 
-## 🚀 Project Entry Points
+${formatCargoWorkspaceMarkdown(hints.cargoWorkspace)}${formatCargoFeaturesMarkdown(hints.features)}${formatUnsafeAuditMarkdown(hints.unsafeAudit)}${formatDependencyGraphMarkdown(hints.dependencyGraph)}## 🚀 Project Entry Points
 ${
   hints.entryPoints?.length > 0
     ? hints.entryPoints.map((ep: string) => `- ${ep}`).join('\n')
@@ -182,7 +188,7 @@ export function formatEnhancedProjectHints(summary: any): string {
 📊 **Public Surfaces:**
 • Exports: ${surfaces.exports
     .slice(0, 5)
-    .map((exp: any) => `${exp.name} (${exp.kind})`)
+    .map((exp: any) => `${exp.qualifiedPath || exp.name} (${exp.kind})`)
     .join(', ')}
 • Routes: ${
     surfaces.routes.length > 0
@@ -200,7 +206,7 @@ export function formatEnhancedProjectHints(summary: any): string {
           .join(', ')
       : 'None'
   }
-• Env Keys: ${surfaces.envKeys.slice(0, 5).join(', ')}
+• Env Keys: ${envKeyList(surfaces)}
 
 🔍 **Top Hints (Ranked):**
 ${hints
@@ -316,10 +322,20 @@ function formatCompactEnhancedHints(summary: any): string {
       .map((r: any) => `${r.method} ${r.path}`)
       .join(', ') || 'None'
   }
-🔑 **Env Keys:** ${surfaces.envKeys.slice(0, 5).join(', ')}
+🔑 **Env Keys:** ${envKeyList(surfaces)}
 
 🎯 **Top Hints:** ${topHints}
-🚀 **Entry Points:** ${proj.entryPoints.slice(0, 3).join(', ')}`;
+🚀 **Entry Points:** ${proj.entryPoints.slice(0, 3).join(', ')}${
+    summary.cargoWorkspace?.members?.length
+      ? `\n📦 **Crates (${summary.cargoWorkspace.members.length}):** ${crateNameList(summary.cargoWorkspace)}`
+      : ''
+  }${summary.features?.crates?.length ? `\n🚩 **Features:** ${featureNameList(summary.features)}` : ''}${
+    summary.unsafeAudit ? `\n☢️ **Unsafe:** ${unsafeAuditLine(summary.unsafeAudit)}` : ''
+  }${
+    summary.dependencyGraph
+      ? `\n🔗 **Dependencies:** ${dependencyGraphLine(summary.dependencyGraph)}`
+      : ''
+  }`;
 }
 
 /**
@@ -344,7 +360,7 @@ ${surfaces.exports
   .slice(0, 10)
   .map(
     (exp: any) =>
-      `- **${exp.name}** (${exp.kind}) - ${exp.role} \`${path.basename(exp.file)}:${exp.line}\``
+      `- **${exp.qualifiedPath || exp.name}** (${exp.kind}) - ${exp.role} \`${path.basename(exp.file)}:${exp.line}\``
   )
   .join('\n')}
 
@@ -370,13 +386,9 @@ ${surfaces.mcpTools
 }
 
 ### Environment Configuration
-${
-  surfaces.envKeys.length > 0
-    ? surfaces.envKeys.map((key: string) => `- \`${key}\``).join('\n')
-    : '- No environment variables detected'
-}
+${formatEnvKeysMarkdown(surfaces)}
 
-## 🎯 Actionable Intelligence
+${formatCargoWorkspaceMarkdown(summary.cargoWorkspace)}${formatCargoFeaturesMarkdown(summary.features)}${formatUnsafeAuditMarkdown(summary.unsafeAudit)}${formatDependencyGraphMarkdown(summary.dependencyGraph)}## 🎯 Actionable Intelligence
 
 ### Top Ranked Components
 ${hints
@@ -466,7 +478,7 @@ export function formatEnhancedMarkdown(summary: any): string {
     .slice(0, 10)
     .map(
       (exp: any) =>
-        `- \`${exp.name}\` (${exp.kind}) • ${exp.role} • \`${path.basename(exp.file)}:${exp.line}\``
+        `- \`${exp.qualifiedPath || exp.name}\` (${exp.kind}) • ${exp.role} • \`${path.basename(exp.file)}:${exp.line}\``
     )
     .join('\n')}${
     surfaces.mcpTools.length > 0
@@ -481,11 +493,7 @@ export function formatEnhancedMarkdown(summary: any): string {
           `- \`${route.method.toUpperCase()} ${route.path}\` • \`${path.basename(route.file)}:${route.line}\``
       )
       .join('\n') || '- No HTTP routes detected'
-  }\n\n### Environment Variables\n${
-    surfaces.envKeys.length > 0
-      ? surfaces.envKeys.map((key: string) => `- \`${key}\``).join('\n')
-      : '- No environment variables detected'
-  }\n\n## 🎯 Actionable Hints (Ranked by Relevance)\n\n${hints
+  }\n\n### Environment Variables\n${formatEnvKeysMarkdown(surfaces)}\n\n${formatCargoWorkspaceMarkdown(summary.cargoWorkspace)}${formatCargoFeaturesMarkdown(summary.features)}${formatUnsafeAuditMarkdown(summary.unsafeAudit)}${formatDependencyGraphMarkdown(summary.dependencyGraph)}## 🎯 Actionable Hints (Ranked by Relevance)\n\n${hints
     .map((hint: any, i: number) => {
       const symbol = hint.symbol ? `${hint.symbol}` : path.basename(hint.file);
       const location = hint.line ? `:${hint.line}` : '';
@@ -504,3 +512,256 @@ export function formatEnhancedMarkdown(summary: any): string {
       : ''
   }\n\n## 🚀 Next Steps\n\n**Mode:** ${next.mode.replace('_', ' ')} • **Focus:** ${next.focus}\n\n### Priority Files\n${next.openFiles.map((file: string) => `- \`${file}\``).join('\n')}\n\n### Validation Commands\n\n${next.checks.map((check: string) => `\`\`\`bash\n${check}\n\`\`\``).join('\n\n')}\n\n---\n*Generated: ${new Date().toISOString()}*`;
 }
+
+/**
+ * Comma-separated crate names for one-line formats
+ */
+function crateNameList(cargoWorkspace: any, limit: number = 12): string {
+  const names = cargoWorkspace.members.slice(0, limit).map((member: any) => member.name);
+  return `${names.join(', ')}${cargoWorkspace.members.length > limit ? ', ...' : ''}`;
+}
+
+/**
+ * Summarize crate targets, e.g. "lib, 2 bins, 3 tests"
+ */
+function describeCrateTargets(targets: any[]): string {
+  const counts: Record<string, number> = {};
+  for (const target of targets) {
+    counts[target.kind] = (counts[target.kind] || 0) + 1;
+  }
+  return (
+    Object.entries(counts)
+      .map(([kind, count]) => (count === 1 ? kind : `${count} ${kind}s`))
+      .join(', ') || 'no targets'
+  );
+}
+
+/**
+ * Markdown section listing Cargo workspace members (empty when the project has no Cargo.toml)
+ */
+function formatCargoWorkspaceMarkdown(cargoWorkspace: any, limit: number = 50): string {
+  if (!cargoWorkspace?.members?.length) {
+    return '';
+  }
+
+  const members = cargoWorkspace.members;
+  const title = cargoWorkspace.isWorkspace
+    ? `Cargo Workspace (${members.length} crates)`
+    : 'Cargo Package';
+  const rows = members.slice(0, limit).map((member: any) => {
+    const deps = member.pathDependencies.length
+      ? ` • path deps: ${member.pathDependencies.join(', ')}`
+      : '';
+    return `- **${member.name}** \`${member.dir}\` — ${describeCrateTargets(member.targets)}${deps}`;
+  });
+  if (members.length > limit) {
+    rows.push(`- ...and ${members.length - limit} more`);
+  }
+
+  return `## 📦 ${title}
+${rows.join('\n')}
+
+Use \`crate: "<name>"\` with local_context to scope queries to one member.
+
+`;
+}
+
+/**
+ * Comma-separated feature names (crate-qualified in workspaces) for one-line formats
+ */
+function featureNameList(features: any, limit: number = 12): string {
+  const qualify = features.crates.length > 1;
+  const names = features.crates.flatMap((entry: any) =>
+    entry.features
+      .filter((feature: any) => !feature.implicit)
+      .map((feature: any) => (qualify ? `${entry.crate}/${feature.name}` : feature.name))
+  );
+  return `${names.slice(0, limit).join(', ')}${names.length > limit ? ', ...' : ''}`;
+}
+
+/**
+ * Markdown section listing Cargo features, what they enable and the code they gate
+ */
+function formatCargoFeaturesMarkdown(features: any, limit: number = 40): string {
+  if (!features?.crates?.length) {
+    return '';
+  }
+
+  const sections = features.crates.map((entry: any) => {
+    const defaults = entry.defaults.length ? ` (default: ${entry.defaults.join(', ')})` : '';
+    const rows = entry.features.slice(0, limit).map((feature: any) => {
+      const enables = [
+        ...feature.enables,
+        ...feature.dependencies.map((dep: string) => `dep:${dep}`),
+        ...feature.external,
+      ];
+      const files = Array.from(
+        new Set(feature.gated.map((site: any) => site.gatedFile || site.file))
+      );
+      const implicit = feature.implicit ? ' _(optional dependency)_' : '';
+      const turnsOn = enables.length ? ` → ${enables.join(', ')}` : '';
+      const where = `${files.slice(0, 3).join(', ')}${files.length > 3 ? ', ...' : ''}`;
+      const count = feature.gated.length;
+      const gated = count ? ` • ${count} gated site${count === 1 ? '' : 's'} in ${where}` : '';
+      return `- **${feature.name}**${implicit}${turnsOn}${gated}`;
+    });
+    if (entry.features.length > limit) {
+      rows.push(`- ...and ${entry.features.length - limit} more`);
+    }
+    return `### ${entry.crate}${defaults}\n${rows.join('\n')}`;
+  });
+
+  return `## 🚩 Cargo Features
+${sections.join('\n\n')}
+
+`;
+}
+
+/**
+ * First env keys and the total, for compact formats
+ */
+function envKeyList(surfaces: any): string {
+  const keys = surfaces.envKeys.slice(0, 5).map((env: any) => env.key);
+  const total = surfaces.envKeyCount ?? surfaces.envKeys.length;
+  return total > keys.length ? `${keys.join(', ')} (${total} total)` : keys.join(', ');
+}
+
+/**
+ * Markdown list of the summarized env keys, noting the ones left out
+ */
+function formatEnvKeysMarkdown(surfaces: any): string {
+  if (surfaces.envKeys.length === 0) return '- No environment variables detected';
+  const hidden = (surfaces.envKeyCount ?? surfaces.envKeys.length) - surfaces.envKeys.length;
+  const more = hidden > 0 ? `\n- ...and ${hidden} more` : '';
+  return surfaces.envKeys.map(formatEnvKeyMarkdown).join('\n') + more;
+}
+
+/**
+ * Markdown list entry for an env key: how it is used and where
+ */
+function formatEnvKeyMarkdown(env: any): string {
+  return `- \`${env.key}\` (${env.usage}) • \`${env.file}:${env.line}\``;
+}
+
+/**
+ * One-line unsafe audit totals for compact formats
+ */
+function unsafeAuditLine(audit: any): string {
+  const crates = audit.crates.length > 1 ? ` across ${audit.crates.length} crates` : '';
+  return `${audit.total} sites${crates}, ${audit.unjustified} without a SAFETY comment`;
+}
+
+/**
+ * Markdown section with unsafe/FFI counts per crate and module, and sites lacking SAFETY comments
+ */
+function formatUnsafeAuditMarkdown(audit: any, limit: number = 25): string {
+  if (!audit?.total) {
+    return '';
+  }
+
+  const crates = audit.crates.map((crate: any) => {
+    const kinds = Object.entries(crate.byKind)
+      .map(([kind, count]) => `${kind} ${count}`)
+      .join(', ');
+    const modules = crate.modules.slice(0, 8).map((module: any) => {
+      const counts = `${module.total} sites, ${module.unjustified} unjustified`;
+      return `- \`${module.module}\` (${module.file}): ${counts}`;
+    });
+    const counts = `${crate.total} sites, ${crate.unjustified} unjustified`;
+    return `### ${crate.crate} — ${counts} (${kinds})\n${modules.join('\n')}`;
+  });
+
+  const missing = audit.sites.filter((site: any) => !site.justified);
+  const rows = missing.slice(0, limit).map((site: any) => {
+    const where = site.function ? ` in \`${site.function}\`` : '';
+    return `- ${site.file}:${site.line} \`${site.detail}\`${where}`;
+  });
+  if (missing.length > limit) {
+    rows.push(`- ...and ${missing.length - limit} more`);
+  }
+  const missingSection = rows.length ? `\n\n**Missing SAFETY comments**\n${rows.join('\n')}` : '';
+
+  return `## ☢️ Unsafe Code & FFI
+${audit.total} sites, ${audit.unjustified} without a \`// SAFETY:\` comment.
+
+${crates.join('\n\n')}${missingSection}
+
+`;
+}
+
+/**
+ * One-line Cargo.lock totals for compact formats
+ */
+function dependencyGraphLine(graph: any): string {
+  const direct = new Set(
+    graph.members.flatMap((member: any) =>
+      member.direct.filter((dep: any) => !dep.member).map((dep: any) => dep.id)
+    )
+  );
+  const duplicates = graph.duplicates.length
+    ? `, duplicated: ${graph.duplicates.map((dup: any) => dup.name).join(', ')}`
+    : '';
+  return `${graph.packages} locked crates (${direct.size} direct)${duplicates}`;
+}
+
+/**
+ * Markdown section with per-member dependencies, duplicated crates, git/path sources,
+ * pre-releases and, for dependency queries, the chains that pull a crate in
+ */
+function formatDependencyGraphMarkdown(graph: any, limit: number = 15): string {
+  if (!graph) {
+    return '';
+  }
+
+  const members = graph.members.map((member: any) => {
+    const direct = member.direct.slice(0, limit).map((dep: any) => {
+      return dep.kind && dep.kind !== 'normal' ? `${dep.id} (${dep.kind})` : dep.id;
+    });
+    if (member.direct.length > limit) {
+      direct.push(`...and ${member.direct.length - limit} more`);
+    }
+    const counts = `${member.direct.length} direct, ${member.transitive.length} transitive`;
+    return `- **${member.crate}** — ${counts}${direct.length ? `: ${direct.join(', ')}` : ''}`;
+  });
+
+  const sections: string[] = [];
+  if (graph.explain) {
+    const chains = graph.explain.paths.map((chain: string[]) => `- ${chain.join(' → ')}`);
+    sections.push(
+      `**Why \`${graph.explain.crate}\`**\n` +
+        (chains.length ? chains.join('\n') : '- No workspace member depends on it')
+    );
+  }
+  if (graph.duplicates.length) {
+    const rows = graph.duplicates.slice(0, limit).map((dup: any) => {
+      const versions = dup.versions.map((version: string) => {
+        const dependents = dup.dependents[version] ?? [];
+        return dependents.length ? `${version} (via ${dependents.join(', ')})` : version;
+      });
+      return `- \`${dup.name}\`: ${versions.join('; ')}`;
+    });
+    sections.push(`**Duplicate versions**\n${rows.join('\n')}`);
+  }
+  if (graph.gitSources.length) {
+    const rows = graph.gitSources.map((pkg: any) => {
+      const ref = pkg.git?.reference ? ` ${pkg.git.reference}` : '';
+      return `- ${pkg.id} from ${pkg.git?.url ?? pkg.source}${ref}`;
+    });
+    sections.push(`**Git sources**\n${rows.join('\n')}`);
+  }
+  if (graph.pathSources.length) {
+    const rows = graph.pathSources.map((pkg: any) => `- ${pkg.id}`);
+    sections.push(`**Path dependencies outside the workspace**\n${rows.join('\n')}`);
+  }
+  if (graph.prereleases.length) {
+    const rows = graph.prereleases.map((pkg: any) => `- ${pkg.id}`);
+    sections.push(`**Pre-release versions**\n${rows.join('\n')}`);
+  }
+
+  return `## 🔗 Cargo Dependencies
+Cargo.lock v${graph.lockVersion}: ${graph.packages} locked crates.
+
+${members.join('\n')}${sections.length ? `\n\n${sections.join('\n\n')}` : ''}
+
+`;
+}
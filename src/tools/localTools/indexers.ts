@@ -22,6 +22,8 @@ import {
   dbInitializersForFile,
   calculateDbConfidence,
   detectDatabaseEngine,
+  isMigrationPath,
+  MIGRATION_GLOBS,
 } from './utils/dbEvidence';
 import {
   isPublicSurface,
@@ -31,6 +33,11 @@ import {
   shouldExcludeFromPublicApi,
   ApiSymbol,
 } from './utils/publicApi';
+import { createRustModuleResolver, findRustCrateRoots } from './utils/rustModules';
+import { collectRustApi } from './utils/rustPublicApi';
+import { extractRustRoutes } from './utils/rustRoutes';
+import { extractRustEnvKeys } from './utils/rustEnv';
+import { extractRustItemDocs, rustDocSummary } from '../../core/compactor/rustDocs';
 
 export interface ExportItem {
   name: string;
@@ -41,6 +48,8 @@ export interface ExportItem {
   params?: number;
   signature?: string;
   role?: string;
+  qualifiedPath?: string;
+  visibility?: 'public' | 'crate';
 }
 
 export interface RouteItem {
@@ -62,7 +71,9 @@ export interface EnvItem {
   key: string;
   file: string;
   line: number;
-  usage: 'read' | 'default' | 'config';
+  usage: 'read' | 'default' | 'config' | 'required' | 'optional' | 'compile-time';
+  usages?: EnvItem['usage'][]; // Every usage of a deduplicated key, strongest first
+  references?: number; // How many reads were merged into a deduplicated key
 }
 
 export interface DbInfo {
@@ -128,10 +139,58 @@ export async function buildExportIndex(files: FileInfo[]): Promise<ExportItem[]>
     }
   }
 
+  // Rust visibility is decided by the crate's module tree, so crates are walked as a whole
+  if (files.some(file => file.language === 'rust')) {
+    exports.push(...(await buildRustExports(files)));
+  }
+
   // Verifier #7: De-duplicate exports
   const dedupedExports = deduplicateExports(exports);
 
-  return dedupedExports.sort((a, b) => a.name.localeCompare(b.name));
+  // Crate-internal Rust items sort after everything that is reachable from outside
+  return dedupedExports.sort(
+    (a, b) =>
+      Number(a.visibility === 'crate') - Number(b.visibility === 'crate') ||
+      a.name.localeCompare(b.name)
+  );
+}
+
+/**
+ * Rust exports: `pub` items of each crate root with qualified paths and visibility
+ */
+async function buildRustExports(files: FileInfo[]): Promise<ExportItem[]> {
+  const sources = new Map<string, string>();
+  for (const file of files) {
+    if (file.language !== 'rust') continue;
+    try {
+      sources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf-8'));
+    } catch (error) {
+      logger.warn('Could not read file for export analysis', {
+        file: file.relPath,
+        error: (error as Error).message,
+      });
+    }
+  }
+
+  const items: ExportItem[] = [];
+  const fileLines = new Map<string, string[]>();
+  for (const root of findRustCrateRoots(files)) {
+    for (const item of collectRustApi(root, sources)) {
+      if (!isPublicSurface(item.file)) continue;
+      if (shouldExcludeFromPublicApi(item.name, item.kind, item.file)) continue;
+      if (!fileLines.has(item.file)) {
+        fileLines.set(item.file, (sources.get(item.file) ?? '').split('\n'));
+      }
+      items.push({
+        ...item,
+        jsdoc: extractJSDocFromLines(fileLines.get(item.file)!, item.line - 1, 'rust'),
+        role: inferExportRole(item.name.split('::').pop()!, item.kind, item.file),
+      });
+    }
+  }
+
+  // A file shared by a lib and a bin root keeps its public (lib) entry when deduplicated
+  return items.sort((a, b) => Number(a.visibility === 'crate') - Number(b.visibility === 'crate'));
 }
 
 /**
@@ -139,12 +198,23 @@ export async function buildExportIndex(files: FileInfo[]): Promise<ExportItem[]>
  */
 export async function buildImportGraph(files: FileInfo[]): Promise<Map<string, string[]>> {
   const graph = new Map<string, string[]>();
+  // Rust imports resolve through the crate module tree (mod/use), not relative paths
+  const rustResolver = files.some(file => file.language === 'rust')
+    ? createRustModuleResolver(files)
+    : undefined;
 
   for (const file of files) {
-    if (!['typescript', 'javascript'].includes(file.language)) continue;
+    const isRust = file.language === 'rust';
+    if (!['typescript', 'javascript'].includes(file.language) && !isRust) continue;
 
     try {
       const content = await readFile(file.absPath, 'utf-8');
+
+      if (isRust && rustResolver) {
+        graph.set(file.relPath, rustResolver.resolveImports(file.relPath, content));
+        continue;
+      }
+
       const imports = extractImportsFromContent(content);
 
       const resolvedImports = imports
@@ -169,8 +239,22 @@ export async function buildImportGraph(files: FileInfo[]): Promise<Map<string, s
  */
 export async function detectRoutes(files: FileInfo[]): Promise<RouteItem[]> {
   const routes: RouteItem[] = [];
+  const rustSources = new Map<string, string>();
 
   for (const file of files) {
+    if (file.language === 'rust') {
+      // Rust routers are assembled across files, so resolve them together below
+      if (/(^|\/)(tests|benches|examples)\//.test(toPosix(file.relPath))) continue;
+      try {
+        rustSources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf-8'));
+      } catch (error) {
+        logger.warn('Could not analyze routes in file', {
+          file: file.relPath,
+          error: (error as Error).message,
+        });
+      }
+      continue;
+    }
     if (!['typescript', 'javascript'].includes(file.language)) continue;
 
     try {
@@ -185,6 +269,10 @@ export async function detectRoutes(files: FileInfo[]): Promise<RouteItem[]> {
     }
   }
 
+  if (rustSources.size > 0) {
+    routes.push(...extractRustRoutes(rustSources));
+  }
+
   // Fix for critique items #1 and #5: Deduplicate routes and normalize paths
   return deduplicateRoutes(routes);
 }
@@ -215,13 +303,42 @@ export async function detectMcpTools(files: FileInfo[]): Promise<ToolItem[]> {
   return tools;
 }
 
+// Strongest first: a key the program cannot start without outranks one that has a fallback
+const ENV_USAGE_STRENGTH: EnvItem['usage'][] = [
+  'required',
+  'compile-time',
+  'read',
+  'config',
+  'default',
+  'optional',
+];
+
+function envUsageRank(usage: EnvItem['usage']): number {
+  return ENV_USAGE_STRENGTH.indexOf(usage);
+}
+
 /**
  * Detect environment variable usage
  */
 export async function detectEnvKeys(files: FileInfo[]): Promise<EnvItem[]> {
   const envKeys: EnvItem[] = [];
+  const rustSources = new Map<string, string>();
 
   for (const file of files) {
+    if (file.language === 'rust') {
+      // Config structs (envy, config, figment) may live in another file than their loader
+      if (/(^|\/)(tests|benches|examples)\//.test(toPosix(file.relPath))) continue;
+      try {
+        rustSources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf-8'));
+      } catch (error) {
+        logger.warn('Could not analyze env keys in file', {
+          file: file.relPath,
+          error: (error as Error).message,
+        });
+      }
+      continue;
+    }
+
     // Expand to support multiple languages
     const supportedLanguages = ['typescript', 'javascript', 'python', 'php', 'go', 'ruby'];
     if (!supportedLanguages.includes(file.language)) continue;
@@ -238,21 +355,32 @@ export async function detectEnvKeys(files: FileInfo[]): Promise<EnvItem[]> {
     }
   }
 
-  // Deduplicate by key
+  if (rustSources.size > 0) {
+    envKeys.push(...extractRustEnvKeys(rustSources));
+  }
+
+  // Deduplicate by key, merging usages; the key points at its strongest usage
   const uniqueKeys = new Map<string, EnvItem>();
   for (const envKey of envKeys) {
-    if (!uniqueKeys.has(envKey.key)) {
-      uniqueKeys.set(envKey.key, envKey);
-    }
+    const existing = uniqueKeys.get(envKey.key);
+    const usages = new Set([...(existing?.usages ?? []), envKey.usage]);
+    const strongest =
+      existing && envUsageRank(existing.usage) <= envUsageRank(envKey.usage) ? existing : envKey;
+    uniqueKeys.set(envKey.key, {
+      ...strongest,
+      usages: Array.from(usages).sort((a, b) => envUsageRank(a) - envUsageRank(b)),
+      references: (existing?.references ?? 0) + 1,
+    });
   }
 
   return Array.from(uniqueKeys.values()).sort((a, b) => a.key.localeCompare(b.key));
 }
 
 /**
- * Detect database engines and initialization patterns
+ * Detect database engines and initialization patterns. SQL migrations are globbed from
+ * `projectPath`, since file discovery never returns `.sql` files
  */
-export async function detectDb(files: FileInfo[]): Promise<DbInfo> {
+export async function detectDb(files: FileInfo[], projectPath?: string): Promise<DbInfo> {
   let engine: DbInfo['engine'] = 'unknown';
   const initializers: DbInfo['initializers'] = [];
   const connections: DbInfo['connections'] = [];
@@ -266,12 +394,18 @@ export async function detectDb(files: FileInfo[]): Promise<DbInfo> {
   const allEvidence: Array<{ file: string; line: number; match: string }> = [];
   const engineVotes: Record<string, number> = {};
 
+  const migrations = new Set(files.map(file => toPosix(file.relPath)).filter(isMigrationPath));
+  if (projectPath) {
+    for (const migration of await findMigrationFiles(projectPath)) migrations.add(migration);
+  }
+
   for (const file of files) {
-    if (!['typescript', 'javascript', 'python'].includes(file.language)) continue;
+    const posixPath = toPosix(file.relPath);
+    if (migrations.has(posixPath)) continue;
+    if (!['typescript', 'javascript', 'python', 'rust'].includes(file.language)) continue;
 
     try {
       const content = await readFile(file.absPath, 'utf-8');
-      const posixPath = toPosix(file.relPath);
 
       // Use improved database detection
       const { engine: detectedEngine, evidence: fileEvidence } = detectDatabaseEngine(content);
@@ -306,6 +440,10 @@ export async function detectDb(files: FileInfo[]): Promise<DbInfo> {
     }
   }
 
+  for (const migration of Array.from(migrations).sort()) {
+    allEvidence.push({ file: migration, line: 1, match: `migration: ${migration}` });
+  }
+
   // Determine primary engine from votes
   const topEngine = Object.entries(engineVotes).sort(([, a], [, b]) => b - a)[0];
   if (topEngine) {
@@ -332,6 +470,26 @@ export async function detectDb(files: FileInfo[]): Promise<DbInfo> {
   };
 }
 
+/**
+ * SQL migrations under the project, as POSIX paths relative to it
+ */
+async function findMigrationFiles(projectPath: string): Promise<string[]> {
+  try {
+    const { globby } = await import('globby');
+    const found = await globby(MIGRATION_GLOBS, {
+      cwd: projectPath,
+      ignore: ['**/node_modules/**', '**/target/**', '**/.git/**'],
+    });
+    return found.map(toPosix).filter(isMigrationPath);
+  } catch (error) {
+    logger.warn('Could not list SQL migrations', {
+      projectPath,
+      error: (error as Error).message,
+    });
+    return [];
+  }
+}
+
 /**
  * Get basic git information (last commit, author, count)
  */
@@ -478,7 +636,7 @@ function extractExportsFromContent(
         if (!name) continue;
 
         // Extract documentation from previous lines
-        const jsdoc = extractJSDocFromLines(lines, i);
+        const jsdoc = extractJSDocFromLines(lines, i, language);
 
         exports.push({
           name,
@@ -1278,6 +1436,7 @@ function extractDbConnections(
     /\.connect\s*\(/gi,
     /createConnection\s*\(/gi,
     /openDatabase\s*\(/gi,
+    /::(?:connect|connect_lazy|establish)\s*\(|\bConnection::open\s*\(/,
   ];
 
   for (let i = 0; i < lines.length; i++) {
@@ -1300,7 +1459,17 @@ function extractDbConnections(
 
 // Utility functions
 
-function extractJSDocFromLines(lines: string[], currentLine: number): string | undefined {
+function extractJSDocFromLines(
+  lines: string[],
+  currentLine: number,
+  language?: string
+): string | undefined {
+  // Rust `///`, `/** */` and `#[doc = "..."]` docs, possibly above attributes
+  if (language === 'rust') {
+    const rustDocs = extractRustItemDocs(lines, currentLine);
+    return rustDocs ? rustDocSummary(rustDocs.text) : undefined;
+  }
+
   // Look backwards for JSDoc comments
   const jsdocLines: string[] = [];
   let i = currentLine - 1;
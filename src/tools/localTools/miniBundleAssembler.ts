@@ -15,6 +15,7 @@ import { FileInfo } from '../../core/compactor/fileDiscovery';
 import { logger } from '../../utils/logger';
 import * as path from 'path';
 import { estimateTokens } from '../utils/toolHelpers';
+import { RustCfgView } from './utils/rustCfg';
 
 // ===== INTERFACES =====
 
@@ -23,6 +24,7 @@ export interface SnippetExtractionOptions {
   contextLines: number;
   includeHelpers: boolean;
   normalizeCode: boolean;
+  cfgView?: RustCfgView; // blank out Rust items compiled out under the requested cfg
 }
 
 export interface SnippetCandidate {
@@ -56,12 +58,14 @@ export const SNIPPET_PRIORITIES = {
 // ===== MAIN ASSEMBLY FUNCTION =====
 
 /**
- * Assemble mini-bundle with token budgeting
+ * Assemble mini-bundle with token budgeting. With a cfg view, targets inside compiled-out
+ * Rust code are skipped and compiled-out items are left out of the snippets
  */
 export async function assembleMiniBundle(
   jumpTargets: JumpTarget[],
   allFiles: FileInfo[],
-  maxTokens: number
+  maxTokens: number,
+  cfgView?: RustCfgView
 ): Promise<BundleSnippet[]> {
   logger.info('📦 Assembling mini-bundle', {
     targetCount: jumpTargets.length,
@@ -77,12 +81,16 @@ export async function assembleMiniBundle(
     contextLines: 30, // Lines above/below symbol
     includeHelpers: true,
     normalizeCode: true,
+    cfgView,
   };
 
   // 1. Extract snippets for each target
   const snippetCandidates: SnippetCandidate[] = [];
 
   for (const target of jumpTargets) {
+    if (cfgView && !cfgView.isActive(target.file, target.start)) {
+      continue;
+    }
     try {
       const candidate = await extractSnippetCandidate(target, options);
       if (candidate) {
@@ -136,7 +144,8 @@ async function extractSnippetCandidate(
 ): Promise<SnippetCandidate | null> {
   try {
     // Read file content
-    const content = readFileSync(target.file, 'utf8');
+    const source = readFileSync(target.file, 'utf8');
+    const content = options.cfgView ? options.cfgView.blankInactive(target.file, source) : source;
     const lines = content.split('\n');
 
     // Extract snippet with context
@@ -152,7 +161,11 @@ async function extractSnippetCandidate(
     }
 
     // Normalize snippet if requested
-    const normalizedSnippet = options.normalizeCode ? normalizeSnippet(rawSnippet) : rawSnippet;
+    let normalizedSnippet = options.normalizeCode ? normalizeSnippet(rawSnippet) : rawSnippet;
+    if (options.cfgView) {
+      // Compiled-out items were blanked in place; drop the gaps they leave
+      normalizedSnippet = normalizedSnippet.replace(/\n(?:[ \t]*\n){2,}/g, '\n\n');
+    }
 
     // Count tokens
     const tokenCount = estimateTokens(normalizedSnippet);
@@ -16,10 +16,35 @@ import {
   formatEnhancedJSON,
   formatEnhancedMarkdown,
 } from './formatters/projectHintsFormatters';
-import { buildEnhancedProjectSummary, generateAnswerDraft } from './enhancedHints';
+import {
+  buildEnhancedProjectSummary,
+  buildCargoWorkspaceSummary,
+  buildCargoFeatureSummary,
+  buildUnsafeAuditSummary,
+  buildCargoDependencySummary,
+  generateAnswerDraft,
+} from './enhancedHints';
 import { FileDiscovery, FileInfo } from '../../core/compactor/fileDiscovery';
 import * as path from 'path';
 
+/**
+ * Rust monorepos: surface each Cargo workspace member as its own unit, with its features,
+ * unsafe sites and dependency graph. The structured format gets these from
+ * buildEnhancedProjectSummary() instead
+ */
+async function addCargoHints(
+  hints: ProjectHints,
+  projectPath: string,
+  query?: string
+): Promise<void> {
+  hints.cargoWorkspace = buildCargoWorkspaceSummary(projectPath);
+  if (!hints.cargoWorkspace) return;
+
+  hints.features = await buildCargoFeatureSummary(projectPath);
+  hints.unsafeAudit = await buildUnsafeAuditSummary(projectPath);
+  hints.dependencyGraph = await buildCargoDependencySummary(projectPath, query);
+}
+
 /**
  * Analyze file composition by type across the project
  */
@@ -203,6 +228,7 @@ export async function handleProjectHints(args: any): Promise<any> {
           maxFileSizeForSymbols,
           format,
         })) as string;
+        hints.cargoWorkspace = buildCargoWorkspaceSummary(resolvedProjectPath);
         logger.info('✅ Generated formatted hints', {
           format,
           length: formattedHints.length,
@@ -251,11 +277,13 @@ export async function handleProjectHints(args: any): Promise<any> {
             enhanced: true,
             embeddingAssisted: hintsGenerator['shouldUseEmbeddingAssistedHints']?.() || false,
             fileComposition: fileCompositionStructured,
+            crates: enhancedSummary.cargoWorkspace?.members.map(member => member.name),
           },
         };
       } else {
         // Use local formatting for remaining cases
         logger.info('📝 Using local formatting for', { format });
+        await addCargoHints(hints, resolvedProjectPath, query);
         formattedHints = formatProjectHints(hints, format);
       }
 
@@ -286,6 +314,7 @@ export async function handleProjectHints(args: any): Promise<any> {
           enhanced: false,
           embeddingAssisted: hintsGenerator['shouldUseEmbeddingAssistedHints']?.() || false,
           fileComposition,
+          crates: hints.cargoWorkspace?.members.map(member => member.name),
         },
       };
     }
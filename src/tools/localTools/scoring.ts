@@ -50,10 +50,11 @@ export function calculateScore(
   const file = item.file;
   const name = 'name' in item ? item.name : 'path' in item ? item.path : '';
   const kind = 'kind' in item ? item.kind : 'method' in item ? 'route' : 'tool';
+  const visibility = 'visibility' in item ? item.visibility : undefined;
 
   const components: ScoreComponents = {
     pathPrior: pathPrior(file),
-    surfaceBoost: surfaceBoost(kind),
+    surfaceBoost: surfaceBoost(kind, visibility),
     degreeBoost: degreeBoost(file, context.importGraph),
     recencyBoost: recencyBoost(context.gitMap[file]),
     keywordScore: keywordScore(name, file, context.queryTerms),
@@ -110,9 +111,17 @@ export function pathPrior(file: string): number {
 
 /**
  * Surface visibility boost for public APIs
- * Prioritizes functions and classes over constants and types
+ * Prioritizes functions and classes over constants and types; Rust items reachable from
+ * outside the crate rank above pub(crate) internals
  */
-export function surfaceBoost(kind: string): number {
+export function surfaceBoost(kind: string, visibility?: ExportItem['visibility']): number {
+  const base = kindBoost(kind);
+  if (visibility === 'public') return base * 1.1;
+  if (visibility === 'crate') return base * 0.75;
+  return base;
+}
+
+function kindBoost(kind: string): number {
   switch (kind) {
     case 'function':
       return 1.2;
@@ -257,7 +266,7 @@ export function generateRankedHints(context: ScoreContext, maxHints: number = 7)
 
     return {
       file: item.file,
-      symbol: 'name' in item ? item.name : undefined,
+      symbol: 'name' in item ? symbolLabel(item) : undefined,
       line: item.line,
       role: inferRole(item),
       why,
@@ -269,6 +278,13 @@ export function generateRankedHints(context: ScoreContext, maxHints: number = 7)
   return scoredItems.sort((a, b) => b.rawScore - a.rawScore).slice(0, maxHints);
 }
 
+/**
+ * Display name for a hint; Rust items use their crate path (mycrate::client::Client::connect)
+ */
+function symbolLabel(item: ExportItem | ToolItem): string {
+  return ('qualifiedPath' in item && item.qualifiedPath) || item.name;
+}
+
 /**
  * Infer the role/purpose of an item for display
  */
@@ -21,6 +21,7 @@ import { systemMapComposer, formatSystemMapAsMarkdown } from './systemMapCompose
 import { sharedRetriever } from '../../shared/retrieval/retriever';
 import * as path from 'path';
 import { compileExcludePatterns, isExcludedPath } from '../utils/toolHelpers';
+import { RUST_CFG_SCHEMA } from './utils/rustCfg';
 
 // Simple single-flight guard to avoid duplicate concurrent runs for the same key
 const inFlightRequests: Map<string, Promise<any>> = new Map();
@@ -44,7 +45,8 @@ export const localSemanticCompactTool = {
         type: 'string',
         enum: ['understand', 'debug', 'trace', 'spec', 'test'],
         default: 'understand',
-        description: 'Type of analysis task - affects query processing and output format',
+        description:
+          'Type of analysis task - affects query processing and output format. "test" returns the Rust tests covering the item or .rs file named in the query',
       },
       maxSimilarChunks: {
         type: 'number',
@@ -75,7 +77,8 @@ export const localSemanticCompactTool = {
       astQueries: {
         type: 'array',
         items: { type: 'object' },
-        description: 'Optional custom AST queries to supplement automatic detection',
+        description:
+          'Optional custom AST queries to supplement automatic detection, e.g. {"kind":"import","source":"axum"}. Kinds: import, export, call, new, env, route; for Rust also impl {trait?, type?} (with a trait: its definition, impls, derives and subtraits), trait {name?}, derive {trait}, attribute {path} and macro {name}.',
       },
       attackPlan: {
         type: 'string',
@@ -106,6 +109,16 @@ export const localSemanticCompactTool = {
         description:
           'Additional patterns to exclude from analysis (e.g., ["*.md", "docs/**", "*.test.js"])',
       },
+      crate: {
+        type: 'string',
+        description:
+          'Cargo workspace member to scope the analysis to, by package name or directory (e.g., "billing-core"). Rust projects only.',
+      },
+      cfg: {
+        ...RUST_CFG_SCHEMA,
+        description:
+          'Rust build configuration (e.g., {"target_os": "linux", "features": ["tls"], "test": false}). Items whose #[cfg(...)] is false under it are left out; options not given are treated as unknown and keep their code. Rust projects only.',
+      },
       useEmbeddings: {
         type: 'boolean',
         default: false,
@@ -141,7 +154,9 @@ export async function handleSemanticCompact(args: any): Promise<any> {
       const q = (args?.query || '').toString().slice(0, 200);
       const p = validateAndResolvePath(args.projectPath);
       const f = args?.format || 'enhanced';
-      return `${path.resolve(p)}::${f}::${q}`;
+      const c = args?.crate || '';
+      const cfg = args?.cfg ? JSON.stringify(args.cfg) : '';
+      return `${path.resolve(p)}::${f}::${c}::${cfg}::${q}`;
     } catch {
       return `default-key`;
     }
@@ -169,6 +184,8 @@ export async function handleSemanticCompact(args: any): Promise<any> {
         attackPlan = 'auto',
         format = 'enhanced',
         excludePatterns = [],
+        crate,
+        cfg,
         // Legacy parameters for backward compatibility
         projectPath,
         folderPath,
@@ -187,7 +204,8 @@ export async function handleSemanticCompact(args: any): Promise<any> {
         const localStorageEnabled = process.env.USE_LOCAL_EMBEDDINGS === 'true';
         const enhancedAvailable = EnhancedSemanticCompactor.isEnhancedModeAvailable();
 
-        if (localStorageEnabled && enhancedAvailable) {
+        // Embedding retrieval is not crate- or cfg-aware, so scoped queries stay on the AST path
+        if (localStorageEnabled && enhancedAvailable && !crate && !cfg) {
           try {
             logger.info('🧠 Auto-enabled embeddings (local storage active)', {
               projectPath: resolvedProjectPath,
@@ -304,6 +322,8 @@ export async function handleSemanticCompact(args: any): Promise<any> {
             astQueries,
             attackPlan: attackPlan as any,
             excludePatterns,
+            crate,
+            cfg,
           });
 
           if (enhancedResult.success) {
@@ -337,7 +357,11 @@ export async function handleSemanticCompact(args: any): Promise<any> {
             const relevantChunks = await sharedRetriever.retrieve(query, 'overview');
 
             // Compose System Map
-            const systemMap = await systemMapComposer.composeSystemMap(query, relevantChunks);
+            const systemMap = await systemMapComposer.composeSystemMap(
+              query,
+              relevantChunks,
+              resolvedProjectPath
+            );
 
             // Format as markdown
             const systemMapMarkdown = formatSystemMapAsMarkdown(systemMap);
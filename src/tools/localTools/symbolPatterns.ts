@@ -2,17 +2,21 @@
  * @fileOverview: Ast-grep patterns for multi-language symbol extraction
  * @module: SymbolPatterns
  * @description: Defines structural patterns for extracting functions, classes, etc., using ast-grep syntax.
- * Patterns are simple (pattern mode) for now; can extend to rules later.
+ * Most patterns are simple (pattern mode); Rust entries use rules to capture optional parts.
  * Based on schemas in ./schemas/ (e.g., python_rule.json for "function_definition").
  */
 
 import * as path from 'path';
 
 export interface SymbolPattern {
-  pattern: string; // Ast-grep pattern string
+  pattern?: string; // Ast-grep pattern string; omitted for rule-based entries
   lang: string; // Ast-grep language code (e.g., 'py', 'go')
   captures?: string[]; // Expected metavariables (e.g., ['$NAME', '$PARAMS'])
   description: string;
+  // Ast-grep rule (run via `scan --inline-rules`) used instead of `pattern` when a single
+  // pattern cannot cover optional modifiers such as visibility or generics
+  rule?: Record<string, unknown>;
+  symbolKind?: string; // Human label for matches (e.g., 'struct', 'trait')
 }
 
 export interface LanguagePatterns {
@@ -20,8 +24,48 @@ export interface LanguagePatterns {
   classes: SymbolPattern[];
   methods?: SymbolPattern[]; // Optional for OOP langs
   exports?: SymbolPattern[]; // Lang-specific exports
+  types?: SymbolPattern[]; // Interfaces/traits/type aliases
+  constants?: SymbolPattern[]; // Module-level constants/statics
+  macros?: SymbolPattern[]; // Macro definitions
 }
 
+export type SymbolPatternType =
+  | 'functions'
+  | 'classes'
+  | 'methods'
+  | 'exports'
+  | 'types'
+  | 'constants'
+  | 'macros';
+
+/**
+ * Build an ast-grep rule for a Rust item kind that captures `$NAME` and, when present, the
+ * `pub` visibility as `$VIS`. Optional parts use `any` with a `not` branch so items without
+ * them still match.
+ */
+function rustItemRule(kinds: string[], extra: Record<string, unknown>[] = []) {
+  return {
+    all: [
+      { any: kinds.map(kind => ({ kind })) },
+      { has: { field: 'name', pattern: '$NAME' } },
+      optionalCapture(
+        { kind: 'visibility_modifier', pattern: '$VIS' },
+        { kind: 'visibility_modifier' }
+      ),
+      ...extra,
+    ],
+  };
+}
+
+function optionalCapture(capture: Record<string, unknown>, absent: Record<string, unknown>) {
+  return { any: [{ has: capture }, { not: { has: absent } }] };
+}
+
+const RUST_FN_PARTS = [
+  { has: { field: 'parameters', pattern: '$PARAMS' } },
+  optionalCapture({ field: 'return_type', pattern: '$RET' }, { field: 'return_type' }),
+];
+
 /**
  * Symbol extraction patterns by language.
  * Extend as needed; test with executeAstGrep.
@@ -59,13 +103,126 @@ export const symbolPatterns: Record<string, LanguagePatterns> = {
   rs: {
     functions: [
       {
-        pattern: 'fn ',
         lang: 'rs',
-        captures: [],
-        description: 'Rust function definition',
+        captures: ['$NAME', '$PARAMS', '$RET', '$VIS'],
+        description: 'Rust free function (outside impl/trait blocks)',
+        rule: rustItemRule(
+          ['function_item'],
+          [
+            ...RUST_FN_PARTS,
+            {
+              not: {
+                inside: { any: [{ kind: 'impl_item' }, { kind: 'trait_item' }], stopBy: 'end' },
+              },
+            },
+          ]
+        ),
+        symbolKind: 'fn',
+      },
+    ],
+    methods: [
+      {
+        lang: 'rs',
+        captures: ['$NAME', '$PARAMS', '$RET', '$VIS', '$TYPE', '$TRAIT'],
+        description: 'Rust method inside an inherent or trait impl block',
+        rule: rustItemRule(
+          ['function_item'],
+          [
+            ...RUST_FN_PARTS,
+            {
+              inside: {
+                kind: 'impl_item',
+                stopBy: 'end',
+                has: { field: 'type', pattern: '$TYPE' },
+                any: [
+                  { has: { field: 'trait', pattern: '$TRAIT' } },
+                  { not: { has: { field: 'trait' } } },
+                ],
+              },
+            },
+          ]
+        ),
+        symbolKind: 'method',
+      },
+      {
+        lang: 'rs',
+        captures: ['$NAME', '$PARAMS', '$RET', '$TYPE'],
+        description: 'Rust trait method (required or provided)',
+        rule: rustItemRule(
+          ['function_item', 'function_signature_item'],
+          [
+            ...RUST_FN_PARTS,
+            {
+              inside: {
+                kind: 'trait_item',
+                stopBy: 'end',
+                has: { field: 'name', pattern: '$TYPE' },
+              },
+            },
+          ]
+        ),
+        symbolKind: 'trait method',
+      },
+    ],
+    classes: [
+      {
+        lang: 'rs',
+        captures: ['$NAME', '$VIS'],
+        description: 'Rust struct (named, tuple or unit)',
+        rule: rustItemRule(['struct_item', 'union_item']),
+        symbolKind: 'struct',
+      },
+      {
+        lang: 'rs',
+        captures: ['$NAME', '$VIS'],
+        description: 'Rust enum',
+        rule: rustItemRule(['enum_item']),
+        symbolKind: 'enum',
+      },
+    ],
+    types: [
+      {
+        lang: 'rs',
+        captures: ['$NAME', '$VIS'],
+        description: 'Rust trait definition',
+        rule: rustItemRule(['trait_item']),
+        symbolKind: 'trait',
+      },
+      {
+        lang: 'rs',
+        captures: ['$NAME', '$VIS', '$TYPE'],
+        description: 'Rust type alias',
+        rule: rustItemRule(
+          ['type_item'],
+          [
+            { has: { field: 'type', pattern: '$TYPE' } },
+            { not: { inside: { kind: 'impl_item', stopBy: 'end' } } },
+          ]
+        ),
+        symbolKind: 'type',
+      },
+    ],
+    constants: [
+      {
+        lang: 'rs',
+        captures: ['$NAME', '$VIS', '$TYPE'],
+        description: 'Rust const or static item',
+        rule: rustItemRule(
+          ['const_item', 'static_item'],
+          [{ has: { field: 'type', pattern: '$TYPE' } }]
+        ),
+        symbolKind: 'const',
+      },
+    ],
+    macros: [
+      {
+        lang: 'rs',
+        captures: ['$NAME'],
+        description: 'Rust declarative macro (macro_rules!)',
+        rule: { all: [{ kind: 'macro_definition' }, { has: { field: 'name', pattern: '$NAME' } }] },
+        symbolKind: 'macro',
       },
     ],
-    classes: [], // Rust uses structs/impls; add "struct $NAME {" later
   },
   java: {
     functions: [], // Methods under classes
@@ -92,10 +249,7 @@ export const symbolPatterns: Record<string, LanguagePatterns> = {
 /**
  * Get patterns for a language and symbol type.
  */
-export function getPatterns(
-  lang: string,
-  type: 'functions' | 'classes' | 'methods' | 'exports'
-): SymbolPattern[] {
+export function getPatterns(lang: string, type: SymbolPatternType): SymbolPattern[] {
   const patterns = symbolPatterns[lang];
   if (!patterns) return [];
   return patterns[type] || [];
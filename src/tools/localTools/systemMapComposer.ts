@@ -10,6 +10,13 @@ import { logComposerTelemetry } from '../../shared/telemetry';
 import { logger } from '../../utils/logger';
 import { LocalEmbeddingStorage } from '../../local/embeddingStorage';
 import { LocalEmbeddingGenerator } from '../../local/embeddingGenerator';
+import { FileDiscovery } from '../../core/compactor/fileDiscovery';
+import {
+  describeTraitImpl,
+  findTraitImplementors,
+  loadRustTraitIndex,
+  RustTraitImpl,
+} from './utils/rustTraits';
 
 export interface SystemMapSection {
   title: string;
@@ -33,6 +40,7 @@ export interface SystemMap {
   configSecrets: SystemMapSection;
   keyFiles: SystemMapSection;
   flow: SystemMapSection;
+  traitImplementations?: SystemMapSection; // Rust projects only
   metadata: {
     queryFacets: string[];
     anchorsHit: string[];
@@ -71,9 +79,14 @@ export class SystemMapComposer {
   }
 
   /**
-   * Compose a System Map from retrieved chunks
+   * Compose a System Map from retrieved chunks. With a project path, Rust projects also get a
+   * trait implementation section built from the whole source tree rather than the chunks
    */
-  async composeSystemMap(query: string, chunks: ScoredChunk[]): Promise<SystemMap> {
+  async composeSystemMap(
+    query: string,
+    chunks: ScoredChunk[],
+    projectPath?: string
+  ): Promise<SystemMap> {
     const startTime = Date.now();
 
     try {
@@ -107,6 +120,11 @@ export class SystemMapComposer {
         },
       };
 
+      const traitImplementations = projectPath
+        ? await this.buildTraitImplementationsSection(projectPath)
+        : undefined;
+      if (traitImplementations) systemMap.traitImplementations = traitImplementations;
+
       // Calculate section counts for telemetry
       const sectionCounts: Record<string, number> = {};
       Object.keys(systemMap).forEach(key => {
@@ -537,6 +555,83 @@ export class SystemMapComposer {
     };
   }
 
+  /**
+   * Build Trait Implementations section: project traits with their implementors and subtraits,
+   * plus the external traits the project implements most
+   */
+  private async buildTraitImplementationsSection(
+    projectPath: string
+  ): Promise<SystemMapSection | undefined> {
+    try {
+      const files = await new FileDiscovery(projectPath, {
+        supportedExtensions: ['.rs'],
+      }).discoverFiles();
+      if (files.length === 0) return undefined;
+
+      const index = await loadRustTraitIndex(files);
+      if (index.traits.length === 0 && index.impls.length === 0) return undefined;
+
+      const items: SystemMapItem[] = [];
+      const localTraits = new Set(index.traits.map(def => def.name));
+
+      index.traits
+        .map(def => ({ def, ...findTraitImplementors(index, def.name) }))
+        .sort((a, b) => b.impls.length - a.impls.length)
+        .slice(0, 12)
+        .forEach(({ def, impls, subtraits }) => {
+          const supertraits =
+            def.supertraits.length > 0 ? `: ${def.supertraits.join(' + ')}` : '';
+          const parts = [
+            impls.length > 0
+              ? `${impls.length} implementor${impls.length === 1 ? '' : 's'}: ${impls
+                  .slice(0, 8)
+                  .map(describeTraitImpl)
+                  .join(', ')}${impls.length > 8 ? ', …' : ''}`
+              : 'No implementations in this project',
+          ];
+          if (subtraits.length > 0) {
+            parts.push(`Subtraits: ${subtraits.map(sub => sub.name).join(', ')}`);
+          }
+
+          items.push({
+            title: `trait ${def.name}${def.generics ?? ''}${supertraits}`,
+            content: parts.join('\n'),
+            location: `${def.file}:${def.line}`,
+            signals: Array.from(new Set(impls.map(traitImplSignal))),
+          });
+        });
+
+      // External traits (std, serde, ...) the project implements, minus ubiquitous derives
+      const external = new Map<string, RustTraitImpl[]>();
+      for (const impl of index.impls) {
+        if (localTraits.has(impl.trait) || impl.negative) continue;
+        if (impl.source === 'derive' && COMMON_DERIVES.has(impl.trait)) continue;
+        external.set(impl.trait, [...(external.get(impl.trait) || []), impl]);
+      }
+      const externalSummary = Array.from(external.entries())
+        .sort(([, a], [, b]) => b.length - a.length)
+        .slice(0, 10)
+        .map(([trait, impls]) => {
+          const derived = impls.every(impl => impl.source === 'derive') ? ' (derive)' : '';
+          return `${trait} ×${impls.length}${derived}`;
+        });
+      if (externalSummary.length > 0) {
+        items.push({ title: 'External traits', content: externalSummary.join(', ') });
+      }
+
+      return {
+        title: '🧩 Trait Implementations',
+        items,
+        description: 'Project traits, their implementors and supertrait relations',
+      };
+    } catch (error) {
+      logger.debug('Trait implementation section skipped', {
+        error: error instanceof Error ? error.message : String(error),
+      });
+      return undefined;
+    }
+  }
+
   /**
    * Extract function signature from code
    */
@@ -670,6 +765,25 @@ export class SystemMapComposer {
   }
 }
 
+// Derives nearly every type carries; listing them drowns out the interesting traits
+const COMMON_DERIVES = new Set([
+  'Debug',
+  'Clone',
+  'Copy',
+  'PartialEq',
+  'Eq',
+  'PartialOrd',
+  'Ord',
+  'Hash',
+  'Default',
+]);
+
+function traitImplSignal(impl: RustTraitImpl): string {
+  if (impl.source === 'derive') return 'derive';
+  if (impl.blanket) return 'blanket';
+  return impl.generics ? 'generic' : 'impl';
+}
+
 // Export singleton instance
 export const systemMapComposer = new SystemMapComposer();
 
@@ -693,6 +807,7 @@ export function formatSystemMapAsMarkdown(systemMap: SystemMap): string {
     'keyFiles',
     'flow',
   ];
+  if (systemMap.traitImplementations) sectionKeys.push('traitImplementations');
 
   sectionKeys.forEach(key => {
     const section = systemMap[key] as SystemMapSection;
@@ -0,0 +1,302 @@
+/**
+ * @fileOverview: Cargo.lock dependency graph
+ * @module: CargoLock
+ * @keyFunctions:
+ *   - parseCargoLock(): Read a lockfile (v1-v4) into packages with resolved dependency edges
+ *   - analyzeDependencyGraph(): Direct vs transitive dependencies per workspace member,
+ *     duplicated crates, git and path sources, pre-release versions
+ *   - findDependencyPaths(): Shortest chains from workspace members to a dependency
+ *   - loadCargoLock(): Locate and parse the lockfile of the workspace enclosing a project
+ * @context: Works offline from the lockfile alone. Cargo.lock does not record dependency kinds,
+ *           so direct dependencies are annotated from the member manifests when available.
+ *           Entries in `dependencies` are `name`, `name version` or `name version (source)`,
+ *           depending on what is needed to make them unambiguous.
+ */
+
+import * as path from 'path';
+import { readFile } from 'fs/promises';
+import { logger } from '../../../utils/logger';
+import {
+  analyzeCargoWorkspace,
+  CargoDependency,
+  CargoWorkspace,
+  findCargoWorkspaceRoot,
+} from './cargoWorkspace';
+import { asStringList, asTable, parseToml } from './toml';
+
+export type LockSourceKind = 'registry' | 'git' | 'path';
+
+export interface LockPackage {
+  id: string; // `syn@2.0.48`
+  name: string;
+  version: string;
+  source?: string; // as written; absent for workspace members and path dependencies
+  sourceKind: LockSourceKind;
+  git?: { url: string; reference?: string; commit?: string };
+  dependencies: string[]; // ids
+  member: boolean; // workspace member
+}
+
+export interface CargoLockGraph {
+  version: number; // lockfile format; 1 or 2 when the file has no `version` key
+  packages: LockPackage[];
+}
+
+export interface DirectDependency {
+  id: string;
+  kind?: CargoDependency['kind']; // from the member manifest; absent when it isn't known
+  member: boolean;
+}
+
+export interface MemberDependencies {
+  crate: string;
+  version: string;
+  direct: DirectDependency[];
+  transitive: string[]; // ids reachable only through other packages, members excluded
+}
+
+export interface DuplicateCrate {
+  name: string;
+  versions: string[];
+  dependents: Record<string, string[]>; // version -> ids of the packages depending on it
+}
+
+export interface CargoDependencyReport {
+  lockVersion: number;
+  packages: number; // packages outside the workspace
+  members: MemberDependencies[];
+  duplicates: DuplicateCrate[];
+  gitSources: LockPackage[];
+  pathSources: LockPackage[]; // path dependencies outside the workspace
+  prereleases: LockPackage[];
+}
+
+const DEPENDENCY_REF = /^(\S+)(?: (\S+))?(?: \((.+)\))?$/;
+const PRERELEASE = /^\d+\.\d+\.\d+-/;
+
+/**
+ * Parse Cargo.lock content. `memberNames` marks workspace members; without it every package
+ * without a source counts as one. Throws on malformed TOML
+ */
+export function parseCargoLock(content: string, memberNames?: string[]): CargoLockGraph {
+  const lock = parseToml(content);
+  const entries = Array.isArray(lock.package) ? lock.package : [];
+  const members = memberNames ? new Set(memberNames) : undefined;
+  const packages: Array<LockPackage & { refs: string[] }> = [];
+  const ids = new Set<string>();
+
+  for (const entry of entries) {
+    const table = asTable(entry);
+    if (!table || typeof table.name !== 'string' || typeof table.version !== 'string') continue;
+    const source = typeof table.source === 'string' ? table.source : undefined;
+    let id = `${table.name}@${table.version}`;
+    if (ids.has(id)) id = `${id} (${source ?? 'path'})`;
+    ids.add(id);
+
+    packages.push({
+      id,
+      name: table.name,
+      version: table.version,
+      source,
+      sourceKind: !source ? 'path' : source.startsWith('git+') ? 'git' : 'registry',
+      git: source?.startsWith('git+') ? parseGitSource(source) : undefined,
+      dependencies: [],
+      member: !source && (members ? members.has(table.name) : true),
+      refs: asStringList(table.dependencies),
+    });
+  }
+
+  const byName = new Map<string, LockPackage[]>();
+  for (const pkg of packages) {
+    byName.set(pkg.name, [...(byName.get(pkg.name) || []), pkg]);
+  }
+  for (const pkg of packages) {
+    pkg.dependencies = pkg.refs
+      .map(ref => resolveDependencyRef(ref, byName)?.id)
+      .filter((id): id is string => !!id);
+  }
+
+  return {
+    version: typeof lock.version === 'number' ? lock.version : lock.metadata ? 1 : 2,
+    packages: packages.map(({ refs, ...pkg }) => pkg),
+  };
+}
+
+/**
+ * Summarize the graph: per-member direct and transitive dependencies, crates locked at more
+ * than one version, and packages from git, from paths outside the workspace or at pre-release
+ * versions. Direct dependencies get their kind from the workspace manifests when given
+ */
+export function analyzeDependencyGraph(
+  graph: CargoLockGraph,
+  workspace?: CargoWorkspace | null
+): CargoDependencyReport {
+  const byId = new Map(graph.packages.map(pkg => [pkg.id, pkg]));
+  const external = graph.packages.filter(pkg => !pkg.member);
+
+  const members = graph.packages
+    .filter(pkg => pkg.member)
+    .map(pkg => {
+      const manifest = workspace?.members.find(member => member.name === pkg.name);
+      const direct = pkg.dependencies.map(id => {
+        const dep = byId.get(id)!;
+        const declared = manifest?.dependencies.filter(candidate => candidate.name === dep.name);
+        // A crate listed under several kinds (normal and dev) is reported by its strongest
+        const kind = declared?.map(candidate => candidate.kind).sort(byKindStrength)[0];
+        return { id, member: dep.member, ...(kind ? { kind } : {}) };
+      });
+
+      const directIds = new Set(pkg.dependencies);
+      const transitive = Array.from(reachable(byId, pkg.dependencies)).filter(
+        id => !directIds.has(id) && !byId.get(id)!.member
+      );
+      return { crate: pkg.name, version: pkg.version, direct, transitive };
+    });
+
+  const byName = new Map<string, LockPackage[]>();
+  for (const pkg of external) {
+    byName.set(pkg.name, [...(byName.get(pkg.name) || []), pkg]);
+  }
+  const duplicates: DuplicateCrate[] = [];
+  for (const [name, versions] of byName) {
+    if (versions.length < 2) continue;
+    const dependents: Record<string, string[]> = {};
+    for (const pkg of versions) {
+      dependents[pkg.version] = graph.packages
+        .filter(parent => parent.dependencies.includes(pkg.id))
+        .map(parent => parent.id);
+    }
+    duplicates.push({
+      name,
+      versions: versions.map(pkg => pkg.version).sort(compareVersions),
+      dependents,
+    });
+  }
+  duplicates.sort((a, b) => b.versions.length - a.versions.length || a.name.localeCompare(b.name));
+
+  return {
+    lockVersion: graph.version,
+    packages: external.length,
+    members,
+    duplicates,
+    gitSources: external.filter(pkg => pkg.sourceKind === 'git'),
+    pathSources: external.filter(pkg => pkg.sourceKind === 'path'),
+    prereleases: external.filter(pkg => PRERELEASE.test(pkg.version)),
+  };
+}
+
+/**
+ * Shortest chains of package ids from workspace members to every locked version of `name`,
+ * one per member and version, shortest first. Empty when nothing depends on it
+ */
+export function findDependencyPaths(
+  graph: CargoLockGraph,
+  name: string,
+  limit: number = 5
+): string[][] {
+  const byId = new Map(graph.packages.map(pkg => [pkg.id, pkg]));
+  const targets = new Set(graph.packages.filter(pkg => pkg.name === name).map(pkg => pkg.id));
+  const paths: string[][] = [];
+
+  for (const member of graph.packages.filter(pkg => pkg.member)) {
+    if (targets.has(member.id)) continue;
+    const previous = new Map<string, string>([[member.id, '']]);
+    const queue = [member.id];
+    const found = new Set<string>();
+
+    while (queue.length > 0 && found.size < targets.size) {
+      const id = queue.shift()!;
+      for (const next of byId.get(id)?.dependencies ?? []) {
+        if (previous.has(next)) continue;
+        previous.set(next, id);
+        if (targets.has(next)) {
+          found.add(next);
+          const chain = [next];
+          for (let step = id; step; step = previous.get(step)!) chain.unshift(step);
+          paths.push(chain);
+        } else {
+          queue.push(next);
+        }
+      }
+    }
+  }
+
+  return paths.sort((a, b) => a.length - b.length).slice(0, limit);
+}
+
+/**
+ * Read the Cargo.lock of the workspace enclosing projectPath. Undefined when there is none
+ * or it cannot be parsed
+ */
+export async function loadCargoLock(
+  projectPath: string
+): Promise<{ graph: CargoLockGraph; workspace: CargoWorkspace | null } | undefined> {
+  const root = findCargoWorkspaceRoot(projectPath) ?? path.resolve(projectPath);
+  const lockPath = path.join(root, 'Cargo.lock');
+  let content: string;
+  try {
+    content = await readFile(lockPath, 'utf-8');
+  } catch {
+    return undefined;
+  }
+
+  try {
+    const workspace = analyzeCargoWorkspace(root);
+    const graph = parseCargoLock(content, workspace?.members.map(member => member.name));
+    return { graph, workspace };
+  } catch (error) {
+    logger.warn('Could not parse Cargo.lock', { lockPath, error: (error as Error).message });
+    return undefined;
+  }
+}
+
+// ===== Helpers =====
+
+function resolveDependencyRef(
+  ref: string,
+  byName: Map<string, LockPackage[]>
+): LockPackage | undefined {
+  const match = DEPENDENCY_REF.exec(ref.trim());
+  if (!match) return undefined;
+  const [, name, version, source] = match;
+  const candidates = (byName.get(name) || []).filter(
+    pkg => (!version || pkg.version === version) && (!source || pkg.source === source)
+  );
+  return candidates[0];
+}
+
+/**
+ * `git+https://github.com/org/repo?branch=main#1a2b3c` -> url, `branch=main`, commit
+ */
+function parseGitSource(source: string): LockPackage['git'] {
+  const [location, commit] = source.slice('git+'.length).split('#');
+  const [url, query] = location.split('?');
+  return { url, ...(query ? { reference: query } : {}), ...(commit ? { commit } : {}) };
+}
+
+function reachable(byId: Map<string, LockPackage>, start: string[]): Set<string> {
+  const seen = new Set<string>();
+  const stack = [...start];
+  while (stack.length > 0) {
+    const id = stack.pop()!;
+    if (seen.has(id)) continue;
+    seen.add(id);
+    stack.push(...(byId.get(id)?.dependencies ?? []));
+  }
+  return seen;
+}
+
+function byKindStrength(a: CargoDependency['kind'], b: CargoDependency['kind']): number {
+  const order = ['normal', 'build', 'dev'];
+  return order.indexOf(a) - order.indexOf(b);
+}
+
+function compareVersions(a: string, b: string): number {
+  const parts = (version: string) => version.split(/[.+-]/).map(part => Number(part));
+  const [left, right] = [parts(a), parts(b)];
+  for (let i = 0; i < Math.max(left.length, right.length); i++) {
+    const diff = (left[i] ?? 0) - (right[i] ?? 0);
+    if (diff) return Number.isNaN(diff) ? a.localeCompare(b) : diff;
+  }
+  return 0;
+}
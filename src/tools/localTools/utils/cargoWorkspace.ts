@@ -0,0 +1,458 @@
+/**
+ * @fileOverview: Cargo workspace analysis for Rust monorepos
+ * @module: CargoWorkspace
+ * @keyFunctions:
+ *   - analyzeCargoWorkspace(): Resolve members, package names, targets and path dependencies
+ *   - findCargoWorkspaceRoot(): Climb from a crate directory to the enclosing [workspace] manifest
+ *   - findCrate(): Look up a member crate by package name, lib name or directory
+ *   - crateForFile(): Map a project-relative file to the member crate that owns it
+ *   - resolveCrateScope(): Turn a `crate` tool argument into a file filter
+ * @context: Lets each workspace member crate be treated as its own project unit
+ */
+
+import * as fs from 'fs';
+import * as path from 'path';
+import { minimatch } from 'minimatch';
+import { parseToml, asTable, asStringList, TomlTable } from './toml';
+import { toPosix } from './pathUtils';
+import { logger } from '../../../utils/logger';
+
+export type CargoTargetKind = 'lib' | 'bin' | 'example' | 'test' | 'bench';
+
+export interface CargoTarget {
+  kind: CargoTargetKind;
+  name: string;
+  path: string; // POSIX, relative to the workspace root
+}
+
+export interface CargoDependency {
+  name: string;
+  kind: 'normal' | 'dev' | 'build';
+  path?: string; // POSIX, relative to the workspace root (path dependencies only)
+  crate?: string; // Package name of the member the path points at
+  optional?: boolean;
+}
+
+export interface CargoCrate {
+  name: string;
+  version?: string;
+  dir: string; // POSIX, relative to the workspace root ('.' for the root package)
+  manifestPath: string;
+  targets: CargoTarget[];
+  dependencies: CargoDependency[];
+  pathDependencies: CargoDependency[];
+}
+
+export interface CargoWorkspace {
+  root: string;
+  isWorkspace: boolean;
+  memberGlobs: string[];
+  excludeGlobs: string[];
+  members: CargoCrate[];
+}
+
+export interface CrateScope {
+  workspace: CargoWorkspace;
+  crate: CargoCrate;
+  includes(relPath: string): boolean;
+}
+
+const DEPENDENCY_SECTIONS: Array<[string, CargoDependency['kind']]> = [
+  ['dependencies', 'normal'],
+  ['dev-dependencies', 'dev'],
+  ['build-dependencies', 'build'],
+];
+
+const AUTO_TARGET_DIRS: Array<[CargoTargetKind, string, string]> = [
+  ['bin', 'src/bin', 'autobins'],
+  ['example', 'examples', 'autoexamples'],
+  ['test', 'tests', 'autotests'],
+  ['bench', 'benches', 'autobenches'],
+];
+
+/**
+ * Read and parse a Cargo.toml (or Cargo.lock); returns null when missing or malformed
+ */
+export function readCargoManifest(manifestPath: string): TomlTable | null {
+  try {
+    if (!fs.existsSync(manifestPath)) return null;
+    return parseToml(fs.readFileSync(manifestPath, 'utf8'));
+  } catch (error) {
+    logger.debug('Failed to parse Cargo manifest', {
+      manifestPath,
+      error: (error as Error).message,
+    });
+    return null;
+  }
+}
+
+/**
+ * Find the directory of the [workspace] manifest that owns startDir.
+ * Falls back to the nearest package manifest, or null when no Cargo.toml is found.
+ */
+export function findCargoWorkspaceRoot(startDir: string): string | null {
+  let current = path.resolve(startDir);
+  let nearestPackage: string | null = null;
+
+  for (;;) {
+    const manifest = readCargoManifest(path.join(current, 'Cargo.toml'));
+    if (manifest) {
+      const workspace = asTable(manifest.workspace);
+      if (workspace) {
+        const memberDir = nearestPackage ?? current;
+        if (memberDir === current || isWorkspaceMember(current, workspace, memberDir)) {
+          return current;
+        }
+      }
+      const pkg = asTable(manifest.package);
+      if (!nearestPackage && pkg) {
+        // An explicit `package.workspace` key points straight at the root
+        if (typeof pkg.workspace === 'string') {
+          return path.resolve(current, pkg.workspace);
+        }
+        nearestPackage = current;
+      }
+    }
+
+    const parent = path.dirname(current);
+    if (parent === current) return nearestPackage;
+    current = parent;
+  }
+}
+
+/**
+ * Analyze the Cargo workspace rooted at projectPath.
+ * A lone package is reported as a workspace with a single member.
+ */
+export function analyzeCargoWorkspace(projectPath: string): CargoWorkspace | null {
+  const root = path.resolve(projectPath);
+  const manifest = readCargoManifest(path.join(root, 'Cargo.toml'));
+  if (!manifest) return null;
+
+  const workspace = asTable(manifest.workspace);
+  const memberGlobs = asStringList(workspace?.members);
+  const excludeGlobs = asStringList(workspace?.exclude);
+  const workspaceDeps = asTable(workspace?.dependencies) ?? {};
+
+  const memberDirs = new Set<string>();
+  if (asTable(manifest.package)) memberDirs.add('.');
+  for (const dir of expandMemberGlobs(root, memberGlobs)) {
+    if (!excludeGlobs.some(pattern => minimatch(dir, normalizeGlob(pattern)))) {
+      memberDirs.add(dir);
+    }
+  }
+
+  const members: CargoCrate[] = [];
+  for (const dir of memberDirs) {
+    const crateManifest =
+      dir === '.' ? manifest : readCargoManifest(path.join(root, dir, 'Cargo.toml'));
+    const crate = crateManifest && describeCrate(root, dir, crateManifest, workspaceDeps);
+    if (crate) members.push(crate);
+  }
+
+  // Path dependencies may point outside the member globs; resolve what we can by directory
+  const byDir = new Map(members.map(member => [member.dir, member.name]));
+  for (const member of members) {
+    for (const dep of member.pathDependencies) {
+      dep.crate = dep.path ? byDir.get(dep.path) : undefined;
+    }
+  }
+
+  members.sort((a, b) => a.dir.localeCompare(b.dir));
+
+  return {
+    root,
+    isWorkspace: !!workspace,
+    memberGlobs,
+    excludeGlobs,
+    members,
+  };
+}
+
+/**
+ * Find a member crate by package name, lib target name (snake_case) or directory
+ */
+export function findCrate(workspace: CargoWorkspace, query: string): CargoCrate | undefined {
+  const wanted = query.trim().replace(/\\/g, '/').replace(/\/$/, '');
+  const snake = wanted.replace(/-/g, '_');
+  return (
+    workspace.members.find(member => member.name === wanted) ||
+    workspace.members.find(member => member.name.replace(/-/g, '_') === snake) ||
+    workspace.members.find(
+      member => member.dir === wanted || path.posix.basename(member.dir) === wanted
+    )
+  );
+}
+
+/**
+ * Map a workspace-relative file path to the member crate containing it (deepest match wins)
+ */
+export function crateForFile(workspace: CargoWorkspace, relPath: string): CargoCrate | undefined {
+  const file = toPosix(relPath);
+  let best: CargoCrate | undefined;
+  for (const member of workspace.members) {
+    const inside = member.dir === '.' || file === member.dir || file.startsWith(member.dir + '/');
+    if (inside && (!best || best.dir === '.' || member.dir.length > best.dir.length)) {
+      best = member;
+    }
+  }
+  return best;
+}
+
+/**
+ * Check whether a project-relative path belongs to the given crate and not to a nested member
+ */
+export function isFileInCrate(
+  workspace: CargoWorkspace,
+  crate: CargoCrate,
+  relPath: string
+): boolean {
+  return crateForFile(workspace, relPath)?.dir === crate.dir;
+}
+
+/**
+ * Resolve a `crate` argument against the workspace enclosing projectPath.
+ * Throws with the list of known members when the crate cannot be found.
+ */
+export function resolveCrateScope(projectPath: string, crateName: string): CrateScope {
+  const projectRoot = path.resolve(projectPath);
+  const workspaceRoot = findCargoWorkspaceRoot(projectRoot) ?? projectRoot;
+  const workspace = analyzeCargoWorkspace(workspaceRoot);
+  if (!workspace || workspace.members.length === 0) {
+    throw new Error(
+      `No Cargo workspace found for ${projectPath}; the crate filter needs a Cargo.toml`
+    );
+  }
+
+  const crate = findCrate(workspace, crateName);
+  if (!crate) {
+    const known = workspace.members.map(member => member.name);
+    throw new Error(
+      `Unknown crate "${crateName}". Workspace members: ${known.slice(0, 20).join(', ')}${known.length > 20 ? ', ...' : ''}`
+    );
+  }
+
+  // File paths are relative to projectPath, which may sit below the workspace root
+  const prefix = toPosix(path.relative(workspace.root, projectRoot));
+  return {
+    workspace,
+    crate,
+    includes: (relPath: string) =>
+      isFileInCrate(workspace, crate, prefix ? path.posix.join(prefix, toPosix(relPath)) : relPath),
+  };
+}
+
+function describeCrate(
+  root: string,
+  dir: string,
+  manifest: TomlTable,
+  workspaceDeps: TomlTable
+): CargoCrate | null {
+  const pkg = asTable(manifest.package);
+  if (!pkg || typeof pkg.name !== 'string') return null;
+
+  const crateDir = path.join(root, dir);
+  const dependencies = collectDependencies(root, crateDir, manifest, workspaceDeps);
+
+  return {
+    name: pkg.name,
+    version: typeof pkg.version === 'string' ? pkg.version : undefined,
+    dir,
+    manifestPath: path.posix.join(dir, 'Cargo.toml'),
+    targets: collectTargets(root, crateDir, pkg.name, pkg, manifest),
+    dependencies,
+    pathDependencies: dependencies.filter(dep => dep.path !== undefined),
+  };
+}
+
+function collectTargets(
+  root: string,
+  crateDir: string,
+  packageName: string,
+  pkg: TomlTable,
+  manifest: TomlTable
+): CargoTarget[] {
+  const targets: CargoTarget[] = [];
+  const seen = new Set<string>();
+  const rel = (file: string) => toPosix(path.relative(root, path.join(crateDir, file)));
+  const add = (kind: CargoTargetKind, name: string, file: string) => {
+    const key = `${kind}:${name}`;
+    if (seen.has(key)) return;
+    seen.add(key);
+    targets.push({ kind, name, path: rel(file) });
+  };
+
+  const lib = asTable(manifest.lib);
+  const libPath = typeof lib?.path === 'string' ? lib.path : 'src/lib.rs';
+  if (lib || fs.existsSync(path.join(crateDir, libPath))) {
+    const libName = typeof lib?.name === 'string' ? lib.name : packageName.replace(/-/g, '_');
+    add('lib', libName, libPath);
+  }
+
+  // Explicit [[bin]], [[example]], [[test]] and [[bench]] tables take precedence
+  for (const [kind, dirName] of AUTO_TARGET_DIRS) {
+    const entries = manifest[kind];
+    if (!Array.isArray(entries)) continue;
+    for (const entry of entries) {
+      const table = asTable(entry);
+      if (!table || typeof table.name !== 'string') continue;
+      const isMainBin = kind === 'bin' && table.name === packageName;
+      const fallback = isMainBin ? 'src/main.rs' : `${dirName}/${table.name}.rs`;
+      add(kind, table.name, typeof table.path === 'string' ? table.path : fallback);
+    }
+  }
+
+  if (pkg.autobins !== false && fs.existsSync(path.join(crateDir, 'src/main.rs'))) {
+    add('bin', packageName, 'src/main.rs');
+  }
+
+  for (const [kind, dirName, autoKey] of AUTO_TARGET_DIRS) {
+    if (pkg[autoKey] === false) continue;
+    for (const [name, file] of discoverTargetFiles(path.join(crateDir, dirName))) {
+      add(kind, name, `${dirName}/${file}`);
+    }
+  }
+
+  return targets;
+}
+
+/**
+ * Cargo's target auto-discovery: `<dir>/<name>.rs` and `<dir>/<name>/main.rs`
+ */
+function discoverTargetFiles(dir: string): Array<[string, string]> {
+  let entries: fs.Dirent[];
+  try {
+    entries = fs.readdirSync(dir, { withFileTypes: true });
+  } catch {
+    return [];
+  }
+
+  const found: Array<[string, string]> = [];
+  for (const entry of entries) {
+    if (entry.isFile() && entry.name.endsWith('.rs')) {
+      found.push([entry.name.slice(0, -3), entry.name]);
+    } else if (entry.isDirectory() && fs.existsSync(path.join(dir, entry.name, 'main.rs'))) {
+      found.push([entry.name, `${entry.name}/main.rs`]);
+    }
+  }
+  return found.sort(([a], [b]) => a.localeCompare(b));
+}
+
+function collectDependencies(
+  root: string,
+  crateDir: string,
+  manifest: TomlTable,
+  workspaceDeps: TomlTable
+): CargoDependency[] {
+  const deps: CargoDependency[] = [];
+  const sections: Array<[TomlTable | undefined, CargoDependency['kind']]> = [];
+
+  for (const [section, kind] of DEPENDENCY_SECTIONS) {
+    sections.push([asTable(manifest[section]), kind]);
+  }
+  // [target.'cfg(...)'.dependencies] and friends
+  for (const target of Object.values(asTable(manifest.target) ?? {})) {
+    for (const [section, kind] of DEPENDENCY_SECTIONS) {
+      sections.push([asTable(asTable(target)?.[section]), kind]);
+    }
+  }
+
+  for (const [table, kind] of sections) {
+    for (const [name, spec] of Object.entries(table ?? {})) {
+      const dep: CargoDependency = { name, kind };
+      let specTable = asTable(spec);
+      let baseDir = crateDir;
+
+      if (specTable?.workspace === true) {
+        // `foo = { workspace = true }` inherits the root [workspace.dependencies] entry
+        const inherited = asTable(workspaceDeps[name]);
+        if (specTable.optional === true) dep.optional = true;
+        specTable = inherited;
+        baseDir = root;
+      }
+
+      if (specTable) {
+        if (typeof specTable.path === 'string') {
+          dep.path = toPosix(path.relative(root, path.resolve(baseDir, specTable.path))) || '.';
+        }
+        if (specTable.optional === true) dep.optional = true;
+      }
+      deps.push(dep);
+    }
+  }
+
+  return deps;
+}
+
+function isWorkspaceMember(root: string, workspace: TomlTable, memberDir: string): boolean {
+  const rel = toPosix(path.relative(root, memberDir));
+  if (!rel || rel.startsWith('..')) return false;
+  const excluded = asStringList(workspace.exclude).some(pattern =>
+    minimatch(rel, normalizeGlob(pattern))
+  );
+  if (excluded) return false;
+  return asStringList(workspace.members).some(pattern => minimatch(rel, normalizeGlob(pattern)));
+}
+
+/**
+ * Expand `members` globs (e.g. "crates/*", "services/**") into directories that contain a
+ * Cargo.toml
+ */
+function expandMemberGlobs(root: string, patterns: string[]): string[] {
+  const dirs = new Set<string>();
+
+  for (const pattern of patterns.map(normalizeGlob)) {
+    let candidates = ['.'];
+    for (const segment of pattern.split('/')) {
+      const next: string[] = [];
+      for (const base of candidates) {
+        if (segment === '**') {
+          next.push(base, ...subdirectories(root, base));
+          continue;
+        }
+        if (!/[*?[{]/.test(segment)) {
+          next.push(path.posix.join(base, segment));
+          continue;
+        }
+        try {
+          for (const entry of fs.readdirSync(path.join(root, base), { withFileTypes: true })) {
+            if (entry.isDirectory() && minimatch(entry.name, segment)) {
+              next.push(path.posix.join(base, entry.name));
+            }
+          }
+        } catch {
+          // Missing directory: the glob simply matches nothing here
+        }
+      }
+      candidates = Array.from(new Set(next));
+    }
+
+    for (const dir of candidates) {
+      if (fs.existsSync(path.join(root, dir, 'Cargo.toml'))) dirs.add(dir);
+    }
+  }
+
+  return Array.from(dirs);
+}
+
+/**
+ * Every directory below `dir`, skipping build output and hidden directories
+ */
+function subdirectories(root: string, dir: string): string[] {
+  const found: string[] = [];
+  try {
+    for (const entry of fs.readdirSync(path.join(root, dir), { withFileTypes: true })) {
+      if (!entry.isDirectory() || entry.name.startsWith('.')) continue;
+      if (entry.name === 'target' || entry.name === 'node_modules') continue;
+      const child = path.posix.join(dir, entry.name);
+      found.push(child, ...subdirectories(root, child));
+    }
+  } catch {
+    // Unreadable directory: nothing below it matches
+  }
+  return found;
+}
+
+function normalizeGlob(pattern: string): string {
+  return toPosix(pattern).replace(/^\.\//, '').replace(/\/+$/, '');
+}
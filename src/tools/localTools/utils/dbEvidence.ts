@@ -5,7 +5,10 @@
  *   - collectDbEvidence(): Find database-related code patterns in file content
  *   - isServerishPath(): Check if path represents server-side code
  *   - dbInitializersForFile(): Get database initializers only for server files
- * @context: Prevents UI files from being incorrectly identified as database initializers
+ *   - isMigrationPath(): Check if path is a SQL migration (sqlx, diesel, rails, knex)
+ * @context: Prevents UI files from being incorrectly identified as database initializers.
+ *           Covers JS/Python drivers and ORMs plus Rust sqlx, diesel, sea-orm, rusqlite and
+ *           tokio-postgres
  */
 
 import { isServerishPath } from './pathUtils';
@@ -27,6 +30,13 @@ const DB_IMPORTS = [
   /\bnew\s+Pool\s*\(/,
   /\bnew\s+Client\s*\(/,
   /\bnew\s+Database\s*\(/,
+  // Rust crates
+  /\buse\s+(?:sqlx|diesel|sea_orm|rusqlite|tokio_postgres|deadpool_postgres|postgres|mongodb|redis)\b/,
+  /\b(?:Pg|MySql|Sqlite|Any)Pool(?:Options)?::(?:connect|new)\b/,
+  /\b(?:rusqlite::)?Connection::open(?:_in_memory)?\s*\(/,
+  /\btokio_postgres::connect\s*\(/,
+  /\b(?:Pg|Mysql|Sqlite)Connection::establish\s*\(/,
+  /\bDatabase::connect\s*\(/,
 ];
 
 // Database environment variable patterns
@@ -42,6 +52,7 @@ const DB_ENV = [
   /\bDB_NAME\b/,
   /\bDB_USER\b/,
   /\bDB_PASSWORD\b/,
+  /\benv::var\s*\(\s*"(?:DATABASE_URL|POSTGRES_URL|MYSQL_URL|MONGODB_URI|REDIS_URL)"/,
 ];
 
 // SQL usage patterns
@@ -50,6 +61,7 @@ const DB_QUERY_PATTERNS = [
   /\.query\s*\(/,
   /\.execute\s*\(/,
   /\.run\s*\(/,
+  /\.fetch_(?:one|all|optional)\s*\(/,
   /SELECT\s+.*FROM\s+/i,
   /INSERT\s+INTO\s+/i,
   /UPDATE\s+.*SET\s+/i,
@@ -57,6 +69,12 @@ const DB_QUERY_PATTERNS = [
   /CREATE\s+TABLE\s+/i,
   /ALTER\s+TABLE\s+/i,
   /DROP\s+TABLE\s+/i,
+  // sqlx query macros, diesel schema and sea-orm entities
+  /\b(?:sqlx::)?(?:query|query_as|query_scalar|query_file|query_file_as)!\s*\(/,
+  /\bsqlx::migrate!\s*\(/,
+  /^\s*(?:diesel::)?table!\s*\{/,
+  /#\[derive\([^)]*\bDeriveEntityModel\b/,
+  /#\[sea_orm\s*\(\s*table_name\s*=/,
 ];
 
 /**
@@ -114,8 +132,8 @@ export function collectDbEvidence(text: string): Evidence[] {
  * This prevents UI files from being incorrectly classified as database initializers
  */
 export function dbInitializersForFile(posixPath: string, text: string): Evidence[] {
-  // UI/client files cannot be DB initializers
-  if (!isServerishPath(posixPath)) {
+  // UI/client files cannot be DB initializers; Rust crates have no UI/server split by path
+  if (!isServerishPath(posixPath) && !posixPath.endsWith('.rs')) {
     return [];
   }
 
@@ -131,6 +149,8 @@ export function dbInitializersForFile(posixPath: string, text: string): Evidence
     /(?:const|let|var)\s+(\w*(?:init|connect|setup|bootstrap|create.*(?:connection|pool|client))\w*)\s*=/gi,
     /(\w+)\s*:\s*(?:async\s+)?function.*(?:init|connect|setup|bootstrap)/gi,
     /class\s+(\w*(?:Database|Connection|Pool|Client|Repository)\w*)/gi,
+    /\bfn\s+(\w*(?:init|connect|setup|bootstrap|establish|create_(?:pool|connection|client))\w*)/gi,
+    /\b(?:struct|trait)\s+(\w*(?:Database|Db|Connection|Pool|Repository|Repo|Store))\b/g,
   ];
 
   const initializers: Evidence[] = [];
@@ -177,7 +197,7 @@ export function calculateDbConfidence(evidence: Evidence[]): number {
     const match = item.match.toLowerCase();
 
     // Import evidence (strongest)
-    if (match.includes('from') || match.includes('import')) {
+    if (match.includes('from') || match.includes('import') || /^use\s/.test(match)) {
       if (!seenTypes.has('import')) {
         score += 30;
         seenTypes.add('import');
@@ -185,7 +205,11 @@ export function calculateDbConfidence(evidence: Evidence[]): number {
     }
 
     // Environment evidence (strong)
-    if (match.includes('process.env') || match.includes('DATABASE_URL')) {
+    if (
+      match.includes('process.env') ||
+      match.includes('DATABASE_URL') ||
+      match.includes('env::var')
+    ) {
       if (!seenTypes.has('env')) {
         score += 25;
         seenTypes.add('env');
@@ -201,12 +225,23 @@ export function calculateDbConfidence(evidence: Evidence[]): number {
     }
 
     // Connection patterns (moderate)
-    if (/new\s+(pool|client|database)/i.test(match) || match.includes('.connect(')) {
+    if (
+      /new\s+(pool|client|database)/i.test(match) ||
+      /(?:\.|::)connect\(|::(?:open|establish)\(/.test(match)
+    ) {
       if (!seenTypes.has('connection')) {
         score += 20;
         seenTypes.add('connection');
       }
     }
+
+    // Schema evidence: migrations, diesel table!, sea-orm entities (moderate)
+    if (/^migration:|table!|deriveentitymodel|migrate!/.test(match)) {
+      if (!seenTypes.has('schema')) {
+        score += 15;
+        seenTypes.add('schema');
+      }
+    }
   }
 
   // Normalize to 0-1 range
@@ -245,23 +280,33 @@ export function detectDatabaseEngine(text: string): { engine: string; evidence:
       /DATABASE_URL.*postgres/i,
       /POSTGRES_URL/i,
       /\.query\s*\(\s*['"`]SELECT/i,
+      /\b(?:PgPool|PgPoolOptions|PgConnection|PgConnectOptions|PgRow)\b/,
+      /\b(?:tokio_postgres|deadpool_postgres|bb8_postgres)::/,
     ],
     mysql: [
       /from\s+['"]mysql2?['"]|import.*mysql/i,
       /MYSQL_URL|MYSQL_HOST/i,
       /mysql\.createConnection/i,
+      /\b(?:MySqlPool|MySqlPoolOptions|MySqlConnection|MysqlConnection)\b|\bmysql_async::/,
     ],
     sqlite: [
       /from\s+['"](?:better-)?sqlite3?['"]|import.*sqlite/i,
       /\.sqlite|\.db['"`]/i,
       /SQLITE_/i,
+      /\b(?:SqlitePool|SqlitePoolOptions|SqliteConnection)\b|\brusqlite::/,
     ],
     mongodb: [
       /from\s+['"]mongodb?['"]|import.*mongo/i,
       /MONGODB_URI|MONGO_URL/i,
       /MongoClient|mongoose/i,
+      /\bmongodb::(?:Client|options)\b/,
+    ],
+    redis: [
+      /from\s+['"](?:io)?redis['"]|import.*redis/i,
+      /REDIS_URL/i,
+      /createClient.*redis/i,
+      /\bredis::(?:Client|aio|Commands)\b/,
     ],
-    redis: [/from\s+['"](?:io)?redis['"]|import.*redis/i, /REDIS_URL/i, /createClient.*redis/i],
     'vector-chroma': [/from\s+['"]chromadb['"]|import.*chromadb/i, /new\s+ChromaClient/i],
   };
 
@@ -281,3 +326,22 @@ export function detectDatabaseEngine(text: string): { engine: string; evidence:
 
   return { engine: 'unknown', evidence };
 }
+
+/**
+ * Globs for SQL migrations, which file discovery skips as an unsupported language: sqlx
+ * `migrations/*.sql`, diesel `<version>/up.sql` and `down.sql` (also under a custom
+ * `migrations_directory`), `db/migrate/`
+ */
+export const MIGRATION_GLOBS = [
+  '**/migrations/**/*.sql',
+  '**/db/migrate/**/*.sql',
+  '**/up.sql',
+  '**/down.sql',
+];
+
+/**
+ * Check if a path is a SQL migration file, matching what MIGRATION_GLOBS finds
+ */
+export function isMigrationPath(posixPath: string): boolean {
+  return /(^|\/)((migrations|db\/migrate)\/.+\.sql|(up|down)\.sql)$/i.test(posixPath);
+}
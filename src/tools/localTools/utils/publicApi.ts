@@ -5,7 +5,9 @@
  *   - isPublicSurface(): Check if file should be included in public API analysis
  *   - extractApiSignature(): Extract function signature from AST-like parsing
  *   - formatSignature(): Format export with parameters for agent consumption
- * @context: Focuses on server-side programmatic APIs that agents can understand and use
+ *   - rustItemSignature(): One-line signature for a Rust item header
+ * @context: Focuses on server-side programmatic APIs that agents can understand and use.
+ *           Rust crates are walked from lib.rs instead (see rustPublicApi.ts)
  */
 
 import { isServerishPath, toPosix } from './pathUtils';
@@ -18,6 +20,8 @@ export type ApiSymbol = {
   params?: number; // parameter count from AST
   signature?: string; // formatted signature
   role?: string; // inferred role (handler, validator, etc.)
+  qualifiedPath?: string; // Rust: crate path, e.g. mycrate::client::Client::connect
+  visibility?: 'public' | 'crate'; // Rust: reachable outside the crate vs crate-internal
 };
 
 /**
@@ -25,6 +29,11 @@ export type ApiSymbol = {
  * Only server-side files should be included in public API analysis
  */
 export function isPublicSurface(posixPath: string): boolean {
+  // Rust visibility comes from the module tree, not the directory layout
+  if (posixPath.endsWith('.rs')) {
+    return !/(\/|^)(tests|benches|examples|target)\//.test(posixPath);
+  }
+
   // Must be server-side code
   if (!isServerishPath(posixPath)) {
     return false;
@@ -99,6 +108,69 @@ export function extractApiSignature(
   return {};
 }
 
+/**
+ * Signature of the Rust item starting at lines[index], without visibility, body or where clause
+ */
+export function rustItemSignature(
+  lines: string[],
+  index: number
+): { params?: number; signature: string } {
+  // Headers can span several lines (long parameter lists, where clauses)
+  let header = '';
+  for (let i = index; i < Math.min(lines.length, index + 8); i++) {
+    header += ` ${lines[i].trim()}`;
+    if (/[{;]/.test(lines[i])) break;
+  }
+
+  header = header
+    .replace(/\s+/g, ' ')
+    .trim()
+    .replace(/^(?:#\[[^\]]*\]\s*)*/, '')
+    .replace(/^pub(?:\s*\([^)]*\))?\s+/, '');
+  const isFn = /^(?:[\w"]+\s+)*?fn\s/.test(header);
+  const end = header.search(isFn || /^(?:type|macro_rules)\b/.test(header) ? /[{;]/ : /[{;=]/);
+  const signature = (end === -1 ? header : header.slice(0, end))
+    .replace(/\s+where\s.*$/, '')
+    .trim();
+
+  if (!isFn) return { signature };
+
+  const open = signature.indexOf('(');
+  if (open === -1) return { signature };
+  const params = splitRustParams(signature.slice(open + 1)).filter(
+    param => !/^(?:&\s*(?:'\w+\s+)?)?(?:mut\s+)?self\b/.test(param)
+  );
+  return { params: params.length, signature };
+}
+
+/**
+ * Split a Rust parameter list (text after the opening paren) on top-level commas
+ */
+function splitRustParams(text: string): string[] {
+  const params: string[] = [];
+  let depth = 0;
+  let current = '';
+  for (let i = 0; i < text.length; i++) {
+    const char = text[i];
+    if (char === '-' && text[i + 1] === '>') {
+      current += '->';
+      i++;
+      continue;
+    }
+    if (char === ')' && depth === 0) break;
+    if ('(<['.includes(char)) depth++;
+    else if (')>]'.includes(char)) depth--;
+    if (char === ',' && depth === 0) {
+      params.push(current.trim());
+      current = '';
+    } else {
+      current += char;
+    }
+  }
+  params.push(current.trim());
+  return params.filter(Boolean);
+}
+
 /**
  * Count parameters in a parameter string, handling complex cases
  */
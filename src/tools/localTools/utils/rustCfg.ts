@@ -0,0 +1,488 @@
+/**
+ * @fileOverview: cfg predicate evaluation and configuration-specific views of Rust sources
+ * @module: RustCfg
+ * @keyFunctions:
+ *   - parseCfgPredicate(): Parse `all(..)`, `any(..)`, `not(..)`, `key` and `key = "value"`
+ *   - evaluateCfg(): Evaluate a predicate under a build configuration (true, false or unknown)
+ *   - normalizeCfgConfig(): Validate the `cfg` tool argument
+ *   - RUST_CFG_SCHEMA: Input schema of the `cfg` tool argument, shared by the tools taking it
+ *   - findInactiveRanges(): Items of one file whose #[cfg] is false under a configuration
+ *   - buildRustCfgView(): Inactive items and files of a project, following `mod x;` declarations
+ *   - cfgGatedItem(): The item an outer attribute applies to and its extent
+ * @context: Lets local_context and local_file_summary show only what compiles for a configuration
+ *           such as `{ target_os: "linux", features: ["tls"], test: false }`. Options the
+ *           configuration doesn't mention stay unknown; only predicates that are definitely false
+ *           drop code, so an empty configuration changes nothing.
+ */
+
+import * as path from 'path';
+import { readFile } from 'fs/promises';
+import { FileInfo } from '../../../core/compactor/fileDiscovery';
+import { logger } from '../../../utils/logger';
+import { toPosix } from './pathUtils';
+import { resolveModDeclaration } from './rustModules';
+import { lineAt, maskRustSource, matchBracket } from './rustSource';
+
+export interface RustCfgConfig {
+  target_os?: string;
+  target_family?: string;
+  target_arch?: string;
+  target_env?: string;
+  target_vendor?: string;
+  target_pointer_width?: string;
+  target_endian?: string;
+  features?: string[];
+  test?: boolean;
+  debug_assertions?: boolean;
+}
+
+/**
+ * JSON schema of RustCfgConfig; tools add their own description
+ */
+export const RUST_CFG_SCHEMA = {
+  type: 'object',
+  properties: {
+    target_os: { type: 'string' },
+    target_family: { type: 'string' },
+    target_arch: { type: 'string' },
+    target_env: { type: 'string' },
+    target_vendor: { type: 'string' },
+    target_pointer_width: { type: 'string' },
+    target_endian: { type: 'string' },
+    features: { type: 'array', items: { type: 'string' } },
+    test: { type: 'boolean' },
+    debug_assertions: { type: 'boolean' },
+  },
+  additionalProperties: false,
+};
+
+export type CfgPredicate =
+  | { op: 'all' | 'any' | 'not'; args: CfgPredicate[] }
+  | { op: 'option'; key: string; value?: string };
+
+export interface CfgRange {
+  start: number;
+  end: number; // exclusive
+  line: number;
+  endLine: number;
+  predicate: string;
+  item?: string;
+  module?: { name: string; pathAttr?: string }; // `#[cfg(..)] mod name;`
+}
+
+export interface RustCfgView {
+  config: RustCfgConfig;
+  ranges: Map<string, CfgRange[]>; // absolute path -> inactive items
+  inactiveFiles: Set<string>; // absolute paths of files that are not compiled at all
+  isActive(file: string, offset?: number): boolean;
+  blankInactive(file: string, content: string): string;
+}
+
+export interface CfgGatedItem {
+  label: string; // `fn connect`, `mod tls`, `impl Drop for Conn`
+  start: number;
+  end: number; // offset of the closing `}` or terminating `;`
+  module?: string; // name of a `mod name;` declaration
+}
+
+const TARGET_KEYS = new Set([
+  'target_os',
+  'target_arch',
+  'target_env',
+  'target_vendor',
+  'target_pointer_width',
+  'target_endian',
+]);
+const STRING_KEYS = new Set([...TARGET_KEYS, 'target_family']);
+const FLAG_KEYS = new Set(['test', 'debug_assertions']);
+
+const UNIX_OSES = new Set([
+  'linux',
+  'macos',
+  'ios',
+  'tvos',
+  'watchos',
+  'android',
+  'freebsd',
+  'netbsd',
+  'openbsd',
+  'dragonfly',
+  'solaris',
+  'illumos',
+  'haiku',
+  'redox',
+  'emscripten',
+]);
+
+const CFG_ATTRIBUTE = /#(!?)\[\s*cfg\s*\(/g;
+const GATED_ITEM = new RegExp(
+  String.raw`^\s*(?:#\[[^\]]*\]\s*)*(?:pub(?:\s*\([^)]*\))?\s+)?` +
+    String.raw`(?:(?:const|async|unsafe|extern\s+"[^"]*"|default)\s+)*` +
+    String.raw`(?:(fn|struct|enum|union|trait|mod|type|static|const|macro_rules!)` +
+    String.raw`\s*([A-Za-z_]\w*)|(impl|use)\b\s*([^{;]*))`
+);
+const MOD_DECLARATION =
+  /((?:#\[[^\]]*\]\s*)*)(?:pub(?:\s*\([^)]*\))?\s+)?mod\s+([A-Za-z_][A-Za-z0-9_]*)\s*;/g;
+const MODULE_FILE_NAMES = new Set(['lib.rs', 'main.rs', 'mod.rs']);
+const CRATE_ROOT_DIRS = /(^|\/)(src\/bin|tests|examples|benches)$/;
+// Integration tests: `tests/*.rs` and `tests/<name>/main.rs` of a crate, compiled by `cargo test`
+const INTEGRATION_TEST = /(^|\/)tests\/(?:[^/]+\.rs|[^/]+\/main\.rs)$/;
+
+/**
+ * Parse a cfg predicate. Anything that isn't all/any/not or an option is kept as an opaque
+ * option, which evaluates to unknown
+ */
+export function parseCfgPredicate(text: string): CfgPredicate {
+  const trimmed = text.trim();
+  const call = /^(all|any|not)\s*\(([\s\S]*)\)$/.exec(trimmed);
+  if (call) {
+    return {
+      op: call[1] as 'all' | 'any' | 'not',
+      args: splitCfgArgs(call[2]).map(parseCfgPredicate),
+    };
+  }
+  const option = /^([A-Za-z_][\w:]*)\s*(?:=\s*"([^"]*)")?$/.exec(trimmed);
+  return option ? { op: 'option', key: option[1], value: option[2] } : { op: 'option', key: '' };
+}
+
+/**
+ * Evaluate a predicate: true or false when the configuration decides it, undefined otherwise
+ */
+export function evaluateCfg(predicate: CfgPredicate, config: RustCfgConfig): boolean | undefined {
+  switch (predicate.op) {
+    case 'not': {
+      const value = predicate.args[0] ? evaluateCfg(predicate.args[0], config) : undefined;
+      return value === undefined ? undefined : !value;
+    }
+    case 'all':
+    case 'any': {
+      const values = predicate.args.map(arg => evaluateCfg(arg, config));
+      // all() is decided by any false argument, any() by any true one
+      const decisive = predicate.op === 'any';
+      if (values.includes(decisive)) return decisive;
+      return values.includes(undefined) ? undefined : !decisive;
+    }
+    default:
+      return evaluateOption(predicate.key, predicate.value, config);
+  }
+}
+
+/**
+ * Validate the `cfg` tool argument. Unknown keys throw so typos don't silently keep all code;
+ * an empty object means no configuration
+ */
+export function normalizeCfgConfig(value: unknown): RustCfgConfig | undefined {
+  if (value === undefined || value === null) return undefined;
+  if (typeof value !== 'object' || Array.isArray(value)) {
+    throw new Error('cfg must be an object, e.g. { "target_os": "linux", "test": false }');
+  }
+
+  const config: RustCfgConfig = {};
+  for (const [key, raw] of Object.entries(value as Record<string, unknown>)) {
+    if (raw === undefined || raw === null) continue;
+    if (key === 'features') {
+      const list = typeof raw === 'string' ? raw.split(',') : raw;
+      if (!Array.isArray(list) || list.some(item => typeof item !== 'string')) {
+        throw new Error('cfg.features must be a list of feature names');
+      }
+      config.features = list.map(item => item.trim()).filter(Boolean);
+    } else if (FLAG_KEYS.has(key)) {
+      if (typeof raw !== 'boolean') throw new Error(`cfg.${key} must be true or false`);
+      config[key as 'test' | 'debug_assertions'] = raw;
+    } else if (STRING_KEYS.has(key)) {
+      if (typeof raw !== 'string' && typeof raw !== 'number') {
+        throw new Error(`cfg.${key} must be a string`);
+      }
+      config[key as 'target_os'] = String(raw);
+    } else {
+      const supported = ['features', ...FLAG_KEYS, ...STRING_KEYS].join(', ');
+      throw new Error(`Unknown cfg option "${key}". Supported: ${supported}`);
+    }
+  }
+
+  return Object.keys(config).length > 0 ? config : undefined;
+}
+
+/**
+ * Items of a Rust file that are compiled out under the configuration, in source order.
+ * An inner `#![cfg(..)]` that is false covers the whole file
+ */
+export function findInactiveRanges(content: string, config: RustCfgConfig): CfgRange[] {
+  const { code, masked } = maskRustSource(content);
+  const ranges: CfgRange[] = [];
+  let match: RegExpExecArray | null;
+
+  CFG_ATTRIBUTE.lastIndex = 0;
+  while ((match = CFG_ATTRIBUTE.exec(masked)) !== null) {
+    const open = match.index + match[0].length - 1;
+    const close = matchBracket(masked, open);
+    const predicate = code.slice(open + 1, close).replace(/\s+/g, ' ').trim();
+    if (evaluateCfg(parseCfgPredicate(predicate), config) !== false) continue;
+
+    if (match[1] === '!') {
+      // Only an inner attribute at the top of the file gates the file itself
+      if (braceDepth(masked, match.index) > 0) continue;
+      return [
+        {
+          start: 0,
+          end: content.length,
+          line: 1,
+          endLine: lineAt(masked, masked.trimEnd().length),
+          predicate,
+        },
+      ];
+    }
+
+    const attrEnd = masked.indexOf(']', close) + 1;
+    const item = cfgGatedItem(masked, attrEnd);
+    const end = (item ? item.end : statementEnd(masked, attrEnd)) + 1;
+    const range: CfgRange = {
+      start: match.index,
+      end,
+      line: lineAt(masked, match.index),
+      endLine: lineAt(masked, end - 1),
+      predicate,
+      item: item?.label,
+    };
+    if (item?.module) {
+      const pathAttr = /#\[\s*path\s*=\s*"([^"]+)"/.exec(code.slice(attrEnd, item.start))?.[1];
+      range.module = { name: item.module, pathAttr };
+    }
+    ranges.push(range);
+    // Gates nested in a dropped item are dropped with it
+    CFG_ATTRIBUTE.lastIndex = Math.max(CFG_ATTRIBUTE.lastIndex, end);
+  }
+
+  return ranges;
+}
+
+/**
+ * Inactive items and files of a project's Rust sources. Files declared through a compiled-out
+ * `mod x;` are inactive together with their own submodules, and with `test: false` so are
+ * integration tests
+ */
+export async function buildRustCfgView(
+  files: FileInfo[],
+  config: RustCfgConfig
+): Promise<RustCfgView> {
+  const rustFiles = files.filter(file => file.language === 'rust');
+  const byRelPath = new Map(rustFiles.map(file => [toPosix(file.relPath), file.absPath]));
+  const fileSet = new Set(byRelPath.keys());
+  const ranges = new Map<string, CfgRange[]>();
+  const contents = new Map<string, string>();
+  const inactive = new Set<string>(); // relative paths
+  const queue: string[] = [];
+  const drop = (relPath: string | undefined) => {
+    if (relPath && !inactive.has(relPath)) {
+      inactive.add(relPath);
+      queue.push(relPath);
+    }
+  };
+
+  for (const [relPath, absPath] of byRelPath) {
+    let content: string;
+    try {
+      content = await readFile(absPath, 'utf-8');
+    } catch (error) {
+      logger.warn('Could not read file for cfg analysis', {
+        file: relPath,
+        error: (error as Error).message,
+      });
+      continue;
+    }
+    contents.set(relPath, content);
+
+    const fileRanges = findInactiveRanges(content, config);
+    if (fileRanges.length > 0) ranges.set(absPath, fileRanges);
+    if (fileRanges.some(range => range.start === 0 && range.end === content.length)) {
+      drop(relPath);
+    }
+    for (const range of fileRanges) {
+      if (!range.module) continue;
+      const { name, pathAttr } = range.module;
+      drop(resolveModDeclaration(relPath, childModuleDir(relPath), name, pathAttr, fileSet));
+    }
+    if (config.test === false && INTEGRATION_TEST.test(relPath) && !relPath.includes('/src/')) {
+      drop(relPath);
+    }
+  }
+
+  // Submodules of a compiled-out file are compiled out too
+  while (queue.length > 0) {
+    const relPath = queue.shift()!;
+    const masked = maskRustSource(contents.get(relPath) ?? '').masked;
+    let match: RegExpExecArray | null;
+    MOD_DECLARATION.lastIndex = 0;
+    while ((match = MOD_DECLARATION.exec(masked)) !== null) {
+      const pathAttr = /#\[path\s*=\s*"([^"]+)"\s*\]/.exec(
+        (contents.get(relPath) ?? '').slice(match.index, match.index + match[1].length)
+      )?.[1];
+      drop(resolveModDeclaration(relPath, childModuleDir(relPath), match[2], pathAttr, fileSet));
+    }
+  }
+
+  const inactiveFiles = new Set(Array.from(inactive, relPath => byRelPath.get(relPath)!));
+  const absPathOf = (file: string) =>
+    path.isAbsolute(file) ? file : byRelPath.get(toPosix(file)) ?? file;
+
+  return {
+    config,
+    ranges,
+    inactiveFiles,
+    isActive(file: string, offset?: number): boolean {
+      const absPath = absPathOf(file);
+      if (inactiveFiles.has(absPath)) return false;
+      if (offset === undefined) return true;
+      return !(ranges.get(absPath) || []).some(range => range.start <= offset && offset < range.end);
+    },
+    blankInactive(file: string, content: string): string {
+      let result = content;
+      for (const range of ranges.get(absPathOf(file)) || []) {
+        result =
+          result.slice(0, range.start) +
+          result.slice(range.start, range.end).replace(/[^\n]/g, ' ') +
+          result.slice(range.end);
+      }
+      return result;
+    },
+  };
+}
+
+/**
+ * The item an outer attribute ending at `offset` is attached to (masked source)
+ */
+export function cfgGatedItem(masked: string, offset: number): CfgGatedItem | undefined {
+  const match = GATED_ITEM.exec(masked.slice(offset, offset + 1000));
+  if (!match) return undefined;
+
+  const start = offset + match[0].length - (match[2] ?? match[4]).length;
+  const label = match[1]
+    ? `${match[1].replace('!', '')} ${match[2]}`
+    : `${match[3]} ${match[4].replace(/\s+/g, ' ').trim()}`;
+
+  // Extent: through the matching `}` of a body, or the terminating `;`
+  let end = masked.length - 1;
+  for (let i = offset + match[0].length; i < masked.length; i++) {
+    if (masked[i] === ';') {
+      end = i;
+      break;
+    }
+    if (masked[i] === '{' || (match[1] === 'macro_rules!' && /[([]/.test(masked[i]))) {
+      end = matchBracket(masked, i);
+      break;
+    }
+  }
+
+  const module = match[1] === 'mod' && masked[end] === ';' ? match[2] : undefined;
+  return { label, start, end, module };
+}
+
+/**
+ * Directory holding the files of `mod x;` declared in `file` (2018 edition layout)
+ */
+export function childModuleDir(file: string): string {
+  const dir = path.posix.dirname(file);
+  const base = path.posix.basename(file);
+  if (MODULE_FILE_NAMES.has(base) || CRATE_ROOT_DIRS.test(dir)) {
+    return dir;
+  }
+  return path.posix.join(dir, base.replace(/\.rs$/, ''));
+}
+
+/**
+ * Split cfg arguments on commas outside parentheses and string literals
+ */
+export function splitCfgArgs(text: string): string[] {
+  const parts: string[] = [];
+  let depth = 0;
+  let inString = false;
+  let current = '';
+  for (let i = 0; i < text.length; i++) {
+    const char = text[i];
+    if (inString) {
+      if (char === '\\') {
+        current += char + (text[i + 1] ?? '');
+        i++;
+        continue;
+      }
+      if (char === '"') inString = false;
+    } else if (char === '"') {
+      inString = true;
+    } else if (char === '(') {
+      depth++;
+    } else if (char === ')') {
+      depth--;
+    } else if (char === ',' && depth === 0) {
+      parts.push(current);
+      current = '';
+      continue;
+    }
+    current += char;
+  }
+  parts.push(current);
+  return parts.map(part => part.trim()).filter(Boolean);
+}
+
+function evaluateOption(
+  key: string,
+  value: string | undefined,
+  config: RustCfgConfig
+): boolean | undefined {
+  if (key === 'feature') {
+    return value === undefined || !config.features ? undefined : config.features.includes(value);
+  }
+  if (FLAG_KEYS.has(key)) {
+    const flag = config[key as 'test' | 'debug_assertions'];
+    return value === undefined ? flag : undefined;
+  }
+
+  const family = config.target_family ?? targetFamilyOf(config.target_os);
+  if (key === 'unix' || key === 'windows') {
+    return value === undefined && family ? family === key : undefined;
+  }
+  if (key === 'target_family') {
+    return value !== undefined && family ? family === value : undefined;
+  }
+  if (TARGET_KEYS.has(key)) {
+    const actual = config[key as 'target_os'];
+    return value !== undefined && actual !== undefined ? actual === value : undefined;
+  }
+  return undefined;
+}
+
+function targetFamilyOf(targetOs: string | undefined): string | undefined {
+  if (!targetOs) return undefined;
+  if (targetOs === 'windows') return 'windows';
+  return UNIX_OSES.has(targetOs) ? 'unix' : undefined;
+}
+
+/**
+ * End of an attributed statement, field or expression: the `;` or `,` that terminates it at
+ * its own nesting level, or the end of a block it consists of
+ */
+function statementEnd(masked: string, offset: number): number {
+  let depth = 0;
+  for (let i = offset; i < masked.length; i++) {
+    const char = masked[i];
+    if (char === '(' || char === '[' || char === '{') {
+      depth++;
+    } else if (char === ')' || char === ']' || char === '}') {
+      if (depth === 0) return i - 1; // closes the enclosing item
+      if (--depth === 0 && char === '}' && !/^\s*(else\b|[.?;,])/.test(masked.slice(i + 1))) {
+        return i;
+      }
+    } else if ((char === ';' || char === ',') && depth === 0) {
+      return i;
+    }
+  }
+  return masked.length - 1;
+}
+
+function braceDepth(text: string, offset: number): number {
+  let depth = 0;
+  for (let i = 0; i < offset; i++) {
+    if (text[i] === '{') depth++;
+    else if (text[i] === '}') depth--;
+  }
+  return depth;
+}
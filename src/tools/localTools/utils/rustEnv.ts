@@ -0,0 +1,408 @@
+/**
+ * @fileOverview: Environment variable detection for Rust sources
+ * @module: RustEnv
+ * @keyFunctions:
+ *   - extractRustEnvKeys(): Find env keys read by a Rust project, classified by how they're used
+ * @context: Covers std::env / dotenvy reads, env!/option_env!, clap `#[arg(env)]` fields and
+ *           struct-driven loaders (envy, config `Environment`, figment `Env`), whose keys are the
+ *           prefixed field names of the deserialized struct, possibly defined in another file
+ */
+
+import { blankRustTestModules, lineAt, maskRustSource } from './rustSource';
+
+export interface RustEnvKey {
+  key: string;
+  file: string;
+  line: number;
+  usage: 'read' | 'required' | 'optional' | 'compile-time';
+}
+
+interface RustSource {
+  file: string;
+  code: string;
+  masked: string;
+}
+
+interface StructField {
+  name: string;
+  type: string;
+  line: number;
+  attrs: string;
+}
+
+interface RustStruct {
+  file: string;
+  fields: StructField[];
+}
+
+interface EnvLoader {
+  prefix: string;
+  separator?: string;
+  offset: number;
+  target: RegExp;
+}
+
+// Set by cargo for every crate, and additionally for build scripts
+const CARGO_PROVIDED = /^CARGO(?:_\w+)?$/;
+const BUILD_SCRIPT_PROVIDED =
+  /^(?:OUT_DIR|TARGET|HOST|NUM_JOBS|OPT_LEVEL|DEBUG|PROFILE|RUSTC\w*|RUSTDOC|DEP_\w+)$/;
+
+const RUNTIME_READ =
+  /\b(?:std::)?(?:env|dotenvy|dotenv)::var(_os)?\s*\(\s*(?:"([^"]+)"|([A-Z][A-Z0-9_]*))\s*\)/g;
+const COMPILE_TIME_READ = /\b(?:std::)?(?:env|option_env|dotenv)!\s*\(\s*"([^"]+)"/g;
+const OPTIONAL_HANDLING =
+  /\.\s*(?:ok|is_ok|is_err|is_some|is_none|unwrap_or\w*|map_or(?:_else)?|or_else|or)\s*\(/;
+const REQUIRED_HANDLING = /\?|\.\s*(?:expect|unwrap)\s*\(/;
+
+/**
+ * Extract env keys from all Rust sources of a project (relPath -> content)
+ */
+export function extractRustEnvKeys(sources: Map<string, string>): RustEnvKey[] {
+  const parsed: RustSource[] = [];
+  for (const [file, content] of sources) {
+    parsed.push({ file, ...blankRustTestModules(maskRustSource(content)) });
+  }
+
+  const structs = new Map<string, RustStruct>();
+  for (const source of parsed) {
+    for (const [name, fields] of parseStructs(source)) {
+      if (!structs.has(name)) structs.set(name, { file: source.file, fields });
+    }
+  }
+
+  const keys: RustEnvKey[] = [];
+  for (const source of parsed) {
+    keys.push(...directReads(source));
+    keys.push(...clapEnvArgs(source));
+    for (const loader of findEnvLoaders(source)) {
+      keys.push(...loaderKeys(source, loader, structs));
+    }
+  }
+
+  const isBuildScript = (file: string) => /(^|\/)build\.rs$/.test(file);
+  return keys.filter(
+    key =>
+      !CARGO_PROVIDED.test(key.key) &&
+      !(isBuildScript(key.file) && BUILD_SCRIPT_PROVIDED.test(key.key))
+  );
+}
+
+/**
+ * env::var / var_os / dotenvy::var reads and the env!/option_env!/dotenv! macros
+ */
+function directReads(source: RustSource): RustEnvKey[] {
+  const { code, masked, file } = source;
+  const keys: RustEnvKey[] = [];
+  const constants = stringConstants(code);
+
+  for (const match of code.matchAll(RUNTIME_READ)) {
+    const key = match[2] ?? constants.get(match[3]);
+    if (!key) continue;
+    const chain = topLevelChain(masked, match.index! + match[0].length);
+    const optional = OPTIONAL_HANDLING.exec(chain);
+    const required = REQUIRED_HANDLING.exec(chain);
+    const lineStart = masked.lastIndexOf('\n', match.index!) + 1;
+    const before = masked.slice(lineStart, match.index!);
+
+    // The first handler applied to the Result decides: `.map(..).unwrap_or(..)` is optional
+    let usage: RustEnvKey['usage'] = 'read';
+    if (/\b(?:if\s+let\s+(?:Ok|Some)\s*\([^)]*\)\s*=|match)\s*$/.test(before)) {
+      usage = 'optional';
+    } else if (optional && (!required || optional.index < required.index)) {
+      usage = 'optional';
+    } else if (required) {
+      usage = 'required';
+    } else if (match[1]) {
+      // var_os yields an Option; without unwrapping it the key is optional
+      usage = 'optional';
+    }
+    keys.push({ key, file, line: lineAt(code, match.index!), usage });
+  }
+
+  for (const match of code.matchAll(COMPILE_TIME_READ)) {
+    keys.push({ key: match[1], file, line: lineAt(code, match.index!), usage: 'compile-time' });
+  }
+
+  return keys;
+}
+
+/**
+ * clap derive fields: `#[arg(long, env = "PORT")]`, `#[clap(env)]` (key is the field name)
+ */
+function clapEnvArgs(source: RustSource): RustEnvKey[] {
+  const { code, masked, file } = source;
+  const keys: RustEnvKey[] = [];
+  const attrRegex = /#\[\s*(?:arg|clap|structopt)\s*\(([^\]]*)\)\s*\]/g;
+  const fieldRegex =
+    /^((?:\s*#\[[^\]]*\])*\s*(?:pub(?:\([^)]*\))?\s+)?)([A-Za-z_]\w*)\s*:\s*([^,}]+)/;
+
+  for (const match of code.matchAll(attrRegex)) {
+    const args = match[1];
+    const env = /\benv\s*=\s*"([^"]+)"/.exec(args);
+    if (!env && !/(?:^|[\s,])env\s*(?:,|$)/.test(args)) continue;
+
+    const fieldStart = match.index! + match[0].length;
+    const field = fieldRegex.exec(masked.slice(fieldStart));
+    if (!field) continue;
+
+    const type = field[3].trim();
+    const optional =
+      /^(?:Option|Vec)\s*</.test(type) ||
+      type === 'bool' ||
+      /\bdefault_(?:value|value_t|values_t|missing_value)\b|\brequired\s*=\s*false/.test(args);
+    keys.push({
+      key: env ? env[1] : field[2].toUpperCase(),
+      file,
+      line: lineAt(code, fieldStart + field[1].length),
+      usage: optional ? 'optional' : 'required',
+    });
+  }
+
+  return keys;
+}
+
+/**
+ * Struct-driven env loaders and the type they deserialize into
+ */
+function findEnvLoaders(source: RustSource): EnvLoader[] {
+  const { code, masked } = source;
+  const loaders: EnvLoader[] = [];
+  const chainAt = (offset: number) => code.slice(...statementRange(masked, offset));
+
+  const envyRegex = /\benvy::(?:prefixed\s*\(\s*"([^"]*)"\s*\)\s*\.\s*)?from_env\b/g;
+  for (const match of code.matchAll(envyRegex)) {
+    loaders.push({ prefix: match[1] ?? '', offset: match.index!, target: /\bfrom_env/ });
+  }
+
+  // config: Environment::with_prefix("APP") joins prefix and key with `_` unless configured
+  for (const match of code.matchAll(
+    /\bEnvironment::(?:with_prefix\s*\(\s*"([^"]*)"\s*\)|default\s*\(\s*\)|new\s*\(\s*\))/g
+  )) {
+    const chain = chainAt(match.index!);
+    const prefix = match[1] ?? /\.\s*prefix\s*\(\s*"([^"]*)"/.exec(chain)?.[1];
+    const prefixSeparator = /\.\s*prefix_separator\s*\(\s*"([^"]*)"/.exec(chain)?.[1] ?? '_';
+    loaders.push({
+      prefix: prefix ? prefix + prefixSeparator : '',
+      separator: /\.\s*separator\s*\(\s*"([^"]*)"/.exec(chain)?.[1],
+      offset: match.index!,
+      target: /\b(?:try_deserialize|try_into|deserialize)\b/,
+    });
+  }
+
+  // figment: Env::prefixed("APP_") keeps the prefix verbatim; .split("__") enables nesting
+  const figmentRegex = /\bEnv::(?:prefixed\s*\(\s*"([^"]*)"\s*\)|raw\s*\(\s*\))/g;
+  for (const match of code.matchAll(figmentRegex)) {
+    const chain = chainAt(match.index!);
+    loaders.push({
+      prefix: match[1] ?? '',
+      separator: /\.\s*split\s*\(\s*"([^"]*)"/.exec(chain)?.[1],
+      offset: match.index!,
+      target: /\bextract(?:_inner)?\b/,
+    });
+  }
+
+  return loaders;
+}
+
+function loaderKeys(
+  source: RustSource,
+  loader: EnvLoader,
+  structs: Map<string, RustStruct>
+): RustEnvKey[] {
+  const structName = targetStruct(source, loader);
+  const target = structName ? structs.get(structName) : undefined;
+
+  if (!target) {
+    // Unknown shape: still surface the prefix so the key family isn't lost
+    if (!loader.prefix) return [];
+    return [
+      {
+        key: `${loader.prefix}*`,
+        file: source.file,
+        line: lineAt(source.code, loader.offset),
+        usage: 'read',
+      },
+    ];
+  }
+
+  return structKeys(target, loader.prefix, loader.separator, structs, new Set([structName!]));
+}
+
+function structKeys(
+  target: RustStruct,
+  prefix: string,
+  separator: string | undefined,
+  structs: Map<string, RustStruct>,
+  seen: Set<string>
+): RustEnvKey[] {
+  const keys: RustEnvKey[] = [];
+
+  for (const field of target.fields) {
+    const serde = field.attrs.match(/#\[\s*serde\s*\(([^\]]*)\)\s*\]/g)?.join(' ') ?? '';
+    if (/\bskip(?:_deserializing)?\b/.test(serde)) continue;
+
+    const innerType = /^Option\s*<\s*([\w:]+)\s*>$/.exec(field.type)?.[1] ?? field.type;
+    const nestedName = innerType.split('::').pop()!;
+    const nested = !seen.has(nestedName) ? structs.get(nestedName) : undefined;
+    const name = (/\brename\s*=\s*"([^"]+)"/.exec(serde)?.[1] ?? field.name).toUpperCase();
+
+    if (nested && /\bflatten\b/.test(serde)) {
+      keys.push(...structKeys(nested, prefix, separator, structs, new Set([...seen, nestedName])));
+      continue;
+    }
+    if (nested) {
+      if (separator !== undefined) {
+        const nestedPrefix = `${prefix}${name}${separator}`;
+        const nestedSeen = new Set([...seen, nestedName]);
+        keys.push(...structKeys(nested, nestedPrefix, separator, structs, nestedSeen));
+      }
+      continue;
+    }
+
+    const optional = /^Option\s*</.test(field.type) || /\bdefault\b/.test(serde);
+    keys.push({
+      key: `${prefix}${name}`,
+      file: target.file,
+      line: field.line,
+      usage: optional ? 'optional' : 'required',
+    });
+  }
+
+  return keys;
+}
+
+/**
+ * The struct a loader deserializes into: a turbofish on the terminal call, a `let x: T`
+ * annotation on the statement, or the enclosing function's return type
+ */
+function targetStruct(source: RustSource, loader: EnvLoader): string | undefined {
+  const { masked } = source;
+  const statement = masked.slice(...statementRange(masked, loader.offset));
+  const terminal = new RegExp(loader.target.source + String.raw`\s*::\s*<\s*([\w:]+)`).exec(
+    statement
+  );
+  if (terminal) return lastSegment(terminal[1]);
+
+  const annotated = /^\s*let\s+(?:mut\s+)?\w+\s*:\s*([\w:]+)\s*=/.exec(statement);
+  if (annotated) return lastSegment(annotated[1]);
+
+  const preceding = masked.slice(0, loader.offset);
+  const returnType = lastMatch(preceding, /\bfn\s+\w+[^{;]*?->\s*([^{;]+)\{/g)?.[1].trim();
+  if (!returnType) return undefined;
+  const inner = /^(?:[\w:]*Result)\s*<\s*([\w:]+)/.exec(returnType)?.[1] ?? returnType;
+  if (inner === 'Self') {
+    const owner = lastMatch(preceding, /\bimpl\b(?:\s*<[^>]*>)?\s+(?:[\w:]+\s+for\s+)?([\w:]+)/g);
+    return owner ? lastSegment(owner[1]) : undefined;
+  }
+  return /^[\w:]+$/.test(inner) ? lastSegment(inner) : undefined;
+}
+
+/**
+ * `struct Name { field: Type, ... }` definitions with per-field attributes
+ */
+function parseStructs(source: RustSource): Map<string, StructField[]> {
+  const { code, masked } = source;
+  const structs = new Map<string, StructField[]>();
+  const structRegex = /\bstruct\s+([A-Za-z_]\w*)\s*(?:<[^{;]*>)?\s*(?:where[^{;]*)?\{/g;
+
+  for (const match of masked.matchAll(structRegex)) {
+    const bodyStart = match.index! + match[0].length;
+    let depth = 1;
+    let fieldStart = bodyStart;
+    const fields: StructField[] = [];
+
+    for (let i = bodyStart; i < masked.length && depth > 0; i++) {
+      const char = masked[i];
+      if ('([{<'.includes(char)) depth++;
+      else if (')]}>'.includes(char) && !(char === '>' && masked[i - 1] === '-')) depth--;
+
+      if ((char === ',' && depth === 1) || depth === 0) {
+        const chunk = masked.slice(fieldStart, i);
+        const field = /(?:pub(?:\([^)]*\))?\s+)?([A-Za-z_]\w*)\s*:\s*([\s\S]+)$/.exec(
+          chunk.replace(/#\[[^\]]*\]/g, attr => ' '.repeat(attr.length))
+        );
+        if (field) {
+          const nameOffset = fieldStart + field.index + field[0].indexOf(field[1]);
+          fields.push({
+            name: field[1],
+            type: field[2].replace(/\s+/g, ''),
+            line: lineAt(code, nameOffset),
+            attrs: code.slice(fieldStart, nameOffset),
+          });
+        }
+        fieldStart = i + 1;
+      }
+    }
+    structs.set(match[1], fields);
+  }
+
+  return structs;
+}
+
+/**
+ * `const NAME: &str = "VALUE";` so `env::var(NAME)` resolves to its key
+ */
+function stringConstants(code: string): Map<string, string> {
+  const constants = new Map<string, string>();
+  for (const match of code.matchAll(
+    /\b(?:const|static)\s+([A-Z][A-Z0-9_]*)\s*:\s*&(?:'static\s+)?str\s*=\s*"([^"]+)"/g
+  )) {
+    constants.set(match[1], match[2]);
+  }
+  return constants;
+}
+
+/**
+ * Range of the statement containing `offset`: from the previous `;`, `{` or `}` outside the
+ * enclosing call arguments to the next one
+ */
+function statementRange(text: string, offset: number): [number, number] {
+  let start = offset;
+  for (let depth = 0; start > 0; start--) {
+    const char = text[start - 1];
+    if (char === ')' || char === ']') depth++;
+    else if ((char === '(' || char === '[') && depth > 0) depth--;
+    else if (depth === 0 && (char === ';' || char === '{' || char === '}')) break;
+  }
+
+  let end = offset;
+  for (let depth = 0; end < text.length; end++) {
+    const char = text[end];
+    if (char === '(' || char === '[' || char === '{') depth++;
+    else if ((char === ')' || char === ']' || char === '}') && depth > 0) depth--;
+    else if (depth === 0 && (char === ';' || char === '}')) break;
+  }
+
+  return [start, end];
+}
+
+/**
+ * Method chain applied to an expression ending at `offset`, with call arguments emptied:
+ * `.map(|v| v.parse().unwrap()).unwrap_or(80);` -> `.map().unwrap_or()`
+ */
+function topLevelChain(text: string, offset: number): string {
+  let chain = '';
+  let depth = 0;
+  for (let i = offset; i < text.length && chain.length < 200; i++) {
+    const char = text[i];
+    if (char === '(' || char === '[' || char === '{') {
+      if (depth++ === 0) chain += char;
+    } else if (char === ')' || char === ']' || char === '}') {
+      if (depth === 0) break;
+      if (--depth === 0) chain += char;
+    } else if (depth === 0) {
+      if (char === ';' || char === ',') break;
+      chain += char;
+    }
+  }
+  return chain;
+}
+
+function lastMatch(text: string, regex: RegExp): RegExpMatchArray | undefined {
+  const matches = Array.from(text.matchAll(regex));
+  return matches[matches.length - 1];
+}
+
+function lastSegment(typePath: string): string {
+  return typePath.split('::').pop()!;
+}
@@ -0,0 +1,429 @@
+/**
+ * @fileOverview: Cargo feature map and #[cfg(feature)] usage index
+ * @module: RustFeatures
+ * @keyFunctions:
+ *   - parseCargoFeatures(): Read a manifest's [features] table incl. `dep:` and `crate/feature`
+ *   - resolveFeatureEnablement(): Transitive closure of what enabling one feature turns on
+ *   - extractCfgFeatureSites(): Index `#[cfg(..)]`, `#[cfg_attr(..)]` and `cfg!(..)` feature gates
+ *   - analyzeRustFeatures(): Feature tables of every workspace member plus all gated code
+ *   - findFeatureGatedCode(): Code that only compiles when a feature is enabled
+ * @context: Answers "what does feature X turn on" and "what compiles only with X". Gates are
+ *           reduced to the features that must be on (or off) for the predicate to hold, so
+ *           `all(feature = "a", unix)` requires `a` while `any(feature = "a", feature = "b")`
+ *           requires neither on its own.
+ */
+
+import * as path from 'path';
+import { readFile } from 'fs/promises';
+import { FileInfo } from '../../../core/compactor/fileDiscovery';
+import { logger } from '../../../utils/logger';
+import { analyzeCargoWorkspace, crateForFile, readCargoManifest } from './cargoWorkspace';
+import { asStringList, asTable, TomlTable } from './toml';
+import { toPosix } from './pathUtils';
+import { resolveModDeclaration } from './rustModules';
+import { lineAt, maskRustSource, matchBracket } from './rustSource';
+import {
+  CfgPredicate,
+  cfgGatedItem,
+  childModuleDir,
+  parseCfgPredicate,
+  splitCfgArgs,
+} from './rustCfg';
+
+export interface CargoFeatureForward {
+  dependency: string;
+  feature: string;
+  weak: boolean; // `dep?/feature` only applies if something else enables the dependency
+}
+
+export interface CargoFeature {
+  name: string;
+  implicit: boolean; // optional dependency without a `dep:` reference
+  features: string[]; // other features of the same crate
+  dependencies: string[]; // optional dependencies switched on
+  forwards: CargoFeatureForward[];
+}
+
+export interface CrateFeatures {
+  crate: string;
+  dir: string;
+  manifestPath: string;
+  defaults: string[];
+  features: CargoFeature[];
+}
+
+export interface FeatureEnablement {
+  crate: string;
+  feature: string;
+  features: string[]; // transitively enabled, other members written `crate/feature`
+  dependencies: string[]; // optional dependencies pulled in, other members written `crate/dep`
+  external: string[]; // features forwarded to crates outside the workspace
+}
+
+export type CfgFeatureSiteKind = 'cfg' | 'cfg_attr' | 'cfg!';
+
+export interface CfgFeatureSite {
+  file: string;
+  line: number;
+  endLine: number;
+  kind: CfgFeatureSiteKind;
+  predicate: string; // as written, e.g. `all(feature = "tls", not(feature = "native-tls"))`
+  required: string[]; // features that must be enabled for the predicate to hold
+  excluded: string[]; // features that must be disabled
+  mentioned: string[];
+  item?: string; // gated item, e.g. `fn connect`, `mod tls`, `impl Drop for Conn`
+  gatedFile?: string; // whole file behind the gate (`#[cfg] mod x;` or `#![cfg]`)
+  crate?: string;
+}
+
+export interface RustFeatureMap {
+  crates: CrateFeatures[];
+  sites: CfgFeatureSite[];
+}
+
+interface Gate {
+  on: string[];
+  off: string[];
+}
+
+const CFG_ATTRIBUTE = /#(!?)\[\s*(cfg|cfg_attr)\s*\(/g;
+const CFG_MACRO = /(?<![\w:])cfg!\s*\(/g;
+
+/**
+ * Parse the [features] table of a manifest. Optional dependencies that no feature references
+ * through `dep:` become implicit features, as Cargo does
+ */
+export function parseCargoFeatures(
+  manifest: TomlTable,
+  crate: { name: string; dir: string; manifestPath: string }
+): CrateFeatures {
+  const table = asTable(manifest.features) ?? {};
+  const declared = new Set(Object.keys(table));
+  const optional = optionalDependencies(manifest);
+  const explicitDeps = new Set(
+    Object.values(table)
+      .flatMap(value => asStringList(value))
+      .filter(value => value.startsWith('dep:'))
+      .map(value => value.slice(4))
+  );
+
+  const features: CargoFeature[] = [];
+  for (const [name, value] of Object.entries(table)) {
+    if (name === 'default') continue;
+    features.push(describeFeature(name, asStringList(value), declared, optional));
+  }
+  for (const dep of optional) {
+    if (declared.has(dep) || explicitDeps.has(dep)) continue;
+    features.push({ name: dep, implicit: true, features: [], dependencies: [dep], forwards: [] });
+  }
+
+  return {
+    crate: crate.name,
+    dir: crate.dir,
+    manifestPath: crate.manifestPath,
+    defaults: asStringList(table.default),
+    features: features.sort((a, b) => a.name.localeCompare(b.name)),
+  };
+}
+
+/**
+ * Everything enabling `feature` of `crate` turns on, following forwards into other members
+ */
+export function resolveFeatureEnablement(
+  crates: CrateFeatures[],
+  crateName: string,
+  feature: string
+): FeatureEnablement | undefined {
+  const byName = new Map(crates.map(entry => [entry.crate, entry]));
+  const home = byName.get(crateName);
+  if (!home || (feature !== 'default' && !home.features.some(f => f.name === feature))) {
+    return undefined;
+  }
+
+  const qualify = (crate: string, name: string) =>
+    crate === crateName ? name : `${crate}/${name}`;
+  const result: FeatureEnablement = {
+    crate: crateName,
+    feature,
+    features: [],
+    dependencies: [],
+    external: [],
+  };
+  const seen = new Set<string>();
+  const queue: Array<[string, string]> = [[crateName, feature]];
+
+  while (queue.length > 0) {
+    const [crate, name] = queue.shift()!;
+    const key = `${crate}/${name}`;
+    if (seen.has(key)) continue;
+    seen.add(key);
+
+    const entry = byName.get(crate);
+    if (!entry) continue;
+    if (name !== feature || crate !== crateName) {
+      result.features.push(qualify(crate, name));
+    }
+
+    const def = entry.features.find(f => f.name === name);
+    const names = name === 'default' ? entry.defaults : def?.features;
+    for (const next of names ?? []) queue.push([crate, next]);
+    for (const dep of def?.dependencies ?? []) {
+      const qualified = qualify(crate, dep);
+      if (!result.dependencies.includes(qualified)) result.dependencies.push(qualified);
+    }
+    for (const forward of def?.forwards ?? []) {
+      // `dep?/feat` turns nothing on by itself
+      if (forward.weak) continue;
+      if (byName.has(forward.dependency)) {
+        queue.push([forward.dependency, forward.feature]);
+      } else {
+        const external = `${forward.dependency}/${forward.feature}`;
+        if (!result.external.includes(external)) result.external.push(external);
+      }
+    }
+  }
+
+  return result;
+}
+
+/**
+ * Index feature gates in Rust sources (relPath -> content). Gates that don't mention a
+ * feature (`cfg(unix)`, `cfg(test)`) are skipped
+ */
+export function extractCfgFeatureSites(sources: Map<string, string>): CfgFeatureSite[] {
+  const fileSet = new Set(sources.keys());
+  const sites: CfgFeatureSite[] = [];
+
+  for (const [file, content] of sources) {
+    const { code, masked } = maskRustSource(content);
+    let match: RegExpExecArray | null;
+
+    CFG_ATTRIBUTE.lastIndex = 0;
+    while ((match = CFG_ATTRIBUTE.exec(masked)) !== null) {
+      const open = match.index + match[0].length - 1;
+      const close = matchBracket(masked, open);
+      const args = code.slice(open + 1, close);
+      const predicate = match[2] === 'cfg_attr' ? splitCfgArgs(args)[0] ?? '' : args;
+      const site = describeGate(file, predicate, match[2] as CfgFeatureSiteKind);
+      if (!site) continue;
+
+      const attrEnd = masked.indexOf(']', close) + 1;
+      site.line = lineAt(masked, match.index);
+      if (match[1] === '!') {
+        // Inner attribute: the enclosing module (at the top of a file, the file itself)
+        site.gatedFile = file;
+        site.endLine = lineAt(masked, masked.length);
+      } else {
+        const item = cfgGatedItem(masked, attrEnd);
+        site.item = item?.label;
+        site.endLine = item ? lineAt(masked, item.end) : site.line;
+        if (item?.module && match[2] === 'cfg') {
+          const pathAttr = /#\[\s*path\s*=\s*"([^"]+)"/.exec(code.slice(attrEnd, item.start))?.[1];
+          site.gatedFile = resolveModDeclaration(
+            file,
+            childModuleDir(file),
+            item.module,
+            pathAttr,
+            fileSet
+          );
+        }
+      }
+      sites.push(site);
+    }
+
+    CFG_MACRO.lastIndex = 0;
+    while ((match = CFG_MACRO.exec(masked)) !== null) {
+      const open = match.index + match[0].length - 1;
+      const close = matchBracket(masked, open);
+      const site = describeGate(file, code.slice(open + 1, close), 'cfg!');
+      if (!site) continue;
+      site.line = lineAt(masked, match.index);
+      site.endLine = site.line;
+      sites.push(site);
+    }
+  }
+
+  return sites.sort((a, b) => a.file.localeCompare(b.file) || a.line - b.line);
+}
+
+/**
+ * Feature tables of the Cargo workspace at projectPath and the gated code in its Rust files
+ */
+export async function analyzeRustFeatures(
+  projectPath: string,
+  files: FileInfo[]
+): Promise<RustFeatureMap | undefined> {
+  const workspace = analyzeCargoWorkspace(projectPath);
+  if (!workspace || workspace.members.length === 0) return undefined;
+
+  const crates: CrateFeatures[] = [];
+  for (const member of workspace.members) {
+    const manifest = readCargoManifest(path.join(workspace.root, member.manifestPath));
+    if (manifest) crates.push(parseCargoFeatures(manifest, member));
+  }
+
+  const sources = new Map<string, string>();
+  for (const file of files) {
+    if (file.language !== 'rust') continue;
+    try {
+      sources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf-8'));
+    } catch (error) {
+      logger.warn('Could not read file for feature analysis', {
+        file: file.relPath,
+        error: (error as Error).message,
+      });
+    }
+  }
+
+  const sites = extractCfgFeatureSites(sources);
+  for (const site of sites) {
+    site.crate = crateForFile(workspace, site.file)?.name;
+  }
+  return { crates, sites };
+}
+
+/**
+ * Gates that require `feature`, i.e. code that is compiled only when it is enabled.
+ * `crate` narrows the search when several members declare a feature of the same name
+ */
+export function findFeatureGatedCode(
+  map: RustFeatureMap,
+  feature: string,
+  crate?: string
+): CfgFeatureSite[] {
+  return map.sites.filter(
+    site => site.required.includes(feature) && (!crate || !site.crate || site.crate === crate)
+  );
+}
+
+// ===== Manifest =====
+
+function optionalDependencies(manifest: TomlTable): string[] {
+  const names = new Set<string>();
+  const tables: Array<TomlTable | undefined> = [
+    asTable(manifest.dependencies),
+    asTable(manifest['build-dependencies']),
+  ];
+  for (const target of Object.values(asTable(manifest.target) ?? {})) {
+    tables.push(asTable(asTable(target)?.dependencies));
+  }
+  for (const table of tables) {
+    for (const [name, spec] of Object.entries(table ?? {})) {
+      if (asTable(spec)?.optional === true) names.add(name);
+    }
+  }
+  return Array.from(names);
+}
+
+function describeFeature(
+  name: string,
+  values: string[],
+  declared: Set<string>,
+  optional: string[]
+): CargoFeature {
+  const feature: CargoFeature = {
+    name,
+    implicit: false,
+    features: [],
+    dependencies: [],
+    forwards: [],
+  };
+  const addDependency = (dep: string) => {
+    if (!feature.dependencies.includes(dep)) feature.dependencies.push(dep);
+  };
+
+  for (const value of values) {
+    if (value.startsWith('dep:')) {
+      addDependency(value.slice(4));
+      continue;
+    }
+
+    const slash = value.indexOf('/');
+    if (slash === -1) {
+      // A plain name refers to a feature, which may be an optional dependency's implicit one
+      if (declared.has(value) || optional.includes(value)) feature.features.push(value);
+      continue;
+    }
+
+    const weak = value[slash - 1] === '?';
+    const dependency = value.slice(0, weak ? slash - 1 : slash);
+    feature.forwards.push({ dependency, feature: value.slice(slash + 1), weak });
+    // `dep/feat` also switches the dependency on; `dep?/feat` does not
+    if (!weak && optional.includes(dependency)) {
+      if (declared.has(dependency)) feature.features.push(dependency);
+      else addDependency(dependency);
+    }
+  }
+
+  return feature;
+}
+
+// ===== Gates =====
+
+function describeGate(
+  file: string,
+  predicate: string,
+  kind: CfgFeatureSiteKind
+): CfgFeatureSite | undefined {
+  const mentioned = Array.from(
+    new Set(Array.from(predicate.matchAll(/\bfeature\s*=\s*"([^"]*)"/g), match => match[1]))
+  );
+  if (mentioned.length === 0) return undefined;
+
+  let gate: Gate;
+  try {
+    gate = evaluate(parseCfgPredicate(predicate), true);
+  } catch {
+    gate = { on: [], off: [] };
+  }
+
+  return {
+    file,
+    line: 0,
+    endLine: 0,
+    kind,
+    predicate: predicate.replace(/\s+/g, ' ').trim(),
+    required: gate.on,
+    excluded: gate.off,
+    mentioned,
+  };
+}
+
+/**
+ * Features forced on/off when the predicate evaluates to `holds`
+ */
+function evaluate(predicate: CfgPredicate, holds: boolean): Gate {
+  switch (predicate.op) {
+    case 'option': {
+      const name = predicate.key === 'feature' ? predicate.value : undefined;
+      if (name === undefined) return { on: [], off: [] };
+      return holds ? { on: [name], off: [] } : { on: [], off: [name] };
+    }
+    case 'not':
+      return predicate.args[0] ? evaluate(predicate.args[0], !holds) : { on: [], off: [] };
+    case 'all':
+    case 'any': {
+      const gates = predicate.args.map(arg => evaluate(arg, holds));
+      // all() holding and any() failing constrain every argument; the other two only
+      // constrain what all arguments agree on
+      const every = (predicate.op === 'all') === holds;
+      return every ? unionGates(gates) : intersectGates(gates);
+    }
+  }
+}
+
+function unionGates(gates: Gate[]): Gate {
+  return {
+    on: Array.from(new Set(gates.flatMap(gate => gate.on))),
+    off: Array.from(new Set(gates.flatMap(gate => gate.off))),
+  };
+}
+
+function intersectGates(gates: Gate[]): Gate {
+  if (gates.length === 0) return { on: [], off: [] };
+  const [first, ...rest] = gates;
+  return {
+    on: first.on.filter(name => rest.every(gate => gate.on.includes(name))),
+    off: first.off.filter(name => rest.every(gate => gate.off.includes(name))),
+  };
+}
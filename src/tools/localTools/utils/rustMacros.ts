@@ -0,0 +1,388 @@
+/**
+ * @fileOverview: Rust macro definitions and invocation sites
+ * @module: RustMacros
+ * @keyFunctions:
+ *   - buildRustMacroIndex(): Index macro_rules! and proc-macro definitions and every invocation
+ *   - loadRustMacroIndex(): Read the Rust files of a project and index them
+ *   - findMacroUsages(): Definitions and call sites of one macro
+ *   - buildMacroReferences(): Macro <-> calling function edges for the candidate call graph
+ * @context: Macros are invoked three ways: `name!(..)`, `#[name]` attribute macros and
+ *           `#[derive(Name)]`. Invocations are attributed to the enclosing function (bang macros)
+ *           or the annotated item (attribute and derive macros), so a macro links to its callers.
+ *           Built-in attributes and common derive helpers (`#[serde(..)]`) are not invocations.
+ */
+
+import { readFile } from 'fs/promises';
+import { FileInfo } from '../../../core/compactor/fileDiscovery';
+import { logger } from '../../../utils/logger';
+import { toPosix } from './pathUtils';
+import { lineAt, maskRustSource, matchBracket, precedingAttributes } from './rustSource';
+
+export type RustMacroKind = 'declarative' | 'function' | 'derive' | 'attribute';
+
+export interface RustMacroDef {
+  name: string; // as invoked: `define_metric`, `Metric` for a derive
+  kind: RustMacroKind;
+  file: string;
+  line: number;
+  start: number;
+  end: number;
+  fnName?: string; // implementing function of a proc macro
+  helpers: string[]; // helper attributes a derive macro accepts
+  exported: boolean; // #[macro_export], or any proc macro
+}
+
+export interface RustMacroInvocation {
+  name: string; // last path segment
+  path: string; // as written, e.g. `tracing::instrument`
+  kind: 'bang' | 'attribute' | 'derive';
+  file: string;
+  line: number;
+  start: number;
+  end: number;
+  caller?: string; // enclosing function, or the item an attribute/derive is attached to
+}
+
+export interface RustMacroIndex {
+  definitions: RustMacroDef[];
+  invocations: RustMacroInvocation[];
+}
+
+interface FnRange {
+  name: string;
+  start: number;
+  end: number;
+}
+
+const MACRO_RULES = /\bmacro_rules!\s*([A-Za-z_]\w*)/g;
+const PROC_MACRO_ATTR =
+  /#\[\s*(proc_macro|proc_macro_derive|proc_macro_attribute)\b\s*(?:\(([^\]]*)\))?\s*\]/g;
+const BANG_INVOCATION = /(?<![\w:$])((?:\$?[A-Za-z_]\w*\s*::\s*)*)([A-Za-z_]\w*)!\s*([(\[{])/g;
+const ATTRIBUTE = /#!?\[\s*((?:[A-Za-z_]\w*\s*::\s*)*[A-Za-z_]\w*)/g;
+const ANNOTATED_ITEM = new RegExp(
+  String.raw`^\s*(?:#!?\[[^\]]*\]\s*)*(?:pub(?:\s*\([^)]*\))?\s+)?` +
+    String.raw`(?:(?:const|async|unsafe|extern\s+"[^"]*"|default)\s+)*` +
+    String.raw`(?:fn|struct|enum|union|trait|mod|type|static|const)\s+([A-Za-z_]\w*)`
+);
+
+const PROC_MACRO_KINDS: Record<string, RustMacroKind> = {
+  proc_macro: 'function',
+  proc_macro_derive: 'derive',
+  proc_macro_attribute: 'attribute',
+};
+
+// Attributes the compiler and rustdoc handle themselves
+const BUILTIN_ATTRIBUTES = new Set([
+  'allow',
+  'automatically_derived',
+  'bench',
+  'cfg',
+  'cfg_attr',
+  'cold',
+  'crate_name',
+  'crate_type',
+  'deny',
+  'deprecated',
+  'derive',
+  'doc',
+  'expect',
+  'export_name',
+  'feature',
+  'forbid',
+  'global_allocator',
+  'ignore',
+  'inline',
+  'link',
+  'link_name',
+  'link_section',
+  'macro_export',
+  'macro_use',
+  'must_use',
+  'no_implicit_prelude',
+  'no_main',
+  'no_mangle',
+  'no_std',
+  'non_exhaustive',
+  'panic_handler',
+  'path',
+  'proc_macro',
+  'proc_macro_attribute',
+  'proc_macro_derive',
+  'recursion_limit',
+  'repr',
+  'should_panic',
+  'target_feature',
+  'test',
+  'track_caller',
+  'type_length_limit',
+  'used',
+  'warn',
+  'windows_subsystem',
+]);
+
+// Inert helper attributes of widely used derives; they configure a derive, they don't expand
+const COMMON_DERIVE_HELPERS = new Set([
+  'arg',
+  'backtrace',
+  'builder',
+  'clap',
+  'command',
+  'default',
+  'diesel',
+  'error',
+  'from',
+  'garde',
+  'graphql',
+  'schemars',
+  'sea_orm',
+  'serde',
+  'serde_as',
+  'source',
+  'sqlx',
+  'strum',
+  'validate',
+  'value',
+]);
+
+// Tool attributes (`#[rustfmt::skip]`, `#[clippy::msrv]`) are namespaced but not macros
+const TOOL_NAMESPACES = new Set(['rustfmt', 'clippy', 'rustdoc', 'diagnostic']);
+
+/**
+ * Index macro definitions and invocations of a project's Rust sources (relPath -> content)
+ */
+export function buildRustMacroIndex(sources: Map<string, string>): RustMacroIndex {
+  const index: RustMacroIndex = { definitions: [], invocations: [] };
+  const masks = new Map<string, string>();
+
+  for (const [file, content] of sources) {
+    const masked = maskRustSource(content).masked;
+    masks.set(file, masked);
+    indexDefinitions(file, masked, index);
+  }
+
+  // Helpers declared by the project's own derives (`attributes(metric)`) are inert too
+  const helpers = new Set(index.definitions.flatMap(def => def.helpers));
+  for (const [file, masked] of masks) {
+    indexInvocations(file, masked, helpers, index);
+  }
+
+  return index;
+}
+
+/**
+ * Read the Rust files in a file set and index them; unreadable files are skipped
+ */
+export async function loadRustMacroIndex(files: FileInfo[]): Promise<RustMacroIndex> {
+  const sources = new Map<string, string>();
+  for (const file of files) {
+    if (file.language !== 'rust') continue;
+    try {
+      sources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf-8'));
+    } catch (error) {
+      logger.warn('Could not read file for macro analysis', {
+        file: file.relPath,
+        error: (error as Error).message,
+      });
+    }
+  }
+  return buildRustMacroIndex(sources);
+}
+
+/**
+ * Definitions and invocations of a macro. A string matches the name exactly, with or
+ * without the trailing `!` (`define_metric!`); a RegExp is tested against name and path
+ */
+export function findMacroUsages(index: RustMacroIndex, name: string | RegExp): RustMacroIndex {
+  const wanted = typeof name === 'string' ? name.replace(/!$/, '').split('::').pop()! : name;
+  const matches = (candidate: string, path: string) =>
+    typeof wanted === 'string' ? candidate === wanted : wanted.test(candidate) || wanted.test(path);
+
+  return {
+    definitions: index.definitions.filter(def => matches(def.name, def.name)),
+    invocations: index.invocations.filter(call => matches(call.name, call.path)),
+  };
+}
+
+/**
+ * Call-graph edges between project macros and the functions that invoke them, keyed the way
+ * candidate ranking keys symbols (`<file>:<symbol>`). Definitions use the macro name; call
+ * sites use the calling function, or `name!` for invocations outside any function
+ */
+export function buildMacroReferences(
+  index: RustMacroIndex,
+  resolveFile: (relPath: string) => string = relPath => relPath
+): Map<string, string[]> {
+  const references = new Map<string, string[]>();
+  const link = (from: string, to: string) => {
+    const edges = references.get(from) || [];
+    if (!edges.includes(to)) edges.push(to);
+    references.set(from, edges);
+  };
+
+  const definitionsByName = new Map<string, RustMacroDef[]>();
+  for (const def of index.definitions) {
+    definitionsByName.set(def.name, [...(definitionsByName.get(def.name) || []), def]);
+  }
+
+  for (const call of index.invocations) {
+    for (const def of definitionsByName.get(call.name) || []) {
+      const defKey = `${resolveFile(def.file)}:${def.name}`;
+      const callKey = `${resolveFile(call.file)}:${call.caller ?? `${call.name}!`}`;
+      link(defKey, callKey);
+      link(callKey, defKey);
+    }
+  }
+
+  return references;
+}
+
+// ===== Indexing =====
+
+function indexDefinitions(file: string, masked: string, index: RustMacroIndex): void {
+  let match: RegExpExecArray | null;
+
+  MACRO_RULES.lastIndex = 0;
+  while ((match = MACRO_RULES.exec(masked)) !== null) {
+    const open = masked.slice(match.index + match[0].length).search(/[({[]/);
+    const attrs = precedingAttributes(masked, match.index);
+    index.definitions.push({
+      name: match[1],
+      kind: 'declarative',
+      file,
+      line: lineAt(masked, match.index),
+      start: match.index,
+      end:
+        open === -1
+          ? masked.length
+          : matchBracket(masked, match.index + match[0].length + open) + 1,
+      helpers: [],
+      exported: attrs.some(attr => /^#\[\s*macro_export\b/.test(attr)),
+    });
+  }
+
+  PROC_MACRO_ATTR.lastIndex = 0;
+  while ((match = PROC_MACRO_ATTR.exec(masked)) !== null) {
+    const fn = /^[^{;]*?\bfn\s+([A-Za-z_]\w*)/.exec(masked.slice(match.index + match[0].length));
+    if (!fn) continue;
+    const kind = PROC_MACRO_KINDS[match[1]];
+    const args = match[2] || '';
+    // #[proc_macro_derive(Metric, attributes(metric, label))]
+    const derived = /^\s*([A-Za-z_]\w*)/.exec(args)?.[1];
+    const helpers = /attributes\s*\(([^)]*)\)/.exec(args)?.[1];
+    const fnStart = match.index + match[0].length + fn.index;
+    const bodyOpen = masked.indexOf('{', fnStart);
+
+    index.definitions.push({
+      name: kind === 'derive' && derived ? derived : fn[1],
+      kind,
+      file,
+      line: lineAt(masked, match.index),
+      start: match.index,
+      end: bodyOpen === -1 ? fnStart + fn[0].length : matchBracket(masked, bodyOpen) + 1,
+      fnName: fn[1],
+      helpers: helpers ? helpers.split(',').map(helper => helper.trim()).filter(Boolean) : [],
+      exported: true,
+    });
+  }
+}
+
+function indexInvocations(
+  file: string,
+  masked: string,
+  helpers: Set<string>,
+  index: RustMacroIndex
+): void {
+  const fns = functionRanges(masked);
+  const enclosing = (offset: number) =>
+    fns.filter(fn => fn.start <= offset && offset < fn.end).pop()?.name;
+  let match: RegExpExecArray | null;
+
+  BANG_INVOCATION.lastIndex = 0;
+  while ((match = BANG_INVOCATION.exec(masked)) !== null) {
+    if (match[2] === 'macro_rules') continue;
+    const open = match.index + match[0].length - 1;
+    index.invocations.push({
+      name: match[2],
+      path: `${match[1]}${match[2]}`.replace(/\s+/g, ''),
+      kind: 'bang',
+      file,
+      line: lineAt(masked, match.index),
+      start: match.index,
+      end: matchBracket(masked, open) + 1,
+      caller: enclosing(match.index),
+    });
+  }
+
+  ATTRIBUTE.lastIndex = 0;
+  while ((match = ATTRIBUTE.exec(masked)) !== null) {
+    const path = match[1].replace(/\s+/g, '');
+    const segments = path.split('::');
+    const name = segments[segments.length - 1];
+    const open = masked.indexOf('[', match.index);
+    const close = matchBracket(masked, open);
+    const item = ANNOTATED_ITEM.exec(masked.slice(close + 1, close + 1000))?.[1];
+
+    if (path === 'derive') {
+      const list = /^\[\s*derive\s*\(([^)]*)\)/.exec(masked.slice(open, close + 1))?.[1] || '';
+      for (const written of list.split(',')) {
+        const derivePath = written.replace(/\s+/g, '');
+        if (!derivePath) continue;
+        index.invocations.push({
+          name: derivePath.split('::').pop()!,
+          path: derivePath,
+          kind: 'derive',
+          file,
+          line: lineAt(masked, match.index),
+          start: match.index,
+          end: close + 1,
+          caller: item,
+        });
+      }
+      continue;
+    }
+
+    const inert =
+      BUILTIN_ATTRIBUTES.has(name) || COMMON_DERIVE_HELPERS.has(name) || helpers.has(name);
+    if ((segments.length === 1 && inert) || TOOL_NAMESPACES.has(segments[0])) continue;
+
+    index.invocations.push({
+      name,
+      path,
+      kind: 'attribute',
+      file,
+      line: lineAt(masked, match.index),
+      start: match.index,
+      end: close + 1,
+      caller: item,
+    });
+  }
+}
+
+/**
+ * Function bodies in source order, so the last range containing an offset is the innermost
+ */
+function functionRanges(masked: string): FnRange[] {
+  const ranges: FnRange[] = [];
+  const fnRegex = /\bfn\s+([A-Za-z_]\w*)/g;
+  let match: RegExpExecArray | null;
+
+  while ((match = fnRegex.exec(masked)) !== null) {
+    const paramsOpen = masked.indexOf('(', match.index + match[0].length);
+    if (paramsOpen === -1) continue;
+    const paramsClose = matchBracket(masked, paramsOpen);
+
+    // `;` before any `{` means a bodiless trait method or extern declaration
+    let bodyOpen = -1;
+    for (let i = paramsClose + 1; i < masked.length; i++) {
+      if (masked[i] === ';') break;
+      if (masked[i] === '{') {
+        bodyOpen = i;
+        break;
+      }
+    }
+    if (bodyOpen === -1) continue;
+    ranges.push({ name: match[1], start: match.index, end: matchBracket(masked, bodyOpen) });
+  }
+
+  return ranges;
+}
@@ -0,0 +1,387 @@
+/**
+ * @fileOverview: Rust module-tree resolution for the import graph
+ * @module: RustModules
+ * @keyFunctions:
+ *   - createRustModuleResolver(): Index .rs files into crates and module paths
+ *   - findRustCrateRoots(): List lib and bin crate roots present in the file set
+ *   - expandRustUseTree(): Flatten `use a::{b, c::d}` trees into segment paths
+ * @context: Resolves `mod foo;` (incl. #[path]), `use crate::/super::/self::` and workspace-crate
+ *           paths to files, so import-graph ranking and one-hop expansion work on Rust code
+ */
+
+import * as path from 'path';
+import { FileInfo } from '../../../core/compactor/fileDiscovery';
+import { analyzeCargoWorkspace, CargoWorkspace } from './cargoWorkspace';
+import { toPosix } from './pathUtils';
+import { maskRustSource, splitTopLevel } from './rustSource';
+
+/**
+ * A compilation unit: the directory module paths are relative to (e.g. `crates/core/src`)
+ */
+interface RustUnit {
+  dir: string;
+  modulePath: string[];
+}
+
+export interface RustUsePath {
+  segments: string[];
+  glob: boolean;
+  alias?: string;
+}
+
+/**
+ * A crate root file (lib.rs, main.rs, src/bin/x.rs) and the name it is addressed by
+ */
+export interface RustCrateRoot {
+  name: string;
+  rootFile: string;
+  isLibrary: boolean;
+}
+
+export interface RustModuleResolver {
+  resolveImports(relPath: string, content: string): string[];
+}
+
+const SEPARATE_ROOT_DIRS = ['src/bin', 'tests', 'examples', 'benches'];
+
+/**
+ * Build a resolver over all Rust files in the project
+ */
+export function createRustModuleResolver(files: FileInfo[]): RustModuleResolver {
+  const rustFiles = files.filter(file => file.language === 'rust');
+  const fileSet = new Set(rustFiles.map(file => toPosix(file.relPath)));
+  const root = projectRootOf(rustFiles);
+  const cargo = root ? analyzeCargoWorkspace(root) : null;
+  const crateDirs = collectCrateDirs(rustFiles, cargo);
+  const libs = collectLibTargets(fileSet, cargo, root);
+
+  // Module key ("<unit dir>::a::b") -> file
+  const modules = new Map<string, string>();
+  for (const relPath of fileSet) {
+    const unit = unitForFile(relPath, crateDirs);
+    const key = moduleKey(unit.dir, unit.modulePath);
+    // lib.rs wins over main.rs for the shared crate root
+    if (!modules.has(key) || relPath.endsWith('/lib.rs') || relPath === 'lib.rs') {
+      modules.set(key, relPath);
+    }
+  }
+
+  const resolveModule = (dir: string, segments: string[]): string | undefined => {
+    // Longest module prefix that maps to a file; the rest are items inside it
+    for (let length = segments.length; length >= 0; length--) {
+      const file = modules.get(moduleKey(dir, segments.slice(0, length)));
+      if (file) return file;
+    }
+    return undefined;
+  };
+
+  return {
+    resolveImports(relPath: string, content: string): string[] {
+      const current = toPosix(relPath);
+      const unit = unitForFile(current, crateDirs);
+      // Doc comments often contain `use` examples; strings may hold braces or `//`
+      const { code, masked } = maskRustSource(content);
+      const inlineModules = findInlineModules(masked);
+      const resolved = new Set<string>();
+
+      // `mod foo;` declarations
+      // Crate roots and mod.rs files keep child modules beside them; src/foo.rs uses src/foo/
+      const childDir =
+        unit.modulePath.length === 0 || current.endsWith('/mod.rs') || current === 'mod.rs'
+          ? path.posix.dirname(current)
+          : current.replace(/\.rs$/, '');
+      for (const decl of findModDeclarations(code, masked)) {
+        const target = resolveModDeclaration(current, childDir, decl.name, decl.pathAttr, fileSet);
+        if (target) resolved.add(target);
+      }
+
+      // `use` declarations (and `extern crate`)
+      const useRegex = /\b(?:use|extern\s+crate)\s+([^;]+);/g;
+      let match: RegExpExecArray | null;
+      while ((match = useRegex.exec(masked)) !== null) {
+        const position = match.index;
+        const enclosing = inlineModules.filter(m => m.start < position && position < m.end);
+        const scope = [...unit.modulePath, ...enclosing.map(m => m.name)];
+        for (const segments of expandRustUseTree(match[1])) {
+          const target = resolveUsePath(segments, unit, scope, libs, modules, resolveModule);
+          if (target) resolved.add(target);
+        }
+      }
+
+      resolved.delete(current);
+      return Array.from(resolved);
+    },
+  };
+}
+
+/**
+ * Lib and bin crate roots present in the file set; names are the paths the crates are addressed by
+ */
+export function findRustCrateRoots(files: FileInfo[]): RustCrateRoot[] {
+  const rustFiles = files.filter(file => file.language === 'rust');
+  const fileSet = new Set(rustFiles.map(file => toPosix(file.relPath)));
+  const root = projectRootOf(rustFiles);
+  const cargo = root ? analyzeCargoWorkspace(root) : null;
+  const roots: RustCrateRoot[] = [];
+
+  if (cargo && cargo.members.length > 0) {
+    for (const member of cargo.members) {
+      for (const target of member.targets) {
+        if ((target.kind === 'lib' || target.kind === 'bin') && fileSet.has(target.path)) {
+          roots.push({
+            name: target.name.replace(/-/g, '_'),
+            rootFile: target.path,
+            isLibrary: target.kind === 'lib',
+          });
+        }
+      }
+    }
+    return roots;
+  }
+
+  for (const relPath of fileSet) {
+    const match = /^(?:(.*)\/)?src\/(lib|main)\.rs$/.exec(relPath);
+    if (!match) continue;
+    const crateDir = match[1] ?? '';
+    const name = path.posix.basename(crateDir || path.basename(root || '.')).replace(/-/g, '_');
+    roots.push({ name, rootFile: relPath, isLibrary: match[2] === 'lib' });
+  }
+  return roots.sort((a, b) => a.rootFile.localeCompare(b.rootFile));
+}
+
+/**
+ * Flatten a use tree into segment paths: `a::{b, c::{d as e, *}}` -> [a,b], [a,c,d], [a,c]
+ */
+export function expandRustUseTree(tree: string, prefix: string[] = []): string[][] {
+  return parseRustUseTree(tree, prefix).map(entry => entry.segments);
+}
+
+/**
+ * Flatten a use tree keeping renames and globs: `a::{b as c, d::*}` -> a::b as c, a::d::*
+ */
+export function parseRustUseTree(tree: string, prefix: string[] = []): RustUsePath[] {
+  const text = tree.replace(/\s+/g, ' ').trim();
+  const brace = text.indexOf('{');
+
+  if (brace === -1) {
+    const alias = /\s+as\s+(\w+)$/.exec(text)?.[1];
+    const segments = text
+      .replace(/\s+as\s+\w+$/, '')
+      .split('::')
+      .map(segment => segment.trim())
+      .filter(Boolean);
+    const glob = segments[segments.length - 1] === '*';
+    if (glob || segments[segments.length - 1] === 'self') segments.pop();
+    const joined = [...prefix, ...segments];
+    return joined.length > 0 ? [{ segments: joined, glob, ...(alias ? { alias } : {}) }] : [];
+  }
+
+  const head = text
+    .slice(0, brace)
+    .split('::')
+    .map(segment => segment.trim())
+    .filter(Boolean);
+  const inner = text.slice(brace + 1, text.lastIndexOf('}'));
+  return splitTopLevel(inner).flatMap(part => parseRustUseTree(part, [...prefix, ...head]));
+}
+
+function resolveUsePath(
+  segments: string[],
+  unit: RustUnit,
+  scope: string[],
+  libs: Map<string, RustUnit>,
+  modules: Map<string, string>,
+  resolveModule: (dir: string, segments: string[]) => string | undefined
+): string | undefined {
+  const [first, ...rest] = segments;
+  if (!first) return undefined;
+
+  if (first === 'crate') {
+    return resolveModule(unit.dir, rest);
+  }
+
+  if (first === 'self' || first === 'super') {
+    const base = [...scope];
+    let index = 0;
+    if (segments[0] === 'self') index = 1;
+    while (segments[index] === 'super') {
+      base.pop();
+      index++;
+    }
+    return resolveModule(unit.dir, [...base, ...segments.slice(index)]);
+  }
+
+  // Relative to the current module (2018 edition), e.g. `use models::User` after `mod models;`
+  if (modules.has(moduleKey(unit.dir, [...scope, first]))) {
+    return resolveModule(unit.dir, [...scope, ...segments]);
+  }
+
+  // Another crate in the workspace, addressed by its lib name
+  const lib = libs.get(first);
+  if (lib) {
+    return resolveModule(lib.dir, rest);
+  }
+
+  return undefined;
+}
+
+/**
+ * File for `mod name;` declared in `current`; childDir is where non-#[path] children live
+ */
+export function resolveModDeclaration(
+  current: string,
+  childDir: string,
+  name: string,
+  pathAttr: string | undefined,
+  fileSet: Set<string>
+): string | undefined {
+  if (pathAttr) {
+    // #[path] is relative to the directory of the declaring file
+    const dir = path.posix.dirname(current);
+    const target = path.posix.normalize(path.posix.join(dir, toPosix(pathAttr)));
+    return fileSet.has(target) ? target : undefined;
+  }
+
+  for (const candidate of [`${childDir}/${name}.rs`, `${childDir}/${name}/mod.rs`]) {
+    const normalized = candidate.replace(/^\.\//, '');
+    if (fileSet.has(normalized)) return normalized;
+  }
+  return undefined;
+}
+
+function findModDeclarations(
+  code: string,
+  masked: string
+): Array<{ name: string; pathAttr?: string }> {
+  const decls: Array<{ name: string; pathAttr?: string }> = [];
+  const modRegex =
+    /((?:#\[[^\]]*\]\s*)*)(?:pub(?:\s*\([^)]*\))?\s+)?mod\s+([A-Za-z_][A-Za-z0-9_]*)\s*;/g;
+  let match: RegExpExecArray | null;
+  while ((match = modRegex.exec(masked)) !== null) {
+    // `#[path = "..."]` values are blanked in the masked view
+    const attrs = code.slice(match.index, match.index + match[1].length);
+    const pathAttr = /#\[path\s*=\s*"([^"]+)"\s*\]/.exec(attrs)?.[1];
+    decls.push({ name: match[2], pathAttr });
+  }
+  return decls;
+}
+
+/**
+ * Byte ranges of inline `mod name { ... }` blocks, so `use super::*` inside `mod tests`
+ * resolves against the enclosing file
+ */
+function findInlineModules(code: string): Array<{ name: string; start: number; end: number }> {
+  const blocks: Array<{ name: string; start: number; end: number }> = [];
+  const inlineRegex = /\bmod\s+([A-Za-z_][A-Za-z0-9_]*)\s*\{/g;
+  let match: RegExpExecArray | null;
+  while ((match = inlineRegex.exec(code)) !== null) {
+    let depth = 0;
+    let end = code.length;
+    for (let i = match.index + match[0].length - 1; i < code.length; i++) {
+      if (code[i] === '{') depth++;
+      else if (code[i] === '}' && --depth === 0) {
+        end = i;
+        break;
+      }
+    }
+    blocks.push({ name: match[1], start: match.index, end });
+  }
+  return blocks;
+}
+
+function unitForFile(relPath: string, crateDirs: string[]): RustUnit {
+  // Deepest crate directory containing the file; '' is the project root
+  const crateDir =
+    crateDirs
+      .filter(dir => dir === '' || relPath.startsWith(dir + '/'))
+      .sort((a, b) => b.length - a.length)[0] ?? inferCrateDir(relPath);
+  const inCrate = crateDir ? relPath.slice(crateDir.length + 1) : relPath;
+
+  let unitDir = '';
+  for (const rootDir of [...SEPARATE_ROOT_DIRS, 'src']) {
+    if (inCrate.startsWith(rootDir + '/')) {
+      unitDir = rootDir;
+      break;
+    }
+  }
+
+  const inUnit = unitDir ? inCrate.slice(unitDir.length + 1) : inCrate;
+  const segments = inUnit.replace(/\.rs$/, '').split('/');
+  if (segments[segments.length - 1] === 'mod') {
+    segments.pop();
+  } else if (segments.length === 1 && (unitDir !== 'src' || /^(lib|main)$/.test(segments[0]))) {
+    // Crate roots: src/lib.rs, src/main.rs, src/bin/x.rs, tests/x.rs, ...
+    segments.pop();
+  }
+
+  return { dir: joinRel(crateDir, unitDir), modulePath: segments };
+}
+
+function collectCrateDirs(rustFiles: FileInfo[], cargo: CargoWorkspace | null): string[] {
+  if (cargo && cargo.members.length > 0) {
+    return cargo.members.map(member => (member.dir === '.' ? '' : member.dir));
+  }
+  return Array.from(new Set(rustFiles.map(file => inferCrateDir(toPosix(file.relPath)))));
+}
+
+/**
+ * Lib crates reachable by name (`use billing_core::...`), keyed by snake_case lib name
+ */
+function collectLibTargets(
+  fileSet: Set<string>,
+  cargo: CargoWorkspace | null,
+  root: string | undefined
+): Map<string, RustUnit> {
+  const libs = new Map<string, RustUnit>();
+
+  if (cargo && cargo.members.length > 0) {
+    for (const member of cargo.members) {
+      const lib = member.targets.find(target => target.kind === 'lib');
+      if (lib && fileSet.has(lib.path)) {
+        const dir = path.posix.dirname(lib.path);
+        libs.set(lib.name, { dir: dir === '.' ? '' : dir, modulePath: [] });
+      }
+    }
+    return libs;
+  }
+
+  for (const relPath of fileSet) {
+    if (relPath === 'src/lib.rs' || relPath.endsWith('/src/lib.rs')) {
+      const crateDir = relPath === 'src/lib.rs' ? '' : relPath.slice(0, -'/src/lib.rs'.length);
+      const name = path.posix.basename(crateDir || path.basename(root || '.')).replace(/-/g, '_');
+      libs.set(name, { dir: joinRel(crateDir, 'src'), modulePath: [] });
+    }
+  }
+  return libs;
+}
+
+function inferCrateDir(relPath: string): string {
+  const segments = relPath.split('/');
+  const index = segments.lastIndexOf('src');
+  if (index >= 0) return segments.slice(0, index).join('/');
+  const rootIndex = segments.findIndex(segment =>
+    ['tests', 'examples', 'benches'].includes(segment)
+  );
+  if (rootIndex >= 0) return segments.slice(0, rootIndex).join('/');
+  const dir = path.posix.dirname(relPath);
+  return dir === '.' ? '' : dir;
+}
+
+function projectRootOf(files: FileInfo[]): string | undefined {
+  const file = files[0];
+  if (!file) return undefined;
+  const abs = toPosix(file.absPath);
+  const rel = toPosix(file.relPath);
+  if (!abs.endsWith(rel)) return undefined;
+  return path.resolve(file.absPath.slice(0, abs.length - rel.length));
+}
+
+function moduleKey(dir: string, modulePath: string[]): string {
+  return [dir, ...modulePath].join('::');
+}
+
+function joinRel(base: string, child: string): string {
+  if (!base) return child;
+  if (!child) return base;
+  return `${base}/${child}`;
+}
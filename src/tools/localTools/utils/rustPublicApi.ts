@@ -0,0 +1,474 @@
+/**
+ * @fileOverview: Public API surface of Rust crates
+ * @module: RustPublicApi
+ * @keyFunctions:
+ *   - collectRustApi(): Walk a crate from its root through `mod` chains and `pub use` re-exports
+ * @context: An item is public when it is `pub`, every module on its path is `pub mod` (or it is
+ *           re-exported through one), and nothing on the way is #[doc(hidden)]. pub(crate),
+ *           pub(super) and unreachable `pub` items are still reported, as crate-internal.
+ */
+
+import * as path from 'path';
+import { ApiSymbol, rustItemSignature } from './publicApi';
+import { RustCrateRoot, parseRustUseTree, resolveModDeclaration } from './rustModules';
+import { maskRustSource } from './rustSource';
+
+export interface RustApiItem {
+  name: string; // item name, or Type::method for inherent methods
+  kind: ApiSymbol['kind'];
+  file: string;
+  line: number;
+  qualifiedPath: string;
+  visibility: 'public' | 'crate';
+  signature: string;
+  params?: number;
+}
+
+type Visibility = 'public' | 'restricted' | 'private';
+
+interface RawItem {
+  name: string;
+  kind: ApiSymbol['kind'];
+  file: string;
+  line: number;
+  visibility: Visibility;
+  hidden: boolean;
+  signature: string;
+  params?: number;
+  owner?: string; // impl target for methods
+  crateRoot?: boolean; // #[macro_export] macros live at the crate root
+}
+
+interface RawReexport {
+  target: string[];
+  glob: boolean;
+  alias?: string;
+  visibility: Visibility;
+  hidden: boolean;
+}
+
+interface RawModule {
+  path: string[];
+  visibility: Visibility;
+  hidden: boolean;
+  items: RawItem[];
+  reexports: RawReexport[];
+}
+
+interface ScanContext {
+  sources: Map<string, string>;
+  fileSet: Set<string>;
+  modules: Map<string, RawModule>;
+  visited: Set<string>;
+}
+
+type Scope =
+  | { kind: 'mod'; module: RawModule; childDir: string }
+  | { kind: 'impl'; module: RawModule; owner: string; isTraitImpl: boolean }
+  | { kind: 'block' };
+
+/** A name visible in a module, either declared there or brought in by `pub use` */
+interface Exposure {
+  item: RawItem;
+  visibility: Visibility;
+  hidden: boolean;
+}
+
+const VIS = String.raw`(pub(?:\s*\(\s*(?:crate|super|self|in\s+[\w:]+)\s*\))?\s+)?`;
+const QUALIFIERS = String.raw`((?:(?:default|const|async|unsafe|extern(?:\s+"[^"]*")?)\s+)*)`;
+const ITEM_KINDS = 'fn|struct|enum|union|trait|type|const|static|mod';
+const ITEM_REGEX = new RegExp(`^${VIS}${QUALIFIERS}(${ITEM_KINDS})\\s+(?:mut\\s+)?([A-Za-z_]\\w*)`);
+const METHOD_REGEX = new RegExp(`^${VIS}${QUALIFIERS}fn\\s+([A-Za-z_]\\w*)`);
+const USE_REGEX = new RegExp(`^${VIS}use\\s`);
+const MACRO_REGEX = /^macro_rules!\s*([A-Za-z_]\w*)/;
+const IMPL_REGEX = /^(?:unsafe\s+)?impl\b/;
+
+const KIND_MAP: Record<string, ApiSymbol['kind']> = {
+  fn: 'function',
+  struct: 'class',
+  enum: 'class',
+  union: 'class',
+  trait: 'interface',
+  type: 'type',
+  const: 'const',
+  static: 'const',
+};
+
+/**
+ * Collect `pub` items of one crate with their fully qualified paths.
+ * Items of binary crates are never public: nothing outside can name them.
+ */
+export function collectRustApi(root: RustCrateRoot, sources: Map<string, string>): RustApiItem[] {
+  const modules = new Map<string, RawModule>();
+  const rootModule: RawModule = {
+    path: [],
+    visibility: 'public',
+    hidden: false,
+    items: [],
+    reexports: [],
+  };
+  modules.set('', rootModule);
+  scanFile(root.rootFile, rootModule, true, {
+    sources,
+    fileSet: new Set(sources.keys()),
+    modules,
+    visited: new Set(),
+  });
+
+  const exposures = resolveExposures(modules);
+  const reachable = (modulePath: string[]): boolean =>
+    modulePath.every((_, index) => {
+      const module = modules.get(modulePath.slice(0, index + 1).join('::'));
+      return module !== undefined && module.visibility === 'public' && !module.hidden;
+    });
+
+  // Best path per item: public beats crate-internal, then the shortest path wins
+  const best = new Map<RawItem, RustApiItem>();
+  const offer = (candidate: RustApiItem, item: RawItem) => {
+    const current = best.get(item);
+    if (
+      !current ||
+      (candidate.visibility === 'public' && current.visibility !== 'public') ||
+      (candidate.visibility === current.visibility &&
+        candidate.qualifiedPath.split('::').length < current.qualifiedPath.split('::').length)
+    ) {
+      best.set(item, candidate);
+    }
+  };
+
+  for (const module of modules.values()) {
+    const moduleReachable = root.isLibrary && reachable(module.path);
+    for (const [name, exposure] of exposures.get(module.path.join('::')) ?? []) {
+      const { item } = exposure;
+      if (exposure.visibility === 'private') continue;
+      const at = item.crateRoot ? [] : module.path;
+      const isPublic =
+        root.isLibrary &&
+        (item.crateRoot || moduleReachable) &&
+        exposure.visibility === 'public' &&
+        !exposure.hidden;
+      offer(toApiItem(item, name, [root.name, ...at, name], isPublic), item);
+    }
+  }
+
+  // Inherent methods hang off wherever their type ended up
+  const typesByName = new Map<string, RawItem>();
+  for (const module of modules.values()) {
+    for (const item of module.items) {
+      if (!item.owner && item.kind === 'class' && !typesByName.has(item.name)) {
+        typesByName.set(item.name, item);
+      }
+    }
+  }
+  for (const module of modules.values()) {
+    for (const method of module.items) {
+      if (!method.owner || method.visibility === 'private') continue;
+      const owner = typesByName.get(method.owner);
+      const ownerApi = owner ? best.get(owner) : undefined;
+      const ownerPath = ownerApi
+        ? ownerApi.qualifiedPath.split('::')
+        : [root.name, ...module.path, method.owner];
+      const ownerPublic = ownerApi
+        ? ownerApi.visibility === 'public'
+        : root.isLibrary && reachable(module.path);
+      const isPublic = ownerPublic && method.visibility === 'public' && !method.hidden;
+      const name = `${method.owner}::${method.name}`;
+      offer(toApiItem(method, name, [...ownerPath, method.name], isPublic), method);
+    }
+  }
+
+  return Array.from(best.values());
+}
+
+function toApiItem(
+  item: RawItem,
+  name: string,
+  qualified: string[],
+  isPublic: boolean
+): RustApiItem {
+  return {
+    name,
+    kind: item.kind,
+    file: item.file,
+    line: item.line,
+    qualifiedPath: qualified.join('::'),
+    visibility: isPublic ? 'public' : 'crate',
+    signature: item.signature,
+    ...(item.params !== undefined ? { params: item.params } : {}),
+  };
+}
+
+/**
+ * Names visible in each module: its own items plus `pub use` re-exports, iterated so that
+ * re-exports of re-exports (lib.rs -> models/mod.rs -> models/invoice.rs) resolve
+ */
+function resolveExposures(modules: Map<string, RawModule>): Map<string, Map<string, Exposure>> {
+  const exposures = new Map<string, Map<string, Exposure>>();
+  for (const [key, module] of modules) {
+    const own = new Map<string, Exposure>();
+    for (const item of module.items) {
+      if (!item.owner) {
+        own.set(item.name, { item, visibility: item.visibility, hidden: item.hidden });
+      }
+    }
+    exposures.set(key, own);
+  }
+
+  for (let pass = 0, changed = true; changed && pass < 8; pass++) {
+    changed = false;
+    for (const [key, module] of modules) {
+      const visible = exposures.get(key)!;
+      for (const reexport of module.reexports) {
+        const target = resolveReexportTarget(module.path, reexport.target, modules);
+        if (!target) continue;
+
+        const expose = (name: string, source: Exposure) => {
+          if (visible.has(name)) return;
+          visible.set(name, {
+            item: source.item,
+            visibility: reexport.visibility,
+            hidden: reexport.hidden || source.hidden,
+          });
+          changed = true;
+        };
+
+        if (reexport.glob) {
+          // Glob imports only bring in what the target module makes public
+          for (const [name, source] of exposures.get(target.join('::')) ?? []) {
+            if (source.visibility === 'public') expose(name, source);
+          }
+        } else {
+          const name = target[target.length - 1];
+          const source = exposures.get(target.slice(0, -1).join('::'))?.get(name);
+          if (source) expose(reexport.alias ?? name, source);
+        }
+      }
+    }
+  }
+
+  return exposures;
+}
+
+/**
+ * Absolute module path for a `pub use` target, or undefined for other crates
+ */
+function resolveReexportTarget(
+  modulePath: string[],
+  segments: string[],
+  modules: Map<string, RawModule>
+): string[] | undefined {
+  const [first, ...rest] = segments;
+  if (first === 'crate') return rest;
+
+  if (first === 'self' || first === 'super') {
+    const base = [...modulePath];
+    let index = first === 'self' ? 1 : 0;
+    while (segments[index] === 'super') {
+      base.pop();
+      index++;
+    }
+    return [...base, ...segments.slice(index)];
+  }
+
+  if (modules.has([...modulePath, first].join('::'))) return [...modulePath, ...segments];
+  if (modules.has(first)) return segments;
+  return undefined;
+}
+
+/**
+ * Scan one module file, recording items, methods, re-exports and child modules
+ */
+function scanFile(
+  file: string,
+  fileModule: RawModule,
+  isModRoot: boolean,
+  context: ScanContext
+): void {
+  const content = context.sources.get(file);
+  if (content === undefined || context.visited.has(file)) return;
+  context.visited.add(file);
+
+  const { code, masked } = maskRustSource(content);
+  const lines = code.split('\n');
+  const maskedLines = masked.split('\n');
+  const scopes: Scope[] = [
+    {
+      kind: 'mod',
+      module: fileModule,
+      // Crate roots and mod.rs keep child modules beside them; src/foo.rs uses src/foo/
+      childDir: isModRoot ? path.posix.dirname(file) : file.replace(/\.rs$/, ''),
+    },
+  ];
+  let attrs: string[] = [];
+  let pending: Scope | undefined;
+
+  for (let i = 0; i < lines.length; i++) {
+    let text = lines[i].trim();
+
+    // Outer attributes, possibly several per line and spanning lines
+    while (text.startsWith('#[')) {
+      let end = attributeEnd(text);
+      while (end === -1 && i + 1 < lines.length) {
+        text += ` ${lines[++i].trim()}`;
+        end = attributeEnd(text);
+      }
+      if (end === -1) break;
+      attrs.push(text.slice(0, end + 1));
+      text = text.slice(end + 1).trim();
+    }
+    if (!text) continue;
+
+    const scope = scopes[scopes.length - 1];
+    const hidden = attrs.some(attr => /^#\[\s*doc\s*\(\s*hidden\s*\)/.test(attr));
+    const testOnly = attrs.some(attr => /^#\[\s*cfg\s*\(\s*test\s*\)/.test(attr));
+
+    if (scope.kind === 'mod' && !testOnly) {
+      const module = scope.module;
+      const item = ITEM_REGEX.exec(text);
+      const macro = MACRO_REGEX.exec(text);
+      const use = USE_REGEX.exec(text);
+
+      if (item && item[3] === 'mod') {
+        const child: RawModule = {
+          path: [...module.path, item[4]],
+          visibility: visibilityOf(item[1]),
+          hidden,
+          items: [],
+          reexports: [],
+        };
+        context.modules.set(child.path.join('::'), child);
+        if (/^[^{]*;/.test(text)) {
+          const pathAttr = attrs
+            .map(attr => /^#\[\s*path\s*=\s*"([^"]+)"/.exec(attr)?.[1])
+            .find(Boolean);
+          const target = resolveModDeclaration(
+            file,
+            scope.childDir,
+            item[4],
+            pathAttr,
+            context.fileSet
+          );
+          if (target) scanFile(target, child, false, context);
+        } else {
+          pending = { kind: 'mod', module: child, childDir: `${scope.childDir}/${item[4]}` };
+        }
+      } else if (item) {
+        module.items.push({
+          name: item[4],
+          kind: KIND_MAP[item[3]],
+          file,
+          line: i + 1,
+          visibility: visibilityOf(item[1]),
+          hidden,
+          ...rustItemSignature(lines, i),
+        });
+      } else if (macro && attrs.some(attr => /^#\[\s*macro_export\b/.test(attr))) {
+        module.items.push({
+          name: macro[1],
+          kind: 'function',
+          file,
+          line: i + 1,
+          visibility: 'public',
+          hidden,
+          signature: `macro_rules! ${macro[1]}`,
+          crateRoot: true,
+        });
+      } else if (use && use[1]) {
+        // Re-export trees can span lines: `pub use crate::{\n a,\n b,\n};`
+        let statement = text;
+        while (!statement.includes(';') && i + 1 < lines.length) {
+          statement += ` ${lines[++i].trim()}`;
+        }
+        const tree = statement.replace(USE_REGEX, '').replace(/;[\s\S]*$/, '');
+        for (const entry of parseRustUseTree(tree)) {
+          module.reexports.push({
+            target: entry.segments,
+            glob: entry.glob,
+            ...(entry.alias ? { alias: entry.alias } : {}),
+            visibility: visibilityOf(use[1]),
+            hidden,
+          });
+        }
+        attrs = [];
+        continue;
+      } else if (IMPL_REGEX.test(text)) {
+        pending = { kind: 'impl', module, ...implTarget(lines, i) };
+      }
+    } else if (scope.kind === 'impl' && !scope.isTraitImpl) {
+      const method = METHOD_REGEX.exec(text);
+      if (method && method[1]) {
+        scope.module.items.push({
+          name: method[3],
+          kind: 'function',
+          file,
+          line: i + 1,
+          visibility: visibilityOf(method[1]),
+          hidden,
+          owner: scope.owner,
+          ...rustItemSignature(lines, i),
+        });
+      }
+    }
+    attrs = [];
+
+    // Braces are counted on the masked line, so ones inside string and char literals don't nest
+    const lineEnd = lines[i].trimEnd().length;
+    for (const char of maskedLines[i].slice(Math.max(0, lineEnd - text.length), lineEnd)) {
+      if (char === '{') {
+        scopes.push(pending ?? { kind: 'block' });
+        pending = undefined;
+      } else if (char === '}' && scopes.length > 1) {
+        scopes.pop();
+      } else if (char === ';' && pending?.kind === 'mod') {
+        pending = undefined;
+      }
+    }
+  }
+}
+
+function visibilityOf(modifier: string | undefined): Visibility {
+  if (!modifier) return 'private';
+  return /^pub\s*$/.test(modifier) ? 'public' : 'restricted';
+}
+
+/**
+ * Type an impl block attaches to, from a header that may span lines
+ */
+function implTarget(lines: string[], index: number): { owner: string; isTraitImpl: boolean } {
+  let header = '';
+  for (let i = index; i < Math.min(lines.length, index + 8) && !header.includes('{'); i++) {
+    header += ` ${lines[i].trim()}`;
+  }
+  header = header.replace(/^\s*(?:unsafe\s+)?impl\s*/, '');
+
+  // Drop the impl's own generic parameters: impl<T: Clone> ...
+  if (header.startsWith('<')) {
+    let depth = 0;
+    for (let i = 0; i < header.length; i++) {
+      if (header[i] === '<') depth++;
+      else if (header[i] === '>' && --depth === 0) {
+        header = header.slice(i + 1);
+        break;
+      }
+    }
+  }
+
+  const body = header.split(/\s+where\s|\{/)[0];
+  const forMatch = /\sfor\s+(.+)$/.exec(body);
+  const target = (forMatch ? forMatch[1] : body)
+    .replace(/<.*$/, '')
+    .replace(/^[&\s]*(?:dyn\s+)?/, '');
+  return {
+    owner: target.split('::').pop()!.trim(),
+    isTraitImpl: forMatch !== null,
+  };
+}
+
+function attributeEnd(text: string): number {
+  let depth = 0;
+  for (let i = 1; i < text.length; i++) {
+    if (text[i] === '[') depth++;
+    else if (text[i] === ']' && --depth === 0) return i;
+  }
+  return -1;
+}
@@ -0,0 +1,861 @@
+/**
+ * @fileOverview: HTTP route extraction for Rust web frameworks
+ * @module: RustRoutes
+ * @keyFunctions:
+ *   - extractRustRoutes(): Resolve axum, actix-web, rocket, poem and warp routes across files
+ * @context: Rust routers are assembled across functions (`.nest("/api", api::router())`,
+ *           `.service(web::scope(..))`, `.configure(..)`, `.mount("/", routes![..])`,
+ *           `Route::new().at(..)`), so routes are expanded from root routers down, joining
+ *           prefixes on the way. warp filter chains are evaluated from `warp::serve(..)`, with
+ *           `.and` joining path segments and `.or` adding alternatives. Each route points at its
+ *           handler function when the handler can be resolved.
+ */
+
+import { parseRustUseTree } from './rustModules';
+import { blankRustTestModules, lineAt, maskRustSource, matchBracket } from './rustSource';
+
+export interface RustRoute {
+  method: string;
+  path: string;
+  file: string;
+  line: number;
+  handler?: string;
+}
+
+interface RustFn {
+  name: string;
+  file: string;
+  line: number;
+  offset: number;
+  bodyStart: number;
+  bodyEnd: number;
+}
+
+interface AttributeRoute {
+  method: string;
+  path: string;
+}
+
+interface Range {
+  start: number;
+  end: number;
+}
+
+interface WalkContext {
+  fn?: RustFn;
+  stack: Set<RustFn>;
+}
+
+/**
+ * One alternative a warp filter matches
+ */
+interface WarpRoute {
+  path: string;
+  method?: string;
+  handler?: string;
+  handled: boolean; // reached `.map`, `.then` or `.and_then`
+  file: string;
+  at: number;
+}
+
+/**
+ * An expression split into its primary (`warp::path!(..)`, `users()`, `routes`) and the method
+ * calls chained onto it
+ */
+interface CallChain {
+  callee: string;
+  isMacro: boolean;
+  isCall: boolean;
+  args: Range[];
+  inner?: Range;
+  at: number;
+  calls: Array<{ name: string; args: Range[]; at: number }>;
+}
+
+const HTTP_METHODS = 'get|post|put|delete|patch|head|options|trace';
+const ROUTER_CALL = /\.\s*(route|nest|nest_no_strip|merge|service|configure|mount|at)\s*\(/g;
+const ROUTER_HINT = new RegExp(
+  String.raw`\.\s*(?:route|nest|merge|service|configure|mount)\s*\(|\bRouter(?:::<[^>]*>)?::new\b|\bweb::(?:scope|resource)\b|\bApp::new\b|\bRoute::new\b`
+);
+const BUILDER_PRIMARY =
+  /^(?:(?:axum::)?Router(?:::<.*>)?::new|(?:actix_web::)?App::new|(?:poem::)?Route::new)$/;
+const WARP_METHODS = new Set(['get', 'post', 'put', 'delete', 'patch', 'head', 'options']);
+const WARP_HANDLER_CALLS = new Set(['map', 'then', 'and_then']);
+const MAX_DEPTH = 16;
+
+/**
+ * Extract routes from all Rust sources of a project (relPath -> content)
+ */
+export function extractRustRoutes(sources: Map<string, string>): RustRoute[] {
+  return new RustRouteExtractor(sources).extract();
+}
+
+class RustRouteExtractor {
+  private readonly code = new Map<string, string>();
+  private readonly masked = new Map<string, string>();
+  private readonly fns: RustFn[] = [];
+  private readonly fnsByName = new Map<string, RustFn[]>();
+  private readonly imports = new Map<string, Map<string, string[]>>();
+  private readonly attributeRoutes = new Map<RustFn, AttributeRoute[]>();
+  private readonly lets = new Map<RustFn, Map<string, Range>>();
+  private readonly allLets = new Map<RustFn, Array<{ name: string; range: Range }>>();
+
+  // Discovery pass state: what is referenced from another router, and which lets are consumed
+  private dryRun = true;
+  private readonly referencedFns = new Set<RustFn>();
+  private readonly usedLets = new Map<RustFn, Set<string>>();
+  private readonly routes: RustRoute[] = [];
+
+  constructor(sources: Map<string, string>) {
+    for (const [file, content] of sources) {
+      const { code, masked } = blankRustTestModules(maskRustSource(content));
+      this.code.set(file, code);
+      this.masked.set(file, masked);
+      this.indexFunctions(file);
+      this.indexImports(file);
+      this.indexAttributeRoutes(file);
+    }
+  }
+
+  extract(): RustRoute[] {
+    const routerFns = this.fns.filter(fn => this.isRouterFn(fn));
+
+    // Pass 1: find which routers are mounted elsewhere; pass 2: expand from the roots
+    for (const fn of routerFns) this.walkFn(fn, '', { stack: new Set() });
+    this.dryRun = false;
+    for (const fn of routerFns) {
+      if (!this.referencedFns.has(fn)) this.walkFn(fn, '', { stack: new Set() });
+    }
+
+    // Attribute routes never registered through a scope/mount keep their own path
+    for (const [fn, attributeRoutes] of this.attributeRoutes) {
+      if (this.referencedFns.has(fn)) continue;
+      for (const route of attributeRoutes) {
+        this.emit(route.method, joinRoutePath('', route.path), fn, fn.name);
+      }
+    }
+
+    this.extractWarpRoutes();
+    return this.routes;
+  }
+
+  // ===== Indexing =====
+
+  private indexFunctions(file: string): void {
+    const masked = this.masked.get(file)!;
+    const fnRegex = /\bfn\s+([A-Za-z_]\w*)/g;
+    let match: RegExpExecArray | null;
+
+    while ((match = fnRegex.exec(masked)) !== null) {
+      const paramsOpen = masked.indexOf('(', match.index + match[0].length);
+      if (paramsOpen === -1) continue;
+      const paramsClose = matchBracket(masked, paramsOpen);
+
+      // Body starts at the first `{` after the signature; `;` means a bodiless trait method
+      let bodyOpen = -1;
+      for (let i = paramsClose + 1; i < masked.length; i++) {
+        if (masked[i] === ';') break;
+        if (masked[i] === '{') {
+          bodyOpen = i;
+          break;
+        }
+      }
+      if (bodyOpen === -1) continue;
+
+      const fn: RustFn = {
+        name: match[1],
+        file,
+        line: lineAt(masked, match.index),
+        offset: match.index,
+        bodyStart: bodyOpen + 1,
+        bodyEnd: matchBracket(masked, bodyOpen),
+      };
+      this.fns.push(fn);
+      const named = this.fnsByName.get(fn.name) ?? [];
+      named.push(fn);
+      this.fnsByName.set(fn.name, named);
+    }
+  }
+
+  private indexImports(file: string): void {
+    const masked = this.masked.get(file)!;
+    const imported = new Map<string, string[]>();
+    const useRegex = /\buse\s+([^;]+);/g;
+    let match: RegExpExecArray | null;
+
+    while ((match = useRegex.exec(masked)) !== null) {
+      for (const entry of parseRustUseTree(match[1])) {
+        if (entry.glob) continue;
+        imported.set(entry.alias ?? entry.segments[entry.segments.length - 1], entry.segments);
+      }
+    }
+    this.imports.set(file, imported);
+  }
+
+  /**
+   * actix-web / rocket handler attributes: #[get("/x")], #[route("/x", method = "GET")]
+   */
+  private indexAttributeRoutes(file: string): void {
+    const code = this.code.get(file)!;
+    const masked = this.masked.get(file)!;
+    const attrRegex = new RegExp(
+      String.raw`#\[\s*(?:actix_web::|rocket::)?(${HTTP_METHODS}|route)\s*\(\s*"([^"]*)"([^\]]*)\]`,
+      'g'
+    );
+    const fileFns = this.fns.filter(fn => fn.file === file);
+    let match: RegExpExecArray | null;
+
+    while ((match = attrRegex.exec(code)) !== null) {
+      const attrEnd = match.index + match[0].length;
+      const fn = fileFns.find(candidate => candidate.offset >= attrEnd);
+      // Only attributes, visibility and qualifiers may sit between the attribute and its fn
+      if (!fn || /[;{}]/.test(masked.slice(attrEnd, fn.offset))) continue;
+
+      const methods =
+        match[1] === 'route'
+          ? Array.from(match[3].matchAll(/method\s*=\s*"(\w+)"/g), m => m[1].toLowerCase())
+          : [match[1]];
+      const routes = this.attributeRoutes.get(fn) ?? [];
+      for (const method of methods.length > 0 ? methods : ['any']) {
+        routes.push({ method, path: match[2] });
+      }
+      this.attributeRoutes.set(fn, routes);
+    }
+  }
+
+  private isRouterFn(fn: RustFn): boolean {
+    const masked = this.masked.get(fn.file)!;
+    return ROUTER_HINT.test(masked.slice(fn.bodyStart, fn.bodyEnd));
+  }
+
+  /**
+   * `let name = <router expression>;` bindings in a function body
+   */
+  private letsOf(fn: RustFn): Map<string, Range> {
+    const cached = this.lets.get(fn);
+    if (cached) return cached;
+
+    const masked = this.masked.get(fn.file)!;
+    const lets = new Map<string, Range>();
+    const letRegex = /\blet\s+(?:mut\s+)?([A-Za-z_]\w*)\s*(?::[^=;]+)?=/g;
+    letRegex.lastIndex = fn.bodyStart;
+    let match: RegExpExecArray | null;
+
+    while ((match = letRegex.exec(masked)) !== null && match.index < fn.bodyEnd) {
+      const start = match.index + match[0].length;
+      const end = statementEnd(masked, start, fn.bodyEnd);
+      const expr = masked.slice(start, end);
+      const call = /^\s*([\w:]+)\s*\(/.exec(expr);
+      const callsRouter = call ? this.resolveFn(call[1], fn.file, f => this.isRouterFn(f)) : null;
+      if (ROUTER_HINT.test(expr) || callsRouter) {
+        lets.set(match[1], { start, end });
+      }
+      letRegex.lastIndex = end;
+    }
+
+    this.lets.set(fn, lets);
+    return lets;
+  }
+
+  // ===== Expansion =====
+
+  private walkFn(fn: RustFn, prefix: string, parent: WalkContext): void {
+    if (parent.stack.has(fn) || parent.stack.size >= MAX_DEPTH) return;
+    const context: WalkContext = { fn, stack: new Set([...parent.stack, fn]) };
+    const lets = this.letsOf(fn);
+
+    // Body outside router lets; lets are expanded where they are used
+    let cursor = fn.bodyStart;
+    for (const range of Array.from(lets.values()).sort((a, b) => a.start - b.start)) {
+      this.walk(fn.file, { start: cursor, end: range.start }, prefix, context);
+      cursor = range.end;
+    }
+    this.walk(fn.file, { start: cursor, end: fn.bodyEnd }, prefix, context);
+
+    // Lets that nothing mounts (e.g. `let app = Router::new()...; serve(app)`) are routers too
+    const used = this.usedLets.get(fn) ?? new Set<string>();
+    for (const [name, range] of lets) {
+      if (this.dryRun || !used.has(name)) this.expand(fn.file, range, prefix, context);
+    }
+  }
+
+  /**
+   * Scan a range for router-building method calls
+   */
+  private walk(file: string, range: Range, prefix: string, context: WalkContext): void {
+    const masked = this.masked.get(file)!;
+    const callRegex = new RegExp(ROUTER_CALL.source, 'g');
+    callRegex.lastIndex = range.start;
+    let match: RegExpExecArray | null;
+
+    while ((match = callRegex.exec(masked)) !== null && match.index < range.end) {
+      const open = match.index + match[0].length - 1;
+      const close = matchBracket(masked, open);
+      const args = splitArgs(masked, open + 1, close);
+      const literal = args[0] ? this.literalAt(file, args[0]) : undefined;
+
+      switch (match[1]) {
+        case 'route':
+          if (args.length >= 2 && literal !== undefined) {
+            this.emitMethodRouter(file, args[1], joinRoutePath(prefix, literal), match.index);
+          }
+          break;
+        case 'nest':
+        case 'nest_no_strip':
+          if (args.length >= 2 && literal !== undefined) {
+            this.expand(file, args[1], joinRoutePath(prefix, literal), context);
+          }
+          break;
+        case 'at':
+          // poem: `.at("/users", get(list).post(create))`, or a bare endpoint for any method
+          if (args.length >= 2 && literal !== undefined) {
+            const routePath = joinRoutePath(prefix, literal);
+            const endpoint = /^\s*([\w:]+)\s*$/.exec(this.textAt(file, args[1]))?.[1];
+            if (endpoint) this.emitHandler('any', routePath, endpoint, file, match.index);
+            else this.emitMethodRouter(file, args[1], routePath, match.index);
+          }
+          break;
+        case 'merge':
+        case 'service':
+          if (args[0]) this.expand(file, args[0], prefix, context);
+          break;
+        case 'configure': {
+          const target = args[0] && this.resolveFn(this.textAt(file, args[0]), file);
+          if (target) this.mountFn(target, prefix, context);
+          break;
+        }
+        case 'mount':
+          if (args.length >= 2 && literal !== undefined) {
+            this.mountRocketRoutes(file, args[1], joinRoutePath(prefix, literal));
+          }
+          break;
+      }
+      callRegex.lastIndex = close + 1;
+    }
+  }
+
+  /**
+   * Expand a router-valued expression: a builder chain, a router function call, a let binding,
+   * an actix scope/resource or an attribute-routed handler
+   */
+  private expand(file: string, range: Range, prefix: string, context: WalkContext): void {
+    const masked = this.masked.get(file)!;
+    const text = masked.slice(range.start, range.end);
+    const primary = /^\s*([A-Za-z_]\w*(?:\s*::\s*(?:<[^>]*>|[A-Za-z_]\w*))*)\s*/.exec(text);
+    if (!primary) return;
+
+    const callee = primary[1].replace(/\s+/g, '');
+    let restStart = range.start + primary[0].length;
+
+    if (masked[restStart] !== '(') {
+      // Bare name: a let-bound router or an attribute-routed handler
+      const letRange = context.fn && !callee.includes('::') && this.letsOf(context.fn).get(callee);
+      if (letRange && context.fn) {
+        this.markLetUsed(context.fn, callee);
+        this.expand(file, letRange, prefix, context);
+      } else {
+        const handler = this.resolveFn(callee, file, fn => this.attributeRoutes.has(fn));
+        if (handler) this.mountFn(handler, prefix, context);
+      }
+      this.walk(file, { start: restStart, end: range.end }, prefix, context);
+      return;
+    }
+
+    const close = matchBracket(masked, restStart);
+    const args = splitArgs(masked, restStart + 1, close);
+    restStart = close + 1;
+    const rest = { start: restStart, end: range.end };
+    const name = callee.split('::').pop()!;
+
+    if (BUILDER_PRIMARY.test(callee)) {
+      this.walk(file, rest, prefix, context);
+    } else if (name === 'scope' && args[0]) {
+      const literal = this.literalAt(file, args[0]);
+      this.walk(file, rest, joinRoutePath(prefix, literal ?? ''), context);
+    } else if (name === 'resource' && args[0]) {
+      const literal = this.literalAt(file, args[0]);
+      if (literal !== undefined) this.expandResource(file, rest, joinRoutePath(prefix, literal));
+    } else {
+      const target = this.resolveFn(callee, file);
+      if (target) this.mountFn(target, prefix, context);
+      this.walk(file, rest, prefix, context);
+    }
+  }
+
+  /**
+   * actix `web::resource("/x").route(web::get().to(h)).to(fallback)`
+   */
+  private expandResource(file: string, range: Range, path: string): void {
+    const masked = this.masked.get(file)!;
+    const callRegex = /\.\s*(route|to)\s*\(/g;
+    callRegex.lastIndex = range.start;
+    let match: RegExpExecArray | null;
+
+    while ((match = callRegex.exec(masked)) !== null && match.index < range.end) {
+      const open = match.index + match[0].length - 1;
+      const close = matchBracket(masked, open);
+      const inner = { start: open + 1, end: close };
+      if (match[1] === 'route') {
+        this.emitMethodRouter(file, inner, path, match.index);
+      } else {
+        this.emitHandler('any', path, this.textAt(file, inner).trim(), file, match.index);
+      }
+      callRegex.lastIndex = close + 1;
+    }
+  }
+
+  private mountFn(fn: RustFn, prefix: string, context: WalkContext): void {
+    this.referencedFns.add(fn);
+    for (const route of this.attributeRoutes.get(fn) ?? []) {
+      this.emit(route.method, joinRoutePath(prefix, route.path), fn, fn.name);
+    }
+    if (this.isRouterFn(fn)) this.walkFn(fn, prefix, context);
+  }
+
+  /**
+   * rocket `.mount("/api", routes![a, b::c])`
+   */
+  private mountRocketRoutes(file: string, range: Range, prefix: string): void {
+    const list = /routes!\s*[[(]([^\])]*)[\])]/.exec(this.textAt(file, range));
+    if (!list) return;
+    for (const name of list[1].split(',').map(part => part.trim())) {
+      const fn = name && this.resolveFn(name, file, f => this.attributeRoutes.has(f));
+      if (fn) this.mountFn(fn, prefix, { stack: new Set() });
+    }
+  }
+
+  /**
+   * Methods and handlers of an axum MethodRouter (`get(h).post(h2)`, `on(MethodFilter::GET, h)`)
+   * or an actix route (`web::get().to(h)`)
+   */
+  private emitMethodRouter(file: string, range: Range, path: string, at: number): void {
+    const text = this.textAt(file, range);
+
+    const actixHandler = /\.\s*to\s*\(\s*([\w:]+)/.exec(text);
+    if (actixHandler) {
+      const method =
+        new RegExp(String.raw`\bweb::(${HTTP_METHODS})\s*\(\s*\)`).exec(text)?.[1] ??
+        /\bMethod::([A-Z]+)\b/.exec(text)?.[1].toLowerCase() ??
+        'any';
+      this.emitHandler(method, path, actixHandler[1], file, at);
+      return;
+    }
+
+    const methodRegex = new RegExp(
+      String.raw`(?:^|[^\w])(?:axum::routing::|routing::)?(${HTTP_METHODS}|any)\s*\(\s*([\w:]+(?:::<[^>]*>)?)?`,
+      'g'
+    );
+    for (const match of text.matchAll(methodRegex)) {
+      this.emitHandler(match[1], path, match[2], file, at);
+    }
+    for (const match of text.matchAll(/\bon\s*\(\s*MethodFilter::(\w+)\s*,\s*([\w:]+)/g)) {
+      this.emitHandler(match[1].toLowerCase(), path, match[2], file, at);
+    }
+  }
+
+  private emitHandler(
+    method: string,
+    path: string,
+    handler: string | undefined,
+    file: string,
+    at: number
+  ): void {
+    const cleaned = handler?.replace(/::<.*$/, '');
+    const target = cleaned ? this.resolveFn(cleaned, file) : undefined;
+    if (target) {
+      this.emit(method, path, target, cleaned);
+    } else {
+      this.emit(method, path, { file, line: lineAt(this.masked.get(file)!, at) }, cleaned);
+    }
+  }
+
+  private emit(
+    method: string,
+    path: string,
+    location: { file: string; line: number },
+    handler?: string
+  ): void {
+    if (this.dryRun) return;
+    this.routes.push({
+      method,
+      path,
+      file: location.file,
+      line: location.line,
+      ...(handler ? { handler } : {}),
+    });
+  }
+
+  // ===== warp =====
+
+  /**
+   * warp filter chains. Roots are `warp::serve(..)` arguments; a library without a server uses
+   * the filter functions no other filter function builds on
+   */
+  private extractWarpRoutes(): void {
+    const roots: Array<{ file: string; range: Range; fn?: RustFn }> = [];
+    for (const [file, masked] of this.masked) {
+      for (const match of masked.matchAll(/\bwarp::serve\s*\(/g)) {
+        const open = match.index! + match[0].length - 1;
+        const filter = splitArgs(masked, open + 1, matchBracket(masked, open))[0];
+        if (filter) roots.push({ file, range: filter, fn: this.enclosingFn(file, match.index!) });
+      }
+    }
+
+    if (roots.length === 0) {
+      const filterFns = this.fns.filter(fn => this.usesWarp(fn) && this.tailExpression(fn));
+      const referenced = new Set<RustFn>();
+      for (const fn of filterFns) {
+        this.evalWarp(fn.file, this.tailExpression(fn)!, { fn, stack: new Set([fn]) }, referenced);
+      }
+      for (const fn of filterFns) {
+        if (!referenced.has(fn)) roots.push({ file: fn.file, range: this.tailExpression(fn)!, fn });
+      }
+    }
+
+    for (const root of roots) {
+      const context = { fn: root.fn, stack: new Set(root.fn ? [root.fn] : []) };
+      for (const route of this.evalWarp(root.file, root.range, context)) {
+        // Filters such as `warp::body::json()` alone are not endpoints
+        if (!route.path && !route.method && !route.handled) continue;
+        const method = route.method ?? 'any';
+        this.emitHandler(method, route.path || '/', route.handler, route.file, route.at);
+      }
+    }
+  }
+
+  /**
+   * Alternatives a warp filter expression matches: `.and` concatenates path segments and keeps
+   * the method and handler of either side, `.or` adds alternatives
+   */
+  private evalWarp(
+    file: string,
+    range: Range,
+    context: WalkContext,
+    referenced?: Set<RustFn>
+  ): WarpRoute[] {
+    const chain = this.parseCallChain(file, range);
+    if (!chain) return [{ path: '', handled: false, file, at: range.start }];
+
+    let routes = this.evalWarpPrimary(file, chain, context, referenced);
+    for (const call of chain.calls) {
+      if (call.name === 'and' && call.args[0]) {
+        const right = this.evalWarp(file, call.args[0], context, referenced);
+        routes = routes.flatMap(left => right.map(other => joinWarpRoutes(left, other)));
+      } else if (call.name === 'or' && call.args[0]) {
+        routes = [...routes, ...this.evalWarp(file, call.args[0], context, referenced)];
+      } else if (WARP_HANDLER_CALLS.has(call.name)) {
+        const handler = call.args[0]
+          ? /^\s*([\w:]+)\s*$/.exec(this.textAt(file, call.args[0]))?.[1]
+          : undefined;
+        routes = routes.map(route => ({
+          ...route,
+          handler: handler ?? route.handler,
+          handled: true,
+          file,
+          at: call.at,
+        }));
+      }
+    }
+    return routes;
+  }
+
+  private evalWarpPrimary(
+    file: string,
+    chain: CallChain,
+    context: WalkContext,
+    referenced?: Set<RustFn>
+  ): WarpRoute[] {
+    const base: WarpRoute = { path: '', handled: false, file, at: chain.at };
+    const warpName = this.warpName(file, chain.callee);
+
+    if (warpName !== undefined) {
+      if (warpName === 'path' && chain.isMacro && chain.inner) {
+        return [{ ...base, path: this.warpPathMacro(file, chain.inner) }];
+      }
+      if (warpName === 'path') {
+        const literal = chain.args[0] && this.literalAt(file, chain.args[0]);
+        return [{ ...base, path: literal ? joinRoutePath('', literal) : '' }];
+      }
+      if (warpName === 'path::param') {
+        const type = /::\s*<\s*([^>]+?)\s*>/.exec(chain.callee)?.[1] ?? 'param';
+        return [{ ...base, path: `/{${type}}` }];
+      }
+      if (warpName === 'path::tail') return [{ ...base, path: '/*' }];
+      if (WARP_METHODS.has(warpName)) return [{ ...base, method: warpName }];
+      return [base];
+    }
+
+    if (!chain.isCall) {
+      // A let-bound filter; the latest binding before this use, so `let f = f.or(g)` works
+      const binding = context.fn
+        ? this.allLetsOf(context.fn)
+            .filter(candidate => candidate.name === chain.callee && candidate.range.end < chain.at)
+            .pop()
+        : undefined;
+      return binding ? this.evalWarp(file, binding.range, context, referenced) : [base];
+    }
+
+    // A function returning a filter
+    const target = this.resolveFn(chain.callee, file);
+    const tail = target && this.tailExpression(target);
+    if (!target || !tail || context.stack.has(target) || context.stack.size >= MAX_DEPTH) {
+      return [base];
+    }
+    referenced?.add(target);
+    const inner = { fn: target, stack: new Set([...context.stack, target]) };
+    return this.evalWarp(target.file, tail, inner, referenced);
+  }
+
+  /**
+   * `warp::path!("users" / u32 / "posts")` -> `/users/{u32}/posts`
+   */
+  private warpPathMacro(file: string, range: Range): string {
+    const masked = this.masked.get(file)!;
+    const segments: string[] = [];
+    let start = range.start;
+    for (let i = range.start; i <= range.end; i++) {
+      if (i < range.end && masked[i] !== '/') continue;
+      const segment = this.textAt(file, { start, end: i }).trim();
+      const literal = /^"((?:\\.|[^"\\])*)"$/.exec(segment)?.[1];
+      if (literal !== undefined) segments.push(literal);
+      else if (segment && segment !== '..') segments.push(`{${segment}}`);
+      start = i + 1;
+    }
+    return segments.length > 0 ? `/${segments.join('/')}` : '';
+  }
+
+  /**
+   * Name of a warp item relative to the crate (`path::param`, `get`), or undefined when the
+   * callee is not from warp. `filters::` and `method::` modules are dropped
+   */
+  private warpName(file: string, callee: string): string | undefined {
+    let segments = callee.replace(/::\s*<.*?>/g, '').split('::');
+    if (segments[0] !== 'warp') {
+      const imported = this.imports.get(file)?.get(segments[0]);
+      if (!imported || imported[0] !== 'warp') return undefined;
+      segments = [...imported, ...segments.slice(1)];
+    }
+    return segments
+      .slice(1)
+      .filter(segment => segment !== 'filters' && segment !== 'method')
+      .join('::');
+  }
+
+  private usesWarp(fn: RustFn): boolean {
+    const imported = Array.from(this.imports.get(fn.file)?.values() ?? []);
+    return (
+      imported.some(segments => segments[0] === 'warp') ||
+      /\bwarp::/.test(this.masked.get(fn.file)!.slice(fn.bodyStart, fn.bodyEnd))
+    );
+  }
+
+  /**
+   * Split an expression into its primary and the method calls chained onto it
+   */
+  private parseCallChain(file: string, range: Range): CallChain | undefined {
+    const masked = this.masked.get(file)!;
+    const text = masked.slice(range.start, range.end);
+    const head = /^\s*([A-Za-z_]\w*(?:\s*::\s*(?:<[^>]*>|[A-Za-z_]\w*))*)\s*(!)?\s*/.exec(text);
+    if (!head) return undefined;
+
+    const chain: CallChain = {
+      callee: head[1].replace(/\s+/g, ''),
+      isMacro: !!head[2],
+      isCall: false,
+      args: [],
+      at: range.start + text.length - text.trimStart().length,
+      calls: [],
+    };
+    let cursor = range.start + head[0].length;
+    if (cursor < range.end && '([{'.includes(masked[cursor])) {
+      const close = matchBracket(masked, cursor);
+      chain.isCall = true;
+      chain.args = splitArgs(masked, cursor + 1, close);
+      chain.inner = { start: cursor + 1, end: close };
+      cursor = close + 1;
+    }
+
+    const callRegex = /^\s*\.\s*([A-Za-z_]\w*)\s*(?:::\s*<[^>]*>\s*)?\(/;
+    let call: RegExpExecArray | null;
+    while ((call = callRegex.exec(masked.slice(cursor, range.end))) !== null) {
+      const open = cursor + call[0].length - 1;
+      const close = matchBracket(masked, open);
+      chain.calls.push({
+        name: call[1],
+        args: splitArgs(masked, open + 1, close),
+        at: cursor + call[0].indexOf('.'),
+      });
+      cursor = close + 1;
+    }
+    return chain;
+  }
+
+  /**
+   * The value a function body evaluates to: what follows its last top-level `;` or block
+   */
+  private tailExpression(fn: RustFn): Range | undefined {
+    const masked = this.masked.get(fn.file)!;
+    let depth = 0;
+    let start = fn.bodyStart;
+    for (let i = fn.bodyStart; i < fn.bodyEnd; i++) {
+      const char = masked[i];
+      if (char === '(' || char === '[' || char === '{') depth++;
+      else if (char === ')' || char === ']' || char === '}') {
+        if (--depth === 0 && char === '}') start = i + 1;
+      } else if (char === ';' && depth === 0) start = i + 1;
+    }
+    return masked.slice(start, fn.bodyEnd).trim() ? { start, end: fn.bodyEnd } : undefined;
+  }
+
+  /**
+   * Every `let name = ...;` in a function body, in source order
+   */
+  private allLetsOf(fn: RustFn): Array<{ name: string; range: Range }> {
+    const cached = this.allLets.get(fn);
+    if (cached) return cached;
+
+    const masked = this.masked.get(fn.file)!;
+    const lets: Array<{ name: string; range: Range }> = [];
+    const letRegex = /\blet\s+(?:mut\s+)?([A-Za-z_]\w*)\s*(?::[^=;]+)?=/g;
+    letRegex.lastIndex = fn.bodyStart;
+    let match: RegExpExecArray | null;
+
+    while ((match = letRegex.exec(masked)) !== null && match.index < fn.bodyEnd) {
+      const start = match.index + match[0].length;
+      const end = statementEnd(masked, start, fn.bodyEnd);
+      lets.push({ name: match[1], range: { start, end } });
+      letRegex.lastIndex = end;
+    }
+
+    this.allLets.set(fn, lets);
+    return lets;
+  }
+
+  private enclosingFn(file: string, offset: number): RustFn | undefined {
+    return this.fns
+      .filter(fn => fn.file === file && fn.bodyStart <= offset && offset < fn.bodyEnd)
+      .reduce<RustFn | undefined>(
+        (inner, fn) => (!inner || fn.bodyStart > inner.bodyStart ? fn : inner),
+        undefined
+      );
+  }
+
+  private markLetUsed(fn: RustFn, name: string): void {
+    if (!this.dryRun) return;
+    const used = this.usedLets.get(fn) ?? new Set<string>();
+    used.add(name);
+    this.usedLets.set(fn, used);
+  }
+
+  // ===== Resolution helpers =====
+
+  /**
+   * Resolve a function path (`list`, `users::list`, `crate::api::router`) to its definition,
+   * using the file's imports and matching qualifiers against file paths
+   */
+  private resolveFn(
+    reference: string,
+    fromFile: string,
+    filter: (fn: RustFn) => boolean = () => true
+  ): RustFn | undefined {
+    let segments = reference
+      .replace(/::<.*?>/g, '')
+      .split('::')
+      .filter(segment => segment && !['crate', 'self', 'super'].includes(segment));
+    if (segments.length === 0) return undefined;
+
+    const imported = this.imports.get(fromFile)?.get(segments[0]);
+    if (imported) {
+      segments = [...imported, ...segments.slice(1)].filter(
+        segment => !['crate', 'self', 'super'].includes(segment)
+      );
+    }
+
+    const name = segments[segments.length - 1];
+    const qualifiers = segments.slice(0, -1).reverse();
+    const score = (fn: RustFn) => {
+      const modulePath = fn.file
+        .replace(/\.rs$/, '')
+        .replace(/\/mod$/, '')
+        .split('/')
+        .reverse();
+      let matched = 0;
+      while (matched < qualifiers.length && modulePath[matched] === qualifiers[matched]) matched++;
+      return matched * 2 + (fn.file === fromFile ? 1 : 0);
+    };
+
+    // A qualified path (`users::list`, `Files::new`) must match the module path or the same file
+    const candidates = (this.fnsByName.get(name) ?? [])
+      .filter(filter)
+      .filter(fn => qualifiers.length === 0 || score(fn) > 0);
+    if (candidates.length <= 1) return candidates[0];
+    return candidates.reduce((best, fn) => (score(fn) > score(best) ? fn : best));
+  }
+
+  private literalAt(file: string, range: Range): string | undefined {
+    const match = /^\s*"((?:\\.|[^"\\])*)"\s*$/.exec(this.textAt(file, range));
+    return match ? match[1] : undefined;
+  }
+
+  private textAt(file: string, range: Range): string {
+    return this.code.get(file)!.slice(range.start, range.end);
+  }
+}
+
+/**
+ * Join a router prefix and a route path: ("/api", "/") -> "/api", ("", "users") -> "/users"
+ */
+export function joinRoutePath(prefix: string, path: string): string {
+  const joined = `${prefix}/${path}`.replace(/\/{2,}/g, '/');
+  const trimmed = joined.length > 1 ? joined.replace(/\/$/, '') : joined;
+  return trimmed.startsWith('/') ? trimmed : `/${trimmed}`;
+}
+
+/**
+ * `left.and(right)`: path segments join; the method and handler may come from either side
+ */
+function joinWarpRoutes(left: WarpRoute, right: WarpRoute): WarpRoute {
+  const anchor = right.handled || (!left.handled && !left.path) ? right : left;
+  return {
+    path: left.path + right.path,
+    method: right.method ?? left.method,
+    handler: right.handler ?? left.handler,
+    handled: left.handled || right.handled,
+    file: anchor.file,
+    at: anchor.at,
+  };
+}
+
+/**
+ * Top-level comma-separated argument ranges between start and end
+ */
+function splitArgs(text: string, start: number, end: number): Range[] {
+  const args: Range[] = [];
+  let depth = 0;
+  let argStart = start;
+  for (let i = start; i < end; i++) {
+    const char = text[i];
+    if (char === '(' || char === '[' || char === '{') depth++;
+    else if (char === ')' || char === ']' || char === '}') depth--;
+    else if (char === ',' && depth === 0) {
+      args.push({ start: argStart, end: i });
+      argStart = i + 1;
+    }
+  }
+  if (text.slice(argStart, end).trim()) args.push({ start: argStart, end });
+  return args;
+}
+
+/**
+ * End of the statement starting at `start`: the first top-level `;`
+ */
+function statementEnd(text: string, start: number, limit: number): number {
+  let depth = 0;
+  for (let i = start; i < limit; i++) {
+    const char = text[i];
+    if (char === '(' || char === '[' || char === '{') depth++;
+    else if (char === ')' || char === ']' || char === '}') depth--;
+    else if (char === ';' && depth === 0) return i;
+  }
+  return limit;
+}
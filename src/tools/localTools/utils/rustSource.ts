@@ -0,0 +1,257 @@
+/**
+ * @fileOverview: Source masking, offset, line and bracket helpers shared by the text-based Rust
+ *                analyzers
+ * @module: RustSource
+ * @keyFunctions:
+ *   - maskRustSource(): Blank comments and string contents while keeping offsets and lines
+ *   - blankRustTestModules(): Blank `#[cfg(test)] mod` blocks in masked sources
+ *   - matchBracket(): Index of the bracket closing the one at an offset
+ *   - closingAngle(): Index of the `>` closing a generic parameter list
+ *   - splitTopLevel(): Split on a separator outside brackets and generics
+ *   - precedingAttributes(): Outer `#[...]` attributes directly above an item
+ *   - itemBodyStart(): Offset of the `{` opening an item's body
+ *   - lineAt() / lineOffsets() / lineAtOffset(): 1-based line numbers of offsets
+ * @context: The bracket and item helpers expect sources run through maskRustSource() first, so
+ *           brackets inside comments, strings and char literals are already blanked
+ */
+
+/**
+ * Offset-preserving views of a Rust file: `code` has comments blanked, `masked` additionally
+ * blanks string and char literal contents so braces and parens can be matched structurally
+ */
+export function maskRustSource(content: string): { code: string; masked: string } {
+  let code = '';
+  let masked = '';
+  const blank = (text: string) => text.replace(/[^\n]/g, ' ');
+  let i = 0;
+
+  while (i < content.length) {
+    const rest = content.slice(i, i + 12);
+    let end = -1;
+
+    if (rest.startsWith('//')) {
+      const newline = content.indexOf('\n', i);
+      end = newline === -1 ? content.length : newline;
+      code += blank(content.slice(i, end));
+      masked += blank(content.slice(i, end));
+      i = end;
+      continue;
+    }
+
+    if (rest.startsWith('/*')) {
+      // Block comments nest in Rust
+      let depth = 0;
+      end = i;
+      while (end < content.length) {
+        if (content.startsWith('/*', end)) {
+          depth++;
+          end += 2;
+        } else if (content.startsWith('*/', end)) {
+          end += 2;
+          if (--depth === 0) break;
+        } else {
+          end++;
+        }
+      }
+      code += blank(content.slice(i, end));
+      masked += blank(content.slice(i, end));
+      i = end;
+      continue;
+    }
+
+    const identBefore = i > 0 && /\w/.test(content[i - 1]);
+    const raw = identBefore ? null : /^b?r(#*)"/.exec(rest);
+    if (raw) {
+      const close = content.indexOf(`"${raw[1]}`, i + raw[0].length);
+      end = close === -1 ? content.length : close + 1 + raw[1].length;
+      const literal = content.slice(i, end);
+      const closing = literal.slice(-1 - raw[1].length);
+      code += literal;
+      masked += raw[0] + blank(literal.slice(raw[0].length, -closing.length)) + closing;
+      i = end;
+      continue;
+    }
+
+    if (content[i] === '"') {
+      end = i + 1;
+      while (end < content.length && content[end] !== '"') {
+        end += content[end] === '\\' ? 2 : 1;
+      }
+      end = Math.min(end + 1, content.length);
+      code += content.slice(i, end);
+      masked += `"${blank(content.slice(i + 1, end - 1))}"`;
+      i = end;
+      continue;
+    }
+
+    if (content[i] === "'") {
+      // Char literal ('a', '\n', '\u{1F600}') vs lifetime ('a)
+      const char = /^'(?:\\(?:x[0-9a-fA-F]{2}|u\{[0-9a-fA-F]+\}|.)|[^'\\\n])'/.exec(
+        content.slice(i, i + 12)
+      );
+      if (char) {
+        code += char[0];
+        masked += `'${blank(char[0].slice(1, -1))}'`;
+        i += char[0].length;
+        continue;
+      }
+    }
+
+    code += content[i];
+    masked += content[i];
+    i++;
+  }
+
+  return { code, masked };
+}
+
+/**
+ * Blank `#[cfg(test)] mod x { ... }` blocks in both views of a masked source, so test-only
+ * code doesn't show up as routes, env keys and the like
+ */
+export function blankRustTestModules(source: { code: string; masked: string }): {
+  code: string;
+  masked: string;
+} {
+  let { code, masked } = source;
+  const testRegex = /#\[\s*cfg\s*\(\s*test\s*\)\s*\]\s*(?:pub(?:\([^)]*\))?\s+)?mod\s+\w+\s*\{/g;
+  let match: RegExpExecArray | null;
+  while ((match = testRegex.exec(masked)) !== null) {
+    const start = match.index;
+    let depth = 0;
+    let end = masked.length;
+    for (let i = start + match[0].length - 1; i < masked.length; i++) {
+      if (masked[i] === '{') depth++;
+      else if (masked[i] === '}' && --depth === 0) {
+        end = i + 1;
+        break;
+      }
+    }
+    const blank = (text: string) =>
+      text.slice(0, start) + text.slice(start, end).replace(/[^\n]/g, ' ') + text.slice(end);
+    code = blank(code);
+    masked = blank(masked);
+    testRegex.lastIndex = end;
+  }
+  return { code, masked };
+}
+
+/**
+ * Index of the bracket closing the one at `open`, counting `()`, `[]` and `{}` alike.
+ * Unbalanced text yields the last index before `to`
+ */
+export function matchBracket(text: string, open: number, to = text.length): number {
+  let depth = 0;
+  for (let i = open; i < to; i++) {
+    const char = text[i];
+    if (char === '(' || char === '[' || char === '{') depth++;
+    else if ((char === ')' || char === ']' || char === '}') && --depth === 0) return i;
+  }
+  return to - 1;
+}
+
+/**
+ * Index of the `>` closing the `<` at `open`; the `>` of `->` is not a bracket
+ */
+export function closingAngle(text: string, open = 0): number {
+  let depth = 0;
+  for (let i = open; i < text.length; i++) {
+    if (text[i] === '<') depth++;
+    else if (text[i] === '>' && text[i - 1] !== '-' && --depth === 0) return i;
+  }
+  return text.length - 1;
+}
+
+/**
+ * Trimmed, non-empty parts of `text` split on `separator` outside brackets and generics
+ */
+export function splitTopLevel(text: string, separator = ','): string[] {
+  const parts: string[] = [];
+  let depth = 0;
+  let start = 0;
+  for (let i = 0; i < text.length; i++) {
+    const char = text[i];
+    if ('<([{'.includes(char)) depth++;
+    else if (')]}'.includes(char) || (char === '>' && text[i - 1] !== '-')) depth--;
+    else if (char === separator && depth === 0) {
+      parts.push(text.slice(start, i));
+      start = i + 1;
+    }
+  }
+  parts.push(text.slice(start));
+  return parts.map(part => part.trim()).filter(Boolean);
+}
+
+/**
+ * Outer attributes directly above `offset`, nearest last
+ */
+export function precedingAttributes(masked: string, offset: number): string[] {
+  const attrs: string[] = [];
+  let i = offset - 1;
+  for (;;) {
+    while (i >= 0 && /\s/.test(masked[i])) i--;
+    if (masked[i] !== ']') break;
+
+    let depth = 0;
+    let j = i;
+    for (; j >= 0; j--) {
+      if (masked[j] === ']') depth++;
+      else if (masked[j] === '[' && --depth === 0) break;
+    }
+    if (j <= 0 || masked[j - 1] !== '#') break;
+    attrs.unshift(masked.slice(j - 1, i + 1));
+    i = j - 2;
+  }
+  return attrs;
+}
+
+/**
+ * Offset of the `{` opening an item's body, undefined for declarations ending in `;`
+ */
+export function itemBodyStart(masked: string, offset: number): number | undefined {
+  let depth = 0;
+  for (let i = offset; i < masked.length; i++) {
+    const char = masked[i];
+    if (char === '(' || char === '[' || char === '<') depth++;
+    else if (char === ')' || char === ']' || (char === '>' && masked[i - 1] !== '-')) depth--;
+    else if (char === ';' && depth <= 0) return undefined;
+    else if (char === '{' && depth <= 0) return i;
+  }
+  return undefined;
+}
+
+/**
+ * 1-based line of `offset`; use lineOffsets() and lineAtOffset() for many lookups in one file
+ */
+export function lineAt(text: string, offset: number): number {
+  let line = 1;
+  for (let i = 0; i < offset && i < text.length; i++) {
+    if (text[i] === '\n') line++;
+  }
+  return line;
+}
+
+/**
+ * Offset at which each line of `content` starts
+ */
+export function lineOffsets(content: string): number[] {
+  const starts = [0];
+  for (let i = 0; i < content.length; i++) {
+    if (content[i] === '\n') starts.push(i + 1);
+  }
+  return starts;
+}
+
+/**
+ * 1-based line of `offset`, given the line starts from lineOffsets()
+ */
+export function lineAtOffset(starts: number[], offset: number): number {
+  let low = 0;
+  let high = starts.length - 1;
+  while (low < high) {
+    const mid = (low + high + 1) >> 1;
+    if (starts[mid] <= offset) low = mid;
+    else high = mid - 1;
+  }
+  return low + 1;
+}
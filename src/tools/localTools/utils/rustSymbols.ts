@@ -0,0 +1,329 @@
+/**
+ * @fileOverview: Rust symbols for AST queries, in the same kinds as the Babel extractor
+ * @module: RustSymbols
+ * @keyFunctions:
+ *   - extractRustSymbols(): fns, structs/enums, traits, consts/statics/lets, `use` imports,
+ *     `pub` exports and calls in one Rust file
+ *   - findRustConstructorCalls(): `Type::new(..)` style constructor calls
+ *   - extractRustAttributes(): Every `#[..]`/`#![..]` attribute with the item or field it annotates
+ *   - extractRustInherentImpls(): `impl Type { .. }` blocks, which the trait index leaves out
+ * @context: Lets astQueries (import/export/call/new/...) run on Rust without a parser. Works on
+ *           the masked source, so strings, comments and attributes never produce symbols.
+ *           Offsets index into the original file content.
+ */
+
+import { parseRustUseTree } from './rustModules';
+import {
+  blankRustTestModules,
+  closingAngle,
+  lineAtOffset,
+  lineOffsets,
+  maskRustSource,
+  matchBracket,
+} from './rustSource';
+import { rustItemSignature } from './publicApi';
+
+export interface RustSymbol {
+  name: string;
+  kind: 'function' | 'class' | 'interface' | 'variable' | 'import' | 'export' | 'call';
+  start: number;
+  end: number;
+  line: number;
+  signature?: string;
+}
+
+export interface RustConstructorCall {
+  type: string;
+  constructor: string; // `new`, `with_capacity`, `default`, ...
+  start: number;
+  end: number;
+  line: number;
+}
+
+export interface RustAttribute {
+  path: string; // as written, e.g. `tracing::instrument`
+  text: string; // whole attribute, whitespace collapsed: `#[tracing::instrument(skip(self))]`
+  inner: boolean; // `#![...]`
+  target?: string; // annotated item or field; absent for inner attributes
+  start: number;
+  end: number;
+  line: number;
+}
+
+export interface RustInherentImpl {
+  forType: string; // `Cache<K, V>`
+  generics?: string; // impl<...> parameters as written
+  start: number;
+  end: number;
+  line: number;
+}
+
+const ITEM =
+  /(?<![\w'])((?:pub(?:\s*\(\s*(?:crate|super|self|in\s+[\w:]+)\s*\))?\s+)?)((?:(?:default|async|const|unsafe|extern\s*"[^"]*")\s+)*)(fn|struct|enum|union|trait|const|static|mod)\s+(?:r#)?(\w+)/g;
+const USE_DECL = /(?<![\w'])((?:pub(?:\s*\([^)]*\))?\s+)?)(use|extern\s+crate)\s+([^;]+);/g;
+const ATTRIBUTE = /#(!?)\[\s*((?:[A-Za-z_]\w*\s*::\s*)*[A-Za-z_]\w*)/g;
+const ATTRIBUTE_TARGET = new RegExp(
+  String.raw`^\s*(?:#\[[^\]]*\]\s*)*(?:pub(?:\s*\([^)]*\))?\s+)?` +
+    String.raw`(?:(?:(?:default|async|const|unsafe|extern\s*"[^"]*")\s+)*` +
+    String.raw`(?:fn|struct|enum|union|trait|mod|type|static|const|macro_rules!)\s+(?:r#)?(\w+)` +
+    String.raw`|(?:r#)?([A-Za-z_]\w*)\s*(?::(?!:)|[,({=]|$))`
+);
+const IMPL = /^[ \t]*(?:(?:default|unsafe)\s+)*impl\b/gm;
+const LET_BINDING = /\blet\s+(?:mut\s+)?(?:r#)?([a-z_]\w*)\s*[:=;]/g;
+const CALL =
+  /((?:[A-Za-z_]\w*\s*(?:::\s*<[^()]*?>\s*)?::\s*)*[A-Za-z_]\w*)\s*(?:::\s*<[^()]*?>\s*)?\(/g;
+// Words followed by `(` that are not calls
+const NOT_CALLEES = new Set([
+  'if',
+  'while',
+  'match',
+  'for',
+  'in',
+  'return',
+  'loop',
+  'as',
+  'fn',
+  'impl',
+  'where',
+  'move',
+  'dyn',
+  'Fn',
+  'FnMut',
+  'FnOnce',
+  // Enum variants are matched in patterns far more often than they are constructed
+  'Some',
+  'Ok',
+  'Err',
+]);
+const ITEM_KINDS: Record<string, RustSymbol['kind'] | undefined> = {
+  fn: 'function',
+  struct: 'class',
+  enum: 'class',
+  union: 'class',
+  trait: 'interface',
+  const: 'variable',
+  static: 'variable',
+};
+
+/**
+ * Symbols of one Rust file. `pub` items are reported twice, as their own kind and as an export
+ */
+export function extractRustSymbols(content: string): RustSymbol[] {
+  const masked = blankAttributes(maskRustSource(content).masked);
+  const lines = content.split('\n');
+  const starts = lineOffsets(content);
+  const symbols: RustSymbol[] = [];
+  const fnBodies: Array<{ start: number; end: number }> = [];
+
+  for (const match of masked.matchAll(ITEM)) {
+    const [, visibility, , keyword, name] = match;
+    const start = match.index!;
+    const line = lineAtOffset(starts, start);
+    const end = itemEnd(masked, start + match[0].length, keyword);
+    const kind = ITEM_KINDS[keyword];
+
+    if (kind) {
+      const signature =
+        keyword === 'fn' ? rustItemSignature(lines, line - 1).signature : undefined;
+      symbols.push({ name, kind, start, end, line, ...(signature ? { signature } : {}) });
+    }
+    if (keyword === 'fn' && masked[end - 1] === '}') {
+      fnBodies.push({ start: masked.indexOf('{', start + match[0].length), end });
+    }
+    if (visibility.trim() === 'pub') {
+      symbols.push({ name, kind: 'export', start, end, line });
+    }
+  }
+
+  for (const match of masked.matchAll(USE_DECL)) {
+    const [, visibility, keyword, tree] = match;
+    const start = match.index!;
+    const end = start + match[0].length;
+    const line = lineAtOffset(starts, start);
+    // `extern crate foo as bar` parses like `use foo as bar`
+    for (const entry of parseRustUseTree(tree)) {
+      const source = [...entry.segments, ...(entry.glob ? ['*'] : [])];
+      symbols.push({ name: `import from ${source.join('::')}`, kind: 'import', start, end, line });
+      if (visibility.trim() === 'pub' && keyword === 'use') {
+        const name = entry.alias ?? source[source.length - 1];
+        symbols.push({ name, kind: 'export', start, end, line });
+      }
+    }
+  }
+
+  // Nested fns lie inside their parent's body; each offset is reported once
+  const seen = new Set<number>();
+  for (const body of fnBodies) {
+    const text = masked.slice(body.start, body.end);
+
+    for (const match of text.matchAll(LET_BINDING)) {
+      const start = body.start + match.index!;
+      if (seen.has(start)) continue;
+      seen.add(start);
+      const end = start + match[0].length - 1;
+      const line = lineAtOffset(starts, start);
+      symbols.push({ name: match[1], kind: 'variable', start, end, line });
+    }
+
+    for (const match of text.matchAll(CALL)) {
+      const start = body.start + match.index!;
+      // `Vec::<u8>::with_capacity` -> `Vec::with_capacity`
+      const path = match[1].replace(/::\s*<[^()]*?>/g, '').replace(/\s+/g, '');
+      if (seen.has(start) || NOT_CALLEES.has(path.split('::')[0])) continue;
+      if (/\bfn\s+$/.test(masked.slice(Math.max(0, start - 4), start))) continue;
+      seen.add(start);
+
+      // `recv.method(..)` like `obj.method` in JS; a chained call keeps just the method
+      const before = masked.slice(Math.max(0, start - 80), start);
+      const receiver = /([A-Za-z_]\w*)?\s*\.\s*$/.exec(before);
+      const open = start + match[0].length - 1;
+      const close = matchBracket(masked, open);
+      symbols.push({
+        name: receiver?.[1] ? `${receiver[1]}.${path}` : path,
+        kind: 'call',
+        start: receiver?.[1] ? start - receiver[0].length : start,
+        end: close + 1,
+        line: lineAtOffset(starts, start),
+      });
+    }
+  }
+
+  return symbols.sort((a, b) => a.start - b.start);
+}
+
+/**
+ * Calls of associated constructors on types matching `typePattern`: `Type::new(..)`,
+ * `Type::new_with(..)`, `Type::with_capacity(..)`, `Type::default()`, `Vec::<u8>::new()`
+ */
+export function findRustConstructorCalls(
+  content: string,
+  typePattern: string
+): RustConstructorCall[] {
+  const masked = maskRustSource(content).masked;
+  const starts = lineOffsets(content);
+  const regex = new RegExp(
+    `(?<![\\w.])(?:\\w+::)*(${typePattern})\\s*(?:::\\s*<[^()]*?>\\s*)?::\\s*(new\\w*|with_\\w+|default)\\s*\\(`,
+    'g'
+  );
+  const calls: RustConstructorCall[] = [];
+
+  for (const match of masked.matchAll(regex)) {
+    const start = match.index!;
+    const close = matchBracket(masked, start + match[0].length - 1);
+    calls.push({
+      type: match[1],
+      constructor: match[2],
+      start,
+      end: close + 1,
+      line: lineAtOffset(starts, start),
+    });
+  }
+
+  return calls;
+}
+
+/**
+ * All attributes of a file, built-in ones included. Outer attributes name the item, enum variant
+ * or field they annotate as `target`
+ */
+export function extractRustAttributes(content: string): RustAttribute[] {
+  const masked = maskRustSource(content).masked;
+  const starts = lineOffsets(content);
+  const attributes: RustAttribute[] = [];
+
+  for (const match of masked.matchAll(ATTRIBUTE)) {
+    const start = match.index!;
+    const open = masked.indexOf('[', start);
+    const close = matchBracket(masked, open);
+    const inner = match[1] === '!';
+    const target = inner ? undefined : ATTRIBUTE_TARGET.exec(masked.slice(close + 1, close + 1000));
+
+    attributes.push({
+      path: match[2].replace(/\s+/g, ''),
+      text: content.slice(start, close + 1).replace(/\s+/g, ' '),
+      inner,
+      ...(target && (target[1] || target[2]) ? { target: target[1] || target[2] } : {}),
+      start,
+      end: close + 1,
+      line: lineAtOffset(starts, start),
+    });
+  }
+
+  return attributes;
+}
+
+/**
+ * `impl Type { .. }` blocks without a trait. Test modules are skipped, as in the trait index
+ */
+export function extractRustInherentImpls(content: string): RustInherentImpl[] {
+  const { masked } = blankRustTestModules(maskRustSource(content));
+  const starts = lineOffsets(content);
+  const impls: RustInherentImpl[] = [];
+
+  for (const match of masked.matchAll(IMPL)) {
+    const headerStart = match.index! + match[0].length;
+    const open = masked.indexOf('{', headerStart);
+    if (open === -1) continue;
+    let head = masked.slice(headerStart, open).replace(/\s+/g, ' ').trim();
+
+    let generics: string | undefined;
+    if (head.startsWith('<')) {
+      const close = closingAngle(head);
+      generics = head.slice(0, close + 1);
+      head = head.slice(close + 1).trim();
+    }
+    const forType = head.split(/\bwhere\b/)[0].trim();
+    // A top-level ` for ` (outside generic arguments) makes it a trait impl
+    let flat = forType;
+    while (/<[^<>]*>/.test(flat)) flat = flat.replace(/<[^<>]*>/g, '');
+    if (!forType || / for /.test(` ${flat} `)) continue;
+
+    const close = matchBracket(masked, open);
+    const start = match.index! + match[0].search(/\S/);
+    impls.push({
+      forType,
+      ...(generics ? { generics } : {}),
+      start,
+      end: close + 1,
+      line: lineAtOffset(starts, start),
+    });
+  }
+
+  return impls;
+}
+
+// ===== Helpers =====
+
+/**
+ * End offset of an item whose name ends at `from`: after its `{...}` body or its `;`
+ */
+function itemEnd(masked: string, from: number, keyword: string): number {
+  // A const/static initializer may contain braces (`Foo { .. }`), so only `;` ends it
+  const braceEnds = !['const', 'static'].includes(keyword);
+  let depth = 0;
+  for (let i = from; i < masked.length; i++) {
+    const ch = masked[i];
+    if (ch === '{' && depth === 0 && braceEnds) return matchBracket(masked, i) + 1;
+    if (ch === '(' || ch === '[' || ch === '{') depth++;
+    else if (ch === ')' || ch === ']' || ch === '}') {
+      if (--depth < 0) return i;
+    } else if (ch === ';' && depth === 0) {
+      return i + 1;
+    }
+  }
+  return masked.length;
+}
+
+/**
+ * Blank `#[...]` and `#![...]` so attribute arguments are not mistaken for calls
+ */
+function blankAttributes(masked: string): string {
+  let out = masked;
+  for (const match of masked.matchAll(/#!?\[/g)) {
+    const start = match.index!;
+    const close = matchBracket(masked, start + match[0].length - 1);
+    const blank = masked.slice(start, close + 1).replace(/[^\n]/g, ' ');
+    out = out.slice(0, start) + blank + out.slice(close + 1);
+  }
+  return out;
+}
@@ -0,0 +1,616 @@
+/**
+ * @fileOverview: Rust test discovery and code-to-test linkage
+ * @module: RustTests
+ * @keyFunctions:
+ *   - buildRustTestIndex(): Test functions, `#[cfg(test)]` modules, benches and doctests, each
+ *     linked to the project items it exercises
+ *   - loadRustTestIndex(): Read the Rust files of a project and index them
+ *   - findTestsForSymbol(): Tests exercising an item (`RateLimiter::acquire`, `parse`, a type)
+ *   - findTestsForFile(): Tests in a file plus the tests exercising items defined in it
+ *   - findRustTests(): Resolve the symbol or file a query asks about and return its tests
+ * @context: Backs local_context with taskType "test". Tests are `#[test]`-style attributes
+ *           (`tokio::test`, `rstest`, `test_case`, `#[test]` inside `proptest!`), `#[bench]` and
+ *           criterion targets in `benches/`, and fenced examples in `///` docs. A test covers the
+ *           items it names: `Type::method` paths, bare calls of free functions, types, and
+ *           `.method()` calls whose owner is unambiguous or a type the test mentions.
+ */
+
+import { readFile } from 'fs/promises';
+import { FileInfo } from '../../../core/compactor/fileDiscovery';
+import { logger } from '../../../utils/logger';
+import { extractRustItemDocs, splitRustDoctests } from '../../../core/compactor/rustDocs';
+import { analyzeCargoWorkspace, crateForFile } from './cargoWorkspace';
+import { toPosix } from './pathUtils';
+import {
+  closingAngle,
+  itemBodyStart,
+  lineAtOffset,
+  lineOffsets,
+  maskRustSource,
+  matchBracket,
+  precedingAttributes,
+} from './rustSource';
+
+export type RustTestKind = 'unit' | 'integration' | 'bench' | 'doctest';
+
+export interface RustTestCase {
+  name: string; // fn name, or `RateLimiter::acquire (line 12)` for a doctest
+  path: string; // as `cargo test` lists it: `limiter::tests::acquire_waits`
+  file: string;
+  line: number;
+  endLine: number;
+  start: number;
+  end: number;
+  kind: RustTestKind;
+  harness: string; // `test`, `tokio::test`, `rstest`, `proptest`, `bench`, `criterion`, `doctest`
+  covers: string[]; // project items exercised: `RateLimiter::acquire`, `RateLimiter`, `parse`
+  crate?: string;
+}
+
+export interface RustTestModule {
+  file: string;
+  name: string;
+  line: number;
+  endLine: number;
+}
+
+export interface RustItem {
+  name: string; // `parse`, `RateLimiter`, `RateLimiter::acquire`
+  kind: 'fn' | 'method' | 'type';
+  file: string;
+  line: number;
+}
+
+export interface RustTestIndex {
+  tests: RustTestCase[];
+  testModules: RustTestModule[];
+  items: RustItem[];
+}
+
+interface Range {
+  start: number;
+  end: number;
+}
+
+interface PendingTest {
+  test: RustTestCase;
+  body: string; // masked source the test's references are read from
+  scope: string; // enclosing test module or file, for the types a method call may belong to
+  documents?: string; // item a doctest is attached to
+}
+
+const FN_ITEM = /\bfn\s+([A-Za-z_]\w*)/g;
+const FN_QUALIFIERS =
+  /(?:(?:pub(?:\s*\([^)]*\))?|const|async|unsafe|default|extern\s*(?:"[^"]*")?)\s+)*$/;
+const TYPE_ITEM = /\b(?:struct|enum|union|trait|type)\s+([A-Za-z_]\w*)/g;
+const INLINE_MOD = /\bmod\s+([A-Za-z_]\w*)\s*\{/g;
+const IMPL_ITEM = /^[ \t]*(?:(?:default|unsafe)\s+)*impl\b/gm;
+const PROPTEST_BLOCK = /\bproptest!\s*\{/g;
+const CRITERION_GROUP = /\bcriterion_group!\s*([({])/g;
+const ATTRIBUTE_PATH = /^#\[\s*((?:[A-Za-z_]\w*\s*::\s*)*[A-Za-z_]\w*)/;
+// Test attributes whose last segment isn't `test` (`tokio::test`, `sqlx::test` are covered)
+const TEST_ATTRIBUTES = new Set([
+  'rstest',
+  'test_case',
+  'test_matrix',
+  'quickcheck',
+  'wasm_bindgen_test',
+  'bench',
+]);
+const ITEM_PATH = /\b([A-Za-z_]\w*)\s*::\s*([A-Za-z_]\w*)/g;
+const METHOD_CALL = /\.\s*([a-z_]\w*)\s*(?:::\s*<[^>]*>\s*)?\(/g;
+const BARE_CALL = /(?<![\w.:])([a-z_]\w*)\s*(?:::\s*<[^>]*>\s*)?\(/g;
+const TYPE_MENTION = /\b([A-Z]\w*)\b/g;
+const DOC_FENCE = /^\s*(`{3,}|~{3,})(.*)$/;
+
+/**
+ * Index the tests of a project's Rust sources (relPath -> content) and link each test to the
+ * items it exercises. Tests are in file and line order
+ */
+export function buildRustTestIndex(sources: Map<string, string>): RustTestIndex {
+  const index: RustTestIndex = { tests: [], testModules: [], items: [] };
+  const pending: PendingTest[] = [];
+
+  for (const [file, content] of sources) {
+    indexFile(file, content, index, pending);
+  }
+
+  const resolve = referenceResolver(index.items);
+  for (const { test, body, scope, documents } of pending) {
+    const covers = resolve(body, scope).filter(name => name !== test.name);
+    test.covers = documents ? unique([documents, ...covers]) : covers;
+    index.tests.push(test);
+  }
+
+  // Doctests of types are found after the functions of their file
+  const fileOrder = new Map(Array.from(sources.keys(), (file, order) => [file, order]));
+  index.tests.sort((a, b) => fileOrder.get(a.file)! - fileOrder.get(b.file)! || a.start - b.start);
+
+  return index;
+}
+
+/**
+ * Read the Rust files in a file set and index their tests. With a project path, tests are
+ * attributed to Cargo workspace members; unreadable files are skipped
+ */
+export async function loadRustTestIndex(
+  files: FileInfo[],
+  projectPath?: string
+): Promise<RustTestIndex> {
+  const sources = new Map<string, string>();
+  for (const file of files) {
+    if (file.language !== 'rust') continue;
+    try {
+      sources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf-8'));
+    } catch (error) {
+      logger.warn('Could not read file for test discovery', {
+        file: file.relPath,
+        error: (error as Error).message,
+      });
+    }
+  }
+
+  const index = buildRustTestIndex(sources);
+  const workspace = projectPath ? analyzeCargoWorkspace(projectPath) : null;
+  if (workspace) {
+    for (const test of index.tests) {
+      test.crate = crateForFile(workspace, test.file)?.name;
+    }
+  }
+  return index;
+}
+
+/**
+ * Tests exercising an item. `RateLimiter::acquire` matches that method only, a bare `acquire`
+ * matches a free function or any method of that name, and a type matches tests of the type or
+ * any of its methods. Module prefixes (`crate::limiter::`) are ignored
+ */
+export function findTestsForSymbol(index: RustTestIndex, symbol: string): RustTestCase[] {
+  const wanted = normalizeSymbol(symbol);
+  if (!wanted) return [];
+  const isType = /^[A-Z]/.test(wanted) && !wanted.includes('::');
+
+  return index.tests.filter(test =>
+    test.covers.some(
+      cover =>
+        cover === wanted ||
+        (!wanted.includes('::') && cover.endsWith(`::${wanted}`)) ||
+        (isType && cover.startsWith(`${wanted}::`))
+    )
+  );
+}
+
+/**
+ * Tests to run after changing a file: the tests it contains and the tests exercising the items
+ * it defines, in file and line order
+ */
+export function findTestsForFile(index: RustTestIndex, relPath: string): RustTestCase[] {
+  const file = toPosix(relPath);
+  const defined = new Set(index.items.filter(item => item.file === file).map(item => item.name));
+
+  return index.tests.filter(
+    test => test.file === file || test.covers.some(cover => defined.has(cover))
+  );
+}
+
+/**
+ * The tests a query asks for: a `.rs` path names a changed file, otherwise the first project
+ * item the query names (`RateLimiter::acquire`, a backticked or exact item name). Undefined when
+ * the query names neither
+ */
+export function findRustTests(
+  index: RustTestIndex,
+  query: string
+): { subject: string; tests: RustTestCase[] } | undefined {
+  const files = unique([...index.items.map(item => item.file), ...index.tests.map(t => t.file)]);
+  for (const written of query.match(/[\w./-]+\.rs\b/g) || []) {
+    const relPath = toPosix(written).replace(/^\.\//, '');
+    const file = files.find(
+      candidate => candidate === relPath || candidate.endsWith(`/${relPath}`)
+    );
+    if (file) return { subject: file, tests: findTestsForFile(index, file) };
+  }
+
+  const names = new Set(index.items.map(item => item.name));
+  const candidates = [
+    ...(query.match(/(?:[A-Za-z_]\w*::)+[A-Za-z_]\w*/g) || []),
+    ...Array.from(query.matchAll(/`([^`]+)`/g), match => match[1]),
+    ...(query.match(/[A-Za-z_]\w*/g) || []),
+  ];
+  for (const candidate of candidates) {
+    const symbol = normalizeSymbol(candidate);
+    if (symbol && names.has(symbol)) {
+      return { subject: symbol, tests: findTestsForSymbol(index, symbol) };
+    }
+  }
+  return undefined;
+}
+
+/**
+ * Command that runs one test: `cargo test -p net --lib limiter::tests::acquire_waits`
+ */
+export function cargoTestCommand(test: RustTestCase): string {
+  const parts = ['cargo', test.kind === 'bench' ? 'bench' : 'test'];
+  if (test.crate) parts.push('-p', test.crate);
+  const target = test.file.split('/').pop()!.replace(/\.rs$/, '');
+
+  switch (test.kind) {
+    case 'doctest':
+      parts.push('--doc', `'${test.name.replace(/ \(line \d+\)$/, '')}'`);
+      break;
+    case 'integration':
+      parts.push('--test', target, test.path);
+      break;
+    case 'bench':
+      if (/(^|\/)benches\//.test(test.file)) parts.push('--bench', target);
+      parts.push(test.path);
+      break;
+    default:
+      parts.push(test.path);
+  }
+  return parts.join(' ');
+}
+
+// ===== Indexing =====
+
+function indexFile(file: string, content: string, index: RustTestIndex, pending: PendingTest[]) {
+  const { masked } = maskRustSource(content);
+  const lines = content.split('\n');
+  const starts = lineOffsets(content);
+  const isIntegration = /(^|\/)tests\//.test(file);
+  const isBenchTarget = /(^|\/)benches\//.test(file);
+  const isTestTarget = isIntegration || isBenchTarget;
+  const isExample = /(^|\/)examples\//.test(file);
+
+  const modules = inlineModules(masked);
+  const testModules = modules.filter(mod => mod.isTest);
+  const impls = implBlocks(masked);
+  const proptests = macroBodies(masked, PROPTEST_BLOCK);
+  const criterionTargets = criterionGroupTargets(masked);
+  const within = (ranges: Range[], offset: number) =>
+    ranges.filter(range => range.start <= offset && offset < range.end);
+  const modulePath = (offset: number) => [
+    ...fileModulePath(file),
+    ...within(modules, offset).map(mod => mod.name),
+  ];
+  const ownerAt = (offset: number) => within(impls, offset).pop()?.selfType;
+
+  for (const mod of testModules) {
+    index.testModules.push({
+      file,
+      name: mod.name,
+      line: lineAtOffset(starts, mod.itemStart),
+      endLine: lineAtOffset(starts, mod.end - 1),
+    });
+  }
+
+  let match: RegExpExecArray | null;
+  FN_ITEM.lastIndex = 0;
+  while ((match = FN_ITEM.exec(masked)) !== null) {
+    const name = match[1];
+    const open = itemBodyStart(masked, match.index + match[0].length);
+    const line = lineAtOffset(starts, match.index);
+    const qualifiers = FN_QUALIFIERS.exec(masked.slice(starts[line - 1], match.index));
+    const itemStart = match.index - (qualifiers?.[0].length ?? 0);
+    const attrs = precedingAttributes(masked, itemStart);
+    const harness = testHarness(attrs, name, {
+      inProptest: within(proptests, match.index).length > 0,
+      isCriterionTarget: criterionTargets.has(name),
+    });
+
+    const enclosingTestModule = within(testModules, match.index).pop();
+    if (harness && open !== undefined) {
+      const start = attrs.length > 0 ? attributesStart(masked, itemStart) : itemStart;
+      const end = matchBracket(masked, open) + 1;
+      const isBench = harness === 'bench' || harness === 'criterion' || isBenchTarget;
+      const kind: RustTestKind = isBench ? 'bench' : isIntegration ? 'integration' : 'unit';
+      pending.push({
+        test: {
+          name,
+          path: [...modulePath(match.index), name].join('::'),
+          file,
+          line: lineAtOffset(starts, start),
+          endLine: lineAtOffset(starts, end - 1),
+          start,
+          end,
+          kind,
+          harness,
+          covers: [],
+        },
+        body: masked.slice(open, end),
+        scope: enclosingTestModule
+          ? masked.slice(enclosingTestModule.start, enclosingTestModule.end)
+          : masked,
+      });
+      continue;
+    }
+    const inTestCode = isTestTarget || enclosingTestModule !== undefined;
+    if (inTestCode || isExample) continue;
+
+    const owner = ownerAt(match.index);
+    const qualified = owner ? `${owner}::${name}` : name;
+    index.items.push({ name: qualified, kind: owner ? 'method' : 'fn', file, line });
+    pending.push(...doctests(file, lines, starts, line, qualified, modulePath(match.index)));
+  }
+
+  if (isTestTarget || isExample) return;
+  TYPE_ITEM.lastIndex = 0;
+  while ((match = TYPE_ITEM.exec(masked)) !== null) {
+    if (within(testModules, match.index).length > 0) continue;
+    const line = lineAtOffset(starts, match.index);
+    // `type Output = ..` inside an impl is an associated type, not an item
+    if (/\btype\b/.test(match[0]) && ownerAt(match.index)) continue;
+    index.items.push({ name: match[1], kind: 'type', file, line });
+    pending.push(...doctests(file, lines, starts, line, match[1], modulePath(match.index)));
+  }
+}
+
+/**
+ * The harness running a function, undefined for ordinary functions
+ */
+function testHarness(
+  attrs: string[],
+  name: string,
+  context: { inProptest: boolean; isCriterionTarget: boolean }
+): string | undefined {
+  for (const attr of attrs) {
+    const path = ATTRIBUTE_PATH.exec(attr)?.[1].replace(/\s+/g, '');
+    if (!path) continue;
+    const last = path.split('::').pop()!;
+    if (last === 'test' || TEST_ATTRIBUTES.has(last)) {
+      return context.inProptest && path === 'test' ? 'proptest' : path;
+    }
+  }
+  return context.isCriterionTarget && !/^(main|criterion_\w+)$/.test(name)
+    ? 'criterion'
+    : undefined;
+}
+
+/**
+ * Doctests in the docs of the item whose first line is `line` (1-based). Named the way rustdoc
+ * names them: `RateLimiter::acquire (line 12)`, the line being the opening fence
+ */
+function doctests(
+  file: string,
+  lines: string[],
+  starts: number[],
+  line: number,
+  qualified: string,
+  modulePath: string[]
+): PendingTest[] {
+  const docs = extractRustItemDocs(lines, line - 1);
+  if (!docs || docs.doctests.length === 0) return [];
+
+  const results: PendingTest[] = [];
+  let fence: { marker: string; line: number; body: string[] } | undefined;
+  for (let row = docs.startLine; row <= docs.endLine; row++) {
+    const text = lines[row].trim().replace(/^\/\/\/\s?/, '');
+    const fenceMatch = DOC_FENCE.exec(text);
+    if (!fence) {
+      if (fenceMatch) fence = { marker: fenceMatch[1], line: row + 1, body: [text] };
+      continue;
+    }
+    fence.body.push(text);
+    if (!fenceMatch || !fenceMatch[1].startsWith(fence.marker) || fenceMatch[2].trim()) continue;
+
+    const [code] = splitRustDoctests(fence.body.join('\n')).doctests;
+    if (code !== undefined) {
+      const name = `${qualified} (line ${fence.line})`;
+      results.push({
+        test: {
+          name,
+          path: `${file} - ${[...modulePath, qualified].join('::')} (line ${fence.line})`,
+          file,
+          line: fence.line,
+          endLine: row + 1,
+          start: starts[fence.line - 1],
+          end: starts[row] + lines[row].length,
+          kind: 'doctest',
+          harness: 'doctest',
+          covers: [],
+        },
+        body: maskRustSource(code).masked,
+        scope: '',
+        documents: qualified,
+      });
+    }
+    fence = undefined;
+  }
+  return results;
+}
+
+// ===== Linkage =====
+
+/**
+ * Resolve the references in a test body to project items
+ */
+function referenceResolver(items: RustItem[]): (body: string, scope: string) => string[] {
+  const names = new Set(items.map(item => item.name));
+  const types = new Set(items.filter(item => item.kind === 'type').map(item => item.name));
+  const freeFns = new Set(items.filter(item => item.kind === 'fn').map(item => item.name));
+  const owners = new Map<string, string[]>();
+  for (const item of items) {
+    if (item.kind !== 'method') continue;
+    const [owner, method] = item.name.split('::');
+    const known = owners.get(method) || [];
+    if (!known.includes(owner)) owners.set(method, [...known, owner]);
+  }
+
+  const typesIn = (text: string) =>
+    new Set(Array.from(text.matchAll(TYPE_MENTION), match => match[1]));
+
+  return (body, scope) => {
+    const covers: string[] = [];
+    const mentioned = typesIn(body);
+    let match: RegExpExecArray | null;
+
+    ITEM_PATH.lastIndex = 0;
+    while ((match = ITEM_PATH.exec(body)) !== null) {
+      const path = `${match[1]}::${match[2]}`;
+      if (names.has(path)) covers.push(path);
+      // `crate::parse(..)`, `zlim::parse(..)` from a doctest or integration test
+      else if (/^[a-z_]/.test(match[1]) && freeFns.has(match[2])) covers.push(match[2]);
+      // Step back so `a::B::c` also yields `B::c`
+      ITEM_PATH.lastIndex = match.index + match[0].length - match[2].length;
+    }
+
+    METHOD_CALL.lastIndex = 0;
+    while ((match = METHOD_CALL.exec(body)) !== null) {
+      const method = match[1];
+      const candidates = owners.get(method) || [];
+      let resolved =
+        candidates.length === 1 ? candidates : candidates.filter(owner => mentioned.has(owner));
+      // `l.reset()` on a value built by a helper: the types the test module works with decide
+      if (resolved.length === 0 && candidates.length > 1) {
+        const inScope = typesIn(scope);
+        resolved = candidates.filter(owner => inScope.has(owner));
+      }
+      covers.push(...resolved.map(owner => `${owner}::${method}`));
+    }
+
+    BARE_CALL.lastIndex = 0;
+    while ((match = BARE_CALL.exec(body)) !== null) {
+      if (freeFns.has(match[1])) covers.push(match[1]);
+    }
+
+    for (const type of mentioned) {
+      if (types.has(type)) covers.push(type);
+    }
+    return unique(covers);
+  };
+}
+
+/**
+ * `crate::limiter::RateLimiter::acquire()` -> `RateLimiter::acquire`, `limiter::parse` -> `parse`
+ */
+function normalizeSymbol(symbol: string): string | undefined {
+  const segments = symbol
+    .replace(/\(.*$/, '')
+    .split('::')
+    .map(segment => segment.trim())
+    .filter(Boolean);
+  if (segments.length === 0 || !segments.every(segment => /^[A-Za-z_]\w*$/.test(segment))) {
+    return undefined;
+  }
+  const last = segments[segments.length - 1];
+  const owner = segments[segments.length - 2];
+  return owner && /^[A-Z]/.test(owner) ? `${owner}::${last}` : last;
+}
+
+// ===== Source structure =====
+
+/**
+ * Inline `mod name { .. }` blocks, flagging `#[cfg(test)]` ones
+ */
+function inlineModules(
+  masked: string
+): Array<Range & { name: string; itemStart: number; isTest: boolean }> {
+  const modules: Array<Range & { name: string; itemStart: number; isTest: boolean }> = [];
+  let match: RegExpExecArray | null;
+  INLINE_MOD.lastIndex = 0;
+  while ((match = INLINE_MOD.exec(masked)) !== null) {
+    const lineStart = masked.lastIndexOf('\n', match.index) + 1;
+    const attrs = precedingAttributes(masked, lineStart + leadingSpace(masked, lineStart));
+    const open = match.index + match[0].length - 1;
+    modules.push({
+      name: match[1],
+      start: open,
+      end: matchBracket(masked, open) + 1,
+      itemStart: attrs.length ? attributesStart(masked, lineStart) : match.index,
+      isTest: attrs.some(attr => /^#\[\s*cfg\s*\(\s*test\s*\)\s*\]$/.test(attr)),
+    });
+  }
+  return modules;
+}
+
+/**
+ * `impl` blocks with the name of their self type (`impl<T> Trait for Pool<T>` -> `Pool`)
+ */
+function implBlocks(masked: string): Array<Range & { selfType: string }> {
+  const blocks: Array<Range & { selfType: string }> = [];
+  let match: RegExpExecArray | null;
+  IMPL_ITEM.lastIndex = 0;
+  while ((match = IMPL_ITEM.exec(masked)) !== null) {
+    const headerStart = match.index + match[0].length;
+    const open = itemBodyStart(masked, headerStart);
+    if (open === undefined) continue;
+
+    let header = masked.slice(headerStart, open).replace(/\bwhere\b[\s\S]*$/, '');
+    if (header.trimStart().startsWith('<')) {
+      const generics = header.indexOf('<');
+      header = header.slice(closingAngle(header, generics) + 1);
+    }
+    const forIndex = header.search(/\bfor\b/);
+    const selfType = forIndex === -1 ? header : header.slice(forIndex + 3);
+    const name = /^[\s&]*(?:mut\s+|dyn\s+)?(?:[A-Za-z_]\w*\s*::\s*)*([A-Za-z_]\w*)/.exec(selfType);
+    if (name) blocks.push({ start: open, end: matchBracket(masked, open) + 1, selfType: name[1] });
+  }
+  return blocks;
+}
+
+function macroBodies(masked: string, pattern: RegExp): Range[] {
+  const ranges: Range[] = [];
+  let match: RegExpExecArray | null;
+  pattern.lastIndex = 0;
+  while ((match = pattern.exec(masked)) !== null) {
+    const open = match.index + match[0].length - 1;
+    ranges.push({ start: open, end: matchBracket(masked, open) + 1 });
+  }
+  return ranges;
+}
+
+/**
+ * Functions registered with `criterion_group!(benches, a, b)` or `criterion_group! { name =
+ * benches; config = ..; targets = a, b }`
+ */
+function criterionGroupTargets(masked: string): Set<string> {
+  const targets = new Set<string>();
+  let match: RegExpExecArray | null;
+  CRITERION_GROUP.lastIndex = 0;
+  while ((match = CRITERION_GROUP.exec(masked)) !== null) {
+    const open = match.index + match[0].length - 1;
+    const args = masked.slice(open + 1, matchBracket(masked, open));
+    const list = /\btargets\s*=\s*([^;]*)/.exec(args)?.[1] ?? args.split(',').slice(1).join(',');
+    for (const target of list.split(',')) {
+      const name = /^\s*(?:[A-Za-z_]\w*::)*([A-Za-z_]\w*)\s*$/.exec(target)?.[1];
+      if (name) targets.add(name);
+    }
+  }
+  return targets;
+}
+
+/**
+ * Module path of a file inside its crate as `cargo test` prints it: `src/net/limiter.rs` ->
+ * `net::limiter`; crate roots and test/bench targets are the empty path
+ */
+function fileModulePath(file: string): string[] {
+  const inSrc = /(?:^|\/)src\/(.*)\.rs$/.exec(file)?.[1];
+  if (inSrc === undefined) return [];
+  const segments = inSrc.split('/');
+  if (segments[segments.length - 1] === 'mod') segments.pop();
+  if (segments.length === 1 && /^(lib|main)$/.test(segments[0])) segments.pop();
+  if (segments[0] === 'bin') return [];
+  return segments;
+}
+
+/**
+ * Offset of the first outer attribute above `offset`
+ */
+function attributesStart(masked: string, offset: number): number {
+  const attrs = precedingAttributes(masked, offset);
+  let start = offset;
+  for (let k = attrs.length - 1; k >= 0; k--) {
+    start = masked.lastIndexOf(attrs[k], start - 1);
+  }
+  return start;
+}
+
+function leadingSpace(text: string, lineStart: number): number {
+  let i = lineStart;
+  while (text[i] === ' ' || text[i] === '\t') i++;
+  return i - lineStart;
+}
+
+function unique<T>(values: T[]): T[] {
+  return Array.from(new Set(values));
+}
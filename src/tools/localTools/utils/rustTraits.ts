@@ -0,0 +1,396 @@
+/**
+ * @fileOverview: Trait implementation graph for Rust projects
+ * @module: RustTraits
+ * @keyFunctions:
+ *   - buildRustTraitIndex(): Index trait definitions, supertraits, `impl Trait for Type`, derives
+ *   - loadRustTraitIndex(): Read the Rust files of a project and index them
+ *   - findTraitImplementors(): Answer "who implements X", including subtraits of X
+ * @context: Traits are matched by their last path segment (`fmt::Display` and `Display` are the
+ *           same trait), since impls name traits through whatever `use` is in scope. Blanket impls
+ *           (`impl<T: Read> Source for T`) are kept apart from generic impls on concrete types
+ *           (`impl<T> Source for Vec<T>`) so callers can say which types they cover.
+ */
+
+import { readFile } from 'fs/promises';
+import { FileInfo } from '../../../core/compactor/fileDiscovery';
+import { logger } from '../../../utils/logger';
+import { toPosix } from './pathUtils';
+import {
+  blankRustTestModules,
+  closingAngle,
+  lineAt,
+  maskRustSource,
+  splitTopLevel,
+} from './rustSource';
+
+export interface RustTraitDef {
+  name: string;
+  file: string;
+  line: number;
+  start: number;
+  end: number;
+  generics?: string;
+  supertraits: string[]; // last path segments, lifetimes and `?Sized` dropped
+  isUnsafe: boolean;
+}
+
+export interface RustTraitImpl {
+  trait: string; // last path segment without generic arguments
+  traitPath: string; // as written, e.g. `serde::Serialize` or `From<io::Error>`
+  forType: string;
+  file: string;
+  line: number;
+  start: number;
+  end: number;
+  source: 'impl' | 'derive';
+  generics?: string; // impl<...> parameters as written
+  blanket: boolean; // the implementing type is one of the impl's own type parameters
+  negative: boolean; // impl !Send for X
+  bounds: string[]; // bounds on the blanket parameter, from impl<> and where clauses
+}
+
+export interface RustTraitIndex {
+  traits: RustTraitDef[];
+  impls: RustTraitImpl[];
+}
+
+export interface TraitImplementors {
+  definitions: RustTraitDef[];
+  impls: RustTraitImpl[];
+  subtraits: RustTraitDef[]; // traits that (transitively) require the queried one
+}
+
+const TRAIT_REGEX =
+  /^[ \t]*(?:pub(?:\s*\([^)]*\))?\s+)?(unsafe\s+)?(?:auto\s+)?trait\s+([A-Za-z_]\w*)/gm;
+const IMPL_REGEX = /^[ \t]*(?:(?:default|unsafe)\s+)*impl\b/gm;
+const DERIVE_REGEX = /#\[\s*(?:cfg_attr\s*\([^\]]*?,\s*)?derive\s*\(([^)]*)\)/g;
+const DERIVE_TARGET = new RegExp(
+  String.raw`^\s*(?:#\[[^\]]*\]\s*)*(?:pub(?:\s*\([^)]*\))?\s+)?` +
+    String.raw`(?:struct|enum|union)\s+([A-Za-z_]\w*)(\s*<[^{;(]*>)?`
+);
+
+/**
+ * Index the traits and impls of a project's Rust sources (relPath -> content).
+ * Code in `#[cfg(test)] mod` blocks is skipped so test doubles don't count as implementors
+ */
+export function buildRustTraitIndex(sources: Map<string, string>): RustTraitIndex {
+  const index: RustTraitIndex = { traits: [], impls: [] };
+
+  for (const [file, content] of sources) {
+    const { code, masked } = blankRustTestModules(maskRustSource(content));
+    indexTraits(file, code, masked, index);
+    indexImpls(file, code, masked, index);
+    indexDerives(file, masked, index);
+  }
+
+  return index;
+}
+
+/**
+ * Read the Rust files in a file set and index them; unreadable files are skipped
+ */
+export async function loadRustTraitIndex(files: FileInfo[]): Promise<RustTraitIndex> {
+  const sources = new Map<string, string>();
+  for (const file of files) {
+    if (file.language !== 'rust') continue;
+    try {
+      sources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf-8'));
+    } catch (error) {
+      logger.warn('Could not read file for trait analysis', {
+        file: file.relPath,
+        error: (error as Error).message,
+      });
+    }
+  }
+  return buildRustTraitIndex(sources);
+}
+
+/**
+ * Everything that implements a trait. A string matches the trait name or path exactly
+ * (`Storage`, `crate::storage::Storage`); a RegExp is tested against both
+ */
+export function findTraitImplementors(
+  index: RustTraitIndex,
+  trait: string | RegExp
+): TraitImplementors {
+  const matches = (name: string, written: string) =>
+    typeof trait === 'string'
+      ? name === traitName(trait) || written === trait
+      : trait.test(name) || trait.test(written);
+
+  const definitions = index.traits.filter(def => matches(def.name, def.name));
+  const names = new Set([
+    ...definitions.map(def => def.name),
+    ...index.impls.filter(impl => matches(impl.trait, impl.traitPath)).map(impl => impl.trait),
+  ]);
+
+  // Subtraits: anything that lists a matched trait among its (transitive) supertraits
+  const subtraits: RustTraitDef[] = [];
+  const required = new Set(names);
+  for (let changed = true; changed; ) {
+    changed = false;
+    for (const def of index.traits) {
+      if (required.has(def.name) || !def.supertraits.some(name => required.has(name))) continue;
+      required.add(def.name);
+      subtraits.push(def);
+      changed = true;
+    }
+  }
+
+  return {
+    definitions,
+    impls: index.impls.filter(impl => names.has(impl.trait) && !impl.negative),
+    subtraits,
+  };
+}
+
+/**
+ * One-line description of an impl: `Type`, `T: Read (blanket)`, `#[derive] Type`
+ */
+export function describeTraitImpl(impl: RustTraitImpl): string {
+  if (impl.source === 'derive') return `${impl.forType} (derive)`;
+  if (impl.blanket) {
+    return `${impl.bounds.length > 0 ? impl.bounds.join(', ') : impl.forType} (blanket)`;
+  }
+  return impl.generics ? `${impl.forType} (generic)` : impl.forType;
+}
+
+// ===== Indexing =====
+
+function indexTraits(file: string, code: string, masked: string, index: RustTraitIndex): void {
+  TRAIT_REGEX.lastIndex = 0;
+  let match: RegExpExecArray | null;
+
+  while ((match = TRAIT_REGEX.exec(masked)) !== null) {
+    const nameEnd = match.index + match[0].length;
+    const headerEnd = itemHeaderEnd(masked, nameEnd);
+    // `trait Alias = A + B;` (trait aliases) have no body and no supertraits list
+    let rest = collapse(code.slice(nameEnd, headerEnd));
+
+    let generics: string | undefined;
+    if (rest.startsWith('<')) {
+      const close = closingAngle(rest, 0);
+      generics = rest.slice(0, close + 1);
+      rest = rest.slice(close + 1).trim();
+    }
+
+    const [bounds, where] = splitWhere(rest);
+    const supertraits = bounds.startsWith(':') ? boundTraits(bounds.slice(1)) : [];
+    // `where Self: Sized + Debug` is another way of writing supertraits
+    for (const predicate of splitTopLevel(where, ',')) {
+      const selfBound = /^Self\s*:(.*)$/.exec(predicate.trim());
+      if (selfBound) supertraits.push(...boundTraits(selfBound[1]));
+    }
+
+    index.traits.push({
+      name: match[2],
+      file,
+      line: lineAt(masked, match.index),
+      start: match.index + match[0].search(/\S/),
+      end: bodyEnd(masked, headerEnd),
+      ...(generics ? { generics } : {}),
+      supertraits: Array.from(new Set(supertraits)).filter(name => name !== 'Sized'),
+      isUnsafe: Boolean(match[1]),
+    });
+  }
+}
+
+function indexImpls(file: string, code: string, masked: string, index: RustTraitIndex): void {
+  IMPL_REGEX.lastIndex = 0;
+  let match: RegExpExecArray | null;
+
+  while ((match = IMPL_REGEX.exec(masked)) !== null) {
+    const headerEnd = itemHeaderEnd(masked, match.index + match[0].length);
+    let rest = collapse(code.slice(match.index + match[0].length, headerEnd));
+
+    let generics: string | undefined;
+    if (rest.startsWith('<')) {
+      const close = closingAngle(rest, 0);
+      generics = rest.slice(0, close + 1);
+      rest = rest.slice(close + 1).trim();
+    }
+
+    const [head, where] = splitWhere(rest);
+    const forAt = topLevelFor(head);
+    if (forAt === -1) continue; // inherent impl
+
+    let traitPath = head.slice(0, forAt).trim();
+    const negative = traitPath.startsWith('!');
+    if (negative) traitPath = traitPath.slice(1).trim();
+    const forType = head.slice(forAt + 4).trim();
+
+    const params = genericParams(generics);
+    const base = forType.replace(/^(?:&\s*(?:'\w+\s+)?(?:mut\s+)?)+/, '').trim();
+    const blanket = params.has(base);
+
+    index.impls.push({
+      trait: traitName(traitPath),
+      traitPath,
+      forType,
+      file,
+      line: lineAt(masked, match.index),
+      start: match.index + match[0].search(/\S/),
+      end: bodyEnd(masked, headerEnd),
+      source: 'impl',
+      ...(generics ? { generics } : {}),
+      blanket,
+      negative,
+      bounds: blanket ? paramBounds(base, generics, where) : [],
+    });
+  }
+}
+
+function indexDerives(file: string, masked: string, index: RustTraitIndex): void {
+  DERIVE_REGEX.lastIndex = 0;
+  let match: RegExpExecArray | null;
+
+  while ((match = DERIVE_REGEX.exec(masked)) !== null) {
+    // The derive may be one of several attributes stacked above the item
+    const attrEnd = masked.indexOf(']', match.index + match[0].length);
+    if (attrEnd === -1) continue;
+    const target = DERIVE_TARGET.exec(masked.slice(attrEnd + 1, attrEnd + 2000));
+    if (!target) continue;
+
+    const forType = target[1] + (target[2] ? genericArgs(target[2].trim()) : '');
+
+    for (const traitPath of splitTopLevel(match[1], ',')) {
+      const written = collapse(traitPath);
+      if (!written) continue;
+      index.impls.push({
+        trait: traitName(written),
+        traitPath: written,
+        forType,
+        file,
+        line: lineAt(masked, match.index),
+        start: match.index,
+        end: attrEnd + 1,
+        source: 'derive',
+        blanket: false,
+        negative: false,
+        bounds: [],
+      });
+    }
+  }
+}
+
+// ===== Header parsing =====
+
+/**
+ * Offset of the `{` or `;` that ends an item header starting at `from`
+ */
+function itemHeaderEnd(masked: string, from: number): number {
+  let depth = 0;
+  for (let i = from; i < masked.length; i++) {
+    const char = masked[i];
+    if (char === '(' || char === '[') depth++;
+    else if (char === ')' || char === ']') depth--;
+    else if ((char === '{' || char === ';') && depth === 0) return i;
+  }
+  return masked.length;
+}
+
+/**
+ * Offset just past the body opened at `open`, or past the `;` of a bodiless item
+ */
+function bodyEnd(masked: string, open: number): number {
+  if (masked[open] !== '{') return open + 1;
+  let depth = 0;
+  for (let i = open; i < masked.length; i++) {
+    if (masked[i] === '{') depth++;
+    else if (masked[i] === '}' && --depth === 0) return i + 1;
+  }
+  return masked.length;
+}
+
+/**
+ * Split `Bounds where Predicates` at a top-level `where`
+ */
+function splitWhere(text: string): [string, string] {
+  const where = /\bwhere\b/.exec(text);
+  return where
+    ? [text.slice(0, where.index).trim(), text.slice(where.index + 5).trim()]
+    : [text.trim(), ''];
+}
+
+/**
+ * Position of the ` for ` separating trait and type, skipping `for<'a>` higher-ranked bounds
+ */
+function topLevelFor(text: string): number {
+  let depth = 0;
+  for (let i = 0; i < text.length; i++) {
+    const char = text[i];
+    if (char === '<' || char === '(' || char === '[') depth++;
+    else if ((char === '>' && text[i - 1] !== '-') || char === ')' || char === ']') depth--;
+    else if (
+      depth === 0 &&
+      text.startsWith('for', i) &&
+      /\s/.test(text[i - 1] ?? '') &&
+      /\s/.test(text[i + 3] ?? '')
+    ) {
+      return i - 1;
+    }
+  }
+  return -1;
+}
+
+/**
+ * Type parameter names declared in `<'a, T: Clone, const N: usize>` (lifetimes excluded)
+ */
+function genericParams(generics: string | undefined): Set<string> {
+  if (!generics) return new Set();
+  return new Set(
+    splitTopLevel(generics.slice(1, -1), ',')
+      .map(param => param.trim().replace(/^const\s+/, ''))
+      .filter(param => param && !param.startsWith("'"))
+      .map(param => /^[A-Za-z_]\w*/.exec(param)?.[0] ?? '')
+      .filter(Boolean)
+  );
+}
+
+/**
+ * Parameters as a type would name them: `<'a, T: Clone, const N: usize>` -> `<'a, T, N>`
+ */
+function genericArgs(generics: string): string {
+  const args = splitTopLevel(generics.slice(1, -1), ',').map(
+    param => param.trim().replace(/^const\s+/, '').split(/\s*[:=]/)[0]
+  );
+  return `<${args.join(', ')}>`;
+}
+
+/**
+ * Bounds on one type parameter, from both `impl<T: A>` and `where T: B`
+ */
+function paramBounds(param: string, generics: string | undefined, where: string): string[] {
+  const predicates = [
+    ...(generics ? splitTopLevel(generics.slice(1, -1), ',') : []),
+    ...splitTopLevel(where, ','),
+  ];
+  const bounds: string[] = [];
+  for (const predicate of predicates) {
+    const bound = new RegExp(`^\\s*${param}\\s*:(.+)$`).exec(predicate);
+    if (bound) bounds.push(`${param}: ${collapse(bound[1])}`);
+  }
+  return bounds;
+}
+
+/**
+ * Trait names in a `A + b::B<X> + 'static + ?Sized` bound list
+ */
+function boundTraits(bounds: string): string[] {
+  return splitTopLevel(bounds, '+')
+    .map(bound => bound.trim())
+    .filter(bound => bound && !bound.startsWith("'") && !bound.startsWith('?'))
+    .map(bound => traitName(bound.replace(/^for\s*<[^>]*>\s*/, '')));
+}
+
+/**
+ * `std::fmt::Display` -> `Display`, `From<io::Error>` -> `From`, `Fn(u8) -> u8` -> `Fn`
+ */
+function traitName(written: string): string {
+  const base = written.replace(/^(?:dyn|impl)\s+/, '').split(/[<(]/)[0].trim();
+  return base.split('::').pop()!.trim();
+}
+
+function collapse(text: string): string {
+  return text.replace(/\s+/g, ' ').trim();
+}
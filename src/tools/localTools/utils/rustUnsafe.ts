@@ -0,0 +1,455 @@
+/**
+ * @fileOverview: Unsafe code and FFI audit for Rust sources
+ * @module: RustUnsafe
+ * @keyFunctions:
+ *   - extractUnsafeSites(): `unsafe` blocks/fns/impls, extern blocks, `#[no_mangle]` exports and
+ *     raw-pointer dereferences, each with the `// SAFETY:` comment that justifies it
+ *   - rollUpUnsafeSites(): Per-crate and per-module counts of sites and unjustified sites
+ *   - analyzeRustUnsafe(): Audit every Rust file of a project, attributed to workspace crates
+ * @context: Feeds security reviews from local_project_hints and ai_project_insights. A site is
+ *           justified by a `// SAFETY:` comment directly above it (attributes may sit in between),
+ *           on its line, or first thing in its block; `unsafe fn` also accepts a `# Safety` doc
+ *           section. Raw-pointer dereferences inherit the justification of their unsafe scope.
+ */
+
+import { readFile } from 'fs/promises';
+import { FileInfo } from '../../../core/compactor/fileDiscovery';
+import { logger } from '../../../utils/logger';
+import { analyzeCargoWorkspace, crateForFile } from './cargoWorkspace';
+import { toPosix } from './pathUtils';
+import {
+  itemBodyStart,
+  lineAtOffset,
+  lineOffsets,
+  maskRustSource,
+  matchBracket,
+} from './rustSource';
+import { cfgGatedItem } from './rustCfg';
+
+export type UnsafeSiteKind =
+  | 'unsafe_block'
+  | 'unsafe_fn'
+  | 'unsafe_impl'
+  | 'extern_block'
+  | 'no_mangle'
+  | 'raw_deref';
+
+export interface UnsafeSite {
+  file: string;
+  line: number;
+  endLine: number;
+  kind: UnsafeSiteKind;
+  detail: string; // `impl Send for Conn`, `extern "C" { open, close }`, `fn ffi_open`, `*ptr`
+  function?: string; // enclosing function
+  safety?: string; // text of the justifying SAFETY comment or `# Safety` section
+  justified: boolean;
+  crate?: string;
+  module?: string; // `crate::sys::raw`
+}
+
+export interface UnsafeModuleRollup {
+  module: string;
+  file: string;
+  total: number;
+  unjustified: number;
+}
+
+export interface UnsafeCrateRollup {
+  crate: string;
+  total: number;
+  unjustified: number;
+  byKind: Partial<Record<UnsafeSiteKind, number>>;
+  modules: UnsafeModuleRollup[];
+}
+
+export interface RustUnsafeAudit {
+  sites: UnsafeSite[];
+  crates: UnsafeCrateRollup[];
+}
+
+interface Scope {
+  start: number; // offset of the opening `{`
+  end: number;
+  safety?: string;
+}
+
+const UNSAFE_BLOCK = /\bunsafe\s*\{/g;
+const UNSAFE_FN = /\bunsafe\s+(?:extern\s*(?:"[^"]*"\s*)?)?fn\s+([A-Za-z_]\w*)/g;
+const UNSAFE_IMPL = /\bunsafe\s+impl\b\s*([^{;]*)/g;
+const EXTERN_BLOCK = /\b(?:unsafe\s+)?extern\s*(?:"[^"]*"\s*)?\{/g;
+const EXPORT_ATTRIBUTE = /#\[\s*(?:unsafe\s*\(\s*)?(no_mangle|export_name)\b[^\]]*\]/g;
+const FN_ITEM = /\bfn\s+([A-Za-z_]\w*)/g;
+// `name: *const T` in parameters, fields and annotated lets
+const RAW_POINTER_BINDING = /\b([A-Za-z_]\w*)\s*:\s*\*\s*(?:const|mut)\b/g;
+// `let p = x.as_ptr();`, `let p = &mut v as *mut T;`, `let p = Box::into_raw(b);`
+const RAW_POINTER_LET = new RegExp(
+  String.raw`\blet\s+(?:mut\s+)?([A-Za-z_]\w*)\s*=\s*[^;]*?(?:\bas\s+\*\s*(?:const|mut)\b|` +
+    String.raw`\.as_(?:mut_)?ptr\s*\(|\bptr::null(?:_mut)?\s*\(|\binto_raw\s*\()`
+);
+const POINTER_ARITHMETIC = new Set(['add', 'sub', 'offset', 'byte_add', 'byte_sub']);
+const DEREF_KEYWORDS = new Set(['return', 'in', 'match', 'if', 'else', 'while', 'break', 'mut']);
+const SAFETY_COMMENT = /\bSAFETY\s*:/i;
+
+/**
+ * Unsafe and FFI sites of Rust sources (relPath -> content), in file and line order
+ */
+export function extractUnsafeSites(sources: Map<string, string>): UnsafeSite[] {
+  const sites: UnsafeSite[] = [];
+
+  for (const [file, content] of sources) {
+    const { code, masked } = maskRustSource(content);
+    const lines = content.split('\n');
+    const lineStarts = lineOffsets(content);
+    const lineOf = (offset: number) => lineAtOffset(lineStarts, offset);
+    const functions = functionBodies(masked);
+    const functionAt = (offset: number) =>
+      functions
+        .filter(fn => fn.start < offset && offset < fn.end)
+        .sort((a, b) => a.end - a.start - (b.end - b.start))[0]?.name;
+    const scopes: Scope[] = [];
+    const push = (site: Omit<UnsafeSite, 'file' | 'justified' | 'function'>, offset: number) => {
+      sites.push({ file, ...site, function: functionAt(offset), justified: !!site.safety });
+    };
+    let match: RegExpExecArray | null;
+
+    UNSAFE_BLOCK.lastIndex = 0;
+    while ((match = UNSAFE_BLOCK.exec(masked)) !== null) {
+      const open = match.index + match[0].length - 1;
+      const end = matchBracket(masked, open);
+      const line = lineOf(match.index);
+      const safety =
+        commentAbove(lines, line) ?? trailingComment(lines, line) ?? firstInBlock(lines, line);
+      scopes.push({ start: open, end, safety });
+      push(
+        { line, endLine: lineOf(end), kind: 'unsafe_block', detail: 'unsafe { .. }', safety },
+        match.index
+      );
+    }
+
+    UNSAFE_FN.lastIndex = 0;
+    while ((match = UNSAFE_FN.exec(masked)) !== null) {
+      const line = lineOf(match.index);
+      const bodyOpen = itemBodyStart(masked, match.index + match[0].length);
+      const end = bodyOpen === undefined ? match.index : matchBracket(masked, bodyOpen);
+      const safety =
+        commentAbove(lines, line) ?? safetyDocSection(lines, line) ?? trailingComment(lines, line);
+      if (bodyOpen !== undefined) scopes.push({ start: bodyOpen, end, safety });
+      push(
+        { line, endLine: lineOf(end), kind: 'unsafe_fn', detail: `fn ${match[1]}`, safety },
+        match.index
+      );
+    }
+
+    UNSAFE_IMPL.lastIndex = 0;
+    while ((match = UNSAFE_IMPL.exec(masked)) !== null) {
+      const line = lineOf(match.index);
+      const bodyOpen = itemBodyStart(masked, match.index + match[0].length);
+      const end = bodyOpen === undefined ? match.index : matchBracket(masked, bodyOpen);
+      const header = match[1].replace(/\s+where\b[\s\S]*$/, '').replace(/\s+/g, ' ').trim();
+      const detail = header.startsWith('<') ? `impl${header}` : `impl ${header}`;
+      const safety = commentAbove(lines, line) ?? trailingComment(lines, line);
+      push({ line, endLine: lineOf(end), kind: 'unsafe_impl', detail, safety }, match.index);
+    }
+
+    EXTERN_BLOCK.lastIndex = 0;
+    while ((match = EXTERN_BLOCK.exec(masked)) !== null) {
+      const open = match.index + match[0].length - 1;
+      const end = matchBracket(masked, open);
+      const line = lineOf(match.index);
+      const abi = /"([^"]*)"/.exec(code.slice(match.index, open))?.[1] ?? 'C';
+      const declared = Array.from(masked.slice(open, end).matchAll(FN_ITEM), fn => fn[1]);
+      const listed = declared.length > 6 ? [...declared.slice(0, 6), '..'] : declared;
+      const safety = commentAbove(lines, line) ?? trailingComment(lines, line);
+      push(
+        {
+          line,
+          endLine: lineOf(end),
+          kind: 'extern_block',
+          detail: `extern "${abi}" { ${listed.join(', ')} }`.replace('{  }', '{}'),
+          safety,
+        },
+        match.index
+      );
+    }
+
+    EXPORT_ATTRIBUTE.lastIndex = 0;
+    while ((match = EXPORT_ATTRIBUTE.exec(masked)) !== null) {
+      const attrEnd = match.index + match[0].length;
+      const item = cfgGatedItem(masked, attrEnd);
+      const line = lineOf(match.index);
+      const exportName =
+        match[1] === 'export_name'
+          ? /"([^"]*)"/.exec(code.slice(match.index, attrEnd))?.[1]
+          : undefined;
+      const label = item?.label ?? 'item';
+      const safety = commentAbove(lines, line) ?? trailingComment(lines, line);
+      push(
+        {
+          line,
+          endLine: item ? lineOf(item.end) : line,
+          kind: 'no_mangle',
+          detail: exportName ? `${label} as "${exportName}"` : label,
+          safety,
+        },
+        match.index
+      );
+    }
+
+    for (const deref of rawPointerDerefs(masked, scopes)) {
+      const line = lineOf(deref.offset);
+      sites.push({
+        file,
+        line,
+        endLine: line,
+        kind: 'raw_deref',
+        detail: deref.expression,
+        function: functionAt(deref.offset),
+        safety: deref.scope.safety,
+        justified: !!deref.scope.safety,
+      });
+    }
+  }
+
+  return sites.sort((a, b) => a.file.localeCompare(b.file) || a.line - b.line);
+}
+
+/**
+ * Count sites and unjustified sites per crate and module, most unjustified first
+ */
+export function rollUpUnsafeSites(sites: UnsafeSite[]): UnsafeCrateRollup[] {
+  const crates = new Map<string, UnsafeCrateRollup>();
+
+  for (const site of sites) {
+    const name = site.crate ?? '(no crate)';
+    let crate = crates.get(name);
+    if (!crate) {
+      crate = { crate: name, total: 0, unjustified: 0, byKind: {}, modules: [] };
+      crates.set(name, crate);
+    }
+    const moduleName = site.module ?? site.file;
+    let module = crate.modules.find(entry => entry.module === moduleName);
+    if (!module) {
+      module = { module: moduleName, file: site.file, total: 0, unjustified: 0 };
+      crate.modules.push(module);
+    }
+
+    const missing = site.justified ? 0 : 1;
+    crate.total++;
+    crate.unjustified += missing;
+    crate.byKind[site.kind] = (crate.byKind[site.kind] ?? 0) + 1;
+    module.total++;
+    module.unjustified += missing;
+  }
+
+  const byRisk = <T extends { total: number; unjustified: number }>(a: T, b: T) =>
+    b.unjustified - a.unjustified || b.total - a.total;
+  return Array.from(crates.values())
+    .map(crate => ({ ...crate, modules: crate.modules.sort(byRisk) }))
+    .sort(byRisk);
+}
+
+/**
+ * Audit the Rust files of a project. Undefined when there is no unsafe code or FFI at all
+ */
+export async function analyzeRustUnsafe(
+  projectPath: string,
+  files: FileInfo[]
+): Promise<RustUnsafeAudit | undefined> {
+  const sources = new Map<string, string>();
+  for (const file of files) {
+    if (file.language !== 'rust') continue;
+    try {
+      sources.set(toPosix(file.relPath), await readFile(file.absPath, 'utf-8'));
+    } catch (error) {
+      logger.warn('Could not read file for unsafe audit', {
+        file: file.relPath,
+        error: (error as Error).message,
+      });
+    }
+  }
+
+  const sites = extractUnsafeSites(sources);
+  if (sites.length === 0) return undefined;
+
+  const workspace = analyzeCargoWorkspace(projectPath);
+  for (const site of sites) {
+    const crate = workspace ? crateForFile(workspace, site.file) : undefined;
+    site.crate = crate?.name;
+    site.module = moduleOf(site.file, crate?.dir ?? '');
+  }
+
+  return { sites, crates: rollUpUnsafeSites(sites) };
+}
+
+// ===== Comments =====
+
+/**
+ * SAFETY text of the comment block directly above a line, looking past attributes
+ */
+function commentAbove(lines: string[], line: number): string | undefined {
+  const block: string[] = [];
+  for (let index = line - 2; index >= 0; index--) {
+    const text = lines[index].trim();
+    if (/^#!?\[/.test(text) && block.length === 0) continue;
+    if (!/^(\/\/|\/\*|\*)/.test(text)) break;
+    block.unshift(text);
+  }
+  return safetyText(block);
+}
+
+function trailingComment(lines: string[], line: number): string | undefined {
+  const text = lines[line - 1] ?? '';
+  const comment = text.indexOf('//');
+  return comment === -1 ? undefined : safetyText([text.slice(comment)]);
+}
+
+/**
+ * SAFETY comment opening the block that starts on `line` (`unsafe {` followed by a comment)
+ */
+function firstInBlock(lines: string[], line: number): string | undefined {
+  const block: string[] = [];
+  for (let index = line; index < lines.length; index++) {
+    const text = lines[index].trim();
+    if (!/^(\/\/|\/\*|\*)/.test(text)) break;
+    block.push(text);
+  }
+  return safetyText(block);
+}
+
+/**
+ * First paragraph of the `# Safety` section in the doc comment above an `unsafe fn`
+ */
+function safetyDocSection(lines: string[], line: number): string | undefined {
+  const docs: string[] = [];
+  for (let index = line - 2; index >= 0; index--) {
+    const text = lines[index].trim();
+    if (/^#!?\[/.test(text)) continue;
+    if (!text.startsWith('///')) break;
+    docs.unshift(text.replace(/^\/\/\/\s?/, ''));
+  }
+
+  const heading = docs.findIndex(text => /^#+\s*Safety\s*$/i.test(text));
+  if (heading === -1) return undefined;
+  const paragraph: string[] = [];
+  for (const text of docs.slice(heading + 1)) {
+    if (/^#/.test(text) || (text === '' && paragraph.length > 0)) break;
+    if (text) paragraph.push(text);
+  }
+  return paragraph.length > 0 ? truncate(paragraph.join(' ')) : 'documented';
+}
+
+function safetyText(comment: string[]): string | undefined {
+  const text = comment
+    .map(line => line.replace(/^(\/\/+!?|\/\*+|\*\/|\*)\s?/, '').replace(/\*\/$/, ''))
+    .join(' ');
+  const match = SAFETY_COMMENT.exec(text);
+  if (!match) return undefined;
+  return truncate(text.slice(match.index + match[0].length).trim()) || 'SAFETY';
+}
+
+function truncate(text: string, limit: number = 200): string {
+  const flat = text.replace(/\s+/g, ' ').trim();
+  return flat.length > limit ? `${flat.slice(0, limit - 3)}...` : flat;
+}
+
+// ===== Raw pointers =====
+
+/**
+ * Dereferences of raw pointers inside unsafe scopes: bindings typed or created as raw pointers,
+ * pointer arithmetic (`*p.add(1)`) and casts (`*(x as *const T)`). Each belongs to its
+ * innermost scope
+ */
+function rawPointerDerefs(
+  masked: string,
+  scopes: Scope[]
+): Array<{ offset: number; expression: string; scope: Scope }> {
+  if (scopes.length === 0) return [];
+
+  const pointers = new Set<string>();
+  for (const match of masked.matchAll(RAW_POINTER_BINDING)) pointers.add(match[1]);
+  for (const match of masked.matchAll(new RegExp(RAW_POINTER_LET.source, 'g'))) {
+    pointers.add(match[1]);
+  }
+
+  const derefs = new Map<number, { offset: number; expression: string; scope: Scope }>();
+  for (const scope of scopes) {
+    const body = masked.slice(scope.start, scope.end);
+    for (let i = body.indexOf('*'); i !== -1; i = body.indexOf('*', i + 1)) {
+      if (!isPrefixPosition(body, i)) continue;
+      const expression = derefExpression(body.slice(i + 1), pointers);
+      if (!expression) continue;
+
+      const offset = scope.start + i;
+      const current = derefs.get(offset);
+      // Innermost scope wins
+      if (!current || scope.end - scope.start < current.scope.end - current.scope.start) {
+        derefs.set(offset, { offset, expression: `*${expression}`, scope });
+      }
+    }
+  }
+
+  return Array.from(derefs.values()).sort((a, b) => a.offset - b.offset);
+}
+
+/**
+ * Whether the `*` at index is a unary dereference rather than multiplication or a pointer type
+ */
+function isPrefixPosition(text: string, index: number): boolean {
+  let before = index - 1;
+  while (before >= 0 && /\s/.test(text[before])) before--;
+  if (before < 0) return true;
+  const char = text[before];
+  if (/[\w)\]}'"]/.test(char)) {
+    const word = /([A-Za-z_]\w*)$/.exec(text.slice(0, before + 1))?.[1];
+    return !!word && DEREF_KEYWORDS.has(word);
+  }
+  return true;
+}
+
+function derefExpression(rest: string, pointers: Set<string>): string | undefined {
+  const cast = /^\s*\(\s*([^()]*?\bas\s+\*\s*(?:const|mut)\s+[\w:<>]+)\s*\)/.exec(rest);
+  if (cast) return `(${cast[1].replace(/\s+/g, ' ')})`;
+
+  const operand = /^\s*([A-Za-z_]\w*(?:\s*\.\s*[A-Za-z_]\w*)*)/.exec(rest);
+  if (!operand || /^(?:const|mut)$/.test(operand[1])) return undefined;
+  const segments = operand[1].split('.').map(segment => segment.trim());
+  const after = rest.slice(operand[0].length);
+  const call = /^\s*\(/.exec(after);
+  if (call) {
+    // `*p.add(1)` is pointer arithmetic; other calls (`*guard.lock()`) deref smart pointers
+    const method = segments.pop()!;
+    if (segments.length === 0 || !POINTER_ARITHMETIC.has(method)) return undefined;
+    const args = after.slice(0, matchBracket(after, call[0].length - 1) + 1);
+    return `${segments.join('.')}.${method}${args.replace(/\s+/g, ' ').trim()}`;
+  }
+  return pointers.has(segments[segments.length - 1]) ? segments.join('.') : undefined;
+}
+
+// ===== Structure =====
+
+function functionBodies(masked: string): Array<{ name: string; start: number; end: number }> {
+  const bodies: Array<{ name: string; start: number; end: number }> = [];
+  let match: RegExpExecArray | null;
+  FN_ITEM.lastIndex = 0;
+  while ((match = FN_ITEM.exec(masked)) !== null) {
+    const open = itemBodyStart(masked, match.index + match[0].length);
+    if (open === undefined) continue;
+    bodies.push({ name: match[1], start: open, end: matchBracket(masked, open) });
+  }
+  return bodies;
+}
+
+/**
+ * `crate::a::b` for `<crate dir>/src/a/b.rs`; other targets keep their directory
+ * (`tests::smoke`, `examples::ffi`)
+ */
+function moduleOf(file: string, crateDir: string): string {
+  const inCrate =
+    crateDir && file.startsWith(crateDir + '/') ? file.slice(crateDir.length + 1) : file;
+  const segments = inCrate.replace(/\.rs$/, '').split('/');
+  if (segments[segments.length - 1] === 'mod') segments.pop();
+  if (segments[0] !== 'src') return segments.join('::');
+  segments.shift();
+  if (segments.length === 1 && /^(lib|main)$/.test(segments[0])) segments.pop();
+  return ['crate', ...segments].join('::');
+}
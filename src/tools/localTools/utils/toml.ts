@@ -0,0 +1,310 @@
+/**
+ * @fileOverview: Minimal TOML reader for Cargo manifests and lockfiles
+ * @module: Toml
+ * @keyFunctions:
+ *   - parseToml(): Parse TOML text into plain objects (tables, arrays of tables, inline tables)
+ * @context: Covers the TOML subset used by Cargo.toml and Cargo.lock without adding a dependency.
+ *           Datetimes are kept as strings; malformed input throws so callers can fall back.
+ */
+
+export type TomlValue = string | number | boolean | TomlValue[] | TomlTable;
+export interface TomlTable {
+  [key: string]: TomlValue;
+}
+
+/**
+ * Parse TOML content into a nested object
+ */
+export function parseToml(content: string): TomlTable {
+  return new TomlReader(content).read();
+}
+
+/**
+ * Narrow an unknown TOML value to a table
+ */
+export function asTable(value: TomlValue | undefined): TomlTable | undefined {
+  return value !== undefined && typeof value === 'object' && !Array.isArray(value)
+    ? value
+    : undefined;
+}
+
+/**
+ * Narrow an unknown TOML value to a list of strings (non-string entries are dropped)
+ */
+export function asStringList(value: TomlValue | undefined): string[] {
+  return Array.isArray(value) ? value.filter((v): v is string => typeof v === 'string') : [];
+}
+
+class TomlReader {
+  private pos = 0;
+
+  constructor(private readonly src: string) {}
+
+  read(): TomlTable {
+    const root: TomlTable = {};
+    let current = root;
+
+    while (this.pos < this.src.length) {
+      this.skipWhitespaceAndComments(true);
+      if (this.pos >= this.src.length) break;
+
+      if (this.src.startsWith('[[', this.pos)) {
+        this.pos += 2;
+        const keys = this.readKey();
+        this.expect(']]');
+        const parent = this.descend(root, keys.slice(0, -1));
+        const last = keys[keys.length - 1];
+        const list = parent[last] ?? (parent[last] = []);
+        if (!Array.isArray(list)) {
+          throw this.error(`'${keys.join('.')}' is not an array of tables`);
+        }
+        const table: TomlTable = {};
+        list.push(table);
+        current = table;
+      } else if (this.src[this.pos] === '[') {
+        this.pos += 1;
+        const keys = this.readKey();
+        this.expect(']');
+        current = this.descend(root, keys);
+      } else {
+        this.readKeyValue(current);
+      }
+      this.endOfLine();
+    }
+
+    return root;
+  }
+
+  private readKeyValue(target: TomlTable): void {
+    const keys = this.readKey();
+    this.skipInlineWhitespace();
+    this.expect('=');
+    this.skipInlineWhitespace();
+    const value = this.readValue();
+    const parent = this.descend(target, keys.slice(0, -1));
+    parent[keys[keys.length - 1]] = value;
+  }
+
+  /**
+   * Walk (and create) nested tables; array-of-table segments resolve to their last entry
+   */
+  private descend(root: TomlTable, keys: string[]): TomlTable {
+    let table = root;
+    for (const key of keys) {
+      let next = table[key];
+      if (next === undefined) {
+        next = {};
+        table[key] = next;
+      }
+      if (Array.isArray(next)) {
+        next = next[next.length - 1];
+      }
+      if (typeof next !== 'object' || next === null || Array.isArray(next)) {
+        throw this.error(`'${key}' is not a table`);
+      }
+      table = next;
+    }
+    return table;
+  }
+
+  private readKey(): string[] {
+    const keys: string[] = [];
+    for (;;) {
+      this.skipInlineWhitespace();
+      const ch = this.src[this.pos];
+      if (ch === '"') {
+        keys.push(this.readBasicString());
+      } else if (ch === "'") {
+        keys.push(this.readLiteralString());
+      } else {
+        const match = /^[A-Za-z0-9_-]+/.exec(this.src.slice(this.pos));
+        if (!match) throw this.error('Expected key');
+        keys.push(match[0]);
+        this.pos += match[0].length;
+      }
+      this.skipInlineWhitespace();
+      if (this.src[this.pos] !== '.') return keys;
+      this.pos += 1;
+    }
+  }
+
+  private readValue(): TomlValue {
+    const ch = this.src[this.pos];
+    if (this.src.startsWith('"""', this.pos)) return this.readMultilineString('"""');
+    if (this.src.startsWith("'''", this.pos)) return this.readMultilineString("'''");
+    if (ch === '"') return this.readBasicString();
+    if (ch === "'") return this.readLiteralString();
+    if (ch === '[') return this.readArray();
+    if (ch === '{') return this.readInlineTable();
+
+    const match = /^[^\s,\]}#]+/.exec(this.src.slice(this.pos));
+    if (!match) throw this.error('Expected value');
+    this.pos += match[0].length;
+    const token = match[0];
+    if (token === 'true') return true;
+    if (token === 'false') return false;
+    const numeric = token.replace(/_/g, '');
+    if (/^[+-]?(\d+(\.\d+)?([eE][+-]?\d+)?|0x[0-9a-fA-F]+|0o[0-7]+|0b[01]+)$/.test(numeric)) {
+      return Number(numeric);
+    }
+    // Datetimes and other bare scalars are preserved verbatim
+    return token;
+  }
+
+  private readArray(): TomlValue[] {
+    this.expect('[');
+    const items: TomlValue[] = [];
+    for (;;) {
+      this.skipWhitespaceAndComments(true);
+      if (this.src[this.pos] === ']') {
+        this.pos += 1;
+        return items;
+      }
+      items.push(this.readValue());
+      this.skipWhitespaceAndComments(true);
+      if (this.src[this.pos] === ',') {
+        this.pos += 1;
+      } else if (this.src[this.pos] !== ']') {
+        throw this.error("Expected ',' or ']' in array");
+      }
+    }
+  }
+
+  private readInlineTable(): TomlTable {
+    this.expect('{');
+    const table: TomlTable = {};
+    this.skipInlineWhitespace();
+    if (this.src[this.pos] === '}') {
+      this.pos += 1;
+      return table;
+    }
+    for (;;) {
+      this.readKeyValue(table);
+      this.skipInlineWhitespace();
+      if (this.src[this.pos] === ',') {
+        this.pos += 1;
+      } else if (this.src[this.pos] === '}') {
+        this.pos += 1;
+        return table;
+      } else {
+        throw this.error("Expected ',' or '}' in inline table");
+      }
+    }
+  }
+
+  private readBasicString(): string {
+    this.expect('"');
+    let out = '';
+    while (this.pos < this.src.length) {
+      const ch = this.src[this.pos++];
+      if (ch === '"') return out;
+      if (ch === '\n') break;
+      out += ch === '\\' ? this.readEscape() : ch;
+    }
+    throw this.error('Unterminated string');
+  }
+
+  private readLiteralString(): string {
+    this.expect("'");
+    const end = this.src.indexOf("'", this.pos);
+    const newline = this.src.indexOf('\n', this.pos);
+    if (end === -1 || (newline !== -1 && newline < end)) {
+      throw this.error('Unterminated string');
+    }
+    const value = this.src.slice(this.pos, end);
+    this.pos = end + 1;
+    return value;
+  }
+
+  private readMultilineString(delimiter: '"""' | "'''"): string {
+    this.pos += 3;
+    // A newline immediately after the opening delimiter is trimmed
+    if (this.src[this.pos] === '\r') this.pos += 1;
+    if (this.src[this.pos] === '\n') this.pos += 1;
+
+    let out = '';
+    while (this.pos < this.src.length) {
+      if (this.src.startsWith(delimiter, this.pos)) {
+        this.pos += 3;
+        return out;
+      }
+      const ch = this.src[this.pos++];
+      if (ch === '\\' && delimiter === '"""') {
+        if (/^[ \t]*\r?\n/.test(this.src.slice(this.pos, this.pos + 80))) {
+          // Line-ending backslash swallows the newline and leading whitespace
+          while (/\s/.test(this.src[this.pos] ?? '')) this.pos += 1;
+        } else {
+          out += this.readEscape();
+        }
+      } else {
+        out += ch;
+      }
+    }
+    throw this.error('Unterminated multi-line string');
+  }
+
+  private readEscape(): string {
+    const ch = this.src[this.pos++];
+    switch (ch) {
+      case 'n':
+        return '\n';
+      case 't':
+        return '\t';
+      case 'r':
+        return '\r';
+      case 'b':
+        return '\b';
+      case 'f':
+        return '\f';
+      case '"':
+        return '"';
+      case '\\':
+        return '\\';
+      case 'u':
+      case 'U': {
+        const length = ch === 'u' ? 4 : 8;
+        const hex = this.src.slice(this.pos, this.pos + length);
+        this.pos += length;
+        return String.fromCodePoint(parseInt(hex, 16));
+      }
+      default:
+        throw this.error(`Invalid escape '\\${ch}'`);
+    }
+  }
+
+  private skipInlineWhitespace(): void {
+    while (this.src[this.pos] === ' ' || this.src[this.pos] === '\t') this.pos += 1;
+  }
+
+  private skipWhitespaceAndComments(includeNewlines: boolean): void {
+    while (this.pos < this.src.length) {
+      const ch = this.src[this.pos];
+      if (ch === ' ' || ch === '\t' || (includeNewlines && (ch === '\n' || ch === '\r'))) {
+        this.pos += 1;
+      } else if (ch === '#') {
+        const newline = this.src.indexOf('\n', this.pos);
+        this.pos = newline === -1 ? this.src.length : newline;
+      } else {
+        return;
+      }
+    }
+  }
+
+  private endOfLine(): void {
+    this.skipWhitespaceAndComments(false);
+    if (this.pos >= this.src.length) return;
+    if (this.src[this.pos] === '\r') this.pos += 1;
+    if (this.src[this.pos] !== '\n') throw this.error('Expected end of line');
+    this.pos += 1;
+  }
+
+  private expect(token: string): void {
+    if (!this.src.startsWith(token, this.pos)) throw this.error(`Expected '${token}'`);
+    this.pos += token.length;
+  }
+
+  private error(message: string): Error {
+    const line = this.src.slice(0, this.pos).split('\n').length;
+    return new Error(`TOML parse error at line ${line}: ${message}`);
+  }
+}
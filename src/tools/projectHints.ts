@@ -38,6 +38,12 @@ import { projectHintsComposer, ProjectHintsWithEvidence } from './projectHints/c
 import { LocalEmbeddingStorage } from '../local/embeddingStorage';
 import { LocalEmbeddingGenerator } from '../local/embeddingGenerator';
 import { compileExcludePatterns, isExcludedPath } from './utils/toolHelpers';
+import type {
+  CargoWorkspaceSummary,
+  CargoFeatureSummary,
+  UnsafeAuditSummary,
+  CargoDependencySummary,
+} from './localTools/enhancedHints';
 
 export interface WordFrequency {
   word: string;
@@ -95,6 +101,12 @@ export interface ProjectHints {
   totalFiles: number;
   codebaseSize: string;
   lastAnalyzed: Date;
+
+  // Rust workspaces, added by the project hints tool for non-structured formats
+  cargoWorkspace?: CargoWorkspaceSummary;
+  features?: CargoFeatureSummary;
+  unsafeAudit?: UnsafeAuditSummary;
+  dependencyGraph?: CargoDependencySummary;
 }
 
 export interface ProjectHintsOptions {